@@ -0,0 +1,63 @@
+package org.whispercat;
+
+import java.io.IOException;
+
+/**
+ * Error returned by a remote API (OpenAI, Open WebUI, Faster-Whisper, ...) with a non-success
+ * HTTP status. The {@link Kind} lets callers react to the cause without parsing messages.
+ */
+public class ApiException extends IOException {
+
+    public enum Kind {
+        AUTHENTICATION,  // 401/403: missing or invalid API key
+        RATE_LIMITED,    // 429: too many requests or quota exceeded
+        CLIENT_ERROR,    // other 4xx: invalid request
+        SERVER_ERROR,    // 5xx: provider-side failure
+        UNKNOWN
+    }
+
+    private final int statusCode;
+    private final Kind kind;
+    private final String apiMessage;
+
+    public ApiException(String apiName, int statusCode, String apiMessage) {
+        super("Error from " + apiName + " (HTTP " + statusCode + "): " + apiMessage);
+        this.statusCode = statusCode;
+        this.kind = classify(statusCode);
+        this.apiMessage = apiMessage;
+    }
+
+    public int getStatusCode() {
+        return statusCode;
+    }
+
+    public Kind getKind() {
+        return kind;
+    }
+
+    /**
+     * @return the error message reported by the API, without the status prefix
+     */
+    public String getApiMessage() {
+        return apiMessage;
+    }
+
+    /**
+     * Maps an HTTP status code to an error kind.
+     */
+    public static Kind classify(int statusCode) {
+        if (statusCode == 401 || statusCode == 403) {
+            return Kind.AUTHENTICATION;
+        }
+        if (statusCode == 429) {
+            return Kind.RATE_LIMITED;
+        }
+        if (statusCode >= 400 && statusCode < 500) {
+            return Kind.CLIENT_ERROR;
+        }
+        if (statusCode >= 500 && statusCode < 600) {
+            return Kind.SERVER_ERROR;
+        }
+        return Kind.UNKNOWN;
+    }
+}
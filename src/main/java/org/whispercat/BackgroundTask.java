@@ -0,0 +1,80 @@
+package org.whispercat;
+
+import javax.swing.*;
+import java.util.concurrent.Callable;
+import java.util.concurrent.ExecutionException;
+import java.util.function.Consumer;
+
+/**
+ * Runs work off the Event Dispatch Thread and hands the result back on it, replacing the
+ * anonymous {@link SwingWorker} with a try/get()/catch in done() that every background
+ * call used to repeat.
+ */
+public final class BackgroundTask {
+
+    private BackgroundTask() {
+    }
+
+    /**
+     * Runs the task in the background.
+     *
+     * @param task      the work, run on a worker thread
+     * @param onSuccess receives the result on the EDT
+     * @param onError   receives the failure on the EDT: the exception the task threw (not the
+     *                  wrapping {@link ExecutionException}) or one thrown by onSuccess
+     * @param <T>       the result type
+     * @return the started worker, e.g. to cancel it
+     */
+    public static <T> SwingWorker<T, Void> run(Callable<T> task, Consumer<T> onSuccess, Consumer<Exception> onError) {
+        return run(task, onSuccess, onError, null);
+    }
+
+    /**
+     * Runs the task in the background.
+     *
+     * @param task      the work, run on a worker thread
+     * @param onSuccess receives the result on the EDT
+     * @param onError   receives the failure on the EDT, see {@link #run(Callable, Consumer, Consumer)}
+     * @param always    runs on the EDT after onSuccess or onError, e.g. to re-enable a button; may be null
+     * @param <T>       the result type
+     * @return the started worker, e.g. to cancel it
+     */
+    public static <T> SwingWorker<T, Void> run(Callable<T> task, Consumer<T> onSuccess, Consumer<Exception> onError,
+                                               Runnable always) {
+        SwingWorker<T, Void> worker = new SwingWorker<T, Void>() {
+            @Override
+            protected T doInBackground() throws Exception {
+                return task.call();
+            }
+
+            @Override
+            protected void done() {
+                try {
+                    onSuccess.accept(get());
+                } catch (ExecutionException e) {
+                    onError.accept(unwrap(e));
+                } catch (InterruptedException e) {
+                    Thread.currentThread().interrupt();
+                    onError.accept(e);
+                } catch (RuntimeException e) {
+                    // Also covers a cancelled task
+                    onError.accept(e);
+                } finally {
+                    if (always != null) {
+                        always.run();
+                    }
+                }
+            }
+        };
+        worker.execute();
+        return worker;
+    }
+
+    private static Exception unwrap(ExecutionException e) {
+        Throwable cause = e.getCause();
+        if (cause instanceof Exception) {
+            return (Exception) cause;
+        }
+        return e;
+    }
+}
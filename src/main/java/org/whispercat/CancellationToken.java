@@ -0,0 +1,50 @@
+package org.whispercat;
+
+import java.util.List;
+import java.util.concurrent.CopyOnWriteArrayList;
+
+/**
+ * Thread-safe flag used to request cancellation of long-running background work.
+ * Workers check {@link #isCancelled()} at safe points (e.g. between pipeline units), or
+ * register a callback to interrupt blocking work such as an HTTP request.
+ */
+public class CancellationToken {
+    private volatile boolean cancelled = false;
+    private final List<Runnable> callbacks = new CopyOnWriteArrayList<>();
+
+    public void cancel() {
+        if (cancelled) {
+            return;
+        }
+        cancelled = true;
+        for (Runnable callback : callbacks) {
+            callback.run();
+        }
+    }
+
+    public boolean isCancelled() {
+        return cancelled;
+    }
+
+    /**
+     * Registers a callback that runs on the cancelling thread when the token is cancelled,
+     * or right away if it already is.
+     *
+     * @param callback the callback, e.g. aborting a request
+     */
+    public void onCancel(Runnable callback) {
+        callbacks.add(callback);
+        if (cancelled && callbacks.remove(callback)) {
+            callback.run();
+        }
+    }
+
+    /**
+     * Unregisters a callback, e.g. once the request it aborts has finished.
+     *
+     * @param callback the callback passed to {@link #onCancel(Runnable)}
+     */
+    public void removeOnCancel(Runnable callback) {
+        callbacks.remove(callback);
+    }
+}
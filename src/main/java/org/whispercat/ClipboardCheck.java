@@ -0,0 +1,128 @@
+package org.whispercat;
+
+import org.apache.logging.log4j.LogManager;
+import org.apache.logging.log4j.Logger;
+
+import java.awt.GraphicsEnvironment;
+import java.awt.Toolkit;
+import java.awt.datatransfer.Clipboard;
+import java.awt.datatransfer.DataFlavor;
+import java.awt.datatransfer.StringSelection;
+import java.awt.datatransfer.Transferable;
+
+/**
+ * Checks that the system clipboard used for auto-paste actually works, by writing a
+ * sentinel string and reading it back. The previous text content is restored afterwards.
+ */
+public final class ClipboardCheck {
+    private static final Logger logger = LogManager.getLogger(ClipboardCheck.class);
+
+    /**
+     * The outcome of a clipboard check.
+     */
+    public static final class Result {
+        private final boolean success;
+        private final String backend;
+        private final String message;
+
+        Result(boolean success, String backend, String message) {
+            this.success = success;
+            this.backend = backend;
+            this.message = message;
+        }
+
+        public boolean isSuccess() {
+            return success;
+        }
+
+        /**
+         * @return a description of the clipboard implementation, e.g. "X11 (XToolkit)"
+         */
+        public String getBackend() {
+            return backend;
+        }
+
+        public String getMessage() {
+            return message;
+        }
+    }
+
+    private ClipboardCheck() {
+    }
+
+    /**
+     * Writes a sentinel to the system clipboard and reads it back. Call on the event dispatch thread.
+     *
+     * @return whether the round trip succeeded, with the detected backend
+     */
+    public static Result run() {
+        String backend = describeBackend();
+        if (GraphicsEnvironment.isHeadless()) {
+            return new Result(false, backend, "No display available, the clipboard cannot be used");
+        }
+        try {
+            return check(Toolkit.getDefaultToolkit().getSystemClipboard(), backend);
+        } catch (RuntimeException e) {
+            logger.warn("Clipboard check failed", e);
+            return new Result(false, backend, "Clipboard not accessible: " + e.getMessage());
+        }
+    }
+
+    /**
+     * Runs the write-then-read check against the given clipboard.
+     *
+     * @param clipboard the clipboard to test
+     * @param backend   the backend description reported in the result
+     * @return the check result
+     */
+    static Result check(Clipboard clipboard, String backend) {
+        String previous = readText(clipboard);
+        String sentinel = "WhisperCat clipboard test " + System.nanoTime();
+        try {
+            clipboard.setContents(new StringSelection(sentinel), null);
+            String readBack = readText(clipboard);
+            if (sentinel.equals(readBack)) {
+                return new Result(true, backend, "Clipboard works");
+            }
+            return new Result(false, backend, readBack == null
+                    ? "Clipboard could not be read back"
+                    : "Clipboard returned different content than was written");
+        } finally {
+            if (previous != null) {
+                clipboard.setContents(new StringSelection(previous), null);
+            }
+        }
+    }
+
+    private static String readText(Clipboard clipboard) {
+        try {
+            Transferable contents = clipboard.getContents(null);
+            if (contents != null && contents.isDataFlavorSupported(DataFlavor.stringFlavor)) {
+                return (String) contents.getTransferData(DataFlavor.stringFlavor);
+            }
+        } catch (Exception e) {
+            logger.debug("Could not read clipboard text", e);
+        }
+        return null;
+    }
+
+    /**
+     * Describes the clipboard implementation from the toolkit and, on Linux, the session type.
+     */
+    static String describeBackend() {
+        String os = System.getProperty("os.name", "unknown");
+        String toolkit = GraphicsEnvironment.isHeadless() ? "headless"
+                : Toolkit.getDefaultToolkit().getClass().getSimpleName();
+        if (os.toLowerCase().contains("linux")) {
+            String session = System.getenv("XDG_SESSION_TYPE");
+            if ((session == null || session.isEmpty()) && System.getenv("WAYLAND_DISPLAY") != null) {
+                session = "wayland";
+            }
+            if ("wayland".equalsIgnoreCase(session)) {
+                return "Wayland via XWayland (" + toolkit + ")";
+            }
+            return "X11 (" + toolkit + ")";
+        }
+        return os + " (" + toolkit + ")";
+    }
+}
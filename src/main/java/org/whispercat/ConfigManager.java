@@ -1,6 +1,7 @@
 package org.whispercat;
 
 import com.google.gson.Gson;
+import com.google.gson.GsonBuilder;
 import com.google.gson.JsonArray;
 import com.google.gson.JsonElement;
 import com.google.gson.JsonParser;
@@ -11,23 +12,71 @@ import org.whispercat.postprocessing.ProcessingUnit;
 import org.whispercat.postprocessing.Pipeline;
 import org.whispercat.postprocessing.ProcessingStepData;
 import org.whispercat.postprocessing.PipelineUnitReference;
+import org.whispercat.recording.IntermediateFileLocation;
+import org.whispercat.recording.OutputSink;
+import org.whispercat.recording.TranscriptFormatter;
 
 import javax.sound.sampled.AudioFormat;
 import java.io.*;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.AtomicMoveNotSupportedException;
+import java.nio.file.Files;
+import java.nio.file.StandardCopyOption;
+import java.security.SecureRandom;
+import java.util.ArrayList;
 import java.util.Arrays;
+import java.util.Base64;
 import java.util.Collections;
+import java.util.EnumSet;
+import java.util.LinkedHashMap;
 import java.util.List;
+import java.util.Locale;
+import java.util.Map;
 import java.util.Properties;
+import java.util.Set;
+import java.util.concurrent.CopyOnWriteArrayList;
+import java.util.concurrent.Executors;
+import java.util.concurrent.ScheduledExecutorService;
+import java.util.concurrent.ScheduledFuture;
+import java.util.concurrent.TimeUnit;
 import java.util.stream.Collectors;
 
 public class ConfigManager {
     private static final Logger logger = LogManager.getLogger(ConfigManager.class);
     private static final String CONFIG_FILE_NAME = "config.properties";
+    // Rapid successive changes (e.g. slider drags) are written once after this delay
+    static final long SAVE_DEBOUNCE_MS = 500;
+    // Changes that keep coming (e.g. holding a slider key) are still written at least this often
+    static final long MAX_SAVE_DELAY_MS = 5000;
     private final Properties properties;
+    private final File configFile;
+    private final ScheduledExecutorService saveExecutor = Executors.newSingleThreadScheduledExecutor(r -> {
+        Thread thread = new Thread(r, "config-save");
+        thread.setDaemon(true);
+        return thread;
+    });
+    private final List<Runnable> saveListeners = new CopyOnWriteArrayList<>();
+    private ScheduledFuture<?> pendingSave;
+    // Times of the first and the latest save request since the last write, -1 when nothing is pending
+    private long firstSaveRequestMillis = -1;
+    private long lastSaveRequestMillis = -1;
 
     public ConfigManager() {
+        this(defaultConfigFile());
+    }
+
+    /**
+     * Creates a configuration backed by the given file instead of the one in the user's
+     * configuration directory, e.g. a temporary file in tests.
+     *
+     * @param configFile the properties file to load from and save to
+     */
+    public ConfigManager(File configFile) {
+        this.configFile = configFile;
         properties = new Properties();
         loadConfig();
+        // Make sure a debounced save is not lost when the application exits
+        Runtime.getRuntime().addShutdownHook(new Thread(this::flushPendingSave, "config-save-flush"));
     }
 
     private void loadConfig() {
@@ -50,17 +99,128 @@ public class ConfigManager {
         return properties.getProperty("keySequence", "");
     }
 
-    public void saveConfig() {
+    /**
+     * Gets the global key combination that runs the last used pipeline on the current
+     * transcription.
+     *
+     * @return comma-separated key codes, empty if not set
+     */
+    public String getPipelineKeyCombination() {
+        return properties.getProperty("pipelineKeyCombination", "");
+    }
+
+    /**
+     * Schedules the configuration to be written to disk. Changes are live in memory immediately;
+     * the file is written once no further save has been requested for {@value #SAVE_DEBOUNCE_MS}ms.
+     */
+    public synchronized void saveConfig() {
+        long now = System.currentTimeMillis();
+        if (firstSaveRequestMillis < 0) {
+            firstSaveRequestMillis = now;
+        }
+        lastSaveRequestMillis = now;
+        if (pendingSave == null) {
+            pendingSave = saveExecutor.schedule(this::saveIfDue, SAVE_DEBOUNCE_MS, TimeUnit.MILLISECONDS);
+        }
+    }
+
+    /**
+     * Runs on the save thread: writes the configuration if the debounce period is over,
+     * otherwise checks again once it will be.
+     */
+    private synchronized void saveIfDue() {
+        if (firstSaveRequestMillis < 0) {
+            pendingSave = null;
+            return;
+        }
+        long now = System.currentTimeMillis();
+        if (shouldPersistNow(now, firstSaveRequestMillis, lastSaveRequestMillis)) {
+            pendingSave = null;
+            saveConfigNow();
+        } else {
+            long dueAt = Math.min(lastSaveRequestMillis + SAVE_DEBOUNCE_MS, firstSaveRequestMillis + MAX_SAVE_DELAY_MS);
+            pendingSave = saveExecutor.schedule(this::saveIfDue, Math.max(1, dueAt - now), TimeUnit.MILLISECONDS);
+        }
+    }
+
+    /**
+     * Decides whether pending changes should be written now: once no further save has been
+     * requested for {@value #SAVE_DEBOUNCE_MS}ms, or once the oldest unsaved change is
+     * {@value #MAX_SAVE_DELAY_MS}ms old.
+     *
+     * @param nowMillis          the current time
+     * @param firstRequestMillis time of the first save request since the last write
+     * @param lastRequestMillis  time of the latest save request
+     * @return true if the configuration should be written
+     */
+    static boolean shouldPersistNow(long nowMillis, long firstRequestMillis, long lastRequestMillis) {
+        return nowMillis - lastRequestMillis >= SAVE_DEBOUNCE_MS
+                || nowMillis - firstRequestMillis >= MAX_SAVE_DELAY_MS;
+    }
+
+    /**
+     * Writes the configuration to disk immediately and notifies save listeners.
+     * All writes go through this method while holding the manager's lock, the same lock
+     * that guards the read-modify-write updates of pipelines and units, so concurrent saves
+     * from different screens cannot interleave or drop each other's changes.
+     * The file is written to a temporary file first and then moved into place.
+     */
+    public synchronized void saveConfigNow() {
+        if (pendingSave != null) {
+            pendingSave.cancel(false);
+            pendingSave = null;
+        }
+        firstSaveRequestMillis = -1;
+        lastSaveRequestMillis = -1;
         File configFile = getConfigFilePath();
-        try (OutputStream output = new FileOutputStream(configFile)) {
-            properties.store(output, null);
+        File tempFile = new File(configFile.getParentFile(), configFile.getName() + ".tmp");
+        try {
+            try (OutputStream output = new FileOutputStream(tempFile)) {
+                properties.store(output, null);
+            }
+            try {
+                Files.move(tempFile.toPath(), configFile.toPath(),
+                        StandardCopyOption.REPLACE_EXISTING, StandardCopyOption.ATOMIC_MOVE);
+            } catch (AtomicMoveNotSupportedException e) {
+                Files.move(tempFile.toPath(), configFile.toPath(), StandardCopyOption.REPLACE_EXISTING);
+            }
             logger.info("Configuration saved successfully to {}", configFile.getAbsolutePath());
         } catch (IOException e) {
             logger.error("Failed to save configuration", e);
+            return;
+        }
+        for (Runnable listener : saveListeners) {
+            listener.run();
+        }
+    }
+
+    /**
+     * Writes a pending debounced save right away, if there is one.
+     */
+    public synchronized void flushPendingSave() {
+        if (firstSaveRequestMillis >= 0) {
+            saveConfigNow();
         }
     }
 
+    /**
+     * Registers a callback that runs (on the saving thread) after the configuration was written.
+     *
+     * @param listener the callback
+     */
+    public void addSaveListener(Runnable listener) {
+        saveListeners.add(listener);
+    }
+
     private File getConfigFilePath() {
+        File configDir = configFile.getParentFile();
+        if (configDir != null && !configDir.exists()) {
+            configDir.mkdirs();
+        }
+        return configFile;
+    }
+
+    private static File defaultConfigFile() {
         String osName = System.getProperty("os.name").toLowerCase();
         String configDirPath;
         if (osName.contains("win")) {
@@ -71,11 +231,7 @@ public class ConfigManager {
             configDirPath = System.getProperty("user.home") + File.separator + "WhisperCat" + File.separator + ".config";
             logger.info("Config Path is:" + configDirPath);
         }
-        File configDir = new File(configDirPath);
-        if (!configDir.exists()) {
-            configDir.mkdirs();
-        }
-        return new File(configDir, CONFIG_FILE_NAME);
+        return new File(configDirPath, CONFIG_FILE_NAME);
     }
 
     public String getProperty(String key) {
@@ -108,6 +264,19 @@ public class ConfigManager {
         return new AudioFormat(sampleRate, sampleSizeInBits, channels, signed, bigEndian);
     }
 
+    /**
+     * Whether recordings from devices that only capture in stereo are downmixed to mono
+     * while they are written, for providers that handle stereo badly.
+     */
+    public boolean isForceMonoOutput() {
+        return Boolean.parseBoolean(properties.getProperty("forceMonoOutput", "true"));
+    }
+
+    public void setForceMonoOutput(boolean enabled) {
+        properties.setProperty("forceMonoOutput", String.valueOf(enabled));
+        saveConfig();
+    }
+
     public int getAudioBitrate() {
         String bitrate = properties.getProperty("audioBitrate", "20000");
         try {
@@ -139,7 +308,7 @@ public class ConfigManager {
     }
 
 
-    public void savePostProcessingData(PostProcessingData data) {
+    public synchronized void savePostProcessingData(PostProcessingData data) {
         Gson gson = new Gson();
         String json = gson.toJson(data);
         JsonArray array;
@@ -194,219 +363,1096 @@ public class ConfigManager {
             array.add(newElement);
         }
 
-        // Save the updated JSON array to properties and call saveConfig()
-        properties.setProperty("postProcessingData", gson.toJson(array));
+        // Save the updated JSON array to properties and call saveConfig()
+        properties.setProperty("postProcessingData", gson.toJson(array));
+        saveConfig();
+    }
+
+    /**
+     * Returns the list of post processing data (as JSON element strings) from the configuration.
+     *
+     * @return List&lt;String&gt; containing each JSON element as a string; empty list if none exists.
+     */
+    public List<PostProcessingData> getPostProcessingDataList() {
+        Gson gson = new Gson();
+        String existing = properties.getProperty("postProcessingData", "[]");
+        if (!existing.trim().isEmpty()) {
+            try {
+                PostProcessingData[] dataArray = gson.fromJson(existing, PostProcessingData[].class);
+                return Arrays.asList(dataArray);
+            } catch (Exception e) {
+                logger.error("Existing postProcessingData is not a valid JSON array", e);
+                Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR, "Failed to load post-processing data");
+            }
+        }
+        ;
+        return Collections.emptyList();
+    }
+
+    public synchronized void deletePostProcessingData(String uuid) {
+        String existing = properties.getProperty("postProcessingData", "[]");
+        if (!existing.trim().isEmpty()) {
+            Gson gson = new Gson();
+            PostProcessingData[] dataArray = gson.fromJson(existing, PostProcessingData[].class);
+            List<PostProcessingData> collect = Arrays.asList(dataArray).stream().filter(data -> !data.uuid.equals(uuid)).collect(Collectors.toList());
+            String json = gson.toJson(collect);
+            properties.setProperty("postProcessingData", json);
+        }
+    }
+
+
+    public void setPostProcessingOnStartup(boolean b) {
+        properties.setProperty("postProcessingOnStartup", String.valueOf(b));
+        saveConfig();
+    }
+
+    public boolean isPostProcessingOnStartup() {
+        return Boolean.parseBoolean(properties.getProperty("postProcessingOnStartup", "false"));
+    }
+
+    public String getWhisperServer() {
+        return properties.getProperty("whisperServer", "OpenAI");
+    }
+
+    public String getFasterWhisperModel() {
+        return properties.getProperty("fasterWhisperModel", "");
+    }
+
+    public String getFasterWhisperLanguage() {
+        return properties.getProperty("fasterWhisperLanguage", "");
+    }
+
+    public String getFasterWhisperServerUrl() {
+        return properties.getProperty("fasterWhisperServerUrl", "");
+    }
+
+    /**
+     * Gets the URL of a local whisper.cpp server (its "server" example).
+     *
+     * @return the server URL, e.g. http://127.0.0.1:8080
+     */
+    public String getWhisperCppServerUrl() {
+        return properties.getProperty("whisperCppServerUrl", "http://127.0.0.1:8080");
+    }
+
+    public void setWhisperCppServerUrl(String url) {
+        properties.setProperty("whisperCppServerUrl", url != null ? url.trim() : "");
+        saveConfig();
+    }
+
+    public String getLastUsedPostProcessingUUID() {
+        return properties.getProperty("lastUsedPostProcessingUUID", "");
+    }
+
+    public void setLastUsedPostProcessingUUID(String uuid) {
+        properties.setProperty("lastUsedPostProcessingUUID", uuid);
+        saveConfig();
+    }
+
+    public boolean isAutoPasteEnabled() {
+        return Boolean.parseBoolean(properties.getProperty("autoPaste", "true"));
+    }
+
+    public void setAutoPasteEnabled(boolean selected) {
+        properties.setProperty("autoPaste", String.valueOf(selected));
+        saveConfig();
+    }
+
+    /**
+     * Gets the minimum transcription confidence required for auto-paste.
+     *
+     * @return Threshold between 0 and 1; 0 always pastes
+     */
+    public double getAutoPasteMinConfidence() {
+        try {
+            double value = Double.parseDouble(properties.getProperty("autoPasteMinConfidence", "0"));
+            return Math.max(0.0, Math.min(1.0, value));
+        } catch (NumberFormatException e) {
+            return 0.0;
+        }
+    }
+
+    public void setAutoPasteMinConfidence(double threshold) {
+        properties.setProperty("autoPasteMinConfidence", String.valueOf(Math.max(0.0, Math.min(1.0, threshold))));
+        saveConfig();
+    }
+
+    /**
+     * Gets the app that auto-paste is limited to, matched against the process name and
+     * title of the focused window. Several apps can be separated by commas.
+     *
+     * @return the target app, empty to paste into any window
+     */
+    public String getAutoPasteTargetApp() {
+        return properties.getProperty("autoPasteTargetApp", "").trim();
+    }
+
+    public void setAutoPasteTargetApp(String app) {
+        properties.setProperty("autoPasteTargetApp", app != null ? app.trim() : "");
+        saveConfig();
+    }
+
+    public boolean isInAppShortcutEnabled() {
+        return Boolean.parseBoolean(properties.getProperty("inAppShortcutEnabled", "true"));
+    }
+
+    public void setInAppShortcutEnabled(boolean enabled) {
+        properties.setProperty("inAppShortcutEnabled", String.valueOf(enabled));
+        saveConfig();
+    }
+
+    // Silence removal settings
+    public boolean isSilenceRemovalEnabled() {
+        return Boolean.parseBoolean(properties.getProperty("silenceRemovalEnabled", "true"));
+    }
+
+    public void setSilenceRemovalEnabled(boolean enabled) {
+        properties.setProperty("silenceRemovalEnabled", String.valueOf(enabled));
+        saveConfig();
+    }
+
+    public float getSilenceThreshold() {
+        return Float.parseFloat(properties.getProperty("silenceThreshold", "0.01"));
+    }
+
+    public void setSilenceThreshold(float threshold) {
+        properties.setProperty("silenceThreshold", String.valueOf(threshold));
+        saveConfig();
+    }
+
+    public int getMinSilenceDuration() {
+        return Integer.parseInt(properties.getProperty("minSilenceDuration", "1500"));
+    }
+
+    public void setMinSilenceDuration(int durationMs) {
+        properties.setProperty("minSilenceDuration", String.valueOf(durationMs));
+        saveConfig();
+    }
+
+    /**
+     * Length of the windows silence detection measures; smaller windows find the short
+     * pauses of fast speech.
+     *
+     * @return Window length in milliseconds (10-500)
+     */
+    public int getSilenceWindowMs() {
+        try {
+            int window = Integer.parseInt(properties.getProperty("silenceWindowMs", "100"));
+            return Math.max(10, Math.min(500, window));
+        } catch (NumberFormatException e) {
+            return 100;
+        }
+    }
+
+    public void setSilenceWindowMs(int windowMs) {
+        properties.setProperty("silenceWindowMs", String.valueOf(Math.max(10, Math.min(500, windowMs))));
+        saveConfig();
+    }
+
+    /**
+     * Audio kept on each side of a removed silence region, so the start and end of words
+     * are not clipped.
+     */
+    public int getSilencePaddingMs() {
+        try {
+            int padding = Integer.parseInt(properties.getProperty("silencePaddingMs", "150"));
+            return Math.max(0, Math.min(500, padding));
+        } catch (NumberFormatException e) {
+            return 150;
+        }
+    }
+
+    public void setSilencePaddingMs(int paddingMs) {
+        properties.setProperty("silencePaddingMs", String.valueOf(paddingMs));
+        saveConfig();
+    }
+
+    public boolean isKeepCompressedFile() {
+        return Boolean.parseBoolean(properties.getProperty("keepCompressedFile", "false"));
+    }
+
+    public void setKeepCompressedFile(boolean keep) {
+        properties.setProperty("keepCompressedFile", String.valueOf(keep));
+        saveConfig();
+    }
+
+    public boolean isPostProcessingEnabled() {
+        return Boolean.parseBoolean(properties.getProperty("postProcessingEnabled", "true"));
+    }
+
+    public void setPostProcessingEnabled(boolean enabled) {
+        properties.setProperty("postProcessingEnabled", String.valueOf(enabled));
+        saveConfig();
+    }
+
+    public int getMinRecordingDurationForSilenceRemoval() {
+        return Integer.parseInt(properties.getProperty("minRecordingDurationForSilenceRemoval", "10"));
+    }
+
+    public void setMinRecordingDurationForSilenceRemoval(int durationSeconds) {
+        properties.setProperty("minRecordingDurationForSilenceRemoval", String.valueOf(durationSeconds));
+        saveConfig();
+    }
+
+    /**
+     * Whether quiet recordings are amplified to a fixed peak level before silence removal
+     * and upload.
+     */
+    public boolean isNormalizeAudio() {
+        return Boolean.parseBoolean(properties.getProperty("normalizeAudio", "false"));
+    }
+
+    public void setNormalizeAudio(boolean enabled) {
+        properties.setProperty("normalizeAudio", String.valueOf(enabled));
+        saveConfig();
+    }
+
+    public int getMinSilenceReductionPercent() {
+        return Integer.parseInt(properties.getProperty("minSilenceReductionPercent", "5"));
+    }
+
+    public void setMinSilenceReductionPercent(int percent) {
+        properties.setProperty("minSilenceReductionPercent", String.valueOf(percent));
+        saveConfig();
+    }
+
+    public boolean isNormalizeOutput() {
+        return Boolean.parseBoolean(properties.getProperty("normalizeOutput", "false"));
+    }
+
+    public void setNormalizeOutput(boolean enabled) {
+        properties.setProperty("normalizeOutput", String.valueOf(enabled));
+        saveConfig();
+    }
+
+    // Timestamp prefix for dictated notes
+    public boolean isPrependTimestamp() {
+        return Boolean.parseBoolean(properties.getProperty("prependTimestamp", "false"));
+    }
+
+    public void setPrependTimestamp(boolean enabled) {
+        properties.setProperty("prependTimestamp", String.valueOf(enabled));
+        saveConfig();
+    }
+
+    /**
+     * Gets the timestamp pattern used when prepending timestamps. Invalid patterns
+     * fall back to the default.
+     *
+     * @return A valid DateTimeFormatter pattern
+     */
+    public String getTimestampFormat() {
+        String pattern = properties.getProperty("timestampFormat", TranscriptFormatter.DEFAULT_TIMESTAMP_FORMAT);
+        return TranscriptFormatter.isValidTimestampFormat(pattern) ? pattern : TranscriptFormatter.DEFAULT_TIMESTAMP_FORMAT;
+    }
+
+    public void setTimestampFormat(String pattern) {
+        properties.setProperty("timestampFormat", pattern);
+        saveConfig();
+    }
+
+    // openwebUIApiKey
+    public String getOpenWebUIApiKey() {
+        return properties.getProperty("openWebUIApiKey", "");
+    }
+
+    public void setOpenWebUIApiKey(String apiKey) {
+        properties.setProperty("openWebUIApiKey", apiKey);
+    }
+
+    public String getOpenWebUIServerUrl() {
+        return properties.getProperty("openWebUIServerUrl", "");
+    }
+
+    public void setOpenWebUIServerUrl(String url) {
+        properties.setProperty("openWebUIServerUrl", url);
+    }
+
+    /**
+     * PEM file with the CA certificate (e.g. a self-signed one) that signed the Open WebUI
+     * server's certificate. When set, certificates are verified against it and the system CAs.
+     */
+    public String getOpenWebUICaCertPath() {
+        return properties.getProperty("openWebUICaCertPath", "").trim();
+    }
+
+    public void setOpenWebUICaCertPath(String path) {
+        properties.setProperty("openWebUICaCertPath", path != null ? path.trim() : "");
+    }
+
+    /**
+     * Whether Open WebUI connections accept any certificate. Only used when no CA certificate
+     * is configured; off by default, self-signed setups have to enable it (or set the CA).
+     */
+    public boolean isOpenWebUIAcceptInvalidCerts() {
+        return Boolean.parseBoolean(properties.getProperty("openWebUIAcceptInvalidCerts", "false"));
+    }
+
+    public void setOpenWebUIAcceptInvalidCerts(boolean accept) {
+        properties.setProperty("openWebUIAcceptInvalidCerts", String.valueOf(accept));
+    }
+
+    /**
+     * Gets the list of custom OpenAI models configured by the user.
+     * Returns default models if no custom models are configured.
+     *
+     * @return List of model names
+     */
+    public List<String> getCustomOpenAIModels() {
+        String modelsJson = properties.getProperty("customOpenAIModels", "");
+        if (modelsJson.trim().isEmpty()) {
+            // Return default models if not configured
+            return Arrays.asList("gpt-4o-mini", "gpt-4o", "gpt-3.5-turbo");
+        }
+        try {
+            Gson gson = new Gson();
+            String[] modelsArray = gson.fromJson(modelsJson, String[].class);
+            return Arrays.asList(modelsArray);
+        } catch (Exception e) {
+            logger.error("Failed to parse custom OpenAI models, returning defaults", e);
+            return Arrays.asList("gpt-4o-mini", "gpt-4o", "gpt-3.5-turbo");
+        }
+    }
+
+    /**
+     * Saves the list of custom OpenAI models.
+     *
+     * @param models List of model names to save
+     */
+    public void setCustomOpenAIModels(List<String> models) {
+        Gson gson = new Gson();
+        String json = gson.toJson(models);
+        properties.setProperty("customOpenAIModels", json);
+        saveConfig();
+    }
+
+    /**
+     * Gets the custom OpenAI models as a comma-separated string for display in UI.
+     *
+     * @return Comma-separated model names
+     */
+    public String getCustomOpenAIModelsString() {
+        List<String> models = getCustomOpenAIModels();
+        return String.join(", ", models);
+    }
+
+    /**
+     * Sets custom OpenAI models from a comma-separated string.
+     *
+     * @param modelsString Comma-separated model names
+     */
+    public void setCustomOpenAIModelsFromString(String modelsString) {
+        if (modelsString == null || modelsString.trim().isEmpty()) {
+            // Reset to defaults
+            setCustomOpenAIModels(Arrays.asList("gpt-4o-mini", "gpt-4o", "gpt-3.5-turbo"));
+            return;
+        }
+        List<String> models = Arrays.stream(modelsString.split(","))
+                .map(String::trim)
+                .filter(s -> !s.isEmpty())
+                .collect(Collectors.toList());
+        setCustomOpenAIModels(models);
+    }
+
+    /**
+     * Gets the base URL for all OpenAI calls (transcription, model list and chat), e.g. a
+     * corporate proxy or a LiteLLM gateway at https://gateway.example.com/v1.
+     *
+     * @return the configured base URL including the version path, or an empty string for
+     * the official OpenAI API
+     */
+    public String getOpenAIBaseUrl() {
+        return properties.getProperty("openAIBaseUrl", "").trim();
+    }
+
+    public void setOpenAIBaseUrl(String url) {
+        properties.setProperty("openAIBaseUrl", url != null ? url.trim() : "");
+        saveConfig();
+    }
+
+    /**
+     * Gets the base URL used for OpenAI chat completions in pipelines.
+     * Empty means the OpenAI base URL ({@link #getOpenAIBaseUrl()}); any OpenAI-compatible
+     * endpoint (e.g. LM Studio or Ollama at http://localhost:11434/v1) can be used instead.
+     *
+     * @return the configured base URL, or an empty string for the default
+     */
+    public String getOpenAIChatBaseUrl() {
+        return properties.getProperty("openAIChatBaseUrl", "").trim();
+    }
+
+    public void setOpenAIChatBaseUrl(String url) {
+        properties.setProperty("openAIChatBaseUrl", url != null ? url.trim() : "");
+        saveConfig();
+    }
+
+    // ========== Application Logging ==========
+
+    /**
+     * Gets the log level for console and in-app logs (e.g. INFO, DEBUG).
+     * Can be overridden with the WHISPERCAT_LOG_LEVEL environment variable.
+     *
+     * @return Log level name
+     */
+    public String getLogLevel() {
+        return properties.getProperty("logLevel", "INFO");
+    }
+
+    public void setLogLevel(String level) {
+        properties.setProperty("logLevel", level);
+        saveConfig();
+    }
+
+    /**
+     * Gets the log level for the rolling log file. "OFF" disables file logging.
+     * Can be overridden with the WHISPERCAT_FILE_LOG_LEVEL environment variable.
+     *
+     * @return Log level name
+     */
+    public String getFileLogLevel() {
+        return properties.getProperty("fileLogLevel", "INFO");
+    }
+
+    public void setFileLogLevel(String level) {
+        properties.setProperty("fileLogLevel", level);
+        saveConfig();
+    }
+
+    /**
+     * Gets the directory for the rolling log file. Empty means the default (~/.whispercat/logs).
+     * Can be overridden with the WHISPERCAT_LOG_DIR environment variable.
+     *
+     * @return Log directory path or empty string
+     */
+    public String getLogDirectory() {
+        return properties.getProperty("logDirectory", "");
+    }
+
+    public void setLogDirectory(String directory) {
+        properties.setProperty("logDirectory", directory);
+        saveConfig();
+    }
+
+    // ========== Display ==========
+
+    /**
+     * Gets the locale for numbers and times shown in the UI, e.g. the decimal separator.
+     *
+     * @return the locale, or null to use the system locale
+     */
+    public Locale getDisplayLocale() {
+        String tag = properties.getProperty("displayLocale", "").trim();
+        return tag.isEmpty() ? null : Locale.forLanguageTag(tag);
+    }
+
+    /**
+     * @param languageTag an IETF language tag such as "de-DE", or empty for the system locale
+     */
+    public void setDisplayLocale(String languageTag) {
+        properties.setProperty("displayLocale", languageTag != null ? languageTag.trim() : "");
+        saveConfig();
+    }
+
+    // ========== Execution Log Panel ==========
+
+    public static final int MIN_LOG_PANEL_HEIGHT = 120;
+    public static final int MAX_LOG_PANEL_HEIGHT = 800;
+    public static final int DEFAULT_LOG_PANEL_HEIGHT = 300;
+
+    /**
+     * Gets the height of the execution log panel on the recorder screen,
+     * clamped to the supported range.
+     *
+     * @return Panel height in pixels
+     */
+    public int getLogPanelHeight() {
+        try {
+            int height = Integer.parseInt(properties.getProperty("logPanelHeight", String.valueOf(DEFAULT_LOG_PANEL_HEIGHT)));
+            return clampLogPanelHeight(height);
+        } catch (NumberFormatException e) {
+            return DEFAULT_LOG_PANEL_HEIGHT;
+        }
+    }
+
+    public void setLogPanelHeight(int height) {
+        properties.setProperty("logPanelHeight", String.valueOf(clampLogPanelHeight(height)));
+        saveConfig();
+    }
+
+    public static int clampLogPanelHeight(int height) {
+        return Math.max(MIN_LOG_PANEL_HEIGHT, Math.min(MAX_LOG_PANEL_HEIGHT, height));
+    }
+
+    public boolean isLogDetached() {
+        return Boolean.parseBoolean(properties.getProperty("logDetached", "false"));
+    }
+
+    public void setLogDetached(boolean detached) {
+        properties.setProperty("logDetached", String.valueOf(detached));
+        saveConfig();
+    }
+
+    /**
+     * Whether the end of the previous transcription is sent as prompt with the next one,
+     * which helps Whisper keep context during continuous dictation.
+     */
+    public boolean isUsePreviousAsPrompt() {
+        return Boolean.parseBoolean(properties.getProperty("usePreviousAsPrompt", "false"));
+    }
+
+    public void setUsePreviousAsPrompt(boolean enabled) {
+        properties.setProperty("usePreviousAsPrompt", String.valueOf(enabled));
+        saveConfig();
+    }
+
+    /**
+     * Gets the language sent with every transcription, set on the recording screen.
+     *
+     * @return an ISO-639-1 code such as "de", or an empty string to let the server detect it
+     */
+    public String getTranscriptionLanguage() {
+        return properties.getProperty("transcriptionLanguage", "").trim();
+    }
+
+    public void setTranscriptionLanguage(String language) {
+        properties.setProperty("transcriptionLanguage", language != null ? language.trim().toLowerCase(Locale.ROOT) : "");
+        saveConfig();
+    }
+
+    /**
+     * Gets the language for Faster-Whisper: the one from the recording screen if set,
+     * otherwise the one chosen together with the model in the settings.
+     *
+     * @return the language code, or an empty string for detection
+     */
+    public String resolveFasterWhisperLanguage() {
+        String language = getTranscriptionLanguage();
+        return language.isEmpty() ? getFasterWhisperLanguage() : language;
+    }
+
+    /**
+     * Gets the initial prompt sent with every transcription, e.g. domain vocabulary and
+     * the spelling of names. Sent before the previous transcription if that is used too.
+     *
+     * @return the prompt, or an empty string for none
+     */
+    public String getTranscriptionPrompt() {
+        return properties.getProperty("transcriptionPrompt", "").trim();
+    }
+
+    public void setTranscriptionPrompt(String prompt) {
+        properties.setProperty("transcriptionPrompt", prompt != null ? prompt.trim() : "");
+        saveConfig();
+    }
+
+    /**
+     * Gets the sampling temperature for transcriptions. Higher values make the output more
+     * random; 0 is the servers' default and is not sent.
+     *
+     * @return the temperature between 0.0 and 1.0
+     */
+    public float getTranscriptionTemperature() {
+        try {
+            float temperature = Float.parseFloat(properties.getProperty("transcriptionTemperature", "0"));
+            return Math.max(0.0f, Math.min(1.0f, temperature));
+        } catch (NumberFormatException e) {
+            return 0.0f;
+        }
+    }
+
+    public void setTranscriptionTemperature(float temperature) {
+        properties.setProperty("transcriptionTemperature", String.valueOf(Math.max(0.0f, Math.min(1.0f, temperature))));
+        saveConfig();
+    }
+
+    /**
+     * Whether speech is translated to English instead of transcribed in its own language.
+     * Only providers with a translation endpoint (OpenAI) support this.
+     */
+    public boolean isTranslateToEnglish() {
+        return Boolean.parseBoolean(properties.getProperty("translateToEnglish", "false"));
+    }
+
+    public void setTranslateToEnglish(boolean translate) {
+        properties.setProperty("translateToEnglish", String.valueOf(translate));
+        saveConfig();
+    }
+
+    /**
+     * Whether to transcribe once more if the server returns an empty result for audio
+     * that is louder than the silence threshold.
+     */
+    public boolean isRetryEmptyTranscription() {
+        return Boolean.parseBoolean(properties.getProperty("retryEmptyTranscription", "false"));
+    }
+
+    public void setRetryEmptyTranscription(boolean enabled) {
+        properties.setProperty("retryEmptyTranscription", String.valueOf(enabled));
+        saveConfig();
+    }
+
+    // ========== Output Sinks ==========
+
+    /**
+     * Gets the destinations final results are delivered to.
+     *
+     * @return The enabled sinks; clipboard only if unset
+     */
+    public Set<OutputSink> getOutputSinks() {
+        String value = properties.getProperty("outputSinks", OutputSink.CLIPBOARD.name());
+        Set<OutputSink> sinks = EnumSet.noneOf(OutputSink.class);
+        for (String name : value.split(",")) {
+            try {
+                if (!name.trim().isEmpty()) {
+                    sinks.add(OutputSink.valueOf(name.trim().toUpperCase()));
+                }
+            } catch (IllegalArgumentException e) {
+                logger.warn("Unknown output sink in configuration: {}", name);
+            }
+        }
+        return sinks;
+    }
+
+    public void setOutputSinks(Set<OutputSink> sinks) {
+        properties.setProperty("outputSinks", sinks.stream().map(Enum::name).collect(Collectors.joining(",")));
+        saveConfig();
+    }
+
+    public String getOutputFilePath() {
+        return properties.getProperty("outputFilePath", "");
+    }
+
+    public void setOutputFilePath(String path) {
+        properties.setProperty("outputFilePath", path != null ? path.trim() : "");
+        saveConfig();
+    }
+
+    public String getOutputWebhookUrl() {
+        return properties.getProperty("outputWebhookUrl", "");
+    }
+
+    public void setOutputWebhookUrl(String url) {
+        properties.setProperty("outputWebhookUrl", url != null ? url.trim() : "");
+        saveConfig();
+    }
+
+    // ========== Intermediate Files ==========
+
+    /**
+     * Gets where converted, silence-removed, compressed and split audio is written.
+     *
+     * @return The location; next to the recording if unset
+     */
+    public IntermediateFileLocation getIntermediateFileLocation() {
+        String value = properties.getProperty("intermediateFileLocation", IntermediateFileLocation.RECORDING_DIR.name());
+        try {
+            return IntermediateFileLocation.valueOf(value.trim().toUpperCase());
+        } catch (IllegalArgumentException e) {
+            logger.warn("Unknown intermediate file location in configuration: {}", value);
+            return IntermediateFileLocation.RECORDING_DIR;
+        }
+    }
+
+    public void setIntermediateFileLocation(IntermediateFileLocation location) {
+        properties.setProperty("intermediateFileLocation", location.name());
+        saveConfig();
+    }
+
+    // ========== MP3 Compression ==========
+
+    /** Sample rates offered for MP3 compression of large uploads. */
+    public static final int[] MP3_SAMPLE_RATES = {8000, 12000, 16000, 22050, 24000, 32000, 44100, 48000};
+
+    /**
+     * Gets the LAME VBR quality used when a recording is compressed to MP3 before upload.
+     *
+     * @return 0 (best, largest) to 9 (worst, smallest); defaults to 4
+     */
+    public int getMp3VbrQuality() {
+        try {
+            return Math.max(0, Math.min(9, Integer.parseInt(properties.getProperty("mp3VbrQuality", "4"))));
+        } catch (NumberFormatException e) {
+            return 4;
+        }
+    }
+
+    public void setMp3VbrQuality(int quality) {
+        properties.setProperty("mp3VbrQuality", String.valueOf(Math.max(0, Math.min(9, quality))));
+        saveConfig();
+    }
+
+    /**
+     * Gets the sample rate of compressed MP3 uploads.
+     *
+     * @return One of {@link #MP3_SAMPLE_RATES}; defaults to 16000
+     */
+    public int getMp3SampleRate() {
+        try {
+            int rate = Integer.parseInt(properties.getProperty("mp3SampleRate", "16000"));
+            return Arrays.stream(MP3_SAMPLE_RATES).anyMatch(r -> r == rate) ? rate : 16000;
+        } catch (NumberFormatException e) {
+            return 16000;
+        }
+    }
+
+    public void setMp3SampleRate(int sampleRate) {
+        properties.setProperty("mp3SampleRate", String.valueOf(sampleRate));
+        saveConfig();
+    }
+
+    public boolean isMp3Mono() {
+        return Boolean.parseBoolean(properties.getProperty("mp3Mono", "true"));
+    }
+
+    public void setMp3Mono(boolean mono) {
+        properties.setProperty("mp3Mono", String.valueOf(mono));
+        saveConfig();
+    }
+
+    // ========== Provider Health ==========
+
+    /**
+     * Gets how long a provider that failed to connect is skipped before it is tried again.
+     *
+     * @return Window in seconds; 0 disables fast failing
+     */
+    public int getUnreachableRetrySeconds() {
+        try {
+            return Math.max(0, Integer.parseInt(properties.getProperty("unreachableRetrySeconds", "30")));
+        } catch (NumberFormatException e) {
+            return 30;
+        }
+    }
+
+    public void setUnreachableRetrySeconds(int seconds) {
+        properties.setProperty("unreachableRetrySeconds", String.valueOf(Math.max(0, seconds)));
         saveConfig();
     }
 
     /**
-     * Returns the list of post processing data (as JSON element strings) from the configuration.
+     * Gets how long a transcription request waits for the server to answer before it fails.
      *
-     * @return List&lt;String&gt; containing each JSON element as a string; empty list if none exists.
+     * @return Timeout in seconds; 0 waits forever
      */
-    public List<PostProcessingData> getPostProcessingDataList() {
-        Gson gson = new Gson();
-        String existing = properties.getProperty("postProcessingData", "[]");
-        if (!existing.trim().isEmpty()) {
-            try {
-                PostProcessingData[] dataArray = gson.fromJson(existing, PostProcessingData[].class);
-                return Arrays.asList(dataArray);
-            } catch (Exception e) {
-                logger.error("Existing postProcessingData is not a valid JSON array", e);
-                Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR, "Failed to load post-processing data");
-            }
-        }
-        ;
-        return Collections.emptyList();
-    }
-
-    public void deletePostProcessingData(String uuid) {
-        String existing = properties.getProperty("postProcessingData", "[]");
-        if (!existing.trim().isEmpty()) {
-            Gson gson = new Gson();
-            PostProcessingData[] dataArray = gson.fromJson(existing, PostProcessingData[].class);
-            List<PostProcessingData> collect = Arrays.asList(dataArray).stream().filter(data -> !data.uuid.equals(uuid)).collect(Collectors.toList());
-            String json = gson.toJson(collect);
-            properties.setProperty("postProcessingData", json);
+    public int getTranscriptionTimeoutSeconds() {
+        try {
+            return Math.max(0, Integer.parseInt(properties.getProperty("transcriptionTimeoutSeconds", "120")));
+        } catch (NumberFormatException e) {
+            return 120;
         }
     }
 
-
-    public void setPostProcessingOnStartup(boolean b) {
-        properties.setProperty("postProcessingOnStartup", String.valueOf(b));
+    public void setTranscriptionTimeoutSeconds(int seconds) {
+        properties.setProperty("transcriptionTimeoutSeconds", String.valueOf(Math.max(0, seconds)));
         saveConfig();
     }
 
-    public boolean isPostProcessingOnStartup() {
-        return Boolean.parseBoolean(properties.getProperty("postProcessingOnStartup", "false"));
-    }
+    // ========== Provider Form Fields ==========
 
-    public String getWhisperServer() {
-        return properties.getProperty("whisperServer", "OpenAI");
+    /**
+     * Gets extra form fields sent with every transcription request to a provider, for
+     * server options the built-in request does not set (e.g. vad_filter, beam_size).
+     *
+     * @param provider the provider name as shown in the settings
+     * @return the fields in configured order; empty if none are configured
+     */
+    public Map<String, String> getExtraFormFields(String provider) {
+        return parseFormFields(properties.getProperty("extraFormFields." + provider, ""));
     }
 
-    public String getFasterWhisperModel() {
-        return properties.getProperty("fasterWhisperModel", "");
+    public void setExtraFormFields(String provider, Map<String, String> fields) {
+        if (fields == null || fields.isEmpty()) {
+            properties.remove("extraFormFields." + provider);
+        } else {
+            properties.setProperty("extraFormFields." + provider, formatFormFields(fields));
+        }
+        saveConfig();
     }
 
-    public String getFasterWhisperLanguage() {
-        return properties.getProperty("fasterWhisperLanguage", "");
+    /**
+     * Parses form fields written one per line as {@code name=value}. Blank lines and lines
+     * without a name are ignored.
+     *
+     * @param text the fields as text
+     * @return the fields in the order they appear
+     */
+    public static Map<String, String> parseFormFields(String text) {
+        Map<String, String> fields = new LinkedHashMap<>();
+        if (text == null) {
+            return fields;
+        }
+        for (String line : text.split("\\r?\\n")) {
+            int separator = line.indexOf('=');
+            String name = (separator >= 0 ? line.substring(0, separator) : line).trim();
+            if (!name.isEmpty()) {
+                fields.put(name, separator >= 0 ? line.substring(separator + 1).trim() : "");
+            }
+        }
+        return fields;
     }
 
-    public String getFasterWhisperServerUrl() {
-        return properties.getProperty("fasterWhisperServerUrl", "");
+    /**
+     * Writes form fields one per line as {@code name=value}.
+     *
+     * @param fields the fields
+     * @return the text form read by {@link #parseFormFields(String)}
+     */
+    public static String formatFormFields(Map<String, String> fields) {
+        return fields.entrySet().stream()
+                .map(entry -> entry.getKey() + "=" + entry.getValue())
+                .collect(Collectors.joining("\n"));
     }
 
-    public String getLastUsedPostProcessingUUID() {
-        return properties.getProperty("lastUsedPostProcessingUUID", "");
+    // ========== Dictation Mode ==========
+
+    /**
+     * Gets how long the speaker must be silent before dictation mode ends the utterance.
+     *
+     * @return Trailing silence in milliseconds (300-10000)
+     */
+    public int getDictationTrailingSilenceMs() {
+        try {
+            int ms = Integer.parseInt(properties.getProperty("dictationTrailingSilenceMs", "1500"));
+            return Math.max(300, Math.min(10000, ms));
+        } catch (NumberFormatException e) {
+            return 1500;
+        }
     }
 
-    public void setLastUsedPostProcessingUUID(String uuid) {
-        properties.setProperty("lastUsedPostProcessingUUID", uuid);
+    public void setDictationTrailingSilenceMs(int ms) {
+        properties.setProperty("dictationTrailingSilenceMs", String.valueOf(Math.max(300, Math.min(10000, ms))));
         saveConfig();
     }
 
-    public boolean isAutoPasteEnabled() {
-        return Boolean.parseBoolean(properties.getProperty("autoPaste", "true"));
+    /**
+     * Whether a recording that ended early because the microphone or the disk failed is
+     * transcribed up to the failure. Otherwise the partial file is only kept.
+     */
+    public boolean isTranscribeInterruptedRecordings() {
+        return Boolean.parseBoolean(properties.getProperty("transcribeInterruptedRecordings", "true"));
     }
 
-    public void setAutoPasteEnabled(boolean selected) {
-        properties.setProperty("autoPaste", String.valueOf(selected));
+    public void setTranscribeInterruptedRecordings(boolean enabled) {
+        properties.setProperty("transcribeInterruptedRecordings", String.valueOf(enabled));
         saveConfig();
     }
 
-    // Silence removal settings
-    public boolean isSilenceRemovalEnabled() {
-        return Boolean.parseBoolean(properties.getProperty("silenceRemovalEnabled", "true"));
+    /**
+     * Gets the length after which a recording is stopped and transcribed automatically,
+     * so a forgotten recording does not grow past the upload limit.
+     *
+     * @return Maximum recording length in seconds, 0 for unlimited
+     */
+    public int getMaxRecordingSeconds() {
+        try {
+            return Math.max(0, Integer.parseInt(properties.getProperty("maxRecordingSeconds", "0")));
+        } catch (NumberFormatException e) {
+            return 0;
+        }
     }
 
-    public void setSilenceRemovalEnabled(boolean enabled) {
-        properties.setProperty("silenceRemovalEnabled", String.valueOf(enabled));
+    public void setMaxRecordingSeconds(int seconds) {
+        properties.setProperty("maxRecordingSeconds", String.valueOf(Math.max(0, seconds)));
         saveConfig();
     }
 
-    public float getSilenceThreshold() {
-        return Float.parseFloat(properties.getProperty("silenceThreshold", "0.01"));
+    /**
+     * Whether a new recording can be started while the previous one is still being
+     * transcribed. Recordings are transcribed one after another in the order they were made.
+     */
+    public boolean isRecordDuringTranscription() {
+        return Boolean.parseBoolean(properties.getProperty("recordDuringTranscription", "false"));
     }
 
-    public void setSilenceThreshold(float threshold) {
-        properties.setProperty("silenceThreshold", String.valueOf(threshold));
+    public void setRecordDuringTranscription(boolean enabled) {
+        properties.setProperty("recordDuringTranscription", String.valueOf(enabled));
         saveConfig();
     }
 
-    public int getMinSilenceDuration() {
-        return Integer.parseInt(properties.getProperty("minSilenceDuration", "1500"));
+    /**
+     * Whether a recording started by hand waits for speech before it keeps any audio.
+     * Dictation mode takes precedence.
+     */
+    public boolean isVoiceActivatedStart() {
+        return Boolean.parseBoolean(properties.getProperty("voiceActivatedStart", "false"));
     }
 
-    public void setMinSilenceDuration(int durationMs) {
-        properties.setProperty("minSilenceDuration", String.valueOf(durationMs));
+    public void setVoiceActivatedStart(boolean enabled) {
+        properties.setProperty("voiceActivatedStart", String.valueOf(enabled));
         saveConfig();
     }
 
-    public boolean isKeepCompressedFile() {
-        return Boolean.parseBoolean(properties.getProperty("keepCompressedFile", "false"));
+    // ========== Recording Retention ==========
+
+    /**
+     * Gets the number of days after which recordings are deleted on startup.
+     *
+     * @return Retention in days; 0 means recordings are kept forever
+     */
+    public int getRecordingRetentionDays() {
+        try {
+            return Math.max(0, Integer.parseInt(properties.getProperty("recordingRetentionDays", "0")));
+        } catch (NumberFormatException e) {
+            return 0;
+        }
     }
 
-    public void setKeepCompressedFile(boolean keep) {
-        properties.setProperty("keepCompressedFile", String.valueOf(keep));
+    public void setRecordingRetentionDays(int days) {
+        properties.setProperty("recordingRetentionDays", String.valueOf(Math.max(0, days)));
         saveConfig();
     }
 
-    public boolean isPostProcessingEnabled() {
-        return Boolean.parseBoolean(properties.getProperty("postProcessingEnabled", "true"));
+    // ========== Local API Server ==========
+
+    public static final int DEFAULT_LOCAL_SERVER_PORT = 8765;
+
+    /**
+     * Whether the local HTTP server for external tools is enabled (bound to 127.0.0.1 only).
+     */
+    public boolean isLocalServerEnabled() {
+        return Boolean.parseBoolean(properties.getProperty("localServerEnabled", "false"));
     }
 
-    public void setPostProcessingEnabled(boolean enabled) {
-        properties.setProperty("postProcessingEnabled", String.valueOf(enabled));
+    public void setLocalServerEnabled(boolean enabled) {
+        properties.setProperty("localServerEnabled", String.valueOf(enabled));
         saveConfig();
     }
 
-    public int getMinRecordingDurationForSilenceRemoval() {
-        return Integer.parseInt(properties.getProperty("minRecordingDurationForSilenceRemoval", "10"));
+    /**
+     * Gets the port of the local HTTP server.
+     *
+     * @return Port between 1024 and 65535, the default if unset or invalid
+     */
+    public int getLocalServerPort() {
+        try {
+            int port = Integer.parseInt(properties.getProperty("localServerPort", String.valueOf(DEFAULT_LOCAL_SERVER_PORT)));
+            return (port >= 1024 && port <= 65535) ? port : DEFAULT_LOCAL_SERVER_PORT;
+        } catch (NumberFormatException e) {
+            return DEFAULT_LOCAL_SERVER_PORT;
+        }
     }
 
-    public void setMinRecordingDurationForSilenceRemoval(int durationSeconds) {
-        properties.setProperty("minRecordingDurationForSilenceRemoval", String.valueOf(durationSeconds));
+    public void setLocalServerPort(int port) {
+        properties.setProperty("localServerPort", String.valueOf(port));
         saveConfig();
     }
 
-    // openwebUIApiKey
-    public String getOpenWebUIApiKey() {
-        return properties.getProperty("openWebUIApiKey", "");
+    /**
+     * Gets the token clients of the local HTTP server must send, so that other local
+     * programs and web pages cannot use it. A random token is generated on first use.
+     *
+     * @return The token of this installation
+     */
+    public String getLocalServerToken() {
+        String token = properties.getProperty("localServerToken", "");
+        if (token.isEmpty()) {
+            byte[] bytes = new byte[24];
+            new SecureRandom().nextBytes(bytes);
+            token = Base64.getUrlEncoder().withoutPadding().encodeToString(bytes);
+            properties.setProperty("localServerToken", token);
+            saveConfig();
+        }
+        return token;
     }
 
-    public void setOpenWebUIApiKey(String apiKey) {
-        properties.setProperty("openWebUIApiKey", apiKey);
-    }
+    // ========== Pipeline Execution ==========
 
-    public String getOpenWebUIServerUrl() {
-        return properties.getProperty("openWebUIServerUrl", "");
+    /**
+     * Gets the max_tokens sent with chat completion requests, limiting the length of
+     * pipeline outputs.
+     *
+     * @return Maximum output tokens, 0 to leave the limit to the provider
+     */
+    public int getChatMaxTokens() {
+        try {
+            return Math.max(0, Integer.parseInt(properties.getProperty("chatMaxTokens", "0")));
+        } catch (NumberFormatException e) {
+            return 0;
+        }
     }
 
-    public void setOpenWebUIServerUrl(String url) {
-        properties.setProperty("openWebUIServerUrl", url);
+    public void setChatMaxTokens(int maxTokens) {
+        properties.setProperty("chatMaxTokens", String.valueOf(Math.max(0, maxTokens)));
+        saveConfig();
     }
 
     /**
-     * Gets the list of custom OpenAI models configured by the user.
-     * Returns default models if no custom models are configured.
+     * Gets the maximum number of chat API calls that may run at the same time
+     * across all pipeline executions.
      *
-     * @return List of model names
+     * @return Maximum concurrent calls (at least 1)
      */
-    public List<String> getCustomOpenAIModels() {
-        String modelsJson = properties.getProperty("customOpenAIModels", "");
-        if (modelsJson.trim().isEmpty()) {
-            // Return default models if not configured
-            return Arrays.asList("gpt-4o-mini", "gpt-4o", "gpt-3.5-turbo");
-        }
+    public int getMaxConcurrentChatCalls() {
         try {
-            Gson gson = new Gson();
-            String[] modelsArray = gson.fromJson(modelsJson, String[].class);
-            return Arrays.asList(modelsArray);
-        } catch (Exception e) {
-            logger.error("Failed to parse custom OpenAI models, returning defaults", e);
-            return Arrays.asList("gpt-4o-mini", "gpt-4o", "gpt-3.5-turbo");
+            return Math.max(1, Integer.parseInt(properties.getProperty("maxConcurrentChatCalls", "2")));
+        } catch (NumberFormatException e) {
+            return 2;
         }
     }
 
+    public void setMaxConcurrentChatCalls(int maxCalls) {
+        properties.setProperty("maxConcurrentChatCalls", String.valueOf(Math.max(1, maxCalls)));
+        saveConfig();
+    }
+
     /**
-     * Saves the list of custom OpenAI models.
+     * Gets how many providers are asked for their model lists at the same time by "Fetch all".
      *
-     * @param models List of model names to save
+     * @return Maximum concurrent model fetches (at least 1)
      */
-    public void setCustomOpenAIModels(List<String> models) {
-        Gson gson = new Gson();
-        String json = gson.toJson(models);
-        properties.setProperty("customOpenAIModels", json);
+    public int getMaxConcurrentModelFetches() {
+        try {
+            return Math.max(1, Integer.parseInt(properties.getProperty("maxConcurrentModelFetches", "2")));
+        } catch (NumberFormatException e) {
+            return 2;
+        }
+    }
+
+    public void setMaxConcurrentModelFetches(int maxFetches) {
+        properties.setProperty("maxConcurrentModelFetches", String.valueOf(Math.max(1, maxFetches)));
         saveConfig();
     }
 
     /**
-     * Gets the custom OpenAI models as a comma-separated string for display in UI.
+     * Gets the maximum time a regular expression replacement may take before it is aborted.
      *
-     * @return Comma-separated model names
+     * @return Timeout in milliseconds; 0 means no limit
      */
-    public String getCustomOpenAIModelsString() {
-        List<String> models = getCustomOpenAIModels();
-        return String.join(", ", models);
+    public long getRegexTimeoutMs() {
+        try {
+            return Math.max(0, Long.parseLong(properties.getProperty("regexTimeoutMs", "2000")));
+        } catch (NumberFormatException e) {
+            return 2000;
+        }
+    }
+
+    public void setRegexTimeoutMs(long timeoutMs) {
+        properties.setProperty("regexTimeoutMs", String.valueOf(Math.max(0, timeoutMs)));
+        saveConfig();
     }
 
+    public static final String DEFAULT_CHAINED_META_PROMPT =
+            "Execute transformations sequentially. Each step's output becomes the next step's input. "
+                    + "Do not explain the steps; output only the final result.";
+
     /**
-     * Sets custom OpenAI models from a comma-separated string.
+     * Gets the instruction that opens the system prompt when consecutive prompt units
+     * are chained into a single API call.
      *
-     * @param modelsString Comma-separated model names
+     * @return The configured meta prompt, or the default if none is set
      */
-    public void setCustomOpenAIModelsFromString(String modelsString) {
-        if (modelsString == null || modelsString.trim().isEmpty()) {
-            // Reset to defaults
-            setCustomOpenAIModels(Arrays.asList("gpt-4o-mini", "gpt-4o", "gpt-3.5-turbo"));
-            return;
+    public String getChainedMetaPrompt() {
+        String prompt = properties.getProperty("chainedMetaPrompt", "");
+        return prompt.trim().isEmpty() ? DEFAULT_CHAINED_META_PROMPT : prompt;
+    }
+
+    public void setChainedMetaPrompt(String prompt) {
+        if (prompt == null || prompt.trim().isEmpty() || prompt.trim().equals(DEFAULT_CHAINED_META_PROMPT)) {
+            properties.remove("chainedMetaPrompt");
+        } else {
+            properties.setProperty("chainedMetaPrompt", prompt.trim());
         }
-        List<String> models = Arrays.stream(modelsString.split(","))
-                .map(String::trim)
-                .filter(s -> !s.isEmpty())
-                .collect(Collectors.toList());
-        setCustomOpenAIModels(models);
+        saveConfig();
     }
 
     // ========== Processing Unit Management ==========
@@ -417,7 +1463,7 @@ public class ConfigManager {
      *
      * @param unit The ProcessingUnit to save
      */
-    public void saveProcessingUnit(ProcessingUnit unit) {
+    public synchronized void saveProcessingUnit(ProcessingUnit unit) {
         Gson gson = new Gson();
         String json = gson.toJson(unit);
         JsonArray array;
@@ -502,7 +1548,7 @@ public class ConfigManager {
      *
      * @param uuid The UUID of the unit to delete
      */
-    public void deleteProcessingUnit(String uuid) {
+    public synchronized void deleteProcessingUnit(String uuid) {
         String existing = properties.getProperty("processingUnits", "[]");
         if (!existing.trim().isEmpty()) {
             Gson gson = new Gson();
@@ -530,6 +1576,106 @@ public class ConfigManager {
                 .orElse(null);
     }
 
+    /**
+     * Saves a copy of a processing unit under a new UUID, with " (copy)" appended to its name.
+     *
+     * @param uuid The UUID of the unit to copy
+     * @return The saved copy, or null if the unit was not found
+     */
+    public synchronized ProcessingUnit duplicateProcessingUnit(String uuid) {
+        // Lookups deserialize a fresh instance, so the copy shares no state with the original
+        ProcessingUnit copy = getProcessingUnitByUuid(uuid);
+        if (copy == null) {
+            return null;
+        }
+        copy.uuid = java.util.UUID.randomUUID().toString();
+        copy.name = copy.name + " (copy)";
+        saveProcessingUnit(copy);
+        return copy;
+    }
+
+    /**
+     * Writes all processing units to a JSON file, e.g. to share the library with another machine.
+     *
+     * @param file the file to write
+     * @throws IOException if the file cannot be written
+     */
+    public void exportProcessingUnits(File file) throws IOException {
+        String json = new GsonBuilder().setPrettyPrinting().create().toJson(getProcessingUnits());
+        Files.write(file.toPath(), json.getBytes(StandardCharsets.UTF_8));
+    }
+
+    /**
+     * Reads processing units from a file written by {@link #exportProcessingUnits(File)}.
+     * Units without a UUID get a new one.
+     *
+     * @param file the file to read
+     * @return the units in the file
+     * @throws IOException if the file cannot be read or is not a list of units
+     */
+    public static List<ProcessingUnit> readProcessingUnits(File file) throws IOException {
+        String json = new String(Files.readAllBytes(file.toPath()), StandardCharsets.UTF_8);
+        ProcessingUnit[] units;
+        try {
+            units = new Gson().fromJson(json, ProcessingUnit[].class);
+        } catch (RuntimeException e) {
+            throw new IOException("Not a processing unit library: " + e.getMessage(), e);
+        }
+        if (units == null) {
+            throw new IOException("Not a processing unit library: the file is empty");
+        }
+        List<ProcessingUnit> result = new ArrayList<>();
+        for (ProcessingUnit unit : units) {
+            if (unit == null) {
+                continue;
+            }
+            if (unit.uuid == null || unit.uuid.trim().isEmpty()) {
+                unit.uuid = java.util.UUID.randomUUID().toString();
+            }
+            result.add(unit);
+        }
+        return result;
+    }
+
+    /**
+     * Adds imported processing units to the library. Units are matched by UUID, so pipelines
+     * that reference a unit keep working on the other machine.
+     *
+     * @param units             the units to import
+     * @param overwriteExisting whether a unit replaces the library unit with the same UUID;
+     *                          otherwise such units are skipped
+     * @return a summary such as "Imported 3 units, skipped 1 existing"
+     */
+    public synchronized String importProcessingUnits(List<ProcessingUnit> units, boolean overwriteExisting) {
+        Set<String> existing = getProcessingUnits().stream()
+                .map(unit -> unit.uuid)
+                .collect(Collectors.toSet());
+        int added = 0;
+        int replaced = 0;
+        int skipped = 0;
+        for (ProcessingUnit unit : units) {
+            if (existing.contains(unit.uuid)) {
+                if (!overwriteExisting) {
+                    skipped++;
+                    continue;
+                }
+                replaced++;
+            } else {
+                added++;
+            }
+            saveProcessingUnit(unit);
+        }
+        StringBuilder summary = new StringBuilder("Imported " + (added + replaced) + " unit(s)");
+        if (replaced > 0) {
+            summary.append(", replaced ").append(replaced).append(" existing");
+        }
+        if (skipped > 0) {
+            summary.append(", skipped ").append(skipped).append(" existing");
+        }
+        logger.info(summary);
+        return summary.toString();
+    }
+
     // ========== Pipeline Management ==========
 
     /**
@@ -538,7 +1684,7 @@ public class ConfigManager {
      *
      * @param pipeline The Pipeline to save
      */
-    public void savePipeline(Pipeline pipeline) {
+    public synchronized void savePipeline(Pipeline pipeline) {
         Gson gson = new Gson();
         String json = gson.toJson(pipeline);
         JsonArray array;
@@ -623,7 +1769,7 @@ public class ConfigManager {
      *
      * @param uuid The UUID of the pipeline to delete
      */
-    public void deletePipeline(String uuid) {
+    public synchronized void deletePipeline(String uuid) {
         String existing = properties.getProperty("pipelines", "[]");
         if (!existing.trim().isEmpty()) {
             Gson gson = new Gson();
@@ -651,6 +1797,25 @@ public class ConfigManager {
                 .orElse(null);
     }
 
+    /**
+     * Saves a copy of a pipeline under a new UUID, with " (copy)" appended to its title.
+     * The copy references the same shared processing units as the original.
+     *
+     * @param uuid The UUID of the pipeline to copy
+     * @return The saved copy, or null if the pipeline was not found
+     */
+    public synchronized Pipeline duplicatePipeline(String uuid) {
+        Pipeline copy = getPipelineByUuid(uuid);
+        if (copy == null) {
+            return null;
+        }
+        copy.uuid = java.util.UUID.randomUUID().toString();
+        copy.title = copy.title + " (copy)";
+        copy.favorite = false;
+        savePipeline(copy);
+        return copy;
+    }
+
     /**
      * Gets the UUID of the last used pipeline.
      *
@@ -675,13 +1840,16 @@ public class ConfigManager {
     /**
      * Migrates old PostProcessingData to the new Pipeline + ProcessingUnit architecture.
      * This should be called once on application startup.
+     *
+     * @return a summary of what was converted, e.g. "Migrated 3 legacy pipelines (7 units)",
+     * or null if nothing was migrated
      */
-    public void migrateOldPostProcessingData() {
+    public synchronized String migrateOldPostProcessingData() {
         // Check if migration has already been done
         String migrated = properties.getProperty("postProcessingMigrated", "false");
         if ("true".equals(migrated)) {
             logger.info("Post-processing data already migrated");
-            return;
+            return null;
         }
 
         logger.info("Starting post-processing data migration...");
@@ -692,10 +1860,13 @@ public class ConfigManager {
             logger.info("No old post-processing data to migrate");
             properties.setProperty("postProcessingMigrated", "true");
             saveConfig();
-            return;
+            return null;
         }
 
         logger.info("Migrating {} old post-processing configurations", oldData.size());
+        int migratedPipelines = 0;
+        int migratedUnits = 0;
+        int failedPipelines = 0;
 
         // Migrate each old PostProcessingData to a new Pipeline
         for (PostProcessingData data : oldData) {
@@ -732,6 +1903,7 @@ public class ConfigManager {
 
                         // Save the unit to the library
                         saveProcessingUnit(unit);
+                        migratedUnits++;
 
                         // Add a reference to this unit in the pipeline
                         PipelineUnitReference ref = new PipelineUnitReference();
@@ -743,9 +1915,11 @@ public class ConfigManager {
 
                 // Save the pipeline
                 savePipeline(pipeline);
+                migratedPipelines++;
                 logger.info("Migrated pipeline: {}", pipeline.title);
 
             } catch (Exception e) {
+                failedPipelines++;
                 logger.error("Error migrating post-processing data: " + data.title, e);
             }
         }
@@ -754,6 +1928,21 @@ public class ConfigManager {
         properties.setProperty("postProcessingMigrated", "true");
         saveConfig();
 
-        logger.info("Post-processing data migration completed successfully");
+        String summary = describeMigration(migratedPipelines, migratedUnits, failedPipelines);
+        logger.info("Post-processing data migration completed: {}", summary);
+        return summary;
+    }
+
+    /**
+     * Describes the result of {@link #migrateOldPostProcessingData()} for the user.
+     */
+    static String describeMigration(int pipelines, int units, int failed) {
+        StringBuilder summary = new StringBuilder("Migrated ")
+                .append(pipelines).append(pipelines == 1 ? " legacy pipeline" : " legacy pipelines")
+                .append(" (").append(units).append(units == 1 ? " unit)" : " units)");
+        if (failed > 0) {
+            summary.append(", ").append(failed).append(" could not be converted (see log)");
+        }
+        return summary.toString();
     }
 }
\ No newline at end of file
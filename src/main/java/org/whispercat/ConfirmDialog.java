@@ -0,0 +1,61 @@
+package org.whispercat;
+
+import javax.swing.JOptionPane;
+import java.awt.Component;
+import java.util.function.ToIntFunction;
+
+/**
+ * Confirmation prompt for destructive actions such as deleting a pipeline. The confirm
+ * button names the action, and Cancel is the default so that pressing Enter does nothing
+ * harmful.
+ */
+public final class ConfirmDialog {
+
+    private static final String CANCEL = "Cancel";
+
+    private ConfirmDialog() {
+    }
+
+    /**
+     * Asks the user to confirm an action.
+     *
+     * @param parent       the component the dialog is centered on, may be null
+     * @param title        the dialog title, e.g. "Delete Pipeline"
+     * @param message      what will happen, e.g. "Delete pipeline 'Notes'?"
+     * @param confirmLabel the label of the confirm button, e.g. "Delete"
+     * @return true if the user confirmed, false if they cancelled or closed the dialog
+     */
+    public static boolean confirm(Component parent, String title, String message, String confirmLabel) {
+        return confirm(confirmLabel, options -> JOptionPane.showOptionDialog(parent, message, title,
+                JOptionPane.YES_NO_OPTION, JOptionPane.WARNING_MESSAGE, null, options, CANCEL));
+    }
+
+    /**
+     * Offers the confirm and cancel options and reports whether confirm was chosen.
+     *
+     * @param confirmLabel the label of the confirm button
+     * @param chooser      shows the options and returns the index of the chosen one, or
+     *                     {@link JOptionPane#CLOSED_OPTION} if the dialog was closed
+     * @return true if the confirm option was chosen
+     */
+    static boolean confirm(String confirmLabel, ToIntFunction<Object[]> chooser) {
+        Object[] options = {confirmLabel, CANCEL};
+        return chooser.applyAsInt(options) == 0;
+    }
+
+    /**
+     * Runs the action if the user confirms it.
+     *
+     * @param parent       the component the dialog is centered on, may be null
+     * @param title        the dialog title
+     * @param message      what will happen
+     * @param confirmLabel the label of the confirm button
+     * @param onConfirm    run on the calling thread after confirmation
+     */
+    public static void confirm(Component parent, String title, String message, String confirmLabel,
+                               Runnable onConfirm) {
+        if (confirm(parent, title, message, confirmLabel)) {
+            onConfirm.run();
+        }
+    }
+}
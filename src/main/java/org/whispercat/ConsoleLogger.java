@@ -1,7 +1,6 @@
 package org.whispercat;
 
 import javax.swing.*;
-import java.text.SimpleDateFormat;
 import java.util.Date;
 
 /**
@@ -10,7 +9,6 @@ import java.util.Date;
 public class ConsoleLogger {
     private static ConsoleLogger instance;
     private JTextArea consoleArea;
-    private final SimpleDateFormat timeFormat = new SimpleDateFormat("HH:mm:ss");
 
     private ConsoleLogger() {
     }
@@ -29,7 +27,7 @@ public class ConsoleLogger {
     public void log(String message) {
         if (consoleArea != null) {
             SwingUtilities.invokeLater(() -> {
-                String timestamp = timeFormat.format(new Date());
+                String timestamp = DisplayFormat.time(new Date());
                 consoleArea.append("[" + timestamp + "] " + message + "\n");
                 // Auto-scroll to bottom
                 consoleArea.setCaretPosition(consoleArea.getDocument().getLength());
@@ -42,7 +40,7 @@ public class ConsoleLogger {
     }
 
     public void logPrompt(String type, String content) {
-        log(type + ": " + (content != null && content.length() > 100 ? content.substring(0, 100) + "..." : content));
+        log(type + ": " + TextUtils.truncateChars(content, 100));
     }
 
     public void logError(String message) {
@@ -0,0 +1,61 @@
+package org.whispercat;
+
+import java.text.DateFormat;
+import java.util.Date;
+import java.util.Locale;
+
+/**
+ * Formats numbers and times shown in the UI and the console log with the display locale
+ * chosen in the settings, e.g. "12,5%" and "15:04" for German instead of "12.5%" and
+ * "3:04 PM". Log files keep the default formatting.
+ */
+public final class DisplayFormat {
+
+    private static volatile Locale locale = Locale.getDefault(Locale.Category.FORMAT);
+
+    private DisplayFormat() {
+    }
+
+    /**
+     * Sets the locale used for all UI formatting.
+     *
+     * @param displayLocale the locale, or null for the system locale
+     */
+    public static void setLocale(Locale displayLocale) {
+        locale = displayLocale != null ? displayLocale : Locale.getDefault(Locale.Category.FORMAT);
+    }
+
+    public static Locale getLocale() {
+        return locale;
+    }
+
+    /**
+     * {@link String#format(Locale, String, Object...)} with the display locale.
+     */
+    public static String format(String pattern, Object... args) {
+        return String.format(locale, pattern, args);
+    }
+
+    /**
+     * @param value    the number
+     * @param decimals the number of decimal places
+     * @return the number with the locale's decimal separator, e.g. "0,015"
+     */
+    public static String number(double value, int decimals) {
+        return String.format(locale, "%." + decimals + "f", value);
+    }
+
+    /**
+     * @return the time of day without seconds, e.g. "3:04 PM" or "15:04"
+     */
+    public static String shortTime(Date date) {
+        return DateFormat.getTimeInstance(DateFormat.SHORT, locale).format(date);
+    }
+
+    /**
+     * @return the time of day with seconds, e.g. "3:04:27 PM" or "15:04:27"
+     */
+    public static String time(Date date) {
+        return DateFormat.getTimeInstance(DateFormat.MEDIUM, locale).format(date);
+    }
+}
@@ -0,0 +1,98 @@
+package org.whispercat;
+
+import org.apache.logging.log4j.LogManager;
+import org.apache.logging.log4j.Logger;
+
+import java.io.IOException;
+import java.io.InputStream;
+import java.nio.charset.StandardCharsets;
+import java.util.Arrays;
+import java.util.List;
+import java.util.Locale;
+import java.util.concurrent.TimeUnit;
+
+/**
+ * Finds out which application is in front, so auto-paste can be limited to one target app.
+ * Uses the tools that ship with each platform: PowerShell on Windows, osascript on macOS
+ * and xdotool on Linux (X11 only).
+ */
+public final class ForegroundWindow {
+    private static final Logger logger = LogManager.getLogger(ForegroundWindow.class);
+    private static final long QUERY_TIMEOUT_MS = 2000;
+
+    private static final String WINDOWS_QUERY =
+            "Add-Type -Name W -Namespace N -MemberDefinition '"
+                    + "[DllImport(\"user32.dll\")] public static extern IntPtr GetForegroundWindow();"
+                    + "[DllImport(\"user32.dll\")] public static extern int GetWindowThreadProcessId(IntPtr h, out int p);';"
+                    + "$p = 0; [void][N.W]::GetWindowThreadProcessId([N.W]::GetForegroundWindow(), [ref]$p);"
+                    + "$proc = Get-Process -Id $p; $proc.ProcessName + ' ' + $proc.MainWindowTitle";
+
+    private ForegroundWindow() {
+    }
+
+    /**
+     * Queries the process and window title of the focused window.
+     *
+     * @return e.g. "obsidian My Notes - Obsidian", or null if it cannot be determined
+     */
+    public static String query() {
+        String os = System.getProperty("os.name", "").toLowerCase(Locale.ROOT);
+        List<String> command;
+        if (os.contains("win")) {
+            command = Arrays.asList("powershell", "-NoProfile", "-NonInteractive", "-Command", WINDOWS_QUERY);
+        } else if (os.contains("mac")) {
+            command = Arrays.asList("osascript", "-e",
+                    "tell application \"System Events\" to get name of first application process whose frontmost is true");
+        } else {
+            command = Arrays.asList("xdotool", "getactivewindow", "getwindowname");
+        }
+        try {
+            Process process = new ProcessBuilder(command).redirectErrorStream(true).start();
+            if (!process.waitFor(QUERY_TIMEOUT_MS, TimeUnit.MILLISECONDS)) {
+                process.destroyForcibly();
+                logger.warn("Timed out querying the focused window");
+                return null;
+            }
+            String output;
+            try (InputStream in = process.getInputStream()) {
+                output = new String(in.readAllBytes(), StandardCharsets.UTF_8).trim();
+            }
+            if (process.exitValue() != 0 || output.isEmpty()) {
+                logger.warn("Could not query the focused window: {}", output);
+                return null;
+            }
+            return output;
+        } catch (IOException e) {
+            logger.warn("Could not query the focused window with {}", command.get(0), e);
+            return null;
+        } catch (InterruptedException e) {
+            Thread.currentThread().interrupt();
+            return null;
+        }
+    }
+
+    /**
+     * Checks whether the focused window belongs to the configured target app. Matching is
+     * case-insensitive and by substring; several apps can be given separated by commas.
+     *
+     * @param target     the configured target app, e.g. "obsidian, notepad"; blank matches any window
+     * @param windowName the focused window as returned by {@link #query()}, may be null
+     * @return true if pasting into the focused window is allowed
+     */
+    public static boolean matches(String target, String windowName) {
+        if (target == null || target.trim().isEmpty()) {
+            return true;
+        }
+        if (windowName == null) {
+            return false;
+        }
+        String window = windowName.toLowerCase(Locale.ROOT);
+        for (String app : target.split(",")) {
+            String name = app.trim().toLowerCase(Locale.ROOT);
+            if (!name.isEmpty() && window.contains(name)) {
+                return true;
+            }
+        }
+        return false;
+    }
+}
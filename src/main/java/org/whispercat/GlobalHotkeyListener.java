@@ -5,55 +5,153 @@ import com.github.kwhat.jnativehook.keyboard.NativeKeyEvent;
 import com.github.kwhat.jnativehook.keyboard.NativeKeyListener;
 import org.apache.logging.log4j.LogManager;
 import org.apache.logging.log4j.Logger;
+import org.whispercat.recording.RecorderForm;
 import org.whispercat.settings.KeyCombinationTextField;
 import org.whispercat.settings.KeySequenceTextField;
 
+import javax.swing.*;
 import java.util.HashSet;
+import java.util.List;
 import java.util.Set;
 
 public class GlobalHotkeyListener implements NativeKeyListener {
     private static final Logger logger = LogManager.getLogger(GlobalHotkeyListener.class);
     private final MainForm ui;
     private final Set<Integer> pressedKeys = new HashSet<>();
-    private String[] hotKeyCombination;
-    private String[] hotKeySequence;
+    private Hotkey hotKeyCombination = Hotkey.NONE;
+    private Hotkey hotKeySequence = Hotkey.NONE;
+    private Hotkey hotKeyPipeline = Hotkey.NONE;
     private int sequenceIndex = 0;
     private long sequenceStartTime = 0;
     private KeyCombinationTextField keyCombinationTextField;
     private KeySequenceTextField keySequenceTextField;
+    private KeyCombinationTextField pipelineKeyTextField;
     private boolean optionsDialogOpen = false;
     private boolean combinationActive = false;
+    private boolean pipelineCombinationActive = false;
+    private final NativeHook nativeHook;
 
-    public GlobalHotkeyListener(MainForm ui, String initialKeyCombination, String initialKeySequence) {
-        this.ui = ui;
-        updateKeyCombination(initialKeyCombination);
-        updateKeySequence(initialKeySequence);
-        System.setProperty("jnativehook.lib.locator", "org.whispercat.CustomLibraryLocator");
-        try {
+    /**
+     * The OS-level keyboard hook the listener registers with.
+     */
+    interface NativeHook {
+        boolean isRegistered();
+
+        void register() throws Exception;
+
+        void addListener(NativeKeyListener listener);
+    }
+
+    private static final NativeHook GLOBAL_SCREEN = new NativeHook() {
+        @Override
+        public boolean isRegistered() {
+            return GlobalScreen.isNativeHookRegistered();
+        }
+
+        @Override
+        public void register() throws Exception {
+            System.setProperty("jnativehook.lib.locator", "org.whispercat.CustomLibraryLocator");
             java.util.logging.Logger jLogger = java.util.logging.Logger.getLogger(GlobalScreen.class.getPackage().getName());
             jLogger.setLevel(java.util.logging.Level.OFF);
             jLogger.setUseParentHandlers(false);
             GlobalScreen.registerNativeHook();
-        } catch (Exception e) {
+        }
+
+        @Override
+        public void addListener(NativeKeyListener listener) {
+            GlobalScreen.addNativeKeyListener(listener);
+        }
+    };
+
+    public GlobalHotkeyListener(MainForm ui, String initialKeyCombination, String initialKeySequence,
+                                String initialPipelineCombination) {
+        this(ui, initialKeyCombination, initialKeySequence, initialPipelineCombination, GLOBAL_SCREEN);
+    }
+
+    /**
+     * Creates a listener that registers with the given hook, e.g. a fake in tests.
+     */
+    GlobalHotkeyListener(MainForm ui, String initialKeyCombination, String initialKeySequence,
+                         String initialPipelineCombination, NativeHook nativeHook) {
+        this.ui = ui;
+        this.nativeHook = nativeHook;
+        updateKeyCombination(initialKeyCombination);
+        updateKeySequence(initialKeySequence);
+        updatePipelineKeyCombination(initialPipelineCombination);
+        try {
+            registerNativeHook();
+        } catch (IllegalStateException e) {
             logger.error("Failed to register native hook", e);
         }
-        GlobalScreen.addNativeKeyListener(this);
+        nativeHook.addListener(this);
+    }
+
+    /**
+     * Re-registers the hotkeys if they differ from the active ones, so changes made in the
+     * settings take effect without a restart. Also retries registering the native hook if
+     * that failed on startup.
+     *
+     * @param combination         the configured key combination (comma-separated key codes)
+     * @param sequence            the configured key sequence (comma-separated key codes)
+     * @param pipelineCombination the key combination that runs the last used pipeline
+     * @return true if the hotkeys changed and were re-registered
+     * @throws IllegalStateException if a key code is invalid or the native hook cannot be registered
+     */
+    public synchronized boolean reregisterHotkeys(String combination, String sequence, String pipelineCombination) {
+        Hotkey newCombination = parseHotkey(combination);
+        Hotkey newSequence = parseHotkey(sequence);
+        Hotkey newPipeline = parseHotkey(pipelineCombination);
+        if (newCombination.equals(hotKeyCombination) && newSequence.equals(hotKeySequence)
+                && newPipeline.equals(hotKeyPipeline)) {
+            return false;
+        }
+        registerNativeHook();
+        hotKeyCombination = newCombination;
+        hotKeySequence = newSequence;
+        hotKeyPipeline = newPipeline;
+        sequenceIndex = 0;
+        sequenceStartTime = 0;
+        pressedKeys.clear();
+        combinationActive = false;
+        pipelineCombinationActive = false;
+        logger.info("Hotkeys re-registered: combination={}, sequence={}, pipeline={}",
+                newCombination, newSequence, newPipeline);
+        return true;
+    }
+
+    private static Hotkey parseHotkey(String keys) {
+        try {
+            return Hotkey.parse(keys);
+        } catch (IllegalArgumentException e) {
+            throw new IllegalStateException(e.getMessage(), e);
+        }
+    }
+
+    private void registerNativeHook() {
+        if (nativeHook.isRegistered()) {
+            return;
+        }
+        try {
+            nativeHook.register();
+        } catch (Exception e) {
+            throw new IllegalStateException("Could not register global hotkeys: " + e.getMessage(), e);
+        }
     }
 
     @Override
     public void nativeKeyPressed(NativeKeyEvent e) {
         if (optionsDialogOpen) {
             // Only intercept keys if one of the keybind fields has focus
-            boolean fieldHasFocus = (keyCombinationTextField != null && keyCombinationTextField.hasFocus()) ||
-                                    (keySequenceTextField != null && keySequenceTextField.hasFocus());
-
-            if (fieldHasFocus) {
+            if (keybindFieldHasFocus()) {
                 if (keyCombinationTextField != null) {
                     keyCombinationTextField.processKeyPressed(e);
                 }
                 if (keySequenceTextField != null) {
                     keySequenceTextField.processKeyPressed(e);
                 }
+                if (pipelineKeyTextField != null) {
+                    pipelineKeyTextField.processKeyPressed(e);
+                }
                 return;
             }
             // If no keybind field has focus, allow normal hotkey processing
@@ -62,25 +160,34 @@ public class GlobalHotkeyListener implements NativeKeyListener {
             return;
         }
         pressedKeys.add(e.getKeyCode());
+        if (processPipelineHotkey()) {
+            return;
+        }
         processRecordingHotkeys(e);
     }
 
+    private boolean keybindFieldHasFocus() {
+        return (keyCombinationTextField != null && keyCombinationTextField.hasFocus()) ||
+               (keySequenceTextField != null && keySequenceTextField.hasFocus()) ||
+               (pipelineKeyTextField != null && pipelineKeyTextField.hasFocus());
+    }
+
     @Override
     public void nativeKeyReleased(NativeKeyEvent e) {
         logger.debug("Key released: {}", e.getKeyCode());
         pressedKeys.remove(e.getKeyCode());
         if (optionsDialogOpen) {
             // Only intercept keys if one of the keybind fields has focus
-            boolean fieldHasFocus = (keyCombinationTextField != null && keyCombinationTextField.hasFocus()) ||
-                                    (keySequenceTextField != null && keySequenceTextField.hasFocus());
-
-            if (fieldHasFocus) {
+            if (keybindFieldHasFocus()) {
                 if (keyCombinationTextField != null) {
                     keyCombinationTextField.processKeyReleased(e);
                 }
                 if (keySequenceTextField != null) {
                     keySequenceTextField.processKeyReleased(e);
                 }
+                if (pipelineKeyTextField != null) {
+                    pipelineKeyTextField.processKeyReleased(e);
+                }
                 return;
             }
             // If no keybind field has focus, allow normal hotkey processing
@@ -88,6 +195,9 @@ public class GlobalHotkeyListener implements NativeKeyListener {
         if (!isKeyCombinationPressed()) {
             combinationActive = false;
         }
+        if (!isPressed(hotKeyPipeline)) {
+            pipelineCombinationActive = false;
+        }
     }
 
     @Override
@@ -95,40 +205,87 @@ public class GlobalHotkeyListener implements NativeKeyListener {
     }
 
     public void updateKeyCombination(String newCombination) {
-        if (newCombination != null && !newCombination.isEmpty()) {
-            hotKeyCombination = newCombination.split(",");
-        } else {
-            hotKeyCombination = null;
+        try {
+            hotKeyCombination = Hotkey.parse(newCombination);
+        } catch (IllegalArgumentException e) {
+            logger.error("Invalid key combination: {}", newCombination, e);
+            hotKeyCombination = Hotkey.NONE;
         }
     }
 
     public void updateKeySequence(String newSequence) {
-        if (newSequence != null && !newSequence.isEmpty()) {
-            hotKeySequence = newSequence.split(",");
-            sequenceIndex = 0;
-            sequenceStartTime = 0;
-        } else {
-            hotKeySequence = null;
-            sequenceIndex = 0;
-            sequenceStartTime = 0;
+        try {
+            hotKeySequence = Hotkey.parse(newSequence);
+        } catch (IllegalArgumentException e) {
+            logger.error("Invalid key sequence: {}", newSequence, e);
+            hotKeySequence = Hotkey.NONE;
+        }
+        sequenceIndex = 0;
+        sequenceStartTime = 0;
+    }
+
+    public void updatePipelineKeyCombination(String newCombination) {
+        try {
+            hotKeyPipeline = Hotkey.parse(newCombination);
+        } catch (IllegalArgumentException e) {
+            logger.error("Invalid pipeline key combination: {}", newCombination, e);
+            hotKeyPipeline = Hotkey.NONE;
         }
     }
 
     private boolean isKeyCombinationPressed() {
-        if (hotKeyCombination == null) {
+        return isPressed(hotKeyCombination);
+    }
+
+    private boolean isPressed(Hotkey hotkey) {
+        if (hotkey.isEmpty()) {
             return false;
         }
-        for (String keyName : hotKeyCombination) {
-            if (!pressedKeys.contains(Integer.valueOf(keyName.trim()))) {
-                logger.debug("Key combination not fully pressed: {}", keyName);
+        for (int keyCode : hotkey.getKeyCodes()) {
+            if (!pressedKeys.contains(keyCode)) {
+                logger.debug("Key combination not fully pressed: {}", keyCode);
                 return false;
             }
         }
         return true;
     }
 
+    /**
+     * Runs the last used pipeline on the current transcription once the pipeline key
+     * combination is fully pressed. It takes precedence over the recording hotkeys.
+     *
+     * @return true if the key press was consumed by the pipeline hotkey
+     */
+    private boolean processPipelineHotkey() {
+        if (!isPressed(hotKeyPipeline)) {
+            return false;
+        }
+        if (!pipelineCombinationActive) {
+            pipelineCombinationActive = true;
+            logger.info("Pipeline key combination pressed, running last used pipeline");
+            runLastPipeline();
+            pressedKeys.clear();
+            sequenceIndex = 0;
+            sequenceStartTime = 0;
+        }
+        return true;
+    }
+
+    /**
+     * Asks the recorder to run the last used pipeline on the event dispatch thread.
+     */
+    void runLastPipeline() {
+        RecorderForm recorderForm = ui.recorderForm;
+        if (recorderForm != null) {
+            SwingUtilities.invokeLater(recorderForm::runLastPipeline);
+        } else {
+            Notificationmanager.getInstance().showNotification(ToastNotification.Type.WARNING,
+                    "Nothing to process yet. Record something first.");
+        }
+    }
+
     private void processRecordingHotkeys(NativeKeyEvent e) {
-        if (hotKeyCombination != null && hotKeyCombination.length > 0 && isKeyCombinationPressed()) {
+        if (!hotKeyCombination.isEmpty() && isKeyCombinationPressed()) {
             if (!combinationActive) {
                 combinationActive = true;
                 logger.info("Key combination pressed, toggling recording");
@@ -160,76 +317,68 @@ public class GlobalHotkeyListener implements NativeKeyListener {
             }
             return;
         }
-        if (hotKeySequence != null && hotKeySequence.length > 0) {
+        if (!hotKeySequence.isEmpty()) {
             long currentTime = System.currentTimeMillis();
             if (sequenceIndex > 0 && currentTime - sequenceStartTime > 1000) {
                 logger.debug("Key sequence timeout. More than 1 sec passed.");
                 sequenceIndex = 0;
                 sequenceStartTime = 0;
             }
-            try {
-                int expectedKeyCode = Integer.valueOf(hotKeySequence[sequenceIndex].trim());
-                if (e.getKeyCode() == expectedKeyCode) {
-                    if (sequenceIndex == 0) {
-                        sequenceStartTime = currentTime;
-                    }
-                    sequenceIndex++;
-                    if (sequenceIndex == hotKeySequence.length) {
-                        if (currentTime - sequenceStartTime <= 1000) {
-                            logger.info("Key sequence completed, toggling recording");
-
-                            // If on settings screen, save settings before switching
-                            if (optionsDialogOpen && ui.settingsForm != null) {
-                                logger.info("Saving settings before switching to recorder");
-                                ui.settingsForm.saveSettings();
-                            }
-
-                            // Switch to recorder form if not already there
-                            ui.setSelectedMenu(0, 0);
-                            // Small delay to ensure UI has switched before toggling
-                            try {
-                                Thread.sleep(50);
-                            } catch (InterruptedException ex) {
-                                logger.debug("Sleep interrupted", ex);
-                            }
-                            if (ui.recorderForm != null) {
-                                ui.recorderForm.toggleRecording();
-                            } else {
-                                logger.warn("RecorderForm is null, cannot toggle recording");
-                                Notificationmanager.getInstance().showNotification(ToastNotification.Type.WARNING,
-                                        "Recording could not be started. Please try again.");
-                            }
+            List<Integer> sequenceKeys = hotKeySequence.getKeyCodes();
+            int expectedKeyCode = sequenceKeys.get(sequenceIndex);
+            if (e.getKeyCode() == expectedKeyCode) {
+                if (sequenceIndex == 0) {
+                    sequenceStartTime = currentTime;
+                }
+                sequenceIndex++;
+                if (sequenceIndex == sequenceKeys.size()) {
+                    if (currentTime - sequenceStartTime <= 1000) {
+                        logger.info("Key sequence completed, toggling recording");
+
+                        // If on settings screen, save settings before switching
+                        if (optionsDialogOpen && ui.settingsForm != null) {
+                            logger.info("Saving settings before switching to recorder");
+                            ui.settingsForm.saveSettings();
+                        }
+
+                        // Switch to recorder form if not already there
+                        ui.setSelectedMenu(0, 0);
+                        // Small delay to ensure UI has switched before toggling
+                        try {
+                            Thread.sleep(50);
+                        } catch (InterruptedException ex) {
+                            logger.debug("Sleep interrupted", ex);
+                        }
+                        if (ui.recorderForm != null) {
+                            ui.recorderForm.toggleRecording();
                         } else {
-                            logger.debug("Key sequence completed, but time limit was reached. Current time limit: 1000ms");
+                            logger.warn("RecorderForm is null, cannot toggle recording");
+                            Notificationmanager.getInstance().showNotification(ToastNotification.Type.WARNING,
+                                    "Recording could not be started. Please try again.");
                         }
-                        sequenceIndex = 0;
-                        sequenceStartTime = 0;
+                    } else {
+                        logger.debug("Key sequence completed, but time limit was reached. Current time limit: 1000ms");
                     }
-                } else {
                     sequenceIndex = 0;
                     sequenceStartTime = 0;
-                    try {
-                        int firstKeyCode = Integer.valueOf(hotKeySequence[0].trim());
-                        if (e.getKeyCode() == firstKeyCode) {
-                            sequenceIndex = 1;
-                            sequenceStartTime = currentTime;
-                        }
-                    } catch (NumberFormatException nfe) {
-                        logger.error("Invalid key code in key sequence", nfe);
-                    }
                 }
-            } catch (NumberFormatException nfe) {
-                logger.error("Invalid key code in key sequence", nfe);
+            } else {
                 sequenceIndex = 0;
                 sequenceStartTime = 0;
+                if (e.getKeyCode() == sequenceKeys.get(0)) {
+                    sequenceIndex = 1;
+                    sequenceStartTime = currentTime;
+                }
             }
         }
     }
 
-    public void setOptionsDialogOpen(boolean open, KeyCombinationTextField keybindField, KeySequenceTextField keySequenceField) {
+    public void setOptionsDialogOpen(boolean open, KeyCombinationTextField keybindField, KeySequenceTextField keySequenceField,
+                                     KeyCombinationTextField pipelineKeyField) {
         this.optionsDialogOpen = open;
         this.keyCombinationTextField = keybindField;
         this.keySequenceTextField = keySequenceField;
+        this.pipelineKeyTextField = pipelineKeyField;
         logger.debug("Options dialog open set to: {}", open);
     }
 }
\ No newline at end of file
@@ -0,0 +1,127 @@
+package org.whispercat;
+
+import com.github.kwhat.jnativehook.keyboard.NativeKeyEvent;
+
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.List;
+import java.util.stream.Collectors;
+
+/**
+ * A parsed hotkey: the native key codes of a key combination (pressed together) or a
+ * key sequence (pressed one after another), in the order they were recorded.
+ * Stored in the configuration as comma-separated key codes, e.g. "29,42,57";
+ * {@link #parse(String)} and {@link #toString()} convert between both forms.
+ */
+public final class Hotkey {
+    public static final Hotkey NONE = new Hotkey(Collections.emptyList());
+
+    private final List<Integer> keyCodes;
+
+    private Hotkey(List<Integer> keyCodes) {
+        this.keyCodes = Collections.unmodifiableList(new ArrayList<>(keyCodes));
+    }
+
+    /**
+     * Creates a hotkey from key codes.
+     *
+     * @param keyCodes the native key codes in order
+     * @return the hotkey
+     */
+    public static Hotkey of(List<Integer> keyCodes) {
+        return keyCodes.isEmpty() ? NONE : new Hotkey(keyCodes);
+    }
+
+    /**
+     * Parses the configuration form of a hotkey.
+     *
+     * @param value comma-separated key codes; null or blank for no hotkey
+     * @return the hotkey, {@link #NONE} for a blank value
+     * @throws IllegalArgumentException if a key code is not a positive number
+     */
+    public static Hotkey parse(String value) {
+        if (value == null || value.trim().isEmpty()) {
+            return NONE;
+        }
+        List<Integer> keyCodes = new ArrayList<>();
+        for (String key : value.split(",")) {
+            try {
+                int keyCode = Integer.parseInt(key.trim());
+                if (keyCode <= 0) {
+                    throw new IllegalArgumentException("Invalid key code in hotkey: " + key.trim());
+                }
+                keyCodes.add(keyCode);
+            } catch (NumberFormatException e) {
+                throw new IllegalArgumentException("Invalid key code in hotkey: " + key.trim(), e);
+            }
+        }
+        return new Hotkey(keyCodes);
+    }
+
+    public boolean isEmpty() {
+        return keyCodes.isEmpty();
+    }
+
+    /**
+     * @return all key codes in order
+     */
+    public List<Integer> getKeyCodes() {
+        return keyCodes;
+    }
+
+    /**
+     * @return the modifier keys (Ctrl, Shift, Alt, Meta) of the hotkey
+     */
+    public List<Integer> getModifiers() {
+        return keyCodes.stream().filter(Hotkey::isModifier).collect(Collectors.toList());
+    }
+
+    /**
+     * @return the keys that are not modifiers
+     */
+    public List<Integer> getKeys() {
+        return keyCodes.stream().filter(code -> !isModifier(code)).collect(Collectors.toList());
+    }
+
+    public boolean contains(int keyCode) {
+        return keyCodes.contains(keyCode);
+    }
+
+    /**
+     * @return the key names for display, e.g. "Ctrl + Shift + Space"
+     */
+    public String toDisplayString() {
+        return keyCodes.stream().map(NativeKeyEvent::getKeyText).collect(Collectors.joining(" + "));
+    }
+
+    static boolean isModifier(int keyCode) {
+        return keyCode == NativeKeyEvent.VC_CONTROL
+                || keyCode == NativeKeyEvent.VC_SHIFT
+                || keyCode == NativeKeyEvent.VC_ALT
+                || keyCode == NativeKeyEvent.VC_META;
+    }
+
+    /**
+     * @return the configuration form, comma-separated key codes
+     */
+    @Override
+    public String toString() {
+        return keyCodes.stream().map(String::valueOf).collect(Collectors.joining(","));
+    }
+
+    @Override
+    public boolean equals(Object o) {
+        if (this == o) {
+            return true;
+        }
+        if (!(o instanceof Hotkey)) {
+            return false;
+        }
+        return keyCodes.equals(((Hotkey) o).keyCodes);
+    }
+
+    @Override
+    public int hashCode() {
+        return keyCodes.hashCode();
+    }
+}
@@ -0,0 +1,74 @@
+package org.whispercat;
+
+import com.fasterxml.jackson.databind.JsonNode;
+import com.fasterxml.jackson.databind.ObjectMapper;
+import org.apache.logging.log4j.LogManager;
+import org.apache.logging.log4j.Logger;
+
+import java.io.IOException;
+
+/**
+ * Shared status check for the HTTP clients, so every API call reports errors the same way.
+ */
+public class HttpErrors {
+    private static final Logger logger = LogManager.getLogger(HttpErrors.class);
+    private static final ObjectMapper MAPPER = new ObjectMapper();
+    private static final int MAX_RAW_MESSAGE_LENGTH = 500;
+
+    private HttpErrors() {
+    }
+
+    /**
+     * Throws an {@link ApiException} if the status code is not a 2xx success.
+     *
+     * @param apiName      name used in the error message, e.g. "OpenAI API"
+     * @param statusCode   the HTTP status code
+     * @param responseBody the response body, used to extract the error message
+     * @throws ApiException if the request was not successful
+     */
+    public static void checkStatus(String apiName, int statusCode, String responseBody) throws ApiException {
+        if (statusCode >= 200 && statusCode < 300) {
+            return;
+        }
+        logger.error("{} returned status code: {}. Response: {}", apiName, statusCode, responseBody);
+        throw new ApiException(apiName, statusCode, extractMessage(responseBody));
+    }
+
+    /**
+     * Extracts a readable error message from an error response body. Understands the OpenAI
+     * format ({"error": {"message": ...}}) and FastAPI-style {"detail": ...} bodies; anything
+     * else is returned as raw (truncated) text.
+     *
+     * @param responseBody the response body
+     * @return the error message, never null
+     */
+    static String extractMessage(String responseBody) {
+        if (responseBody == null || responseBody.trim().isEmpty()) {
+            return "No response body";
+        }
+        try {
+            JsonNode json = MAPPER.readTree(responseBody);
+            JsonNode error = json.path("error");
+            if (error.path("message").isTextual()) {
+                return error.path("message").asText();
+            }
+            if (error.isTextual()) {
+                return error.asText();
+            }
+            JsonNode detail = json.path("detail");
+            if (detail.isTextual()) {
+                return detail.asText();
+            }
+            if (detail.isArray() && detail.size() > 0 && detail.get(0).path("msg").isTextual()) {
+                return detail.get(0).path("msg").asText();
+            }
+            if (json.path("message").isTextual()) {
+                return json.path("message").asText();
+            }
+        } catch (IOException e) {
+            // Not JSON, fall back to the raw body
+        }
+        String raw = responseBody.trim();
+        return TextUtils.truncateChars(raw, MAX_RAW_MESSAGE_LENGTH);
+    }
+}
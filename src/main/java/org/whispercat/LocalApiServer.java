@@ -0,0 +1,237 @@
+package org.whispercat;
+
+import com.fasterxml.jackson.databind.JsonNode;
+import com.fasterxml.jackson.databind.ObjectMapper;
+import com.fasterxml.jackson.databind.node.ObjectNode;
+import com.sun.net.httpserver.HttpExchange;
+import com.sun.net.httpserver.HttpServer;
+import org.apache.logging.log4j.LogManager;
+import org.apache.logging.log4j.Logger;
+import org.whispercat.recording.TranscriptionOutcome;
+import org.whispercat.recording.TranscriptionService;
+
+import java.io.File;
+import java.io.IOException;
+import java.io.InputStream;
+import java.io.OutputStream;
+import java.net.InetAddress;
+import java.net.InetSocketAddress;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Files;
+import java.security.MessageDigest;
+import java.util.concurrent.Executors;
+import java.util.function.BooleanSupplier;
+
+/**
+ * Optional HTTP server that lets other tools (editors, scripts) control WhisperCat.
+ * It only binds to the loopback interface and is disabled by default.
+ *
+ * Endpoints:
+ * POST /transcribe     - JSON body {"path": "..."} or raw audio/* bytes; returns the transcription as JSON
+ * POST /record/toggle  - starts or stops a recording, exactly like the hotkey
+ *
+ * Every request must send the token from the settings as "Authorization: Bearer &lt;token&gt;".
+ * Requests with an Origin header come from a web page in a browser and are rejected.
+ */
+public class LocalApiServer {
+    private static final Logger logger = LogManager.getLogger(LocalApiServer.class);
+
+    private final ConfigManager configManager;
+    private final BooleanSupplier toggleRecording;
+    private final TranscriptionService transcriptionService;
+    private final ObjectMapper mapper = new ObjectMapper();
+    private HttpServer server;
+
+    /**
+     * @param configManager   the configuration (enabled flag, port, transcription settings)
+     * @param toggleRecording callback that toggles recording on the UI; returns false if the
+     *                        recording screen is not available yet
+     */
+    public LocalApiServer(ConfigManager configManager, BooleanSupplier toggleRecording) {
+        this(configManager, toggleRecording, new TranscriptionService(configManager));
+    }
+
+    /**
+     * Creates a server that transcribes with the given service, e.g. a stub in tests.
+     */
+    LocalApiServer(ConfigManager configManager, BooleanSupplier toggleRecording,
+                   TranscriptionService transcriptionService) {
+        this.configManager = configManager;
+        this.toggleRecording = toggleRecording;
+        this.transcriptionService = transcriptionService;
+    }
+
+    /**
+     * Starts the server if it is enabled in the configuration. Failures are logged, not thrown,
+     * so a busy port never prevents the application from starting.
+     */
+    public void start() {
+        if (!configManager.isLocalServerEnabled() || server != null) {
+            return;
+        }
+        int port = configManager.getLocalServerPort();
+        try {
+            server = HttpServer.create(new InetSocketAddress(InetAddress.getLoopbackAddress(), port), 0);
+            server.createContext("/transcribe", this::handleTranscribe);
+            server.createContext("/record/toggle", this::handleToggle);
+            server.setExecutor(Executors.newSingleThreadExecutor(r -> {
+                Thread thread = new Thread(r, "local-api-server");
+                thread.setDaemon(true);
+                return thread;
+            }));
+            server.start();
+            logger.info("Local API server listening on http://127.0.0.1:{}", port);
+        } catch (IOException e) {
+            server = null;
+            logger.error("Failed to start local API server on port {}", port, e);
+            ConsoleLogger.getInstance().logError("Local API server could not start on port " + port + ": " + e.getMessage());
+        }
+    }
+
+    public void stop() {
+        if (server != null) {
+            server.stop(0);
+            server = null;
+            logger.info("Local API server stopped");
+        }
+    }
+
+    private void handleToggle(HttpExchange exchange) throws IOException {
+        try {
+            if (!"POST".equalsIgnoreCase(exchange.getRequestMethod())) {
+                sendError(exchange, 405, "Method not allowed");
+                return;
+            }
+            if (!authorize(exchange)) {
+                return;
+            }
+            if (!toggleRecording.getAsBoolean()) {
+                sendError(exchange, 503, "Recording is not available yet");
+                return;
+            }
+            ObjectNode response = mapper.createObjectNode();
+            response.put("status", "ok");
+            sendJson(exchange, 200, response);
+        } finally {
+            exchange.close();
+        }
+    }
+
+    private void handleTranscribe(HttpExchange exchange) throws IOException {
+        File uploadedFile = null;
+        try {
+            if (!"POST".equalsIgnoreCase(exchange.getRequestMethod())) {
+                sendError(exchange, 405, "Method not allowed");
+                return;
+            }
+            if (!authorize(exchange)) {
+                return;
+            }
+            String contentType = exchange.getRequestHeaders().getFirst("Content-Type");
+            String type = contentType != null ? contentType.trim().toLowerCase() : "";
+            boolean json = type.startsWith("application/json");
+            if (!json && !type.startsWith("audio/")) {
+                sendError(exchange, 415, "Send application/json with a path or audio/* bytes");
+                return;
+            }
+            byte[] body = readBody(exchange.getRequestBody());
+
+            File audioFile;
+            if (json) {
+                JsonNode request = mapper.readTree(body);
+                String path = request.path("path").asText("");
+                if (path.isEmpty()) {
+                    sendError(exchange, 400, "Missing 'path'");
+                    return;
+                }
+                audioFile = new File(path);
+                if (!audioFile.isFile()) {
+                    sendError(exchange, 404, "File not found: " + path);
+                    return;
+                }
+            } else {
+                if (body.length == 0) {
+                    sendError(exchange, 400, "Empty request body");
+                    return;
+                }
+                uploadedFile = File.createTempFile("api_upload_", getUploadSuffix(contentType));
+                Files.write(uploadedFile.toPath(), body);
+                audioFile = uploadedFile;
+            }
+
+            ConsoleLogger.getInstance().log("Local API: transcribing " + audioFile.getName());
+            TranscriptionOutcome outcome = transcriptionService.transcribe(audioFile);
+
+            ObjectNode response = mapper.createObjectNode();
+            response.put("text", outcome.getText());
+            response.put("language", outcome.getLanguage());
+            response.put("durationSeconds", outcome.getDurationSeconds());
+            response.put("provider", outcome.getProvider());
+            response.put("model", outcome.getModel());
+            response.put("confidence", outcome.getConfidence());
+            sendJson(exchange, 200, response);
+        } catch (IOException e) {
+            logger.error("Local API transcription failed", e);
+            sendError(exchange, 500, e.getMessage());
+        } finally {
+            if (uploadedFile != null && !uploadedFile.delete()) {
+                uploadedFile.deleteOnExit();
+            }
+            exchange.close();
+        }
+    }
+
+    /**
+     * Rejects requests from web pages and requests without the configured token.
+     *
+     * @return true if the request may proceed; otherwise the error has been sent
+     */
+    private boolean authorize(HttpExchange exchange) throws IOException {
+        if (exchange.getRequestHeaders().containsKey("Origin")) {
+            sendError(exchange, 403, "Requests from web pages are not allowed");
+            return false;
+        }
+        String authorization = exchange.getRequestHeaders().getFirst("Authorization");
+        String expected = "Bearer " + configManager.getLocalServerToken();
+        if (authorization == null || !MessageDigest.isEqual(
+                authorization.trim().getBytes(StandardCharsets.UTF_8), expected.getBytes(StandardCharsets.UTF_8))) {
+            sendError(exchange, 401, "Missing or invalid token");
+            return false;
+        }
+        return true;
+    }
+
+    private static String getUploadSuffix(String contentType) {
+        if (contentType != null) {
+            String type = contentType.toLowerCase();
+            if (type.contains("mpeg") || type.contains("mp3")) {
+                return ".mp3";
+            }
+            if (type.contains("ogg")) {
+                return ".ogg";
+            }
+        }
+        return ".wav";
+    }
+
+    private static byte[] readBody(InputStream in) throws IOException {
+        try (InputStream body = in) {
+            return body.readAllBytes();
+        }
+    }
+
+    private void sendError(HttpExchange exchange, int status, String message) throws IOException {
+        ObjectNode response = mapper.createObjectNode();
+        response.put("error", message != null ? message : "Unknown error");
+        sendJson(exchange, status, response);
+    }
+
+    private void sendJson(HttpExchange exchange, int status, JsonNode json) throws IOException {
+        byte[] bytes = mapper.writeValueAsString(json).getBytes(StandardCharsets.UTF_8);
+        exchange.getResponseHeaders().set("Content-Type", "application/json; charset=utf-8");
+        exchange.sendResponseHeaders(status, bytes.length);
+        try (OutputStream out = exchange.getResponseBody()) {
+            out.write(bytes);
+        }
+    }
+}
@@ -0,0 +1,104 @@
+package org.whispercat;
+
+import org.apache.logging.log4j.Level;
+import org.apache.logging.log4j.LogManager;
+import org.apache.logging.log4j.Logger;
+import org.apache.logging.log4j.core.LoggerContext;
+
+import java.util.LinkedHashMap;
+import java.util.Map;
+import java.util.function.Function;
+
+/**
+ * Applies the application's log level and file logging settings to Log4j.
+ *
+ * Values are resolved from environment variables first (WHISPERCAT_LOG_LEVEL,
+ * WHISPERCAT_FILE_LOG_LEVEL, WHISPERCAT_LOG_DIR) and then from the configuration.
+ * They are handed to log4j2.xml via system properties and the logger context is reloaded.
+ */
+public class LoggingConfigurator {
+    private static final Logger logger = LogManager.getLogger(LoggingConfigurator.class);
+
+    public static final String ENV_LOG_LEVEL = "WHISPERCAT_LOG_LEVEL";
+    public static final String ENV_FILE_LOG_LEVEL = "WHISPERCAT_FILE_LOG_LEVEL";
+    public static final String ENV_LOG_DIR = "WHISPERCAT_LOG_DIR";
+
+    static final String PROPERTY_LOG_LEVEL = "whispercat.logLevel";
+    static final String PROPERTY_FILE_LOG_LEVEL = "whispercat.fileLogLevel";
+    static final String PROPERTY_ROOT_LEVEL = "whispercat.rootLevel";
+    static final String PROPERTY_LOG_DIR = "whispercat.logDir";
+
+    private LoggingConfigurator() {
+    }
+
+    /**
+     * Resolves the logging settings and reconfigures Log4j accordingly.
+     *
+     * @param configManager the configuration to read defaults from
+     */
+    public static void apply(ConfigManager configManager) {
+        Map<String, String> settings = resolveSettings(System::getenv, configManager);
+        for (Map.Entry<String, String> setting : settings.entrySet()) {
+            System.setProperty(setting.getKey(), setting.getValue());
+        }
+        if (!settings.containsKey(PROPERTY_LOG_DIR)) {
+            System.clearProperty(PROPERTY_LOG_DIR);
+        }
+
+        LoggerContext context = (LoggerContext) LogManager.getContext(false);
+        context.reconfigure();
+        logger.info("Logging configured: level={}, fileLevel={}, logDir={}", settings.get(PROPERTY_LOG_LEVEL),
+                settings.get(PROPERTY_FILE_LOG_LEVEL), settings.getOrDefault(PROPERTY_LOG_DIR, "default"));
+    }
+
+    /**
+     * Maps the environment and configuration to the system properties read by log4j2.xml,
+     * without touching the global logging state.
+     *
+     * @param env           looks up environment variables, e.g. {@code System::getenv}
+     * @param configManager the configuration to read defaults from
+     * @return the system properties to set; the log directory is absent if none is configured
+     */
+    static Map<String, String> resolveSettings(Function<String, String> env, ConfigManager configManager) {
+        Level consoleLevel = resolveLevel(env.apply(ENV_LOG_LEVEL), configManager.getLogLevel());
+        Level fileLevel = resolveLevel(env.apply(ENV_FILE_LOG_LEVEL), configManager.getFileLogLevel());
+        String logDir = firstNonBlank(env.apply(ENV_LOG_DIR), configManager.getLogDirectory());
+
+        // The root level must let through everything either appender wants to see
+        Level rootLevel = consoleLevel.isMoreSpecificThan(fileLevel) ? fileLevel : consoleLevel;
+
+        Map<String, String> settings = new LinkedHashMap<>();
+        settings.put(PROPERTY_LOG_LEVEL, consoleLevel.name());
+        settings.put(PROPERTY_FILE_LOG_LEVEL, fileLevel.name());
+        settings.put(PROPERTY_ROOT_LEVEL, rootLevel.name());
+        if (logDir != null) {
+            settings.put(PROPERTY_LOG_DIR, logDir);
+        }
+        return settings;
+    }
+
+    /**
+     * Parses a level name, preferring the override if it is a valid level.
+     *
+     * @param override   value from the environment, may be null
+     * @param configured value from the configuration
+     * @return the resolved level, INFO if neither value is valid
+     */
+    static Level resolveLevel(String override, String configured) {
+        Level level = Level.toLevel(override != null ? override.trim() : null, null);
+        if (level == null) {
+            level = Level.toLevel(configured != null ? configured.trim() : null, Level.INFO);
+        }
+        return level;
+    }
+
+    private static String firstNonBlank(String first, String second) {
+        if (first != null && !first.trim().isEmpty()) {
+            return first.trim();
+        }
+        if (second != null && !second.trim().isEmpty()) {
+            return second.trim();
+        }
+        return null;
+    }
+}
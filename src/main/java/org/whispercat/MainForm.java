@@ -4,6 +4,7 @@ import com.formdev.flatlaf.FlatClientProperties;
 import com.formdev.flatlaf.extras.FlatSVGIcon;
 import com.formdev.flatlaf.util.UIScale;
 import org.whispercat.recording.RecorderForm;
+import org.whispercat.recording.RecordingRetention;
 import org.whispercat.settings.SettingsForm;
 import org.whispercat.sidemenu.Menu;
 import org.whispercat.sidemenu.MenuAction;
@@ -30,6 +31,7 @@ public class MainForm extends JLayeredPane {
 
 
     private GlobalHotkeyListener globalHotkeyListener;
+    private LocalApiServer localApiServer;
     private ConfigManager configManager;
     public RecorderForm recorderForm;
     public SettingsForm settingsForm;
@@ -70,11 +72,57 @@ public class MainForm extends JLayeredPane {
         add(panelBody);
 
         configManager = new ConfigManager();
+        LoggingConfigurator.apply(configManager);
+        DisplayFormat.setLocale(configManager.getDisplayLocale());
         // Migrate old post-processing data to new Pipeline architecture
-        configManager.migrateOldPostProcessingData();
+        String migrationSummary = configManager.migrateOldPostProcessingData();
+        if (migrationSummary != null) {
+            SwingUtilities.invokeLater(() -> Notificationmanager.getInstance().showNotification(
+                    ToastNotification.Type.INFO, migrationSummary));
+        }
         extractNativeLibraries();
         String hotkey = configManager.getKeyCombination();
-        globalHotkeyListener = new GlobalHotkeyListener(this, hotkey, configManager.getKeySequence());
+        globalHotkeyListener = new GlobalHotkeyListener(this, hotkey, configManager.getKeySequence(),
+                configManager.getPipelineKeyCombination());
+        configManager.addSaveListener(this::reregisterHotkeys);
+        localApiServer = new LocalApiServer(configManager, () -> {
+            RecorderForm form = recorderForm;
+            if (form == null) {
+                return false;
+            }
+            SwingUtilities.invokeLater(form::toggleRecording);
+            return true;
+        });
+        localApiServer.start();
+        purgeOldRecordings();
+    }
+
+    /**
+     * Applies changed hotkeys after the configuration was saved. Runs on the saving thread.
+     */
+    private void reregisterHotkeys() {
+        try {
+            globalHotkeyListener.reregisterHotkeys(configManager.getKeyCombination(), configManager.getKeySequence(),
+                    configManager.getPipelineKeyCombination());
+        } catch (IllegalStateException e) {
+            logger.error("Failed to re-register hotkeys", e);
+            SwingUtilities.invokeLater(() -> Notificationmanager.getInstance().showNotification(
+                    ToastNotification.Type.ERROR, "Hotkey not applied: " + e.getMessage()));
+        }
+    }
+
+    /**
+     * Deletes recordings older than the configured retention period in the background.
+     */
+    private void purgeOldRecordings() {
+        int retentionDays = configManager.getRecordingRetentionDays();
+        if (retentionDays <= 0) {
+            return;
+        }
+        Thread purgeThread = new Thread(() -> RecordingRetention.purge(
+                new File(System.getProperty("java.io.tmpdir")), retentionDays), "recording-retention");
+        purgeThread.setDaemon(true);
+        purgeThread.start();
     }
 
     @Override
@@ -105,9 +153,10 @@ public class MainForm extends JLayeredPane {
             }
 
             // Update hotkeys from config AFTER saving (so new settings take effect)
-            globalHotkeyListener.setOptionsDialogOpen(false, null, null);
+            globalHotkeyListener.setOptionsDialogOpen(false, null, null, null);
             globalHotkeyListener.updateKeyCombination(configManager.getKeyCombination());
             globalHotkeyListener.updateKeySequence(configManager.getKeySequence());
+            globalHotkeyListener.updatePipelineKeyCombination(configManager.getPipelineKeyCombination());
 
             // Reuse RecorderForm instance to preserve state (transcription, logs, etc.)
             if (index == 0) {
@@ -124,7 +173,8 @@ public class MainForm extends JLayeredPane {
                         settingsForm = new SettingsForm(configManager);
                     }
                     showForm(settingsForm);
-                    globalHotkeyListener.setOptionsDialogOpen(true, settingsForm.getKeybindTextField(), settingsForm.getKeySequenceTextField());
+                    globalHotkeyListener.setOptionsDialogOpen(true, settingsForm.getKeybindTextField(), settingsForm.getKeySequenceTextField(),
+                            settingsForm.getPipelineKeyTextField());
                 } else if (subIndex == 2) {
                     showForm(new LogsForm());
                 } else {
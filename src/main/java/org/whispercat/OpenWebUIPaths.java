@@ -0,0 +1,144 @@
+package org.whispercat;
+
+import com.fasterxml.jackson.databind.ObjectMapper;
+import org.apache.http.client.config.RequestConfig;
+import org.apache.http.client.methods.CloseableHttpResponse;
+import org.apache.http.client.methods.HttpGet;
+import org.apache.http.conn.ssl.SSLConnectionSocketFactory;
+import org.apache.http.impl.client.CloseableHttpClient;
+import org.apache.http.impl.client.HttpClientBuilder;
+import org.apache.http.impl.client.HttpClients;
+import org.apache.logging.log4j.LogManager;
+import org.apache.logging.log4j.Logger;
+import org.whispercat.recording.clients.TranscriptionProviders;
+
+import java.io.IOException;
+import java.nio.charset.StandardCharsets;
+import java.util.Map;
+import java.util.concurrent.ConcurrentHashMap;
+
+/**
+ * The API paths of an Open WebUI server, which moved between versions. The version is
+ * queried once per server URL from /api/version; if it cannot be determined, the paths of
+ * current versions are used.
+ */
+public final class OpenWebUIPaths {
+    private static final Logger logger = LogManager.getLogger(OpenWebUIPaths.class);
+    private static final int PROBE_TIMEOUT_MS = 5000;
+
+    /** Paths of Open WebUI 0.3 and later. */
+    public static final OpenWebUIPaths CURRENT = new OpenWebUIPaths("0.3+",
+            "/api/chat/completions", "/api/models", "/api/v1/audio/transcriptions");
+    /** Paths of Open WebUI before 0.3, where the OpenAI and audio APIs were mounted separately. */
+    public static final OpenWebUIPaths LEGACY = new OpenWebUIPaths("<0.3",
+            "/openai/api/chat/completions", "/openai/api/models", "/audio/api/v1/transcriptions");
+
+    private static final Map<String, OpenWebUIPaths> CACHE = new ConcurrentHashMap<>();
+
+    private final String label;
+    private final String chatCompletionsPath;
+    private final String modelsPath;
+    private final String transcriptionPath;
+
+    private OpenWebUIPaths(String label, String chatCompletionsPath, String modelsPath, String transcriptionPath) {
+        this.label = label;
+        this.chatCompletionsPath = chatCompletionsPath;
+        this.modelsPath = modelsPath;
+        this.transcriptionPath = transcriptionPath;
+    }
+
+    public String getChatCompletionsPath() {
+        return chatCompletionsPath;
+    }
+
+    public String getModelsPath() {
+        return modelsPath;
+    }
+
+    public String getTranscriptionPath() {
+        return transcriptionPath;
+    }
+
+    /**
+     * Picks the paths for a server version.
+     *
+     * @param version the version reported by the server, e.g. "0.5.20"; may be null
+     * @return the legacy paths for versions before 0.3, otherwise {@link #CURRENT}
+     */
+    static OpenWebUIPaths forVersion(String version) {
+        if (version == null) {
+            return CURRENT;
+        }
+        String[] parts = version.trim().replaceFirst("^[vV]", "").split("\\.");
+        try {
+            int major = Integer.parseInt(parts[0]);
+            int minor = parts.length > 1 ? Integer.parseInt(parts[1].replaceAll("\\D.*$", "")) : 0;
+            return major == 0 && minor < 3 ? LEGACY : CURRENT;
+        } catch (NumberFormatException e) {
+            return CURRENT;
+        }
+    }
+
+    /**
+     * Gets the paths for the configured server, querying its version on first use. The
+     * result is cached per server URL; a server that cannot be reached is asked again next time.
+     *
+     * @param configManager the configuration
+     * @return the paths, {@link #CURRENT} if the version cannot be determined
+     */
+    public static OpenWebUIPaths detect(ConfigManager configManager) {
+        String baseUrl = TranscriptionProviders.get(TranscriptionProviders.OPEN_WEBUI).resolveBaseUrl(configManager);
+        if (baseUrl.isEmpty()) {
+            return CURRENT;
+        }
+        OpenWebUIPaths cached = CACHE.get(baseUrl);
+        if (cached != null) {
+            return cached;
+        }
+        try {
+            String version = queryVersion(configManager, baseUrl);
+            OpenWebUIPaths paths = forVersion(version);
+            logger.info("Open WebUI at {} reports version {}, using {} API paths", baseUrl, version, paths.label);
+            CACHE.put(baseUrl, paths);
+            return paths;
+        } catch (ApiException e) {
+            // The server answered, but has no version endpoint: keep the defaults for it
+            logger.warn("Open WebUI at {} did not report its version, using default API paths: {}", baseUrl, e.getMessage());
+            CACHE.put(baseUrl, CURRENT);
+            return CURRENT;
+        } catch (IOException e) {
+            logger.warn("Could not query the Open WebUI version at {}, using default API paths", baseUrl, e);
+            return CURRENT;
+        }
+    }
+
+    /**
+     * Forgets the detected versions, e.g. after the server was upgraded.
+     */
+    public static void clearCache() {
+        CACHE.clear();
+    }
+
+    private static String queryVersion(ConfigManager configManager, String baseUrl) throws IOException {
+        HttpClientBuilder builder = HttpClients.custom()
+                .setDefaultRequestConfig(RequestConfig.custom()
+                        .setConnectTimeout(PROBE_TIMEOUT_MS)
+                        .setConnectionRequestTimeout(PROBE_TIMEOUT_MS)
+                        .setSocketTimeout(PROBE_TIMEOUT_MS)
+                        .build());
+        SSLConnectionSocketFactory socketFactory = OpenWebUITls.socketFactory(configManager);
+        if (socketFactory != null) {
+            builder.setSSLSocketFactory(socketFactory);
+        }
+        try (CloseableHttpClient httpClient = builder.build();
+             CloseableHttpResponse response = httpClient.execute(new HttpGet(baseUrl + "/api/version"))) {
+            int statusCode = response.getStatusLine().getStatusCode();
+            String body = response.getEntity() != null
+                    ? new String(response.getEntity().getContent().readAllBytes(), StandardCharsets.UTF_8)
+                    : "";
+            HttpErrors.checkStatus("Open WebUI version API", statusCode, body);
+            String version = new ObjectMapper().readTree(body).path("version").asText("");
+            return version.isEmpty() ? null : version;
+        }
+    }
+}
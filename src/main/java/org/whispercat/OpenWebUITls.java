@@ -0,0 +1,111 @@
+package org.whispercat;
+
+import org.apache.http.conn.ssl.NoopHostnameVerifier;
+import org.apache.http.conn.ssl.SSLConnectionSocketFactory;
+import org.apache.http.ssl.SSLContextBuilder;
+import org.apache.logging.log4j.LogManager;
+import org.apache.logging.log4j.Logger;
+
+import javax.net.ssl.SSLContext;
+import javax.net.ssl.TrustManager;
+import javax.net.ssl.TrustManagerFactory;
+import javax.net.ssl.X509TrustManager;
+import java.io.File;
+import java.io.IOException;
+import java.io.InputStream;
+import java.nio.file.Files;
+import java.security.GeneralSecurityException;
+import java.security.KeyStore;
+import java.security.cert.Certificate;
+import java.security.cert.CertificateFactory;
+import java.security.cert.X509Certificate;
+import java.util.Collection;
+
+/**
+ * TLS setup for connections to an Open WebUI server. In order of precedence:
+ * <ol>
+ *     <li>a configured CA certificate (PEM) is trusted in addition to the system CAs,
+ *     with full certificate and host name verification;</li>
+ *     <li>if accepting invalid certificates is enabled, every certificate is accepted;</li>
+ *     <li>otherwise the default JVM verification is used.</li>
+ * </ol>
+ */
+public final class OpenWebUITls {
+    private static final Logger logger = LogManager.getLogger(OpenWebUITls.class);
+    private static volatile boolean insecureWarningLogged = false;
+
+    private OpenWebUITls() {
+    }
+
+    /**
+     * Creates the socket factory for Open WebUI connections.
+     *
+     * @param configManager the configuration
+     * @return the socket factory, or null to use the HTTP client's default
+     * @throws IOException if the configured CA certificate cannot be loaded
+     */
+    public static SSLConnectionSocketFactory socketFactory(ConfigManager configManager) throws IOException {
+        String caCertPath = configManager.getOpenWebUICaCertPath();
+        try {
+            if (!caCertPath.isEmpty()) {
+                SSLContext sslContext = SSLContextBuilder.create()
+                        .loadTrustMaterial(trustStoreWith(new File(caCertPath)), null)
+                        .build();
+                return new SSLConnectionSocketFactory(sslContext);
+            }
+            if (configManager.isOpenWebUIAcceptInvalidCerts()) {
+                if (!insecureWarningLogged) {
+                    insecureWarningLogged = true;
+                    logger.warn("Certificate verification is disabled for Open WebUI; any server certificate is accepted");
+                }
+                SSLContext sslContext = SSLContextBuilder.create()
+                        .loadTrustMaterial(null, (chain, authType) -> true)
+                        .build();
+                return new SSLConnectionSocketFactory(sslContext, NoopHostnameVerifier.INSTANCE);
+            }
+        } catch (GeneralSecurityException e) {
+            throw new IOException("Could not set up TLS for Open WebUI: " + e.getMessage(), e);
+        }
+        return null;
+    }
+
+    /**
+     * Builds a trust store with the JVM's default CAs plus the certificates of a PEM file.
+     *
+     * @param pemFile file with one or more PEM encoded certificates
+     * @return the trust store
+     * @throws IOException if the file cannot be read or contains no certificate
+     */
+    static KeyStore trustStoreWith(File pemFile) throws IOException, GeneralSecurityException {
+        if (!pemFile.isFile()) {
+            throw new IOException("CA certificate not found: " + pemFile.getAbsolutePath());
+        }
+        KeyStore trustStore = KeyStore.getInstance(KeyStore.getDefaultType());
+        trustStore.load(null, null);
+
+        TrustManagerFactory defaultFactory = TrustManagerFactory.getInstance(TrustManagerFactory.getDefaultAlgorithm());
+        defaultFactory.init((KeyStore) null);
+        int index = 0;
+        for (TrustManager trustManager : defaultFactory.getTrustManagers()) {
+            if (trustManager instanceof X509TrustManager) {
+                for (X509Certificate issuer : ((X509TrustManager) trustManager).getAcceptedIssuers()) {
+                    trustStore.setCertificateEntry("system-" + index++, issuer);
+                }
+            }
+        }
+
+        Collection<? extends Certificate> certificates;
+        try (InputStream in = Files.newInputStream(pemFile.toPath())) {
+            certificates = CertificateFactory.getInstance("X.509").generateCertificates(in);
+        }
+        if (certificates.isEmpty()) {
+            throw new IOException("No certificate found in " + pemFile.getAbsolutePath());
+        }
+        int customIndex = 0;
+        for (Certificate certificate : certificates) {
+            trustStore.setCertificateEntry("openwebui-" + customIndex++, certificate);
+        }
+        logger.info("Trusting {} certificate(s) from {} for Open WebUI", certificates.size(), pemFile.getName());
+        return trustStore;
+    }
+}
@@ -0,0 +1,109 @@
+package org.whispercat;
+
+import org.apache.http.conn.ConnectTimeoutException;
+import org.whispercat.recording.ProviderUnreachableException;
+
+import javax.net.ssl.SSLException;
+import java.net.ConnectException;
+import java.net.NoRouteToHostException;
+import java.net.SocketTimeoutException;
+import java.net.UnknownHostException;
+import java.util.Locale;
+
+/**
+ * Why a call to a transcription or chat provider failed, with guidance for the user.
+ * Derived from the HTTP status and error message of an {@link ApiException}, or from the
+ * type of a connection failure.
+ */
+public enum ProviderError {
+    AUTH("Authentication failed", "Check your API key in Settings."),
+    QUOTA_EXCEEDED("Quota exceeded", "Your account has no quota left. Check your plan and billing with the provider."),
+    RATE_LIMITED("Rate limited", "Too many requests. Wait a moment and try again."),
+    SERVER_ERROR("Server error", "The provider has a problem. Try again later."),
+    NETWORK("Network error", "Could not reach the server. Check the server URL and your connection."),
+    TIMEOUT("Timed out", "The server did not answer in time. Try again, use a shorter recording, or raise the request timeout in Settings."),
+    OTHER("Request failed", "See the log for details.");
+
+    private final String title;
+    private final String guidance;
+
+    ProviderError(String title, String guidance) {
+        this.title = title;
+        this.guidance = guidance;
+    }
+
+    public String getTitle() {
+        return title;
+    }
+
+    /**
+     * @return what the user can do about the error
+     */
+    public String getGuidance() {
+        return guidance;
+    }
+
+    /**
+     * @return a toast text, e.g. "Quota exceeded. Your account has no quota left. ..."
+     */
+    public String describe() {
+        return title + ". " + guidance;
+    }
+
+    /**
+     * Classifies an exception, looking through its causes for an {@link ApiException} or a
+     * connection failure.
+     *
+     * @param error the exception thrown by a provider call
+     * @return the error class, {@link #OTHER} if none applies
+     */
+    public static ProviderError from(Throwable error) {
+        for (Throwable t = error; t != null; t = t.getCause()) {
+            if (t instanceof ApiException) {
+                ApiException api = (ApiException) t;
+                return classify(api.getStatusCode(), api.getApiMessage());
+            }
+            if (t instanceof SocketTimeoutException || t instanceof ConnectTimeoutException) {
+                return TIMEOUT;
+            }
+            if (t instanceof ProviderUnreachableException || t instanceof ConnectException
+                    || t instanceof NoRouteToHostException || t instanceof UnknownHostException
+                    || t instanceof SSLException) {
+                return NETWORK;
+            }
+            if (t.getCause() == t) {
+                break;
+            }
+        }
+        return OTHER;
+    }
+
+    /**
+     * Classifies an error response. The message is needed to tell an exhausted quota from
+     * a temporary rate limit, as OpenAI answers both with HTTP 429.
+     *
+     * @param statusCode the HTTP status code
+     * @param message    the error message reported by the API, may be null
+     * @return the error class, {@link #OTHER} if none applies
+     */
+    public static ProviderError classify(int statusCode, String message) {
+        String text = message != null ? message.toLowerCase(Locale.ROOT) : "";
+        if (statusCode == 402 || text.contains("quota") || text.contains("billing")
+                || text.contains("insufficient credit")) {
+            return QUOTA_EXCEEDED;
+        }
+        if (statusCode == 401 || statusCode == 403 || text.contains("api key")) {
+            return AUTH;
+        }
+        if (statusCode == 429) {
+            return RATE_LIMITED;
+        }
+        if (statusCode == 408 || statusCode == 504) {
+            return TIMEOUT;
+        }
+        if (statusCode >= 500 && statusCode < 600) {
+            return SERVER_ERROR;
+        }
+        return OTHER;
+    }
+}
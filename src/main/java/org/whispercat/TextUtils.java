@@ -0,0 +1,49 @@
+package org.whispercat;
+
+/**
+ * Text helpers that count characters as Unicode code points, so truncation never splits
+ * an emoji or other character outside the Basic Multilingual Plane into a broken half.
+ */
+public class TextUtils {
+
+    public static final String ELLIPSIS = "…";
+
+    private TextUtils() {
+    }
+
+    /**
+     * Shortens text to at most {@code maxChars} characters for display, ending in an
+     * ellipsis if anything was cut. The ellipsis counts towards the limit.
+     *
+     * @param text     the text, may be null
+     * @param maxChars maximum length in characters (code points)
+     * @return the text, shortened if necessary; null if the text was null
+     */
+    public static String truncateChars(String text, int maxChars) {
+        if (text == null || text.codePointCount(0, text.length()) <= maxChars) {
+            return text;
+        }
+        if (maxChars <= 0) {
+            return "";
+        }
+        return cutAt(text, maxChars - 1) + ELLIPSIS;
+    }
+
+    /**
+     * Cuts text to at most {@code maxChars} characters without adding an ellipsis.
+     *
+     * @param text     the text, may be null
+     * @param maxChars maximum length in characters (code points); 0 or less means no limit
+     * @return the text, shortened if necessary
+     */
+    public static String limitChars(String text, int maxChars) {
+        if (text == null || maxChars <= 0 || text.codePointCount(0, text.length()) <= maxChars) {
+            return text;
+        }
+        return cutAt(text, maxChars);
+    }
+
+    private static String cutAt(String text, int codePoints) {
+        return text.substring(0, text.offsetByCodePoints(0, codePoints));
+    }
+}
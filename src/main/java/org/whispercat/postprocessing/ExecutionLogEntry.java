@@ -0,0 +1,67 @@
+package org.whispercat.postprocessing;
+
+/**
+ * Record of one unit executed during a pipeline run.
+ * Entries are kept for the most recent run, including the units completed before a cancellation.
+ */
+public class ExecutionLogEntry {
+    private final String unitName;
+    private final String unitType;
+    private boolean truncated;
+    private int originalOutputChars;
+    private TokenUsage tokenUsage;
+
+    public ExecutionLogEntry(String unitName, String unitType) {
+        this.unitName = unitName;
+        this.unitType = unitType;
+    }
+
+    public String getUnitName() {
+        return unitName;
+    }
+
+    public String getUnitType() {
+        return unitType;
+    }
+
+    /**
+     * @return true if the unit's output exceeded its limit and was cut off
+     */
+    public boolean isTruncated() {
+        return truncated;
+    }
+
+    /**
+     * @return the length of the output before truncation, 0 if it was not truncated
+     */
+    public int getOriginalOutputChars() {
+        return originalOutputChars;
+    }
+
+    /**
+     * Flags the entry as truncated.
+     *
+     * @param originalOutputChars The length of the output before it was cut off
+     */
+    public void markTruncated(int originalOutputChars) {
+        this.truncated = true;
+        this.originalOutputChars = originalOutputChars;
+    }
+
+    /**
+     * @return the tokens the unit's API calls used, or null if no provider reported usage
+     */
+    public TokenUsage getTokenUsage() {
+        return tokenUsage;
+    }
+
+    /**
+     * Adds the usage of one API call made for the unit. For units combined into one chained
+     * call, the usage is recorded on the last unit of the chain.
+     *
+     * @param usage The tokens reported by the provider
+     */
+    public void addTokenUsage(TokenUsage usage) {
+        this.tokenUsage = TokenUsage.sum(tokenUsage, usage);
+    }
+}
@@ -0,0 +1,117 @@
+package org.whispercat.postprocessing;
+
+import org.whispercat.ConfigManager;
+import org.whispercat.postprocessing.clients.OpenWebUIProcessClient;
+import org.whispercat.recording.OpenAIClient;
+
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.concurrent.Callable;
+import java.util.concurrent.ExecutionException;
+import java.util.concurrent.ExecutorService;
+import java.util.concurrent.Executors;
+import java.util.concurrent.Future;
+
+/**
+ * Fetches the chat model lists of several providers at once, with a bounded number of
+ * requests in flight.
+ */
+public class ModelFetcher {
+
+    private static final org.apache.logging.log4j.Logger logger =
+            org.apache.logging.log4j.LogManager.getLogger(ModelFetcher.class);
+
+    /**
+     * The models of one provider, or the reason they could not be fetched.
+     */
+    public static class Result {
+        private final String provider;
+        private final List<String> models;
+        private final String error;
+
+        Result(String provider, List<String> models, String error) {
+            this.provider = provider;
+            this.models = models;
+            this.error = error;
+        }
+
+        public String getProvider() {
+            return provider;
+        }
+
+        /**
+         * @return the models, empty if fetching failed
+         */
+        public List<String> getModels() {
+            return models;
+        }
+
+        /**
+         * @return the error message, or null if fetching succeeded
+         */
+        public String getError() {
+            return error;
+        }
+
+        public boolean isSuccess() {
+            return error == null;
+        }
+    }
+
+    /**
+     * Fetches the models of every provider that has connection settings.
+     *
+     * @param configManager the configuration
+     * @return the results keyed by provider, in the order "OpenAI", "Open WebUI"
+     */
+    public static Map<String, Result> fetchConfigured(ConfigManager configManager) {
+        Map<String, Callable<List<String>>> fetchers = new LinkedHashMap<>();
+        if (configManager.getApiKey() != null && !configManager.getApiKey().toString().trim().isEmpty()) {
+            fetchers.put("OpenAI", () -> new OpenAIClient(configManager).fetchChatModels());
+        }
+        if (!configManager.getOpenWebUIServerUrl().trim().isEmpty()) {
+            fetchers.put("Open WebUI", () -> new OpenWebUIProcessClient(configManager).fetchModels().getModelNames());
+        }
+        return fetchAll(fetchers, configManager.getMaxConcurrentModelFetches());
+    }
+
+    /**
+     * Runs the fetchers concurrently and collects their results.
+     *
+     * @param fetchers      model fetchers keyed by provider
+     * @param maxConcurrent how many fetchers may run at the same time (at least 1)
+     * @return the results keyed by provider, in the order of {@code fetchers}
+     */
+    static Map<String, Result> fetchAll(Map<String, Callable<List<String>>> fetchers, int maxConcurrent) {
+        Map<String, Result> results = new LinkedHashMap<>();
+        if (fetchers.isEmpty()) {
+            return results;
+        }
+        ExecutorService executor = Executors.newFixedThreadPool(Math.max(1, Math.min(maxConcurrent, fetchers.size())));
+        try {
+            Map<String, Future<List<String>>> futures = new LinkedHashMap<>();
+            fetchers.forEach((provider, fetcher) -> futures.put(provider, executor.submit(fetcher)));
+            for (Map.Entry<String, Future<List<String>>> entry : futures.entrySet()) {
+                String provider = entry.getKey();
+                try {
+                    List<String> models = entry.getValue().get();
+                    results.put(provider, new Result(provider,
+                            models != null ? new ArrayList<>(models) : Collections.emptyList(), null));
+                } catch (ExecutionException e) {
+                    Throwable cause = e.getCause() != null ? e.getCause() : e;
+                    logger.warn("Fetching models from {} failed", provider, cause);
+                    results.put(provider, new Result(provider, Collections.emptyList(), cause.getMessage()));
+                } catch (InterruptedException e) {
+                    Thread.currentThread().interrupt();
+                    results.put(provider, new Result(provider, Collections.emptyList(), "Interrupted"));
+                }
+            }
+        } finally {
+            executor.shutdownNow();
+        }
+        return results;
+    }
+}
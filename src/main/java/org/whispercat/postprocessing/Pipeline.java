@@ -12,6 +12,7 @@ public class Pipeline {
     public String title;            // Display name for this pipeline
     public String description;      // Optional description
     public boolean enabled = true;  // Whether this entire pipeline is active
+    public boolean favorite = false; // Pinned to the top of the pipeline list
     public List<PipelineUnitReference> unitReferences = new ArrayList<>();
 
     public Pipeline() {
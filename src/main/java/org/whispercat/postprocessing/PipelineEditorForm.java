@@ -27,7 +27,10 @@ public class PipelineEditorForm extends JPanel {
     private final JPanel unitsContainer;
     private final JScrollPane scrollPane;
     private final Border defaultTextFieldBorder;
+    private final JLabel executionPlanLabel;
+    private final PostProcessingService postProcessingService;
     private String currentUUID;
+    private boolean favorite;
     private List<ProcessingUnit> availableUnits;
 
     private static final org.apache.logging.log4j.Logger logger = org.apache.logging.log4j.LogManager.getLogger(PipelineEditorForm.class);
@@ -36,6 +39,7 @@ public class PipelineEditorForm extends JPanel {
         this.configManager = configManager;
         this.mainForm = mainForm;
         this.availableUnits = configManager.getProcessingUnits();
+        this.postProcessingService = new PostProcessingService(configManager);
 
         setBorder(BorderFactory.createEmptyBorder(60, 20, 10, 10));
         setLayout(new BorderLayout());
@@ -89,6 +93,15 @@ public class PipelineEditorForm extends JPanel {
         enabledPanel.add(enabledCheckBox);
         topPanel.add(enabledPanel);
 
+        // Execution plan summary
+        JPanel planPanel = new JPanel(new FlowLayout(FlowLayout.LEFT, 0, 0));
+        planPanel.setAlignmentX(LEFT_ALIGNMENT);
+        executionPlanLabel = new JLabel();
+        executionPlanLabel.setForeground(Color.GRAY);
+        planPanel.add(executionPlanLabel);
+        topPanel.add(Box.createVerticalStrut(5));
+        topPanel.add(planPanel);
+
         add(topPanel, BorderLayout.NORTH);
 
         // Container for unit references
@@ -109,6 +122,12 @@ public class PipelineEditorForm extends JPanel {
         bottomPanel.add(doneButton);
         bottomPanel.add(Box.createHorizontalStrut(10));
 
+        JButton saveButton = new JButton("Save");
+        saveButton.setToolTipText("Save and keep editing");
+        saveButton.addActionListener(e -> savePipeline());
+        bottomPanel.add(saveButton);
+        bottomPanel.add(Box.createHorizontalStrut(10));
+
         JButton createUnitButton = new JButton("Create New Unit");
         createUnitButton.setToolTipText("Create a new unit and add it to this pipeline");
         createUnitButton.addActionListener(e -> showCreateUnitDialog());
@@ -126,10 +145,63 @@ public class PipelineEditorForm extends JPanel {
         if (existingPipeline != null) {
             loadPipelineData(existingPipeline);
         }
+        updateExecutionPlan();
+    }
+
+    /**
+     * Shows how the optimizer would group the enabled units in their current order,
+     * with the individual steps in the tooltip.
+     */
+    private void updateExecutionPlan() {
+        Pipeline draft = new Pipeline();
+        draft.title = titleField.getText().trim();
+        draft.unitReferences = collectUnitReferences();
+        List<PlannedBatch> plan = postProcessingService.plan(draft);
+        if (plan.isEmpty()) {
+            executionPlanLabel.setText("Execution plan: no enabled units");
+            executionPlanLabel.setToolTipText(null);
+            return;
+        }
+        executionPlanLabel.setText(PostProcessingService.describePlan(plan));
+        StringBuilder tooltip = new StringBuilder("<html>");
+        for (int i = 0; i < plan.size(); i++) {
+            PlannedBatch batch = plan.get(i);
+            tooltip.append(i + 1).append(". ");
+            if (batch.isApiCall()) {
+                tooltip.append(batch.getProvider());
+                if (batch.getModel() != null && !batch.getModel().isEmpty()) {
+                    tooltip.append(" - ").append(batch.getModel());
+                }
+                if (batch.isOptimizable()) {
+                    tooltip.append(" (chained)");
+                }
+            } else {
+                tooltip.append(batch.getType());
+            }
+            tooltip.append(": ").append(escapeHtml(String.join(" \u2192 ", batch.getUnitNames()))).append("<br>");
+        }
+        tooltip.append("</html>");
+        executionPlanLabel.setToolTipText(tooltip.toString());
+    }
+
+    private static String escapeHtml(String text) {
+        return text.replace("&", "&amp;").replace("<", "&lt;").replace(">", "&gt;");
+    }
+
+    private List<PipelineUnitReference> collectUnitReferences() {
+        List<PipelineUnitReference> references = new ArrayList<>();
+        for (Component comp : unitsContainer.getComponents()) {
+            if (comp instanceof UnitReferencePanel) {
+                UnitReferencePanel panel = (UnitReferencePanel) comp;
+                references.add(new PipelineUnitReference(panel.getUnitUuid(), panel.isEnabled()));
+            }
+        }
+        return references;
     }
 
     private void loadPipelineData(Pipeline pipeline) {
         currentUUID = pipeline.uuid;
+        favorite = pipeline.favorite;
         titleField.setText(pipeline.title != null ? pipeline.title : "");
         descriptionField.setText(pipeline.description != null ? pipeline.description : "");
         enabledCheckBox.setSelected(pipeline.enabled);
@@ -188,6 +260,7 @@ public class PipelineEditorForm extends JPanel {
                 } else if ("Text Replacement".equals(unit.type)) {
                     unit.textToReplace = getTextToReplaceFieldText();
                     unit.replacementText = getReplacementTextFieldText();
+                    unit.useRegex = isUseRegexSelected();
                 }
 
                 // Save the unit
@@ -252,6 +325,7 @@ public class PipelineEditorForm extends JPanel {
         unitsContainer.add(panel);
         unitsContainer.revalidate();
         unitsContainer.repaint();
+        updateExecutionPlan();
 
         // Scroll to the new panel
         SwingUtilities.invokeLater(() -> {
@@ -261,10 +335,37 @@ public class PipelineEditorForm extends JPanel {
     }
 
     private void saveAndReturn() {
-        if (!validateData()) {
+        if (!savePipeline()) {
             return;
         }
 
+        // Go back to pipeline list
+        mainForm.setSelectedMenu(2, 1);
+        mainForm.showForm(new PipelineListForm(configManager, mainForm));
+    }
+
+    /**
+     * Saves the pipeline and keeps the editor open. The first save assigns the UUID,
+     * so later saves update the same pipeline.
+     *
+     * @return true if the pipeline was valid and saved
+     */
+    private boolean savePipeline() {
+        if (!validateData()) {
+            return false;
+        }
+
+        persistPipeline();
+        Notificationmanager.getInstance().showNotification(ToastNotification.Type.SUCCESS, "Pipeline saved!");
+        return true;
+    }
+
+    /**
+     * Writes the editor's current state to the configuration without validating it.
+     *
+     * @return the saved pipeline
+     */
+    Pipeline persistPipeline() {
         Pipeline pipeline = new Pipeline();
 
         // Generate UUID if new pipeline
@@ -276,25 +377,12 @@ public class PipelineEditorForm extends JPanel {
         pipeline.title = titleField.getText().trim();
         pipeline.description = descriptionField.getText().trim();
         pipeline.enabled = enabledCheckBox.isSelected();
+        pipeline.favorite = favorite;
 
-        // Collect unit references
-        pipeline.unitReferences = new ArrayList<>();
-        for (Component comp : unitsContainer.getComponents()) {
-            if (comp instanceof UnitReferencePanel) {
-                UnitReferencePanel panel = (UnitReferencePanel) comp;
-                PipelineUnitReference ref = new PipelineUnitReference();
-                ref.unitUuid = panel.getUnitUuid();
-                ref.enabled = panel.isEnabled();
-                pipeline.unitReferences.add(ref);
-            }
-        }
+        pipeline.unitReferences = collectUnitReferences();
 
         configManager.savePipeline(pipeline);
-        Notificationmanager.getInstance().showNotification(ToastNotification.Type.SUCCESS, "Pipeline saved!");
-
-        // Go back to pipeline list
-        mainForm.setSelectedMenu(2, 1);
-        mainForm.showForm(new PipelineListForm(configManager, mainForm));
+        return pipeline;
     }
 
     private boolean validateData() {
@@ -376,6 +464,7 @@ public class PipelineEditorForm extends JPanel {
             // Enabled checkbox on top
             enabledCheckBox = new JCheckBox("Enabled", enabled);
             enabledCheckBox.setToolTipText("Enable or disable this unit in the pipeline");
+            enabledCheckBox.addActionListener(e -> updateExecutionPlan());
             controlPanel.add(enabledCheckBox, BorderLayout.NORTH);
 
             // Button panel
@@ -393,6 +482,7 @@ public class PipelineEditorForm extends JPanel {
                     unitsContainer.add(UnitReferencePanel.this, index - 1);
                     unitsContainer.revalidate();
                     unitsContainer.repaint();
+                    updateExecutionPlan();
                     scrollToComponent(UnitReferencePanel.this);
                 }
             });
@@ -408,6 +498,7 @@ public class PipelineEditorForm extends JPanel {
                     unitsContainer.add(UnitReferencePanel.this, index + 1);
                     unitsContainer.revalidate();
                     unitsContainer.repaint();
+                    updateExecutionPlan();
                     scrollToComponent(UnitReferencePanel.this);
                 }
             });
@@ -421,6 +512,7 @@ public class PipelineEditorForm extends JPanel {
                 unitsContainer.remove(UnitReferencePanel.this);
                 unitsContainer.revalidate();
                 unitsContainer.repaint();
+                updateExecutionPlan();
             });
 
             buttonPanel.add(upButton);
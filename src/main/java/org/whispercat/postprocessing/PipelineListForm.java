@@ -2,11 +2,13 @@ package org.whispercat.postprocessing;
 
 import com.formdev.flatlaf.extras.FlatSVGIcon;
 import org.whispercat.ConfigManager;
+import org.whispercat.ConfirmDialog;
 import org.whispercat.MainForm;
 
 import javax.swing.*;
 import java.awt.*;
 import java.awt.event.ActionEvent;
+import java.util.Comparator;
 import java.util.List;
 
 /**
@@ -46,7 +48,7 @@ public class PipelineListForm extends JPanel {
 
     /**
      * Refreshes the list by reading pipelines from the ConfigManager
-     * and rebuilding the UI.
+     * and rebuilding the UI. Favorites are shown in a pinned section above the rest.
      */
     public void refreshList() {
         listContainer.removeAll();
@@ -55,93 +57,146 @@ public class PipelineListForm extends JPanel {
         List<Pipeline> pipelines = configManager.getPipelines();
         logger.info("Pipelines List: {}", pipelines);
 
-        // Sort the list alphabetically by title (case-insensitive)
-        pipelines.sort((a, b) -> {
-            String titleA = (a.title != null && !a.title.trim().isEmpty()) ? a.title : "No Title";
-            String titleB = (b.title != null && !b.title.trim().isEmpty()) ? b.title : "No Title";
-            return titleA.compareToIgnoreCase(titleB);
-        });
+        sortForDisplay(pipelines);
+
+        boolean hasFavorites = pipelines.stream().anyMatch(p -> p.favorite);
+        boolean othersHeaderAdded = false;
+        if (hasFavorites) {
+            listContainer.add(createSectionLabel("★ Favorites"));
+        }
 
-        // For each pipeline, create a panel showing its title, description, enabled status, and buttons.
         for (Pipeline pipeline : pipelines) {
-            String title = (pipeline.title != null && !pipeline.title.trim().isEmpty()) ? pipeline.title : "No Title";
-            String description = (pipeline.description != null && !pipeline.description.trim().isEmpty()) ? pipeline.description : "No Description";
-
-            // Create an item panel with a titled border using the title.
-            JPanel itemPanel = new JPanel(new BorderLayout());
-            itemPanel.setBorder(BorderFactory.createTitledBorder(title));
-
-            // Create an info panel for the description and status.
-            JPanel infoPanel = new JPanel();
-            infoPanel.setLayout(new BoxLayout(infoPanel, BoxLayout.Y_AXIS));
-
-            JLabel descriptionLabel = new JLabel("Description: " + description);
-            descriptionLabel.setAlignmentX(Component.LEFT_ALIGNMENT);
-            infoPanel.add(descriptionLabel);
-
-            // Add enabled/disabled indicator
-            String enabledText = pipeline.enabled ? "Enabled" : "Disabled";
-            Color enabledColor = pipeline.enabled ? new Color(0, 128, 0) : Color.GRAY;
-            JLabel enabledLabel = new JLabel("Status: " + enabledText);
-            enabledLabel.setAlignmentX(Component.LEFT_ALIGNMENT);
-            enabledLabel.setForeground(enabledColor);
-            infoPanel.add(enabledLabel);
-
-            // Show number of units in pipeline
-            int unitCount = (pipeline.unitReferences != null) ? pipeline.unitReferences.size() : 0;
-            JLabel unitsLabel = new JLabel("Units: " + unitCount);
-            unitsLabel.setAlignmentX(Component.LEFT_ALIGNMENT);
-            infoPanel.add(unitsLabel);
-
-            // Add info panel to the center.
-            itemPanel.add(infoPanel, BorderLayout.CENTER);
-
-            // Create a button panel on the right.
-            JPanel buttonPanel = new JPanel();
-            buttonPanel.setLayout(new BoxLayout(buttonPanel, BoxLayout.Y_AXIS));
-
-            // Edit button.
-            JButton editButton = new JButton();
-            editButton.setIcon(new FlatSVGIcon("icon/svg/edit.svg", 16, 16));
-            editButton.setToolTipText("Edit this Pipeline");
-            editButton.addActionListener((ActionEvent e) -> {
-                mainForm.setSelectedMenu(2, 2); // Adjust menu index as needed
-                mainForm.showForm(new PipelineEditorForm(configManager, mainForm, pipeline));
-            });
-
-            // Delete button.
-            JButton deleteButton = new JButton();
-            deleteButton.setIcon(new FlatSVGIcon("icon/svg/trash.svg", 16, 16));
-            deleteButton.setToolTipText("Delete this Pipeline");
-            deleteButton.addActionListener((ActionEvent e) -> {
-                int confirm = JOptionPane.showConfirmDialog(
-                        this,
-                        "Are you sure you want to delete this pipeline?",
-                        "Confirm Delete",
-                        JOptionPane.YES_NO_OPTION,
-                        JOptionPane.WARNING_MESSAGE
-                );
-                if (confirm == JOptionPane.YES_OPTION) {
-                    configManager.deletePipeline(pipeline.uuid);
-                    refreshList();
-                }
-            });
+            if (hasFavorites && !pipeline.favorite && !othersHeaderAdded) {
+                listContainer.add(Box.createVerticalStrut(10));
+                listContainer.add(createSectionLabel("All Pipelines"));
+                othersHeaderAdded = true;
+            }
+            listContainer.add(createPipelineItem(pipeline));
+        }
 
-            // Add buttons to the button panel with vertical spacing.
-            buttonPanel.add(editButton);
-            buttonPanel.add(Box.createVerticalStrut(5));
-            buttonPanel.add(deleteButton);
+        listContainer.revalidate();
+        listContainer.repaint();
+    }
 
-            // Add the button panel to the right side.
-            itemPanel.add(buttonPanel, BorderLayout.EAST);
+    /**
+     * Sorts pipelines for display: favorites first, then alphabetically by title (case-insensitive).
+     *
+     * @param pipelines the pipelines to sort in place
+     */
+    static void sortForDisplay(List<Pipeline> pipelines) {
+        pipelines.sort(Comparator
+                .comparing((Pipeline p) -> !p.favorite)
+                .thenComparing(PipelineListForm::displayTitle, String.CASE_INSENSITIVE_ORDER));
+    }
 
-            // Adjust the height: set maximum size to its preferred height.
-            itemPanel.setMaximumSize(new Dimension(Integer.MAX_VALUE, itemPanel.getPreferredSize().height));
+    private static String displayTitle(Pipeline pipeline) {
+        return (pipeline.title != null && !pipeline.title.trim().isEmpty()) ? pipeline.title : "No Title";
+    }
 
-            listContainer.add(itemPanel);
-        }
+    private JComponent createSectionLabel(String text) {
+        JLabel label = new JLabel(text);
+        label.setFont(label.getFont().deriveFont(Font.BOLD));
+        label.setBorder(BorderFactory.createEmptyBorder(0, 2, 4, 0));
+        JPanel panel = new JPanel(new FlowLayout(FlowLayout.LEFT, 0, 0));
+        panel.add(label);
+        panel.setMaximumSize(new Dimension(Integer.MAX_VALUE, panel.getPreferredSize().height));
+        return panel;
+    }
 
-        listContainer.revalidate();
-        listContainer.repaint();
+    /**
+     * Creates a panel showing the pipeline's title, description, enabled status, and buttons.
+     */
+    private JPanel createPipelineItem(Pipeline pipeline) {
+        String title = displayTitle(pipeline);
+        String description = (pipeline.description != null && !pipeline.description.trim().isEmpty()) ? pipeline.description : "No Description";
+
+        // Create an item panel with a titled border using the title.
+        JPanel itemPanel = new JPanel(new BorderLayout());
+        itemPanel.setBorder(BorderFactory.createTitledBorder(title));
+
+        // Create an info panel for the description and status.
+        JPanel infoPanel = new JPanel();
+        infoPanel.setLayout(new BoxLayout(infoPanel, BoxLayout.Y_AXIS));
+
+        JLabel descriptionLabel = new JLabel("Description: " + description);
+        descriptionLabel.setAlignmentX(Component.LEFT_ALIGNMENT);
+        infoPanel.add(descriptionLabel);
+
+        // Add enabled/disabled indicator
+        String enabledText = pipeline.enabled ? "Enabled" : "Disabled";
+        Color enabledColor = pipeline.enabled ? new Color(0, 128, 0) : Color.GRAY;
+        JLabel enabledLabel = new JLabel("Status: " + enabledText);
+        enabledLabel.setAlignmentX(Component.LEFT_ALIGNMENT);
+        enabledLabel.setForeground(enabledColor);
+        infoPanel.add(enabledLabel);
+
+        // Show number of units in pipeline
+        int unitCount = (pipeline.unitReferences != null) ? pipeline.unitReferences.size() : 0;
+        JLabel unitsLabel = new JLabel("Units: " + unitCount);
+        unitsLabel.setAlignmentX(Component.LEFT_ALIGNMENT);
+        infoPanel.add(unitsLabel);
+
+        // Add info panel to the center.
+        itemPanel.add(infoPanel, BorderLayout.CENTER);
+
+        // Create a button panel on the right.
+        JPanel buttonPanel = new JPanel();
+        buttonPanel.setLayout(new BoxLayout(buttonPanel, BoxLayout.Y_AXIS));
+
+        // Favorite toggle.
+        JButton favoriteButton = new JButton(pipeline.favorite ? "★" : "☆");
+        favoriteButton.setToolTipText(pipeline.favorite ? "Unpin this Pipeline" : "Pin this Pipeline to the top");
+        favoriteButton.addActionListener((ActionEvent e) -> {
+            pipeline.favorite = !pipeline.favorite;
+            configManager.savePipeline(pipeline);
+            refreshList();
+        });
+
+        // Edit button.
+        JButton editButton = new JButton();
+        editButton.setIcon(new FlatSVGIcon("icon/svg/edit.svg", 16, 16));
+        editButton.setToolTipText("Edit this Pipeline");
+        editButton.addActionListener((ActionEvent e) -> {
+            mainForm.setSelectedMenu(2, 2); // Adjust menu index as needed
+            mainForm.showForm(new PipelineEditorForm(configManager, mainForm, pipeline));
+        });
+
+        // Duplicate button.
+        JButton duplicateButton = new JButton();
+        duplicateButton.setIcon(new FlatSVGIcon("icon/svg/copy.svg", 16, 16));
+        duplicateButton.setToolTipText("Duplicate this Pipeline");
+        duplicateButton.addActionListener((ActionEvent e) -> {
+            configManager.duplicatePipeline(pipeline.uuid);
+            refreshList();
+        });
+
+        // Delete button.
+        JButton deleteButton = new JButton();
+        deleteButton.setIcon(new FlatSVGIcon("icon/svg/trash.svg", 16, 16));
+        deleteButton.setToolTipText("Delete this Pipeline");
+        deleteButton.addActionListener((ActionEvent e) -> ConfirmDialog.confirm(this,
+                "Delete Pipeline",
+                "Delete pipeline '" + pipeline.title + "'? This cannot be undone.",
+                "Delete",
+                () -> {
+                    configManager.deletePipeline(pipeline.uuid);
+                    refreshList();
+                }));
+
+        // Add buttons to the button panel with vertical spacing.
+        buttonPanel.add(favoriteButton);
+        buttonPanel.add(Box.createVerticalStrut(5));
+        buttonPanel.add(editButton);
+        buttonPanel.add(Box.createVerticalStrut(5));
+        buttonPanel.add(duplicateButton);
+        buttonPanel.add(Box.createVerticalStrut(5));
+        buttonPanel.add(deleteButton);
+
+        // Add the button panel to the right side.
+        itemPanel.add(buttonPanel, BorderLayout.EAST);
+
+        // Adjust the height: set maximum size to its preferred height.
+        itemPanel.setMaximumSize(new Dimension(Integer.MAX_VALUE, itemPanel.getPreferredSize().height));
+        return itemPanel;
     }
 }
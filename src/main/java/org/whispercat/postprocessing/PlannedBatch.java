@@ -0,0 +1,58 @@
+package org.whispercat.postprocessing;
+
+import java.util.Collections;
+import java.util.List;
+
+/**
+ * Read-only description of one step of a pipeline's execution plan, as produced by
+ * {@link PostProcessingService#plan(Pipeline)}. A step is either a single unit or an
+ * optimized chain of prompt units that share one API call.
+ */
+public class PlannedBatch {
+    private final List<String> unitNames;
+    private final String type;
+    private final String provider;
+    private final String model;
+    private final boolean optimizable;
+
+    public PlannedBatch(List<String> unitNames, String type, String provider, String model, boolean optimizable) {
+        this.unitNames = Collections.unmodifiableList(unitNames);
+        this.type = type;
+        this.provider = provider;
+        this.model = model;
+        this.optimizable = optimizable;
+    }
+
+    public List<String> getUnitNames() {
+        return unitNames;
+    }
+
+    /**
+     * @return the unit type of the step ("Prompt" or "Text Replacement")
+     */
+    public String getType() {
+        return type;
+    }
+
+    public String getProvider() {
+        return provider;
+    }
+
+    public String getModel() {
+        return model;
+    }
+
+    /**
+     * @return true if two or more prompt units are chained into one API call
+     */
+    public boolean isOptimizable() {
+        return optimizable;
+    }
+
+    /**
+     * @return true if the step calls a chat API, false for local text replacements
+     */
+    public boolean isApiCall() {
+        return "Prompt".equalsIgnoreCase(type);
+    }
+}
@@ -2,7 +2,6 @@ package org.whispercat.postprocessing;
 
 import com.formdev.flatlaf.extras.FlatSVGIcon;
 import org.whispercat.*;
-import org.whispercat.postprocessing.clients.OpenWebUIModelsResponse;
 import org.whispercat.postprocessing.clients.OpenWebUIProcessClient;
 import org.whispercat.recording.RecorderForm;
 
@@ -135,16 +134,8 @@ public class PostProcessingForm extends JPanel {
      * Anschließend werden alle ProcessingStepPanels, die gerade "Open WebUI" als Provider haben, aktualisiert.
      */
     private void loadOpenWebUIModels() {
-        SwingWorker<List<String>, Void> worker = new SwingWorker<List<String>, Void>() {
-            @Override
-            protected List<String> doInBackground() throws Exception {
-                OpenWebUIModelsResponse modelsResponse = openWebUIProcessClient.fetchModels();
-                return modelsResponse.getModelNames();
-            }
-            @Override
-            protected void done() {
-                try {
-                    List<String> models = get();
+        BackgroundTask.run(() -> openWebUIProcessClient.fetchModels().getModelNames(),
+                models -> {
                     openWebUIModelNames.clear();
                     openWebUIModelNames.addAll(models);
                     // Aktualisiere alle ProcessingStepPanels, die "Open WebUI" als Provider nutzen.
@@ -156,14 +147,12 @@ public class PostProcessingForm extends JPanel {
                             }
                         }
                     }
-                } catch (Exception ex) {
+                },
+                ex -> {
                     logger.error("Error loading Open WebUI models: ", ex);
                     Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR,
                             "Error loading Open WebUI models. See logs.");
-                }
-            }
-        };
-        worker.execute();
+                });
     }
 
     /**
@@ -1,24 +1,42 @@
 package org.whispercat.postprocessing;
 
+import com.fasterxml.jackson.databind.ObjectMapper;
+import org.whispercat.CancellationToken;
 import org.whispercat.ConfigManager;
 import org.whispercat.ConsoleLogger;
+import org.whispercat.TextUtils;
 import org.whispercat.Notificationmanager;
+import org.whispercat.ProviderError;
 import org.whispercat.ToastNotification;
+import org.whispercat.postprocessing.clients.ChatCompletion;
 import org.whispercat.postprocessing.clients.OpenWebUIProcessClient;
 import org.whispercat.recording.OpenAIClient;
 
 import java.io.IOException;
 import java.util.ArrayList;
 import java.util.List;
+import java.util.Objects;
+import java.util.concurrent.Semaphore;
 
 public class PostProcessingService {
 
     private static final org.apache.logging.log4j.Logger logger = org.apache.logging.log4j.LogManager.getLogger(PostProcessingService.class);
+    private static final ObjectMapper JSON_MAPPER = new ObjectMapper();
 
     // OpenAIClient instance used to make synchronous calls to the API.
     private OpenAIClient openAIClient;
     private OpenWebUIProcessClient openWebUIClient;
     private ConfigManager configManager;
+    private CancellationToken cancellationToken = new CancellationToken();
+    // Number of units executed by the most recent applyPipeline call
+    private int unitsRun = 0;
+    // Units executed by the most recent applyPipeline call, in execution order
+    private final List<ExecutionLogEntry> executionLog = new ArrayList<>();
+    private TokenUsage tokenUsage;
+
+    // Shared across all service instances so parallel pipeline runs respect the same limit
+    private static Semaphore chatCallLimiter;
+    private static int chatCallLimit;
 
     /**
      * Constructs the PostProcessingService with the given ConfigManager.
@@ -27,9 +45,16 @@ public class PostProcessingService {
      * @param configManager The ConfigManager that contains configuration settings.
      */
     public PostProcessingService(ConfigManager configManager) {
+        this(configManager, new OpenAIClient(configManager), new OpenWebUIProcessClient(configManager));
+    }
+
+    /**
+     * Constructs the PostProcessingService with the given chat clients, e.g. stubs in tests.
+     */
+    PostProcessingService(ConfigManager configManager, OpenAIClient openAIClient, OpenWebUIProcessClient openWebUIClient) {
         this.configManager = configManager;
-        this.openAIClient = new OpenAIClient(configManager);
-        this.openWebUIClient = new OpenWebUIProcessClient(configManager);
+        this.openAIClient = openAIClient;
+        this.openWebUIClient = openWebUIClient;
     }
 
     /**
@@ -135,11 +160,11 @@ public class PostProcessingService {
             // Synchronous call using the provided OpenAIClient.
             if(step.provider.equalsIgnoreCase("OpenAI")){
                 logger.info("Processing using OpenAI API.");
-                String result = openAIClient.processText(step.systemPrompt, fullUserPrompt, step.model);
+                String result = callChat("OpenAI", step.systemPrompt, fullUserPrompt, step.model);
                 return result;
             } else if(step.provider.equalsIgnoreCase("Open WebUI")){
                 logger.info("Processing using Open WebUI.");
-                String result = openWebUIClient.processText(step.systemPrompt, fullUserPrompt, step.model);
+                String result = callChat("Open WebUI", step.systemPrompt, fullUserPrompt, step.model);
                 return result;
             }
         } catch (IOException e) {
@@ -157,7 +182,24 @@ public class PostProcessingService {
      * @return The processed text after all enabled units.
      */
     public String applyPipeline(String originalText, Pipeline pipeline) {
+        return applyPipeline(originalText, pipeline, new CancellationToken());
+    }
+
+    /**
+     * Executes a pipeline that can be stopped through the given token. Cancellation is checked
+     * between units; a unit that is already running is allowed to finish.
+     *
+     * @param originalText The initial transcribed text.
+     * @param pipeline     The pipeline configuration to execute.
+     * @param token        Token used to request cancellation.
+     * @return The processed text after all enabled units, or the partial result if cancelled.
+     */
+    public String applyPipeline(String originalText, Pipeline pipeline, CancellationToken token) {
         ConsoleLogger console = ConsoleLogger.getInstance();
+        this.cancellationToken = token;
+        this.unitsRun = 0;
+        this.executionLog.clear();
+        this.tokenUsage = null;
 
         if (!pipeline.enabled) {
             logger.info("Pipeline '{}' is disabled, skipping execution", pipeline.title);
@@ -195,10 +237,19 @@ public class PostProcessingService {
         // Execute each batch
         String processedText = originalText;
         for (int i = 0; i < batches.size(); i++) {
+            if (cancellationToken.isCancelled()) {
+                logCancellation(pipeline, i, batches.size());
+                return processedText;
+            }
             UnitBatch batch = batches.get(i);
             processedText = executeBatch(processedText, batch, i + 1, batches.size());
         }
 
+        if (cancellationToken.isCancelled()) {
+            logCancellation(pipeline, batches.size(), batches.size());
+            return processedText;
+        }
+
         // Log pipeline completion
         console.separator();
         console.logSuccess("Pipeline completed: " + pipeline.title);
@@ -207,6 +258,253 @@ public class PostProcessingService {
         return processedText;
     }
 
+    /**
+     * Returns how a pipeline would be executed without running it: the batches the
+     * optimizer groups its enabled units into, in execution order.
+     *
+     * @param pipeline The pipeline to analyze
+     * @return One entry per API call or text replacement
+     */
+    public List<PlannedBatch> plan(Pipeline pipeline) {
+        List<PlannedBatch> plan = new ArrayList<>();
+        for (UnitBatch batch : groupUnitsIntoBatches(pipeline)) {
+            List<String> names = new ArrayList<>();
+            for (ProcessingUnit unit : batch.units) {
+                names.add(unit.name);
+            }
+            ProcessingUnit first = batch.units.get(0);
+            plan.add(new PlannedBatch(names, first.type, first.provider, first.model, batch.isOptimizable));
+        }
+        return plan;
+    }
+
+    /**
+     * Summarizes an execution plan for display,
+     * e.g. "Execution plan: 2 API calls (1 optimized chain of 3)".
+     *
+     * @param plan The plan returned by {@link #plan(Pipeline)}
+     * @return A one-line description
+     */
+    public static String describePlan(List<PlannedBatch> plan) {
+        int apiCalls = 0;
+        int localSteps = 0;
+        List<Integer> chainSizes = new ArrayList<>();
+        for (PlannedBatch batch : plan) {
+            if (batch.isApiCall()) {
+                apiCalls++;
+                if (batch.isOptimizable()) {
+                    chainSizes.add(batch.getUnitNames().size());
+                }
+            } else {
+                localSteps++;
+            }
+        }
+        StringBuilder sb = new StringBuilder("Execution plan: ");
+        sb.append(apiCalls).append(apiCalls == 1 ? " API call" : " API calls");
+        if (!chainSizes.isEmpty()) {
+            sb.append(" (").append(chainSizes.size())
+                    .append(chainSizes.size() == 1 ? " optimized chain of " : " optimized chains of ");
+            for (int i = 0; i < chainSizes.size(); i++) {
+                if (i > 0) {
+                    sb.append(i == chainSizes.size() - 1 ? " and " : ", ");
+                }
+                sb.append(chainSizes.get(i));
+            }
+            sb.append(")");
+        }
+        if (localSteps > 0) {
+            sb.append(", ").append(localSteps).append(localSteps == 1 ? " text replacement" : " text replacements");
+        }
+        return sb.toString();
+    }
+
+    /**
+     * Checks whether the providers used by a pipeline's enabled units are configured,
+     * so the UI can refuse to run it instead of failing at run time.
+     *
+     * @param pipeline      The pipeline to check
+     * @param configManager The configuration with API keys and server URLs
+     * @return A message describing what is missing, or null if the pipeline can run
+     */
+    public static String checkPipelineReady(Pipeline pipeline, ConfigManager configManager) {
+        if (pipeline == null || pipeline.unitReferences == null) {
+            return null;
+        }
+        for (PipelineUnitReference ref : pipeline.unitReferences) {
+            if (!ref.enabled) {
+                continue;
+            }
+            ProcessingUnit unit = configManager.getProcessingUnitByUuid(ref.unitUuid);
+            if (unit == null || !"Prompt".equalsIgnoreCase(unit.type)) {
+                continue;
+            }
+            if ("OpenAI".equalsIgnoreCase(unit.provider)) {
+                CharSequence apiKey = configManager.getApiKey();
+                boolean customEndpoint = !configManager.getOpenAIChatBaseUrl().isEmpty();
+                if (!customEndpoint && (apiKey == null || apiKey.toString().trim().isEmpty())) {
+                    return "OpenAI API key is not set (Settings)";
+                }
+            } else if ("Open WebUI".equalsIgnoreCase(unit.provider)) {
+                if (configManager.getOpenWebUIServerUrl().trim().isEmpty()) {
+                    return "Open WebUI server URL is not set (Settings)";
+                }
+            }
+        }
+        return null;
+    }
+
+    /**
+     * Applies a text replacement unit. Regular expressions run with the configured timeout;
+     * if they time out or are invalid, the error is logged and the text is left unchanged.
+     *
+     * @param text The input text
+     * @param unit The text replacement unit
+     * @return The text after the replacement
+     */
+    private String applyTextReplacement(String text, ProcessingUnit unit) {
+        ConsoleLogger console = ConsoleLogger.getInstance();
+        String replacement = unit.replacementText != null ? unit.replacementText : "";
+        if (!unit.useRegex) {
+            String result = text.replace(unit.textToReplace, replacement);
+            console.logSuccess("Text replacement completed");
+            return result;
+        }
+        try {
+            String result = RegexReplacer.replaceAll(text, unit.textToReplace, replacement, configManager.getRegexTimeoutMs());
+            console.logSuccess("Text replacement completed");
+            return result;
+        } catch (RegexTimeoutException e) {
+            logger.error("Regex replacement in unit '{}' timed out", unit.name, e);
+            console.logError(e.getMessage() + " - unit skipped");
+            Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR,
+                    "Regex in '" + unit.name + "' took too long and was skipped.");
+        } catch (IllegalArgumentException | IndexOutOfBoundsException e) {
+            logger.error("Invalid regex replacement in unit '{}'", unit.name, e);
+            console.logError("Invalid regular expression in '" + unit.name + "': " + e.getMessage());
+        }
+        return text;
+    }
+
+    /**
+     * Truncates a unit's output to its configured maximum length and logs a warning
+     * so a runaway response does not inflate the input of the following units.
+     *
+     * @param output The unit's output
+     * @param unit   The unit that produced it
+     * @param entry  The unit's execution log entry, flagged if the output is truncated
+     * @return The output, truncated if it exceeded the unit's limit
+     */
+    private String enforceOutputLimit(String output, ProcessingUnit unit, ExecutionLogEntry entry) {
+        String truncated = truncateOutput(output, unit.maxOutputChars);
+        if (truncated.length() != output.length()) {
+            entry.markTruncated(output.length());
+            logger.warn("Output of unit '{}' truncated from {} to {} characters", unit.name, output.length(), unit.maxOutputChars);
+            ConsoleLogger.getInstance().logError("[truncated] Output of '" + unit.name + "' exceeded " + unit.maxOutputChars +
+                    " characters (" + output.length() + ")");
+        }
+        return truncated;
+    }
+
+    /**
+     * Checks that the output of a unit in JSON mode is valid JSON.
+     *
+     * @param output The unit output
+     * @param unit   The unit that produced it
+     * @return The unchanged output
+     * @throws IOException if the unit is in JSON mode and the output does not parse
+     */
+    private String requireJsonIfRequested(String output, ProcessingUnit unit) throws IOException {
+        if (unit.jsonMode && !isValidJson(output)) {
+            Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR,
+                    "'" + unit.name + "' did not return valid JSON");
+            throw new IOException("Output of '" + unit.name + "' is not valid JSON");
+        }
+        return output;
+    }
+
+    /**
+     * @param text The text to check
+     * @return true if the text parses as a JSON value
+     */
+    static boolean isValidJson(String text) {
+        if (text == null || text.trim().isEmpty()) {
+            return false;
+        }
+        try {
+            JSON_MAPPER.readTree(text);
+            return true;
+        } catch (IOException e) {
+            return false;
+        }
+    }
+
+    /**
+     * Cuts text down to the given number of characters.
+     *
+     * @param text     The text to limit
+     * @param maxChars The maximum length; 0 or less means no limit
+     * @return The text, shortened if necessary
+     */
+    static String truncateOutput(String text, int maxChars) {
+        return TextUtils.limitChars(text, maxChars);
+    }
+
+    /**
+     * @return the number of units executed by the most recent {@link #applyPipeline} call
+     */
+    public int getLastUnitsRun() {
+        return unitsRun;
+    }
+
+    /**
+     * @return the units executed by the most recent {@link #applyPipeline} call, in execution order
+     */
+    public List<ExecutionLogEntry> getLastExecutionLog() {
+        return new ArrayList<>(executionLog);
+    }
+
+    private ExecutionLogEntry addExecutionLogEntry(ProcessingUnit unit) {
+        ExecutionLogEntry entry = new ExecutionLogEntry(unit.name, unit.type);
+        executionLog.add(entry);
+        return entry;
+    }
+
+    /**
+     * @return the tokens used by the most recent {@link #applyPipeline} call, or null if no
+     * provider reported usage
+     */
+    public synchronized TokenUsage getLastTokenUsage() {
+        return tokenUsage;
+    }
+
+    private synchronized void addTokenUsage(TokenUsage usage) {
+        tokenUsage = TokenUsage.sum(tokenUsage, usage);
+    }
+
+    /**
+     * Checks whether a pipeline run did nothing, i.e. no unit was executed and the
+     * text came back unchanged (disabled pipeline, no enabled units or only missing units).
+     *
+     * @param input    the text passed to the pipeline
+     * @param output   the text returned by the pipeline
+     * @param unitsRun the number of units executed
+     * @return true if the run should be reported as having no enabled units
+     */
+    public static boolean isEmptyRun(String input, String output, int unitsRun) {
+        return unitsRun == 0 && Objects.equals(input, output);
+    }
+
+    /**
+     * Logs that the pipeline was stopped before completing all batches.
+     */
+    private void logCancellation(Pipeline pipeline, int completedBatches, int totalBatches) {
+        ConsoleLogger console = ConsoleLogger.getInstance();
+        logger.info("Pipeline '{}' cancelled after {}/{} step(s)", pipeline.title, completedBatches, totalBatches);
+        console.separator();
+        console.log("Pipeline stopped: " + pipeline.title + " (" + completedBatches + "/" + totalBatches + " steps completed)");
+        console.separator();
+    }
+
     /**
      * Generates a descriptive message for the current processing unit.
      */
@@ -222,7 +520,7 @@ public class PostProcessingService {
             }
             sb.append(")");
         } else if ("Text Replacement".equalsIgnoreCase(unit.type)) {
-            sb.append(" (Replace: '").append(unit.textToReplace).append("')");
+            sb.append(unit.useRegex ? " (Replace regex: '" : " (Replace: '").append(unit.textToReplace).append("')");
         }
 
         return sb.toString();
@@ -235,17 +533,20 @@ public class PostProcessingService {
      * @param unit      The processing unit configuration.
      * @param currentUnit Current unit number.
      * @param enabledUnitCount Total enabled units.
+     * @param entry     The unit's execution log entry.
      * @return The processed text from the API response.
      */
-    private String performPromptProcessingWithUnit(String inputText, ProcessingUnit unit, int currentUnit, int enabledUnitCount) {
+    private String performPromptProcessingWithUnit(String inputText, ProcessingUnit unit, int currentUnit, int enabledUnitCount,
+                                                   ExecutionLogEntry entry) {
         ConsoleLogger console = ConsoleLogger.getInstance();
         logger.info("Pre-processing input with unit: {}", unit.name);
 
         // Combine the user prompt with the input text.
         String fullUserPrompt = unit.userPrompt.replaceAll("\\{\\{input}}", inputText);
+        String model = resolveModel(unit.provider, unit.model);
 
         // Log prompts to console
-        console.log("  Provider: " + unit.provider + " | Model: " + unit.model);
+        console.log("  Provider: " + unit.provider + " | Model: " + model);
         if (unit.systemPrompt != null && !unit.systemPrompt.trim().isEmpty()) {
             console.logPrompt("  System Prompt", unit.systemPrompt);
         }
@@ -254,22 +555,113 @@ public class PostProcessingService {
         try {
             if (unit.provider.equalsIgnoreCase("OpenAI")) {
                 console.log("  Calling OpenAI API...");
-                String result = openAIClient.processText(unit.systemPrompt, fullUserPrompt, unit.model);
+                String result = callChat("OpenAI", unit.systemPrompt, fullUserPrompt, model, unit.jsonMode, entry);
                 console.logSuccess("API call completed");
-                return result;
+                return enforceOutputLimit(requireJsonIfRequested(result, unit), unit, entry);
             } else if (unit.provider.equalsIgnoreCase("Open WebUI")) {
                 console.log("  Calling Open WebUI...");
-                String result = openWebUIClient.processText(unit.systemPrompt, fullUserPrompt, unit.model);
+                String result = callChat("Open WebUI", unit.systemPrompt, fullUserPrompt, model, unit.jsonMode, entry);
                 console.logSuccess("API call completed");
-                return result;
+                return enforceOutputLimit(requireJsonIfRequested(result, unit), unit, entry);
             }
         } catch (IOException e) {
             logger.error("Error processing with unit: {}", unit.name, e);
             console.logError("API call failed: " + e.getMessage());
+            ProviderError error = ProviderError.from(e);
+            if (error != ProviderError.OTHER) {
+                console.log("  " + error.getGuidance());
+            }
         }
         return inputText;
     }
 
+    /**
+     * Sends a chat request to the given provider, waiting for a free slot if the configured
+     * number of concurrent chat calls is already in flight.
+     *
+     * @param provider     "OpenAI" or "Open WebUI".
+     * @param systemPrompt The system prompt.
+     * @param userPrompt   The user prompt.
+     * @param model        The model to use.
+     * @return The response text.
+     * @throws IOException if the call fails or is interrupted while waiting.
+     */
+    private String callChat(String provider, String systemPrompt, String userPrompt, String model) throws IOException {
+        return callChat(provider, systemPrompt, userPrompt, model, false, null);
+    }
+
+    /**
+     * Sends a chat request like {@link #callChat(String, String, String, String)}, optionally
+     * requesting a JSON object response. The reported token usage is added to the run's total
+     * and to the unit's execution log entry.
+     *
+     * @param entry The execution log entry of the unit making the call, or null.
+     */
+    private String callChat(String provider, String systemPrompt, String userPrompt, String model,
+                            boolean jsonMode, ExecutionLogEntry entry) throws IOException {
+        Semaphore limiter = getChatCallLimiter(configManager.getMaxConcurrentChatCalls());
+        try {
+            limiter.acquire();
+        } catch (InterruptedException e) {
+            Thread.currentThread().interrupt();
+            throw new IOException("Interrupted while waiting for a free API call slot", e);
+        }
+        try {
+            ChatCompletion completion = "Open WebUI".equalsIgnoreCase(provider)
+                    ? openWebUIClient.complete(systemPrompt, userPrompt, model, jsonMode)
+                    : openAIClient.complete(systemPrompt, userPrompt, model, jsonMode);
+            if (completion.getUsage() != null) {
+                ConsoleLogger.getInstance().log("  Tokens: " + completion.getUsage());
+                addTokenUsage(completion.getUsage());
+                if (entry != null) {
+                    entry.addTokenUsage(completion.getUsage());
+                }
+            }
+            return completion.getText();
+        } finally {
+            limiter.release();
+        }
+    }
+
+    /**
+     * Returns the shared limiter, recreating it if the configured limit has changed.
+     * Calls already holding a permit release it on the instance they acquired it from.
+     */
+    static synchronized Semaphore getChatCallLimiter(int maxConcurrentCalls) {
+        int limit = Math.max(1, maxConcurrentCalls);
+        if (chatCallLimiter == null || chatCallLimit != limit) {
+            chatCallLimiter = new Semaphore(limit, true);
+            chatCallLimit = limit;
+        }
+        return chatCallLimiter;
+    }
+
+    /**
+     * Returns the unit's model, or the provider's default model if the unit has none configured.
+     * For OpenAI the first configured custom model is used. Open WebUI has no default model,
+     * so a blank model is passed through and the server will report the error.
+     *
+     * @param provider The unit's provider.
+     * @param model    The unit's configured model, may be blank.
+     * @return The model to use for the request.
+     */
+    private String resolveModel(String provider, String model) {
+        if (model != null && !model.trim().isEmpty()) {
+            return model;
+        }
+        if ("OpenAI".equalsIgnoreCase(provider)) {
+            List<String> models = configManager.getCustomOpenAIModels();
+            if (!models.isEmpty()) {
+                String fallback = models.get(0);
+                logger.warn("No model configured for {} unit, using default: {}", provider, fallback);
+                ConsoleLogger.getInstance().log("  No model configured, using default: " + fallback);
+                return fallback;
+            }
+        }
+        logger.warn("No model configured for {} unit and no default available", provider);
+        return model;
+    }
+
     /**
      * Inner class representing a batch of units that can be optimized into a single API call.
      */
@@ -286,6 +678,15 @@ public class PostProcessingService {
             if (units.isEmpty()) {
                 return true; // First unit in batch
             }
+            if (!"Prompt".equalsIgnoreCase(units.get(0).type)) {
+                return false; // A text replacement always runs on its own
+            }
+            if (units.get(units.size() - 1).maxOutputChars > 0) {
+                return false; // Output limit must be applied before the next unit runs
+            }
+            if (unit.jsonMode || units.get(units.size() - 1).jsonMode) {
+                return false; // JSON output is requested and validated per unit
+            }
             // Check if same provider and model
             return provider.equals(unit.provider) && model.equals(unit.model);
         }
@@ -356,8 +757,8 @@ public class PostProcessingService {
         StringBuilder systemPrompt = new StringBuilder();
         StringBuilder userPrompt = new StringBuilder();
 
-        // System prompt: explain the chaining and list step contexts
-        systemPrompt.append("Execute transformations sequentially. Each step's output becomes the next step's input.\n\n");
+        // System prompt: explain the chaining (configurable meta prompt) and list step contexts
+        systemPrompt.append(configManager.getChainedMetaPrompt().trim()).append("\n\n");
         systemPrompt.append("Step Contexts:\n");
 
         for (int i = 0; i < batch.units.size(); i++) {
@@ -438,13 +839,19 @@ public class PostProcessingService {
             console.logPrompt("  Compiled User Prompt", userPrompt);
             console.log("");
             console.log("  Executing optimized chain...");
+            unitsRun += batch.units.size();
+            ExecutionLogEntry lastEntry = null;
+            for (ProcessingUnit unit : batch.units) {
+                lastEntry = addExecutionLogEntry(unit);
+            }
 
             try {
                 String result;
+                String model = resolveModel(batch.provider, batch.model);
                 if (batch.provider.equalsIgnoreCase("OpenAI")) {
-                    result = openAIClient.processText(systemPrompt, userPrompt, batch.model);
+                    result = callChat("OpenAI", systemPrompt, userPrompt, model, false, lastEntry);
                 } else if (batch.provider.equalsIgnoreCase("Open WebUI")) {
-                    result = openWebUIClient.processText(systemPrompt, userPrompt, batch.model);
+                    result = callChat("Open WebUI", systemPrompt, userPrompt, model, false, lastEntry);
                 } else {
                     console.logError("Unknown provider: " + batch.provider);
                     return inputText;
@@ -453,7 +860,7 @@ public class PostProcessingService {
                 console.logSuccess("✓ Optimized chain completed - " + savedCalls + " API call" +
                                  (savedCalls > 1 ? "s" : "") + " saved!");
                 console.separator();
-                return result;
+                return enforceOutputLimit(result, batch.units.get(batch.units.size() - 1), lastEntry);
             } catch (IOException e) {
                 logger.error("Error executing chained prompt", e);
                 console.logError("Chained API call failed: " + e.getMessage());
@@ -465,17 +872,22 @@ public class PostProcessingService {
             // Not optimizable: execute units individually
             String processedText = inputText;
             for (int i = 0; i < batch.units.size(); i++) {
+                if (cancellationToken.isCancelled()) {
+                    break;
+                }
                 ProcessingUnit unit = batch.units.get(i);
 
                 // Log unit start
                 console.logStep(unit.name + " (" + unit.type + ")", batchNumber, totalBatches);
+                unitsRun++;
+                ExecutionLogEntry entry = addExecutionLogEntry(unit);
 
                 if ("Prompt".equalsIgnoreCase(unit.type)) {
-                    processedText = performPromptProcessingWithUnit(processedText, unit, batchNumber, totalBatches);
+                    processedText = performPromptProcessingWithUnit(processedText, unit, batchNumber, totalBatches, entry);
                 } else if ("Text Replacement".equalsIgnoreCase(unit.type)) {
-                    console.log("  Replacing: '" + unit.textToReplace + "' → '" + unit.replacementText + "'");
-                    processedText = processedText.replace(unit.textToReplace, unit.replacementText);
-                    console.logSuccess("Text replacement completed");
+                    console.log("  Replacing" + (unit.useRegex ? " (regex)" : "") + ": '"
+                            + unit.textToReplace + "' → '" + unit.replacementText + "'");
+                    processedText = applyTextReplacement(processedText, unit);
                 }
             }
             return processedText;
@@ -15,10 +15,13 @@ public class ProcessingUnit {
     public String model;
     public String systemPrompt;
     public String userPrompt;
+    public int maxOutputChars = 0;  // Output is truncated beyond this length; 0 = no limit
+    public boolean jsonMode = false; // Request a JSON object response and reject other output
 
     // For Text Replacement:
     public String textToReplace;
     public String replacementText;
+    public boolean useRegex = false; // Treat textToReplace as a regular expression
 
     // TODO: Text to Speech in future
 }
@@ -0,0 +1,84 @@
+package org.whispercat.postprocessing;
+
+import java.util.regex.Matcher;
+import java.util.regex.Pattern;
+
+/**
+ * Runs user-entered regular expression replacements with a deadline.
+ *
+ * Java's regex engine cannot be interrupted, so the input is wrapped in a CharSequence
+ * that checks the deadline on every character access. A backtracking pattern reads the
+ * input constantly, so it is aborted shortly after the deadline instead of hanging.
+ */
+public class RegexReplacer {
+
+    private RegexReplacer() {
+    }
+
+    /**
+     * Replaces all matches of the pattern.
+     *
+     * @param input       the text to process
+     * @param regex       the regular expression
+     * @param replacement the replacement; may reference groups as $1
+     * @param timeoutMs   maximum time for the replacement; 0 or less means no limit
+     * @return the text with all matches replaced
+     * @throws RegexTimeoutException if the replacement exceeds the timeout
+     * @throws java.util.regex.PatternSyntaxException if the pattern is invalid
+     * @throws IllegalArgumentException if the replacement references a missing named group
+     * @throws IndexOutOfBoundsException if the replacement references a missing numbered group
+     */
+    public static String replaceAll(String input, String regex, String replacement, long timeoutMs)
+            throws RegexTimeoutException {
+        Pattern pattern = Pattern.compile(regex);
+        if (timeoutMs <= 0) {
+            return pattern.matcher(input).replaceAll(replacement);
+        }
+        long deadline = System.nanoTime() + timeoutMs * 1_000_000L;
+        Matcher matcher = pattern.matcher(new DeadlineCharSequence(input, deadline));
+        try {
+            return matcher.replaceAll(replacement);
+        } catch (DeadlineExceeded e) {
+            throw new RegexTimeoutException(regex, timeoutMs);
+        }
+    }
+
+    private static class DeadlineExceeded extends RuntimeException {
+        DeadlineExceeded() {
+            super(null, null, false, false);
+        }
+    }
+
+    private static class DeadlineCharSequence implements CharSequence {
+        private final CharSequence inner;
+        private final long deadline;
+
+        DeadlineCharSequence(CharSequence inner, long deadline) {
+            this.inner = inner;
+            this.deadline = deadline;
+        }
+
+        @Override
+        public char charAt(int index) {
+            if (System.nanoTime() > deadline) {
+                throw new DeadlineExceeded();
+            }
+            return inner.charAt(index);
+        }
+
+        @Override
+        public int length() {
+            return inner.length();
+        }
+
+        @Override
+        public CharSequence subSequence(int start, int end) {
+            return new DeadlineCharSequence(inner.subSequence(start, end), deadline);
+        }
+
+        @Override
+        public String toString() {
+            return inner.toString();
+        }
+    }
+}
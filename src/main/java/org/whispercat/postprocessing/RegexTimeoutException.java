@@ -0,0 +1,18 @@
+package org.whispercat.postprocessing;
+
+/**
+ * Thrown when a regular expression replacement takes longer than the configured timeout,
+ * typically because of a catastrophically backtracking pattern such as {@code (a+)+$}.
+ */
+public class RegexTimeoutException extends Exception {
+    private final long timeoutMs;
+
+    public RegexTimeoutException(String pattern, long timeoutMs) {
+        super("Regular expression '" + pattern + "' did not finish within " + timeoutMs + " ms");
+        this.timeoutMs = timeoutMs;
+    }
+
+    public long getTimeoutMs() {
+        return timeoutMs;
+    }
+}
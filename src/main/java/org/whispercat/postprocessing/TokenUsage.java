@@ -0,0 +1,63 @@
+package org.whispercat.postprocessing;
+
+import com.fasterxml.jackson.databind.JsonNode;
+
+/**
+ * Tokens consumed by chat completion calls, as reported in the {@code usage} object of the
+ * response. Used to monitor the cost of pipelines.
+ */
+public final class TokenUsage {
+    private final int promptTokens;
+    private final int completionTokens;
+
+    public TokenUsage(int promptTokens, int completionTokens) {
+        this.promptTokens = promptTokens;
+        this.completionTokens = completionTokens;
+    }
+
+    /**
+     * Reads the {@code usage} object of a chat completion response.
+     *
+     * @param response the parsed response body
+     * @return the usage, or null if the provider did not report it
+     */
+    public static TokenUsage fromResponse(JsonNode response) {
+        JsonNode usage = response.path("usage");
+        if (!usage.isObject()) {
+            return null;
+        }
+        return new TokenUsage(usage.path("prompt_tokens").asInt(0), usage.path("completion_tokens").asInt(0));
+    }
+
+    /**
+     * Adds two usages, either of which may be null.
+     *
+     * @return the sum, or null if both are null
+     */
+    public static TokenUsage sum(TokenUsage a, TokenUsage b) {
+        if (a == null) {
+            return b;
+        }
+        if (b == null) {
+            return a;
+        }
+        return new TokenUsage(a.promptTokens + b.promptTokens, a.completionTokens + b.completionTokens);
+    }
+
+    public int getPromptTokens() {
+        return promptTokens;
+    }
+
+    public int getCompletionTokens() {
+        return completionTokens;
+    }
+
+    public int getTotalTokens() {
+        return promptTokens + completionTokens;
+    }
+
+    @Override
+    public String toString() {
+        return promptTokens + " prompt + " + completionTokens + " completion tokens";
+    }
+}
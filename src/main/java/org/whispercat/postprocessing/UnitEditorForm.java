@@ -1,11 +1,12 @@
 package org.whispercat.postprocessing;
 
+import org.whispercat.BackgroundTask;
 import org.whispercat.ConfigManager;
 import org.whispercat.MainForm;
 import org.whispercat.Notificationmanager;
 import org.whispercat.ToastNotification;
-import org.whispercat.postprocessing.clients.OpenWebUIModelsResponse;
 import org.whispercat.postprocessing.clients.OpenWebUIProcessClient;
+import org.whispercat.recording.OpenAIClient;
 
 import javax.swing.*;
 import javax.swing.border.Border;
@@ -31,17 +32,24 @@ public class UnitEditorForm extends JPanel {
     private JPanel replacementPanel;
     private JTextArea systemPromptArea;
     private JTextArea userPromptArea;
+    private JSpinner maxOutputCharsSpinner;
+    private JCheckBox jsonModeCheckBox;
     private Font defaultFont = new JTextArea().getFont();
     private JComboBox<String> providerCombo;
     private JComboBox<String> modelCombo;
     private JTextField textToReplaceField;
     private JTextField replacementTextField;
+    private JCheckBox useRegexCheckBox;
     private Border defaultTextAreaBorder;
     private Border defaultTextFieldBorder;
     private Border defaultReplacementFieldBorder;
     private String currentUUID;
     private String storedModel = "";
     private List<String> openWebUIModelNames = new ArrayList<>();
+    private final List<String> openAIChatModelNames = new ArrayList<>();
+    private JButton fetchModelsButton;
+    private JButton fetchAllModelsButton;
+    private JLabel fetchStatusLabel;
     private OpenWebUIProcessClient openWebUIProcessClient;
 
     private final String SYSTEM_PROMPT_PLACEHOLDER = "Enter system instructions, e.g., 'You are a helpful assistant.'";
@@ -133,6 +141,26 @@ public class UnitEditorForm extends JPanel {
         List<String> customModels = configManager.getCustomOpenAIModels();
         modelCombo = new JComboBox<>(customModels.toArray(new String[0]));
         providerPanel.add(modelCombo);
+        providerPanel.add(Box.createHorizontalStrut(5));
+
+        // Fetch chat-capable models from the selected provider
+        fetchModelsButton = new JButton("Fetch models");
+        fetchModelsButton.setToolTipText("Load available chat models from the selected provider");
+        fetchModelsButton.addActionListener(e -> {
+            if ("Open WebUI".equals(providerCombo.getSelectedItem())) {
+                loadOpenWebUIModels();
+            } else {
+                loadOpenAIChatModels();
+            }
+        });
+        providerPanel.add(fetchModelsButton);
+        providerPanel.add(Box.createHorizontalStrut(5));
+
+        // Fetch the models of all configured providers at once
+        fetchAllModelsButton = new JButton("Fetch all");
+        fetchAllModelsButton.setToolTipText("Load the chat models of every configured provider");
+        fetchAllModelsButton.addActionListener(e -> loadAllProviderModels());
+        providerPanel.add(fetchAllModelsButton);
 
         // Provider change listener
         providerCombo.addItemListener(e -> {
@@ -142,6 +170,15 @@ public class UnitEditorForm extends JPanel {
         });
 
         promptPanel.add(providerPanel);
+
+        // Per-provider result of "Fetch all"
+        JPanel fetchStatusPanel = new JPanel(new FlowLayout(FlowLayout.LEFT, 0, 0));
+        fetchStatusLabel = new JLabel(" ");
+        fetchStatusLabel.setFont(new Font("Dialog", Font.ITALIC, 10));
+        fetchStatusLabel.setForeground(Color.GRAY);
+        fetchStatusPanel.add(fetchStatusLabel);
+        fetchStatusPanel.setMaximumSize(new Dimension(Integer.MAX_VALUE, fetchStatusPanel.getPreferredSize().height));
+        promptPanel.add(fetchStatusPanel);
         promptPanel.add(Box.createVerticalStrut(10));
 
         // System Prompt
@@ -171,6 +208,35 @@ public class UnitEditorForm extends JPanel {
         userScrollPane.setMaximumSize(new Dimension(Integer.MAX_VALUE, userScrollPane.getPreferredSize().height));
         userPanel.add(userScrollPane, BorderLayout.CENTER);
         promptPanel.add(userPanel);
+        promptPanel.add(Box.createVerticalStrut(10));
+
+        // Output length limit
+        JPanel limitPanel = new JPanel(new FlowLayout(FlowLayout.LEFT, 0, 0));
+        limitPanel.add(new JLabel("Max output chars:"));
+        limitPanel.add(Box.createHorizontalStrut(5));
+        maxOutputCharsSpinner = new JSpinner(new SpinnerNumberModel(0, 0, 1_000_000, 100));
+        limitPanel.add(maxOutputCharsSpinner);
+        limitPanel.add(Box.createHorizontalStrut(10));
+        JLabel limitHint = new JLabel("0 = no limit. Longer output is truncated before the next unit.");
+        limitHint.setFont(new Font("Dialog", Font.ITALIC, 10));
+        limitHint.setForeground(Color.GRAY);
+        limitPanel.add(limitHint);
+        limitPanel.setMaximumSize(new Dimension(Integer.MAX_VALUE, limitPanel.getPreferredSize().height));
+        promptPanel.add(limitPanel);
+        promptPanel.add(Box.createVerticalStrut(10));
+
+        // JSON output
+        JPanel jsonModePanel = new JPanel(new FlowLayout(FlowLayout.LEFT, 0, 0));
+        jsonModeCheckBox = new JCheckBox("JSON output");
+        jsonModeCheckBox.setToolTipText("Request a JSON object response; output that is not valid JSON fails the unit");
+        jsonModePanel.add(jsonModeCheckBox);
+        jsonModePanel.add(Box.createHorizontalStrut(10));
+        JLabel jsonModeHint = new JLabel("The prompts must mention JSON for OpenAI to accept the request.");
+        jsonModeHint.setFont(new Font("Dialog", Font.ITALIC, 10));
+        jsonModeHint.setForeground(Color.GRAY);
+        jsonModePanel.add(jsonModeHint);
+        jsonModePanel.setMaximumSize(new Dimension(Integer.MAX_VALUE, jsonModePanel.getPreferredSize().height));
+        promptPanel.add(jsonModePanel);
 
         centerPanel.add(promptPanel);
 
@@ -184,6 +250,10 @@ public class UnitEditorForm extends JPanel {
         replacementPanel.add(new JLabel("Replacement Text:"));
         replacementTextField = new JTextField(10);
         replacementPanel.add(replacementTextField);
+        replacementPanel.add(Box.createHorizontalStrut(10));
+        useRegexCheckBox = new JCheckBox("Regular expression");
+        useRegexCheckBox.setToolTipText("Match 'Text to Replace' as a Java regex; use $1 in the replacement for groups");
+        replacementPanel.add(useRegexCheckBox);
         centerPanel.add(replacementPanel);
 
         add(centerPanel, BorderLayout.CENTER);
@@ -256,9 +326,12 @@ public class UnitEditorForm extends JPanel {
                 userPromptArea.setText(USER_PROMPT_PLACEHOLDER);
                 userPromptArea.setFont(defaultFont.deriveFont(Font.ITALIC));
             }
+            maxOutputCharsSpinner.setValue(Math.max(0, unit.maxOutputChars));
+            jsonModeCheckBox.setSelected(unit.jsonMode);
         } else if ("Text Replacement".equals(unit.type)) {
             textToReplaceField.setText(unit.textToReplace);
             replacementTextField.setText(unit.replacementText);
+            useRegexCheckBox.setSelected(unit.useRegex);
         }
 
         updateFieldsVisibility();
@@ -296,9 +369,12 @@ public class UnitEditorForm extends JPanel {
                 userText = "";
             }
             unit.userPrompt = userText;
+            unit.maxOutputChars = (Integer) maxOutputCharsSpinner.getValue();
+            unit.jsonMode = jsonModeCheckBox.isSelected();
         } else if ("Text Replacement".equals(unit.type)) {
             unit.textToReplace = textToReplaceField.getText();
             unit.replacementText = replacementTextField.getText();
+            unit.useRegex = useRegexCheckBox.isSelected();
         }
 
         configManager.saveProcessingUnit(unit);
@@ -328,6 +404,12 @@ public class UnitEditorForm extends JPanel {
                 Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR,
                         "For 'Text Replacement', the 'Text to Replace' field must be filled.");
                 return false;
+            } else if (useRegexCheckBox.isSelected() && !isValidRegex(textToReplaceField.getText())) {
+                textToReplaceField.setBorder(BorderFactory.createLineBorder(Color.RED));
+                textToReplaceField.requestFocusInWindow();
+                Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR,
+                        "'Text to Replace' is not a valid regular expression.");
+                return false;
             } else {
                 textToReplaceField.setBorder(defaultReplacementFieldBorder);
             }
@@ -403,6 +485,12 @@ public class UnitEditorForm extends JPanel {
             for (String m : customModels) {
                 modelCombo.addItem(m);
             }
+            // Append fetched chat models that are not already configured
+            for (String m : openAIChatModelNames) {
+                if (!customModels.contains(m)) {
+                    modelCombo.addItem(m);
+                }
+            }
             for (int i = 0; i < modelCombo.getItemCount(); i++) {
                 if (modelCombo.getItemAt(i).equals(previousSelection)) {
                     modelCombo.setSelectedItem(previousSelection);
@@ -412,29 +500,96 @@ public class UnitEditorForm extends JPanel {
         }
     }
 
-    private void loadOpenWebUIModels() {
-        SwingWorker<List<String>, Void> worker = new SwingWorker<List<String>, Void>() {
-            @Override
-            protected List<String> doInBackground() throws Exception {
-                OpenWebUIModelsResponse modelsResponse = openWebUIProcessClient.fetchModels();
-                return modelsResponse.getModelNames();
-            }
+    private void loadOpenAIChatModels() {
+        fetchModelsButton.setEnabled(false);
+        BackgroundTask.run(() -> new OpenAIClient(configManager).fetchChatModels(),
+                models -> {
+                    openAIChatModelNames.clear();
+                    openAIChatModelNames.addAll(models);
+                    // Keep the current selection when repopulating
+                    Object selected = modelCombo.getSelectedItem();
+                    if (selected != null) {
+                        storedModel = selected.toString();
+                    }
+                    updateModelCombo();
+                    Notificationmanager.getInstance().showNotification(ToastNotification.Type.SUCCESS,
+                            "Loaded " + models.size() + " OpenAI chat models.");
+                },
+                ex -> {
+                    logger.error("Error loading OpenAI chat models: ", ex);
+                    Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR,
+                            "Error loading OpenAI models. Check your API key.");
+                },
+                () -> fetchModelsButton.setEnabled(true));
+    }
 
-            @Override
-            protected void done() {
-                try {
-                    List<String> models = get();
+    private void loadAllProviderModels() {
+        fetchModelsButton.setEnabled(false);
+        fetchAllModelsButton.setEnabled(false);
+        fetchStatusLabel.setText("Fetching models...");
+        BackgroundTask.run(() -> ModelFetcher.fetchConfigured(configManager),
+                results -> {
+                    if (results.isEmpty()) {
+                        fetchStatusLabel.setText(" ");
+                        Notificationmanager.getInstance().showNotification(ToastNotification.Type.WARNING,
+                                "No provider is configured. Set an API key or server URL in Settings.");
+                        return;
+                    }
+                    List<String> status = new ArrayList<>();
+                    List<String> errors = new ArrayList<>();
+                    boolean allSucceeded = true;
+                    for (ModelFetcher.Result result : results.values()) {
+                        if (!result.isSuccess()) {
+                            allSucceeded = false;
+                            status.add(result.getProvider() + ": failed");
+                            errors.add(result.getProvider() + ": " + result.getError());
+                            continue;
+                        }
+                        status.add(result.getProvider() + ": " + result.getModels().size() + " models");
+                        if ("Open WebUI".equals(result.getProvider())) {
+                            openWebUIModelNames.clear();
+                            openWebUIModelNames.addAll(result.getModels());
+                        } else {
+                            openAIChatModelNames.clear();
+                            openAIChatModelNames.addAll(result.getModels());
+                        }
+                    }
+                    // Keep the current selection when repopulating
+                    Object selected = modelCombo.getSelectedItem();
+                    if (selected != null) {
+                        storedModel = selected.toString();
+                    }
+                    updateModelCombo();
+                    String summary = String.join(" \u00b7 ", status);
+                    fetchStatusLabel.setText(summary);
+                    fetchStatusLabel.setToolTipText(errors.isEmpty() ? null : String.join("\n", errors));
+                    Notificationmanager.getInstance().showNotification(
+                            allSucceeded ? ToastNotification.Type.SUCCESS : ToastNotification.Type.WARNING, summary);
+                },
+                ex -> {
+                    logger.error("Error loading models: ", ex);
+                    fetchStatusLabel.setText(" ");
+                    Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR,
+                            "Error loading models. See logs.");
+                },
+                () -> {
+                    fetchModelsButton.setEnabled(true);
+                    fetchAllModelsButton.setEnabled(true);
+                });
+    }
+
+    private void loadOpenWebUIModels() {
+        BackgroundTask.run(() -> openWebUIProcessClient.fetchModels().getModelNames(),
+                models -> {
                     openWebUIModelNames.clear();
                     openWebUIModelNames.addAll(models);
                     updateModelCombo();
-                } catch (Exception ex) {
+                },
+                ex -> {
                     logger.error("Error loading Open WebUI models: ", ex);
                     Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR,
                             "Error loading Open WebUI models. See logs.");
-                }
-            }
-        };
-        worker.execute();
+                });
     }
 
     private void setPlaceholder(JTextArea textArea, String placeholder, Font defaultFont) {
@@ -504,4 +659,17 @@ public class UnitEditorForm extends JPanel {
     protected String getReplacementTextFieldText() {
         return replacementTextField.getText();
     }
+
+    protected boolean isUseRegexSelected() {
+        return useRegexCheckBox.isSelected();
+    }
+
+    private static boolean isValidRegex(String regex) {
+        try {
+            java.util.regex.Pattern.compile(regex);
+            return true;
+        } catch (java.util.regex.PatternSyntaxException e) {
+            return false;
+        }
+    }
 }
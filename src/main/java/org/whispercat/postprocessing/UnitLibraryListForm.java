@@ -2,11 +2,17 @@ package org.whispercat.postprocessing;
 
 import com.formdev.flatlaf.extras.FlatSVGIcon;
 import org.whispercat.ConfigManager;
+import org.whispercat.ConfirmDialog;
 import org.whispercat.MainForm;
+import org.whispercat.Notificationmanager;
+import org.whispercat.ToastNotification;
 
 import javax.swing.*;
+import javax.swing.filechooser.FileNameExtensionFilter;
 import java.awt.*;
 import java.awt.event.ActionEvent;
+import java.io.File;
+import java.io.IOException;
 import java.util.List;
 
 /**
@@ -34,6 +40,19 @@ public class UnitLibraryListForm extends JPanel {
         headerPanel.add(headerLabel, BorderLayout.CENTER);
         headerPanel.setBorder(BorderFactory.createEmptyBorder(10, 0, 10, 0));
 
+        // Share the library between machines as a JSON file
+        JButton exportButton = new JButton("Export Library");
+        exportButton.setToolTipText("Save all processing units to a JSON file");
+        exportButton.addActionListener(e -> exportLibrary());
+        JButton importButton = new JButton("Import Library");
+        importButton.setToolTipText("Add processing units from a JSON file exported by WhisperCat");
+        importButton.addActionListener(e -> importLibrary());
+        JPanel libraryButtonPanel = new JPanel(new FlowLayout(FlowLayout.RIGHT, 5, 0));
+        libraryButtonPanel.add(exportButton);
+        libraryButtonPanel.add(importButton);
+        libraryButtonPanel.setBorder(BorderFactory.createEmptyBorder(0, 0, 10, 0));
+        add(libraryButtonPanel, BorderLayout.NORTH);
+
         // Create a container that holds the individual unit items.
         listContainer = new JPanel();
         listContainer.setLayout(new BoxLayout(listContainer, BoxLayout.Y_AXIS));
@@ -44,6 +63,70 @@ public class UnitLibraryListForm extends JPanel {
         refreshList();
     }
 
+    private JFileChooser createLibraryFileChooser() {
+        JFileChooser chooser = new JFileChooser();
+        chooser.setFileFilter(new FileNameExtensionFilter("Processing unit library (*.json)", "json"));
+        return chooser;
+    }
+
+    private void exportLibrary() {
+        JFileChooser chooser = createLibraryFileChooser();
+        chooser.setSelectedFile(new File("whispercat-units.json"));
+        if (chooser.showSaveDialog(this) != JFileChooser.APPROVE_OPTION) {
+            return;
+        }
+        File file = chooser.getSelectedFile();
+        if (!file.getName().toLowerCase().endsWith(".json")) {
+            file = new File(file.getParentFile(), file.getName() + ".json");
+        }
+        try {
+            configManager.exportProcessingUnits(file);
+            Notificationmanager.getInstance().showNotification(ToastNotification.Type.SUCCESS,
+                    "Exported " + configManager.getProcessingUnits().size() + " unit(s) to " + file.getName());
+        } catch (IOException ex) {
+            logger.error("Failed to export processing units to {}", file, ex);
+            Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR,
+                    "Export failed: " + ex.getMessage());
+        }
+    }
+
+    private void importLibrary() {
+        JFileChooser chooser = createLibraryFileChooser();
+        if (chooser.showOpenDialog(this) != JFileChooser.APPROVE_OPTION) {
+            return;
+        }
+        File file = chooser.getSelectedFile();
+        List<ProcessingUnit> units;
+        try {
+            units = ConfigManager.readProcessingUnits(file);
+        } catch (IOException ex) {
+            logger.error("Failed to read processing units from {}", file, ex);
+            Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR,
+                    "Import failed: " + ex.getMessage());
+            return;
+        }
+
+        long duplicates = units.stream()
+                .filter(unit -> configManager.getProcessingUnitByUuid(unit.uuid) != null)
+                .count();
+        boolean overwrite = false;
+        if (duplicates > 0) {
+            Object[] options = {"Overwrite", "Skip", "Cancel"};
+            int choice = JOptionPane.showOptionDialog(this,
+                    duplicates + " of " + units.size() + " unit(s) already exist in the library.\n"
+                            + "Overwrite them with the imported version, or keep the existing ones?",
+                    "Import Library", JOptionPane.YES_NO_CANCEL_OPTION, JOptionPane.QUESTION_MESSAGE,
+                    null, options, options[1]);
+            if (choice != 0 && choice != 1) {
+                return;
+            }
+            overwrite = choice == 0;
+        }
+        String summary = configManager.importProcessingUnits(units, overwrite);
+        Notificationmanager.getInstance().showNotification(ToastNotification.Type.SUCCESS, summary);
+        refreshList();
+    }
+
     /**
      * Refreshes the list by reading units from the ConfigManager
      * and rebuilding the UI.
@@ -99,27 +182,33 @@ public class UnitLibraryListForm extends JPanel {
                 mainForm.showForm(new UnitEditorForm(configManager, mainForm, unit));
             });
 
+            // Duplicate button.
+            JButton duplicateButton = new JButton();
+            duplicateButton.setIcon(new FlatSVGIcon("icon/svg/copy.svg", 16, 16));
+            duplicateButton.setToolTipText("Duplicate this Processing Unit");
+            duplicateButton.addActionListener((ActionEvent e) -> {
+                configManager.duplicateProcessingUnit(unit.uuid);
+                refreshList();
+            });
+
             // Delete button.
             JButton deleteButton = new JButton();
             deleteButton.setIcon(new FlatSVGIcon("icon/svg/trash.svg", 16, 16));
             deleteButton.setToolTipText("Delete this Processing Unit");
-            deleteButton.addActionListener((ActionEvent e) -> {
-                int confirm = JOptionPane.showConfirmDialog(
-                        this,
-                        "Are you sure you want to delete this unit? It may be used in pipelines.",
-                        "Confirm Delete",
-                        JOptionPane.YES_NO_OPTION,
-                        JOptionPane.WARNING_MESSAGE
-                );
-                if (confirm == JOptionPane.YES_OPTION) {
-                    configManager.deleteProcessingUnit(unit.uuid);
-                    refreshList();
-                }
-            });
+            deleteButton.addActionListener((ActionEvent e) -> ConfirmDialog.confirm(this,
+                    "Delete Processing Unit",
+                    "Delete unit '" + unit.name + "'? It may be used in pipelines.",
+                    "Delete",
+                    () -> {
+                        configManager.deleteProcessingUnit(unit.uuid);
+                        refreshList();
+                    }));
 
             // Add buttons to the button panel with vertical spacing.
             buttonPanel.add(editButton);
             buttonPanel.add(Box.createVerticalStrut(5));
+            buttonPanel.add(duplicateButton);
+            buttonPanel.add(Box.createVerticalStrut(5));
             buttonPanel.add(deleteButton);
 
             // Add the button panel to the right side.
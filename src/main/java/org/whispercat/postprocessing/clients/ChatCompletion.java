@@ -0,0 +1,43 @@
+package org.whispercat.postprocessing.clients;
+
+import com.fasterxml.jackson.databind.JsonNode;
+import org.whispercat.postprocessing.TokenUsage;
+
+/**
+ * The text and token usage of a chat completion response.
+ */
+public final class ChatCompletion {
+    private final String text;
+    private final TokenUsage usage;
+
+    public ChatCompletion(String text, TokenUsage usage) {
+        this.text = text;
+        this.usage = usage;
+    }
+
+    /**
+     * Reads the first choice's message and the usage from a chat completion response.
+     *
+     * @param response the parsed response body
+     * @return the completion; the text is empty if the response has no choices
+     */
+    public static ChatCompletion fromResponse(JsonNode response) {
+        JsonNode choices = response.path("choices");
+        String text = "";
+        if (choices.isArray() && choices.size() > 0) {
+            text = choices.get(0).path("message").path("content").asText();
+        }
+        return new ChatCompletion(text, TokenUsage.fromResponse(response));
+    }
+
+    public String getText() {
+        return text;
+    }
+
+    /**
+     * @return the tokens used, or null if the provider did not report them
+     */
+    public TokenUsage getUsage() {
+        return usage;
+    }
+}
@@ -1,8 +1,6 @@
 package org.whispercat.postprocessing.clients;
 
-import com.fasterxml.jackson.databind.JsonNode;
 import com.fasterxml.jackson.databind.ObjectMapper;
-import com.fasterxml.jackson.databind.node.ArrayNode;
 import com.fasterxml.jackson.databind.node.ObjectNode;
 import org.apache.http.HttpEntity;
 import org.apache.http.client.methods.CloseableHttpResponse;
@@ -11,25 +9,29 @@ import org.apache.http.client.methods.HttpGet;
 import org.apache.http.entity.StringEntity;
 import org.apache.http.entity.ContentType;
 import org.apache.http.impl.client.CloseableHttpClient;
+import org.apache.http.impl.client.HttpClientBuilder;
 import org.apache.http.impl.client.HttpClients;
-import org.apache.http.conn.ssl.NoopHostnameVerifier;
 import org.apache.http.conn.ssl.SSLConnectionSocketFactory;
-import org.apache.http.ssl.SSLContextBuilder;
 import org.apache.logging.log4j.LogManager;
 import org.apache.logging.log4j.Logger;
 import org.whispercat.ConfigManager;
+import org.whispercat.HttpErrors;
 import org.whispercat.Notificationmanager;
+import org.whispercat.OpenWebUIPaths;
+import org.whispercat.OpenWebUITls;
 import org.whispercat.ToastNotification;
+import org.whispercat.recording.OpenAIClient;
+import org.whispercat.recording.clients.TranscriptionProviders;
 
 import java.io.IOException;
 import java.nio.charset.StandardCharsets;
-import javax.net.ssl.SSLContext;
 
 /**
  * OpenWebUIClient processes text requests, fetches available models and transcribes audio files
  * using the OpenWebUI API. The API base URL is obtained from the ConfigManager.
  *
- * This class now ignores certificate validation.
+ * Certificates are verified as configured in {@link OpenWebUITls}; the API paths follow the
+ * server version as detected by {@link OpenWebUIPaths}.
  */
 public class OpenWebUIProcessClient {
     private static final Logger logger = LogManager.getLogger(OpenWebUIProcessClient.class);
@@ -41,21 +43,25 @@ public class OpenWebUIProcessClient {
     }
 
     /**
-     * Creates an HttpClient which ignores SSL certificate validation.
+     * Creates an HttpClient that verifies certificates as configured in {@link OpenWebUITls}.
      *
-     * @return a CloseableHttpClient instance with an all-trusting SSLContext.
-     * @throws IOException if an error occurs while creating the SSL context.
+     * @return a CloseableHttpClient instance.
+     * @throws IOException if the TLS settings cannot be applied.
      */
     private CloseableHttpClient createHttpClient() throws IOException {
-        try {
-            SSLContext sslContext = SSLContextBuilder.create()
-                    .loadTrustMaterial(null, (chain, authType) -> true)
-                    .build();
-            SSLConnectionSocketFactory csf = new SSLConnectionSocketFactory(sslContext, NoopHostnameVerifier.INSTANCE);
-            return HttpClients.custom().setSSLSocketFactory(csf).build();
-        } catch (Exception e) {
-            throw new IOException(e);
+        SSLConnectionSocketFactory csf = OpenWebUITls.socketFactory(configManager);
+        HttpClientBuilder builder = HttpClients.custom();
+        if (csf != null) {
+            builder.setSSLSocketFactory(csf);
         }
+        return builder.build();
+    }
+
+    /**
+     * @return the configured server URL, resolved like for transcription (https by default)
+     */
+    private String resolveBaseUrl() {
+        return TranscriptionProviders.get(TranscriptionProviders.OPEN_WEBUI).resolveBaseUrl(configManager);
     }
 
     /**
@@ -74,38 +80,46 @@ public class OpenWebUIProcessClient {
      * @throws IOException if an error occurs during the API call.
      */
     public String processText(String systemPrompt, String userPrompt, String model) throws IOException {
+        return processText(systemPrompt, userPrompt, model, false);
+    }
+
+    /**
+     * Processes the transcript like {@link #processText(String, String, String)}, optionally
+     * asking for a JSON object response via "response_format".
+     *
+     * @param systemPrompt the system prompt.
+     * @param userPrompt   the user prompt.
+     * @param model        the model identifier.
+     * @param jsonMode     whether to request a JSON object response.
+     * @return the processed text returned by the API.
+     * @throws IOException if an error occurs during the API call.
+     */
+    public String processText(String systemPrompt, String userPrompt, String model, boolean jsonMode) throws IOException {
+        return complete(systemPrompt, userPrompt, model, jsonMode).getText();
+    }
+
+    /**
+     * Sends a chat completion request and returns the text together with the token usage.
+     * The configured max output tokens are sent if set.
+     *
+     * @param systemPrompt the system prompt.
+     * @param userPrompt   the user prompt.
+     * @param model        the model identifier.
+     * @param jsonMode     whether to request a JSON object response.
+     * @return the completion; its text is empty if the request failed or returned no choices.
+     * @throws IOException if the connection cannot be set up.
+     */
+    public ChatCompletion complete(String systemPrompt, String userPrompt, String model, boolean jsonMode) throws IOException {
         try (CloseableHttpClient httpClient = createHttpClient()) {
-            String baseUrl = configManager.getOpenWebUIServerUrl().trim();
-            if (!baseUrl.toLowerCase().startsWith("http://") && !baseUrl.toLowerCase().startsWith("https://")) {
-                baseUrl = "https://" + baseUrl;
-            }
-            if (baseUrl.endsWith("/")) {
-                baseUrl = baseUrl.substring(0, baseUrl.length() - 1);
-            }
-            String url = baseUrl + "/api/chat/completions";
+            String url = resolveBaseUrl() + OpenWebUIPaths.detect(configManager).getChatCompletionsPath();
             HttpPost httpPost = new HttpPost(url);
 
             httpPost.setHeader("Authorization", "Bearer " + configManager.getProperty("openWebUIApiKey"));
             httpPost.setHeader("Content-Type", "application/json");
 
             ObjectMapper mapper = new ObjectMapper();
-            ObjectNode payload = mapper.createObjectNode();
-            payload.put("model", model);
-
-            // Build messages array.
-            ArrayNode messages = mapper.createArrayNode();
-
-            ObjectNode systemMessage = mapper.createObjectNode();
-            systemMessage.put("role", "system");
-            systemMessage.put("content", systemPrompt);
-            messages.add(systemMessage);
-
-            ObjectNode userMessage = mapper.createObjectNode();
-            userMessage.put("role", "user");
-            userMessage.put("content", userPrompt);
-            messages.add(userMessage);
-
-            payload.set("messages", messages);
+            ObjectNode payload = OpenAIClient.buildChatPayload(mapper, systemPrompt, userPrompt, model, jsonMode,
+                    configManager.getChatMaxTokens());
 
             // Add "params" block.
             ObjectNode paramsNode = mapper.createObjectNode();
@@ -119,24 +133,15 @@ public class OpenWebUIProcessClient {
                 int statusCode = response.getStatusLine().getStatusCode();
                 HttpEntity responseEntity = response.getEntity();
                 String responseString = new String(responseEntity.getContent().readAllBytes(), StandardCharsets.UTF_8);
-                if (statusCode != 200) {
-                    JsonNode errorNode = mapper.readTree(responseString);
-                    String errorMessage = errorNode.path("error").path("message").asText();
-                    throw new IOException("Error from OpenWebUI API: " + errorMessage);
-                }
-                JsonNode jsonResponse = mapper.readTree(responseString);
-                JsonNode choices = jsonResponse.path("choices");
-                if (choices.isArray() && choices.size() > 0) {
-                    JsonNode messageNode = choices.get(0).path("message");
-                    return messageNode.path("content").asText();
-                }
+                HttpErrors.checkStatus("OpenWebUI API", statusCode, responseString);
+                return ChatCompletion.fromResponse(mapper.readTree(responseString));
             } catch (IOException e) {
                 Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR, "Error processing text: " + e.getMessage());
                 logger.error("Error processing text: ", e);
 
             }
         }
-        return "";
+        return new ChatCompletion("", null);
     }
 
     /**
@@ -147,15 +152,7 @@ public class OpenWebUIProcessClient {
      */
     public OpenWebUIModelsResponse fetchModels() throws IOException {
         try (CloseableHttpClient httpClient = createHttpClient()) {
-
-            String baseUrl = configManager.getOpenWebUIServerUrl().trim();
-            if (!baseUrl.toLowerCase().startsWith("http://") && !baseUrl.toLowerCase().startsWith("https://")) {
-                baseUrl = "https://" + baseUrl;
-            }
-            if (baseUrl.endsWith("/")) {
-                baseUrl = baseUrl.substring(0, baseUrl.length() - 1);
-            }
-            String url = baseUrl + "/api/models";
+            String url = resolveBaseUrl() + OpenWebUIPaths.detect(configManager).getModelsPath();
             HttpGet httpGet = new HttpGet(url);
             httpGet.setHeader("Authorization", "Bearer " + configManager.getProperty("openWebUIApiKey"));
             httpGet.setHeader("Content-Type", "application/json");
@@ -165,11 +162,7 @@ public class OpenWebUIProcessClient {
                 HttpEntity responseEntity = response.getEntity();
                 String responseString = new String(responseEntity.getContent().readAllBytes(), StandardCharsets.UTF_8);
                 ObjectMapper mapper = new ObjectMapper();
-                if (statusCode != 200) {
-                    JsonNode errorNode = mapper.readTree(responseString);
-                    String errorMessage = errorNode.path("error").path("message").asText();
-                    throw new IOException("Error from OpenWebUI API: " + errorMessage);
-                }
+                HttpErrors.checkStatus("OpenWebUI API", statusCode, responseString);
                 return mapper.readValue(responseString, OpenWebUIModelsResponse.class);
             }
         }
@@ -0,0 +1,179 @@
+package org.whispercat.recording;
+
+import org.whispercat.recording.clients.TempFile;
+
+import javax.sound.sampled.AudioFileFormat;
+import javax.sound.sampled.AudioFormat;
+import javax.sound.sampled.AudioInputStream;
+import javax.sound.sampled.AudioSystem;
+import javax.sound.sampled.UnsupportedAudioFileException;
+import java.io.ByteArrayInputStream;
+import java.io.File;
+import java.io.IOException;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+import java.util.Locale;
+
+/**
+ * Splits recordings that are too large to upload into overlapping WAV chunks, and merges
+ * the chunk transcriptions back into one text.
+ */
+public class AudioChunker {
+
+    /** Audio shared by neighbouring chunks, so words at a cut are heard completely at least once. */
+    static final double OVERLAP_SECONDS = 2.0;
+    /** The longest run of repeated words that is removed where two chunk texts meet. */
+    static final int MAX_OVERLAP_WORDS = 20;
+    /** Shorter runs, e.g. a single "the", are more likely a coincidence than a repeat of the overlap. */
+    static final int MIN_OVERLAP_WORDS = 3;
+    private static final int WAV_HEADER_BYTES = 44;
+
+    /**
+     * A chunk of a recording.
+     */
+    public static class Chunk {
+        private final File file;
+        private final double startSeconds;
+
+        Chunk(File file, double startSeconds) {
+            this.file = file;
+            this.startSeconds = startSeconds;
+        }
+
+        public File getFile() {
+            return file;
+        }
+
+        /**
+         * @return where the chunk starts in the original recording
+         */
+        public double getStartSeconds() {
+            return startSeconds;
+        }
+    }
+
+    /**
+     * Splits a WAV file into chunks of at most the given size. The recording is read one chunk
+     * at a time, so long recordings are never held in memory as a whole. The caller deletes the
+     * chunk files once they are transcribed; if splitting fails, the chunks written so far are deleted.
+     *
+     * @param audioFile the recording
+     * @param maxBytes  the maximum size of a chunk file
+     * @param outputDir where to write the chunk files
+     * @return the chunks in order
+     * @throws IOException if the file cannot be read or is not a WAV file
+     */
+    public static List<Chunk> split(File audioFile, long maxBytes, File outputDir) throws IOException {
+        List<Chunk> chunks = new ArrayList<>();
+        try (AudioInputStream audioStream = AudioSystem.getAudioInputStream(audioFile)) {
+            AudioFormat format = audioStream.getFormat();
+            int frameSize = format.getFrameSize();
+            float frameRate = format.getFrameRate();
+            long chunkFrames = Math.min((maxBytes - WAV_HEADER_BYTES) / frameSize, Integer.MAX_VALUE / frameSize);
+            long overlapFrames = (long) (OVERLAP_SECONDS * frameRate);
+            if (chunkFrames <= overlapFrames) {
+                throw new IOException("Upload limit is too small to split " + audioFile.getName());
+            }
+
+            String baseName = audioFile.getName().replaceAll("(?i)\\.wav$", "");
+            int chunkBytes = (int) (chunkFrames * frameSize);
+            int overlapBytes = (int) (overlapFrames * frameSize);
+            // The end of the previous chunk, repeated at the start of the next one
+            byte[] overlap = new byte[0];
+            long startFrame = 0;
+            while (true) {
+                byte[] chunkData = new byte[chunkBytes];
+                System.arraycopy(overlap, 0, chunkData, 0, overlap.length);
+                int wanted = chunkBytes - overlap.length;
+                int read = audioStream.readNBytes(chunkData, overlap.length, wanted);
+                read -= read % frameSize;
+                if (read == 0) {
+                    // Nothing left beyond the overlap already sent with the previous chunk
+                    break;
+                }
+                int length = overlap.length + read;
+                File chunkFile = new File(outputDir, baseName + "_part" + (chunks.size() + 1) + ".wav");
+                chunks.add(new Chunk(chunkFile, startFrame / frameRate));
+                try (AudioInputStream chunkStream = new AudioInputStream(
+                        new ByteArrayInputStream(chunkData, 0, length), format, length / frameSize)) {
+                    AudioSystem.write(chunkStream, AudioFileFormat.Type.WAVE, chunkFile);
+                }
+                if (read < wanted) {
+                    break;
+                }
+                overlap = Arrays.copyOfRange(chunkData, length - overlapBytes, length);
+                startFrame += length / frameSize - overlapFrames;
+            }
+        } catch (UnsupportedAudioFileException e) {
+            throw new IOException("Cannot split " + audioFile.getName() + ": " + e.getMessage(), e);
+        } catch (IOException | RuntimeException e) {
+            delete(chunks);
+            throw e;
+        }
+        return chunks;
+    }
+
+    /**
+     * Deletes the chunk files. Files already deleted are skipped.
+     *
+     * @param chunks the chunks
+     */
+    public static void delete(List<Chunk> chunks) {
+        for (Chunk chunk : chunks) {
+            TempFile.owned(chunk.getFile()).close();
+        }
+    }
+
+    /**
+     * Joins chunk transcriptions in order, dropping words repeated because of the overlap:
+     * the longest run of words that ends one text and starts the next is kept only once, if
+     * it is at least {@link #MIN_OVERLAP_WORDS} words long.
+     *
+     * @param texts the chunk transcriptions in order
+     * @return the merged text
+     */
+    public static String mergeTexts(List<String> texts) {
+        StringBuilder merged = new StringBuilder();
+        for (String text : texts) {
+            if (text == null || text.trim().isEmpty()) {
+                continue;
+            }
+            String[] next = text.trim().split("\\s+");
+            if (merged.length() == 0) {
+                merged.append(String.join(" ", next));
+                continue;
+            }
+            String[] previous = merged.toString().split("\\s+");
+            int skip = overlapLength(previous, next);
+            if (skip < next.length) {
+                merged.append(' ').append(String.join(" ", Arrays.copyOfRange(next, skip, next.length)));
+            }
+        }
+        return merged.toString();
+    }
+
+    /**
+     * @return the number of words at the start of {@code next} that repeat the end of {@code previous}
+     */
+    private static int overlapLength(String[] previous, String[] next) {
+        int max = Math.min(MAX_OVERLAP_WORDS, Math.min(previous.length, next.length));
+        for (int length = max; length >= MIN_OVERLAP_WORDS; length--) {
+            boolean matches = true;
+            for (int i = 0; i < length; i++) {
+                if (!normalizeWord(previous[previous.length - length + i]).equals(normalizeWord(next[i]))) {
+                    matches = false;
+                    break;
+                }
+            }
+            if (matches) {
+                return length;
+            }
+        }
+        return 0;
+    }
+
+    private static String normalizeWord(String word) {
+        return word.replaceAll("[\\p{Punct}]", "").toLowerCase(Locale.ROOT);
+    }
+}
@@ -0,0 +1,44 @@
+package org.whispercat.recording;
+
+import java.util.Locale;
+
+/**
+ * Kind of an audio capture device. Java Sound has no loopback API, but platforms expose
+ * system audio as ordinary capture devices: PulseAudio/PipeWire "Monitor of ..." sources
+ * on Linux, "Stereo Mix" on Windows (must be enabled in the sound settings) and virtual
+ * devices such as BlackHole or Loopback on macOS. These are recognized by name.
+ */
+public enum AudioDeviceKind {
+    MICROPHONE,
+    LOOPBACK;
+
+    private static final String[] LOOPBACK_MARKERS = {
+            "monitor of",
+            ".monitor",
+            "stereo mix",
+            "stereomix",
+            "what u hear",
+            "wave out mix",
+            "loopback",
+            "blackhole",
+            "soundflower"
+    };
+
+    /**
+     * Classifies a capture device by its mixer name and description.
+     *
+     * @param name        the mixer name
+     * @param description the mixer description, may be null
+     * @return {@link #LOOPBACK} if the device captures system audio, otherwise {@link #MICROPHONE}
+     */
+    public static AudioDeviceKind classify(String name, String description) {
+        String text = ((name != null ? name : "") + " " + (description != null ? description : ""))
+                .toLowerCase(Locale.ROOT);
+        for (String marker : LOOPBACK_MARKERS) {
+            if (text.contains(marker)) {
+                return LOOPBACK;
+            }
+        }
+        return MICROPHONE;
+    }
+}
@@ -3,58 +3,416 @@ package org.whispercat.recording;
 import org.apache.logging.log4j.LogManager;
 import org.apache.logging.log4j.Logger;
 import org.whispercat.ConfigManager;
-import org.whispercat.Notificationmanager;
-import org.whispercat.ToastNotification;
 
 import javax.sound.sampled.*;
 import java.io.File;
 import java.io.IOException;
+import java.util.ArrayDeque;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.Deque;
+import java.util.List;
+import java.util.concurrent.CountDownLatch;
+import java.util.concurrent.TimeUnit;
 
 public class AudioRecorder {
     private static final Logger logger = LogManager.getLogger(AudioRecorder.class);
+    // A line that delivers no data for this long is treated as disconnected
+    private static final long STALL_TIMEOUT_MS = 2000;
     private final File wavFile;
     private final ConfigManager configManager;
-    private TargetDataLine line;
+    private volatile TargetDataLine line;
+    private volatile boolean running = false;
+    private final CountDownLatch finished = new CountDownLatch(1);
+    private volatile Runnable disconnectListener;
+    // Why the recording ended without stop() being called, surfaced to the user
+    private volatile String recordingError;
+    private volatile boolean stopRequested = false;
+    // Dictation mode: only speech detected by the VAD is recorded
+    private static final long PRE_ROLL_MS = 300;
+    private volatile VoiceActivityDetector voiceActivityDetector;
+    private volatile Runnable speechStartListener;
+    private volatile Runnable utteranceListener;
+    private volatile boolean speechDetected = false;
+    private volatile boolean endOnSilence = false;
+    // Pausing discards captured audio but keeps the line and the output file open
+    private volatile boolean paused = false;
+    // RMS of the last captured chunk, 0.0 to 1.0, for the level meter
+    private volatile float currentLevel = 0f;
+    private long startedAtMs;
+    private long pausedSinceMs;
+    private long pausedTotalMs;
 
     public AudioRecorder(File wavFile, ConfigManager configManager) {
         this.wavFile = wavFile;
         this.configManager = configManager;
     }
 
+    /**
+     * Sets a callback invoked on the recording thread when the input device stops delivering
+     * audio while recording (e.g. a USB microphone was unplugged) or cannot be opened. The
+     * audio captured up to that point has already been written to the output file when the
+     * callback runs, and {@link #getRecordingError()} describes the failure.
+     *
+     * @param listener the callback, or null to remove it
+     */
+    public void setDisconnectListener(Runnable listener) {
+        this.disconnectListener = listener;
+    }
+
+    /**
+     * @return why the recording ended early (device unplugged, line failed to open, ...),
+     * or null if it is running or was stopped normally
+     */
+    public String getRecordingError() {
+        return recordingError;
+    }
+
+    /**
+     * Switches the recorder to dictation mode: audio is discarded until the detector reports
+     * speech (keeping a short pre-roll so the first syllable is not cut off), and the recording
+     * ends by itself once the detector reports the end of the utterance. Call before
+     * {@link #start()}. Both callbacks run on the recording thread; the utterance callback runs
+     * after the WAV file has been finalized.
+     *
+     * @param detector           the voice activity detector
+     * @param onSpeechStart      called when speech starts, may be null
+     * @param onUtteranceEnd     called when the utterance ended, may be null
+     */
+    public void enableDictation(VoiceActivityDetector detector, Runnable onSpeechStart, Runnable onUtteranceEnd) {
+        this.voiceActivityDetector = detector;
+        this.speechStartListener = onSpeechStart;
+        this.utteranceListener = onUtteranceEnd;
+        this.endOnSilence = true;
+    }
+
+    /**
+     * Makes the recording begin only once the detector reports speech: audio before that is
+     * discarded except for a short pre-roll. Unlike dictation mode, the recording then keeps
+     * running until {@link #stop()} is called. Call before {@link #start()}.
+     *
+     * @param detector      the voice activity detector
+     * @param onSpeechStart called on the recording thread when speech starts, may be null
+     */
+    public void enableVoiceActivatedStart(VoiceActivityDetector detector, Runnable onSpeechStart) {
+        this.voiceActivityDetector = detector;
+        this.speechStartListener = onSpeechStart;
+        this.utteranceListener = null;
+        this.endOnSilence = false;
+    }
+
+    /**
+     * Pauses the recording: audio captured until {@link #resume()} is discarded, and the
+     * recording continues in the same file afterwards.
+     */
+    public synchronized void pause() {
+        if (running && !paused) {
+            paused = true;
+            pausedSinceMs = System.currentTimeMillis();
+            logger.info("Recording paused");
+        }
+    }
+
+    /**
+     * Resumes a paused recording.
+     */
+    public synchronized void resume() {
+        if (paused) {
+            pausedTotalMs += System.currentTimeMillis() - pausedSinceMs;
+            paused = false;
+            logger.info("Recording resumed");
+        }
+    }
+
+    public boolean isPaused() {
+        return paused;
+    }
+
+    /**
+     * @return how long the recording has been running, excluding paused intervals
+     */
+    public synchronized long getRecordedMillis() {
+        if (startedAtMs == 0) {
+            return 0;
+        }
+        return activeMillis(startedAtMs, System.currentTimeMillis(), pausedTotalMs, paused ? pausedSinceMs : -1);
+    }
+
+    /**
+     * Computes the recorded time between start and now, excluding pauses.
+     *
+     * @param startMs       when the recording started
+     * @param nowMs         the current time
+     * @param pausedTotalMs length of the completed pauses
+     * @param pausedSinceMs start of the current pause, or -1 if not paused
+     * @return the recorded time in milliseconds, never negative
+     */
+    static long activeMillis(long startMs, long nowMs, long pausedTotalMs, long pausedSinceMs) {
+        long end = pausedSinceMs >= 0 ? pausedSinceMs : nowMs;
+        return Math.max(0, end - startMs - pausedTotalMs);
+    }
+
+    /**
+     * @return the RMS level of the most recent audio, 0.0 to 1.0; 0 while paused or stopped
+     */
+    public float getCurrentLevel() {
+        return running ? currentLevel : 0f;
+    }
+
+    /**
+     * @return true if speech was heard; always true without a voice activity detector
+     */
+    public boolean hasSpeech() {
+        return voiceActivityDetector == null || speechDetected;
+    }
+
     public void start() {
+        AudioFormat captureFormat = configManager.getAudioFormat();
+        TargetDataLine dataLine;
         try {
-            AudioFormat format = configManager.getAudioFormat();
-            DataLine.Info info = new DataLine.Info(TargetDataLine.class, format);
+            DataLine.Info info = new DataLine.Info(TargetDataLine.class, captureFormat);
 
             String selectedMicrophone = configManager.getProperty("selectedMicrophone");
             Mixer.Info selectedMixerInfo = getMixerInfoByName(selectedMicrophone);
 
             Mixer mixer = AudioSystem.getMixer(selectedMixerInfo);
             if (!mixer.isLineSupported(info)) {
-                logger.warn("Line not supported for selected mixer");
-                Notificationmanager.getInstance().showNotification(ToastNotification.Type.WARNING, "Microphone not supported. This can happen if there were too many recordings in a short time. Please restart the application.");
-                return;
+                // Some devices (often system audio loopbacks) only capture in stereo, and
+                // some only deliver unsigned samples
+                AudioFormat fallback = null;
+                for (AudioFormat candidate : fallbackFormats(captureFormat)) {
+                    if (mixer.isLineSupported(new DataLine.Info(TargetDataLine.class, candidate))) {
+                        fallback = candidate;
+                        break;
+                    }
+                }
+                if (fallback == null) {
+                    logger.warn("Line not supported for selected mixer");
+                    failToStart("Microphone not supported. This can happen if there were too many recordings in a short time. Please restart the application.");
+                    return;
+                }
+                logger.info("Preferred capture format not supported, recording as {}", fallback);
+                captureFormat = fallback;
+                info = new DataLine.Info(TargetDataLine.class, captureFormat);
             }
 
-            line = (TargetDataLine) mixer.getLine(info);
-            line.open(format);
-            line.start();
+            dataLine = (TargetDataLine) mixer.getLine(info);
+            dataLine.open(captureFormat);
+            dataLine.start();
+        } catch (LineUnavailableException ex) {
+            logger.error("An error occurred during recording", ex);
+            failToStart("Recording failed: " + ex.getMessage());
+            return;
+        }
+        record(dataLine, captureFormat, captureFormat.getChannels() > 1 && configManager.isForceMonoOutput());
+    }
+
+    /**
+     * Ends a recording whose line could not be opened and notifies the disconnect listener,
+     * unless the recording was already stopped.
+     */
+    private void failToStart(String error) {
+        recordingError = error;
+        finished.countDown();
+        Runnable listener = disconnectListener;
+        if (!stopRequested && listener != null) {
+            listener.run();
+        }
+    }
+
+    /**
+     * Streams audio from an opened line to the output file until {@link #stop()} is called.
+     * If the line fails or stops delivering audio before that, the recording ends early and
+     * the disconnect listener is notified once the file has been finalized. In dictation mode
+     * the recording also ends by itself after the utterance.
+     *
+     * @param dataLine the opened and started input line
+     * @param format   the format of the line
+     */
+    void record(TargetDataLine dataLine, AudioFormat format) {
+        record(dataLine, format, false);
+    }
 
-            AudioInputStream ais = new AudioInputStream(line);
-            AudioSystem.write(ais, AudioFileFormat.Type.WAVE, wavFile);
-        } catch (LineUnavailableException | IOException ex) {
+    /**
+     * Streams audio like {@link #record(TargetDataLine, AudioFormat)}, optionally saving a
+     * multi-channel capture as mono. Unsigned samples are saved as signed ones.
+     *
+     * @param dataLine      the opened and started input line
+     * @param captureFormat the format of the line
+     * @param downmix       whether to average the channels before writing
+     */
+    void record(TargetDataLine dataLine, AudioFormat captureFormat, boolean downmix) {
+        boolean unsigned = captureFormat.getEncoding() == AudioFormat.Encoding.PCM_UNSIGNED;
+        // The format written to the WAV file, always signed
+        AudioFormat format = withChannels(captureFormat, downmix ? 1 : captureFormat.getChannels());
+        boolean disconnected = false;
+        boolean utteranceEnded = false;
+        boolean writerOpen = false;
+        line = dataLine;
+        synchronized (this) {
+            startedAtMs = System.currentTimeMillis();
+        }
+        running = true;
+        // Stream captured chunks straight to disk instead of buffering the whole recording
+        try (WavStreamWriter writer = new WavStreamWriter(wavFile, format)) {
+            writerOpen = true;
+            int bufferSize = dataLine.getBufferSize() / 5 > 0 ? dataLine.getBufferSize() / 5 : 4096;
+            // Reads must cover whole frames
+            byte[] buffer = new byte[bufferSize - bufferSize % captureFormat.getFrameSize()];
+            long lastDataTime = System.currentTimeMillis();
+            VoiceActivityDetector detector = voiceActivityDetector;
+            Deque<byte[]> preRoll = new ArrayDeque<>();
+            long preRollBytes = (long) (format.getFrameRate() * format.getFrameSize() * PRE_ROLL_MS / 1000);
+            long bufferedBytes = 0;
+            while (running) {
+                int read;
+                try {
+                    read = dataLine.read(buffer, 0, buffer.length);
+                } catch (RuntimeException e) {
+                    // Some drivers throw instead of returning when the device goes away
+                    disconnected = running;
+                    recordingError = "Reading from the microphone failed: " + e.getMessage();
+                    logger.warn("Reading from the microphone failed", e);
+                    break;
+                }
+                if (read > 0 && paused) {
+                    // Keep draining the line so it neither overflows nor looks disconnected
+                    lastDataTime = System.currentTimeMillis();
+                    currentLevel = 0f;
+                    continue;
+                }
+                byte[] data = buffer;
+                if (read > 0 && unsigned) {
+                    PcmSamples.unsignedToSigned(buffer, read, captureFormat.getSampleSizeInBits() / 8,
+                            captureFormat.isBigEndian());
+                }
+                if (read > 0 && downmix) {
+                    data = AudioResampler.toMono(Arrays.copyOf(buffer, read), captureFormat);
+                    read = data.length;
+                }
+                float rms = read > 0 ? SilenceRemover.calculateRMS(data, 0, read,
+                        format.getSampleSizeInBits() / 8, format.isBigEndian()) : 0f;
+                currentLevel = rms;
+                if (read > 0 && detector != null) {
+                    lastDataTime = System.currentTimeMillis();
+                    long chunkMs = (long) (read / (double) format.getFrameSize() / format.getFrameRate() * 1000);
+                    VoiceActivityDetector.Event event = detector.process(rms, chunkMs);
+                    if (!speechDetected) {
+                        preRoll.addLast(Arrays.copyOf(data, read));
+                        bufferedBytes += read;
+                        while (bufferedBytes - preRoll.peekFirst().length >= preRollBytes) {
+                            bufferedBytes -= preRoll.removeFirst().length;
+                        }
+                        if (event == VoiceActivityDetector.Event.SPEECH_STARTED) {
+                            speechDetected = true;
+                            logger.info("Dictation: speech detected");
+                            for (byte[] chunk : preRoll) {
+                                writer.writePcm(chunk, 0, chunk.length);
+                            }
+                            preRoll.clear();
+                            Runnable listener = speechStartListener;
+                            if (listener != null) {
+                                listener.run();
+                            }
+                        }
+                    } else {
+                        writer.writePcm(data, 0, read);
+                        if (endOnSilence && event == VoiceActivityDetector.Event.SPEECH_ENDED) {
+                            logger.info("Dictation: utterance ended after trailing silence");
+                            utteranceEnded = running;
+                            break;
+                        }
+                    }
+                } else if (read > 0) {
+                    writer.writePcm(data, 0, read);
+                    lastDataTime = System.currentTimeMillis();
+                } else if (!dataLine.isOpen()) {
+                    disconnected = running;
+                    recordingError = "The microphone was closed by the system";
+                    break;
+                } else if (System.currentTimeMillis() - lastDataTime > STALL_TIMEOUT_MS) {
+                    disconnected = running;
+                    recordingError = "The microphone stopped delivering audio";
+                    break;
+                } else {
+                    Thread.sleep(10);
+                }
+            }
+            logger.info("Wrote {} bytes of audio to {}", writer.getDataBytes(), wavFile.getName());
+        } catch (IOException ex) {
             logger.error("An error occurred during recording", ex);
+            recordingError = writerOpen
+                    ? "Writing the recording failed (disk full?): " + ex.getMessage()
+                    : "Recording failed: " + ex.getMessage();
+            disconnected = !stopRequested;
+        } catch (InterruptedException ex) {
+            Thread.currentThread().interrupt();
+        } finally {
+            finished.countDown();
+        }
+
+        if (utteranceEnded) {
+            Runnable listener = utteranceListener;
+            if (listener != null) {
+                listener.run();
+            }
+        }
+
+        if (disconnected) {
+            logger.warn("Microphone stopped delivering audio, recording ended early");
+            Runnable listener = disconnectListener;
+            if (listener != null) {
+                listener.run();
+            }
         }
     }
 
     public void stop() {
-        if (line != null) {
+        stopRequested = true;
+        running = false;
+        TargetDataLine dataLine = line;
+        if (dataLine != null) {
             logger.info("Stopping Line.");
-            line.stop();
-            line.close();
+            try {
+                dataLine.stop();
+                dataLine.close();
+            } catch (RuntimeException e) {
+                // The device may already be gone (e.g. unplugged)
+                logger.warn("Failed to close the microphone line", e);
+            }
             line = null;
             logger.info("Line closed.");
+            try {
+                // Wait for the recording thread to finalize the WAV header
+                if (!finished.await(5, TimeUnit.SECONDS)) {
+                    logger.warn("Timed out waiting for the WAV file to be finalized");
+                }
+            } catch (InterruptedException e) {
+                Thread.currentThread().interrupt();
+            }
+        }
+    }
+
+    /**
+     * @return the formats tried when the configured one is not supported: stereo, then
+     * unsigned mono and unsigned stereo
+     */
+    private static List<AudioFormat> fallbackFormats(AudioFormat format) {
+        List<AudioFormat> formats = new ArrayList<>();
+        formats.add(withChannels(format, 2));
+        for (int channels = 1; channels <= 2; channels++) {
+            formats.add(new AudioFormat(AudioFormat.Encoding.PCM_UNSIGNED, format.getSampleRate(),
+                    format.getSampleSizeInBits(), channels, format.getSampleSizeInBits() / 8 * channels,
+                    format.getSampleRate(), format.isBigEndian()));
         }
+        return formats;
+    }
+
+    /**
+     * @return a signed PCM format like the given one with the given channel count
+     */
+    private static AudioFormat withChannels(AudioFormat format, int channels) {
+        return new AudioFormat(format.getSampleRate(), format.getSampleSizeInBits(), channels, true, format.isBigEndian());
     }
 
     private Mixer.Info getMixerInfoByName(String name) {
@@ -0,0 +1,339 @@
+package org.whispercat.recording;
+
+import org.whispercat.ConsoleLogger;
+import org.whispercat.DisplayFormat;
+import org.whispercat.recording.clients.TempFile;
+
+import javax.sound.sampled.AudioFileFormat;
+import javax.sound.sampled.AudioFormat;
+import javax.sound.sampled.AudioInputStream;
+import javax.sound.sampled.AudioSystem;
+import java.io.ByteArrayInputStream;
+import java.io.File;
+
+/**
+ * Converts recordings to 16-bit mono PCM at the configured sample rate before transcription.
+ * Resampling uses linear interpolation, which handles non-integer ratios (e.g. 44.1 kHz to
+ * 16 kHz) and keeps the duration of the recording.
+ */
+public class AudioResampler {
+
+    private static final org.apache.logging.log4j.Logger logger =
+        org.apache.logging.log4j.LogManager.getLogger(AudioResampler.class);
+
+    /** Peak level quiet recordings are raised to when normalization is enabled. */
+    public static final float NORMALIZE_TARGET_PEAK = 0.9f;
+
+    /**
+     * Resamples a WAV file if its sample rate differs from the target rate.
+     *
+     * @param originalFile the recording
+     * @param targetRate   the sample rate the providers should receive
+     * @param outputDir    where to write the resampled file
+     * @return a guard that deletes the resampled file when closed, or that holds the original
+     *         if it already matches or cannot be resampled
+     */
+    public static TempFile resampleIfNeeded(File originalFile, float targetRate, File outputDir) {
+        if (!originalFile.getName().toLowerCase().endsWith(".wav")) {
+            return TempFile.borrowed(originalFile);
+        }
+        ConsoleLogger console = ConsoleLogger.getInstance();
+        try (AudioInputStream audioStream = AudioSystem.getAudioInputStream(originalFile)) {
+            AudioFormat format = audioStream.getFormat();
+            if (Math.abs(format.getSampleRate() - targetRate) < 1.0f) {
+                return TempFile.borrowed(originalFile);
+            }
+            if (format.getEncoding() != AudioFormat.Encoding.PCM_SIGNED || format.getSampleSizeInBits() != 16) {
+                console.log("Audio format not supported for resampling (not 16-bit PCM), skipping");
+                return TempFile.borrowed(originalFile);
+            }
+
+            byte[] resampled = resample(audioStream.readAllBytes(), format, targetRate);
+            AudioFormat targetFormat = new AudioFormat(targetRate, 16, format.getChannels(), true, format.isBigEndian());
+            String resampledFileName = originalFile.getName().replaceAll("(?i)\\.wav$", "_resampled.wav");
+            File resampledFile = new File(outputDir, resampledFileName);
+            try (AudioInputStream resampledStream = new AudioInputStream(
+                    new ByteArrayInputStream(resampled), targetFormat, resampled.length / targetFormat.getFrameSize())) {
+                AudioSystem.write(resampledStream, AudioFileFormat.Type.WAVE, resampledFile);
+            } catch (Exception e) {
+                TempFile.owned(resampledFile).close();
+                throw e;
+            }
+
+            console.log(DisplayFormat.format("Resampled audio from %.0f Hz to %.0f Hz", format.getSampleRate(), targetRate));
+            return TempFile.owned(resampledFile);
+        } catch (Exception e) {
+            logger.error("Error resampling audio", e);
+            console.logError("Resampling failed: " + e.getMessage());
+            return TempFile.borrowed(originalFile);
+        }
+    }
+
+    /**
+     * Converts a 24-bit or 32-bit (integer or float) WAV file to 16-bit PCM, the format the
+     * rest of the audio processing works with.
+     *
+     * @param originalFile the recording
+     * @param outputDir    where to write the converted file
+     * @return a guard that deletes the 16-bit file when closed, or that holds the original if it
+     *         already is 16-bit or cannot be converted
+     */
+    public static TempFile toPcm16IfNeeded(File originalFile, File outputDir) {
+        if (!originalFile.getName().toLowerCase().endsWith(".wav")) {
+            return TempFile.borrowed(originalFile);
+        }
+        ConsoleLogger console = ConsoleLogger.getInstance();
+        try (AudioInputStream audioStream = AudioSystem.getAudioInputStream(originalFile)) {
+            AudioFormat format = audioStream.getFormat();
+            int bits = format.getSampleSizeInBits();
+            boolean wideInteger = format.getEncoding() == AudioFormat.Encoding.PCM_SIGNED && (bits == 24 || bits == 32);
+            boolean float32 = format.getEncoding() == AudioFormat.Encoding.PCM_FLOAT && bits == 32;
+            if (!wideInteger && !float32) {
+                return TempFile.borrowed(originalFile);
+            }
+
+            byte[] converted = toPcm16(audioStream.readAllBytes(), format);
+            AudioFormat targetFormat = new AudioFormat(format.getSampleRate(), 16, format.getChannels(), true, format.isBigEndian());
+            String convertedFileName = originalFile.getName().replaceAll("(?i)\\.wav$", "_16bit.wav");
+            File convertedFile = new File(outputDir, convertedFileName);
+            try (AudioInputStream convertedStream = new AudioInputStream(
+                    new ByteArrayInputStream(converted), targetFormat, converted.length / targetFormat.getFrameSize())) {
+                AudioSystem.write(convertedStream, AudioFileFormat.Type.WAVE, convertedFile);
+            } catch (Exception e) {
+                TempFile.owned(convertedFile).close();
+                throw e;
+            }
+
+            console.log(DisplayFormat.format("Converted %d-bit%s audio to 16-bit", bits, float32 ? " float" : ""));
+            return TempFile.owned(convertedFile);
+        } catch (Exception e) {
+            logger.error("Error converting audio to 16-bit", e);
+            console.logError("Sample format conversion failed: " + e.getMessage());
+            return TempFile.borrowed(originalFile);
+        }
+    }
+
+    /**
+     * Converts 24-bit or 32-bit integer PCM, or 32-bit float PCM, to 16-bit signed PCM.
+     * Float samples outside -1.0..1.0 are clipped.
+     *
+     * @param audioData the samples
+     * @param format    the format of the samples
+     * @return 16-bit samples in the same channel layout and byte order
+     */
+    static byte[] toPcm16(byte[] audioData, AudioFormat format) {
+        int sampleBytes = format.getSampleSizeInBits() / 8;
+        boolean bigEndian = format.isBigEndian();
+        boolean isFloat = format.getEncoding() == AudioFormat.Encoding.PCM_FLOAT;
+        int samples = audioData.length / sampleBytes;
+
+        byte[] output = new byte[samples * 2];
+        for (int i = 0; i < samples; i++) {
+            int offset = i * sampleBytes;
+            double normalized = isFloat
+                    ? Float.intBitsToFloat(PcmSamples.read(audioData, offset, 4, bigEndian))
+                    : PcmSamples.readNormalized(audioData, offset, sampleBytes, bigEndian);
+            int value = (int) Math.round(Math.max(-1.0, Math.min(1.0, normalized)) * 32767);
+            writeSample(output, i * 2, value, bigEndian);
+        }
+        return output;
+    }
+
+    /**
+     * Scales a 16-bit WAV file so that its loudest sample reaches the target peak. Helps
+     * silence detection on quiet recordings, whose speech would otherwise fall below the
+     * threshold. Recordings already at or above the target are left unchanged.
+     *
+     * @param originalFile the recording
+     * @param targetPeak   the peak to reach, 0.0-1.0 of full scale
+     * @param outputDir    where to write the normalized file
+     * @return a guard that deletes the normalized file when closed, or that holds the original if
+     *         it needs no gain or cannot be normalized
+     */
+    public static TempFile normalizePeakIfNeeded(File originalFile, float targetPeak, File outputDir) {
+        if (!originalFile.getName().toLowerCase().endsWith(".wav")) {
+            return TempFile.borrowed(originalFile);
+        }
+        ConsoleLogger console = ConsoleLogger.getInstance();
+        try (AudioInputStream audioStream = AudioSystem.getAudioInputStream(originalFile)) {
+            AudioFormat format = audioStream.getFormat();
+            if (format.getEncoding() != AudioFormat.Encoding.PCM_SIGNED || format.getSampleSizeInBits() != 16) {
+                console.log("Audio format not supported for normalization (not 16-bit PCM), skipping");
+                return TempFile.borrowed(originalFile);
+            }
+
+            byte[] audioData = audioStream.readAllBytes();
+            float gain = normalizePeak(audioData, format.isBigEndian(), targetPeak);
+            if (gain <= 1.0f) {
+                return TempFile.borrowed(originalFile);
+            }
+            String normalizedFileName = originalFile.getName().replaceAll("(?i)\\.wav$", "_normalized.wav");
+            File normalizedFile = new File(outputDir, normalizedFileName);
+            try (AudioInputStream normalizedStream = new AudioInputStream(
+                    new ByteArrayInputStream(audioData), format, audioData.length / format.getFrameSize())) {
+                AudioSystem.write(normalizedStream, AudioFileFormat.Type.WAVE, normalizedFile);
+            } catch (Exception e) {
+                TempFile.owned(normalizedFile).close();
+                throw e;
+            }
+
+            console.log(DisplayFormat.format("Normalized audio peak (gain %.1fx, %+.1f dB)", gain, 20 * Math.log10(gain)));
+            return TempFile.owned(normalizedFile);
+        } catch (Exception e) {
+            logger.error("Error normalizing audio", e);
+            console.logError("Normalization failed: " + e.getMessage());
+            return TempFile.borrowed(originalFile);
+        }
+    }
+
+    /**
+     * Scales 16-bit signed PCM samples in place so the maximum absolute amplitude reaches the
+     * target peak. Only raises the level; digital silence and loud audio are left unchanged.
+     *
+     * @param audioData  the samples, modified in place
+     * @param bigEndian  byte order of the samples
+     * @param targetPeak the peak to reach, 0.0-1.0 of full scale
+     * @return the applied gain, 1.0 if the samples were not changed
+     */
+    static float normalizePeak(byte[] audioData, boolean bigEndian, float targetPeak) {
+        int samples = audioData.length / 2;
+        int peak = 0;
+        for (int i = 0; i < samples; i++) {
+            peak = Math.max(peak, Math.abs(readSample(audioData, i * 2, bigEndian)));
+        }
+        if (peak == 0) {
+            return 1.0f;
+        }
+        float gain = (float) (targetPeak * 32767 / peak);
+        if (gain <= 1.0f) {
+            return 1.0f;
+        }
+        for (int i = 0; i < samples; i++) {
+            int value = Math.round(readSample(audioData, i * 2, bigEndian) * gain);
+            writeSample(audioData, i * 2, Math.max(-32768, Math.min(32767, value)), bigEndian);
+        }
+        return gain;
+    }
+
+    /**
+     * Averages the channels of a multi-channel WAV file into one, so that silence detection
+     * and level analysis see a single stream.
+     *
+     * @param originalFile the recording
+     * @param outputDir    where to write the mono file
+     * @return a guard that deletes the mono file when closed, or that holds the original if it
+     *         already is mono or cannot be converted
+     */
+    public static TempFile downmixIfNeeded(File originalFile, File outputDir) {
+        if (!originalFile.getName().toLowerCase().endsWith(".wav")) {
+            return TempFile.borrowed(originalFile);
+        }
+        ConsoleLogger console = ConsoleLogger.getInstance();
+        try (AudioInputStream audioStream = AudioSystem.getAudioInputStream(originalFile)) {
+            AudioFormat format = audioStream.getFormat();
+            if (format.getChannels() <= 1) {
+                return TempFile.borrowed(originalFile);
+            }
+            if (format.getEncoding() != AudioFormat.Encoding.PCM_SIGNED || format.getSampleSizeInBits() != 16) {
+                console.log("Audio format not supported for downmixing (not 16-bit PCM), skipping");
+                return TempFile.borrowed(originalFile);
+            }
+
+            byte[] mono = toMono(audioStream.readAllBytes(), format);
+            AudioFormat monoFormat = new AudioFormat(format.getSampleRate(), 16, 1, true, format.isBigEndian());
+            String monoFileName = originalFile.getName().replaceAll("(?i)\\.wav$", "_mono.wav");
+            File monoFile = new File(outputDir, monoFileName);
+            try (AudioInputStream monoStream = new AudioInputStream(
+                    new ByteArrayInputStream(mono), monoFormat, mono.length / monoFormat.getFrameSize())) {
+                AudioSystem.write(monoStream, AudioFileFormat.Type.WAVE, monoFile);
+            } catch (Exception e) {
+                TempFile.owned(monoFile).close();
+                throw e;
+            }
+
+            console.log(DisplayFormat.format("Downmixed audio from %d channels to mono", format.getChannels()));
+            return TempFile.owned(monoFile);
+        } catch (Exception e) {
+            logger.error("Error downmixing audio", e);
+            console.logError("Downmixing failed: " + e.getMessage());
+            return TempFile.borrowed(originalFile);
+        }
+    }
+
+    /**
+     * Averages interleaved 16-bit signed PCM channels into one. The frame count, and with it
+     * the duration, stays the same.
+     *
+     * @param audioData the interleaved samples
+     * @param format    the format of the samples
+     * @return mono samples in the same byte order
+     */
+    static byte[] toMono(byte[] audioData, AudioFormat format) {
+        int channels = format.getChannels();
+        boolean bigEndian = format.isBigEndian();
+        int frameSize = 2 * channels;
+        int frames = audioData.length / frameSize;
+
+        byte[] output = new byte[frames * 2];
+        for (int frame = 0; frame < frames; frame++) {
+            int sum = 0;
+            for (int channel = 0; channel < channels; channel++) {
+                sum += readSample(audioData, frame * frameSize + channel * 2, bigEndian);
+            }
+            writeSample(output, frame * 2, Math.round(sum / (float) channels), bigEndian);
+        }
+        return output;
+    }
+
+    /**
+     * Resamples interleaved 16-bit signed PCM data.
+     *
+     * @param audioData  the source samples
+     * @param format     the format of the source samples
+     * @param targetRate the sample rate to convert to
+     * @return the resampled data in the same channel layout and byte order
+     */
+    static byte[] resample(byte[] audioData, AudioFormat format, float targetRate) {
+        int channels = format.getChannels();
+        boolean bigEndian = format.isBigEndian();
+        int frameSize = 2 * channels;
+        int sourceFrames = audioData.length / frameSize;
+        double ratio = format.getSampleRate() / targetRate;
+        int targetFrames = (int) Math.round(sourceFrames / ratio);
+
+        byte[] output = new byte[targetFrames * frameSize];
+        for (int frame = 0; frame < targetFrames; frame++) {
+            double position = frame * ratio;
+            int index = (int) position;
+            double fraction = position - index;
+            int next = Math.min(index + 1, sourceFrames - 1);
+            index = Math.min(index, sourceFrames - 1);
+            for (int channel = 0; channel < channels; channel++) {
+                int a = readSample(audioData, index * frameSize + channel * 2, bigEndian);
+                int b = readSample(audioData, next * frameSize + channel * 2, bigEndian);
+                int value = (int) Math.round(a + (b - a) * fraction);
+                writeSample(output, frame * frameSize + channel * 2, value, bigEndian);
+            }
+        }
+        return output;
+    }
+
+    private static int readSample(byte[] data, int offset, boolean bigEndian) {
+        if (bigEndian) {
+            return (short) ((data[offset] << 8) | (data[offset + 1] & 0xFF));
+        }
+        return (short) ((data[offset + 1] << 8) | (data[offset] & 0xFF));
+    }
+
+    private static void writeSample(byte[] data, int offset, int value, boolean bigEndian) {
+        byte high = (byte) (value >> 8);
+        byte low = (byte) value;
+        if (bigEndian) {
+            data[offset] = high;
+            data[offset + 1] = low;
+        } else {
+            data[offset] = low;
+            data[offset + 1] = high;
+        }
+    }
+}
@@ -0,0 +1,79 @@
+package org.whispercat.recording;
+
+import org.whispercat.CancellationToken;
+
+import java.io.File;
+import java.io.IOException;
+import java.util.function.UnaryOperator;
+
+/**
+ * Transcribes a recording and runs the selected pipeline on the result in the same
+ * background task, so the pipeline starts without a round-trip through the UI.
+ * The raw transcript is kept next to the pipeline output for display.
+ */
+class ChainedTranscription {
+    private final TranscriptionOutcome outcome;
+    private final String transcript;
+    private final String processedResult;
+    private final RuntimeException pipelineError;
+
+    private ChainedTranscription(TranscriptionOutcome outcome, String transcript, String processedResult,
+                                 RuntimeException pipelineError) {
+        this.outcome = outcome;
+        this.transcript = transcript;
+        this.processedResult = processedResult;
+        this.pipelineError = pipelineError;
+    }
+
+    /**
+     * Transcribes the recording, formats the text and runs the pipeline on it. The pipeline
+     * is skipped for empty transcripts.
+     *
+     * @param service      the transcription backend
+     * @param audioFile    the recording
+     * @param cancellation cancels the transcription; a pipeline already running is not affected
+     * @param formatter    turns the transcription into the displayed transcript
+     * @param pipeline     runs the pipeline on the transcript, or null to only transcribe
+     * @return the transcript and, if the pipeline ran, its output or failure
+     * @throws TranscriptionCancelledException if the transcription was cancelled
+     * @throws IOException if the transcription fails
+     */
+    static ChainedTranscription run(TranscriptionService service, File audioFile, CancellationToken cancellation,
+                                    UnaryOperator<String> formatter, UnaryOperator<String> pipeline) throws IOException {
+        TranscriptionOutcome outcome = service.transcribe(audioFile, cancellation);
+        String transcript = formatter.apply(outcome.getText());
+        if (pipeline == null || transcript == null || transcript.trim().isEmpty()) {
+            return new ChainedTranscription(outcome, transcript, null, null);
+        }
+        try {
+            return new ChainedTranscription(outcome, transcript, pipeline.apply(transcript), null);
+        } catch (RuntimeException e) {
+            return new ChainedTranscription(outcome, transcript, null, e);
+        }
+    }
+
+    public TranscriptionOutcome getOutcome() {
+        return outcome;
+    }
+
+    /**
+     * @return the formatted transcription, shown even when a pipeline ran
+     */
+    public String getTranscript() {
+        return transcript;
+    }
+
+    /**
+     * @return the pipeline output, or null if no pipeline ran or it failed
+     */
+    public String getProcessedResult() {
+        return processedResult;
+    }
+
+    /**
+     * @return why the pipeline failed, or null
+     */
+    public RuntimeException getPipelineError() {
+        return pipelineError;
+    }
+}
@@ -1,10 +1,17 @@
 package org.whispercat.recording;
 
+import org.apache.logging.log4j.LogManager;
+import org.apache.logging.log4j.Logger;
+import org.whispercat.DisplayFormat;
+import org.whispercat.Notificationmanager;
+import org.whispercat.ToastNotification;
+
 import javax.swing.*;
 import javax.swing.border.EmptyBorder;
 import java.awt.*;
 import java.awt.datatransfer.StringSelection;
-import java.text.SimpleDateFormat;
+import java.io.File;
+import java.io.IOException;
 import java.util.Date;
 import java.util.List;
 
@@ -13,11 +20,16 @@ import java.util.List;
  * Shows all previous pipeline results with timestamps and copy buttons.
  */
 public class HistoryPanel extends JPanel {
+    private static final Logger logger = LogManager.getLogger(HistoryPanel.class);
     private final JButton toggleButton;
     private final JPanel contentPanel;
     private final JScrollPane scrollPane;
     private boolean expanded = false;
-    private static final SimpleDateFormat TIME_FORMAT = new SimpleDateFormat("h:mm a");
+    private final JPanel recordingPanel;
+    private final JLabel recordingLabel;
+    private final JButton playButton;
+    private final RecordingPlayer player = new RecordingPlayer();
+    private File recordingFile;
     private static final int MAX_PREVIEW_LENGTH = 100;
 
     public HistoryPanel() {
@@ -41,6 +53,23 @@ public class HistoryPanel extends JPanel {
         togglePanel.add(toggleButton);
         add(togglePanel);
 
+        // Recording playback row, shown once a recording belongs to the session
+        recordingPanel = new JPanel(new FlowLayout(FlowLayout.LEFT, 10, 0));
+        recordingPanel.setAlignmentX(Component.LEFT_ALIGNMENT);
+        recordingLabel = new JLabel();
+        recordingLabel.setForeground(Color.GRAY);
+        recordingLabel.setFont(recordingLabel.getFont().deriveFont(Font.PLAIN, 11f));
+        playButton = new JButton("\u25B6 Play");
+        playButton.setFont(playButton.getFont().deriveFont(Font.PLAIN, 10f));
+        playButton.setMargin(new Insets(2, 8, 2, 8));
+        playButton.setToolTipText("Play back the recording of this transcription");
+        playButton.addActionListener(e -> togglePlayback());
+        recordingPanel.add(recordingLabel);
+        recordingPanel.add(playButton);
+        recordingPanel.setVisible(false);
+        add(recordingPanel);
+        player.setStateListener(() -> SwingUtilities.invokeLater(this::updatePlayButton));
+
         // Content panel (hidden by default)
         contentPanel = new JPanel();
         contentPanel.setLayout(new BoxLayout(contentPanel, BoxLayout.Y_AXIS));
@@ -108,6 +137,47 @@ public class HistoryPanel extends JPanel {
         contentPanel.repaint();
     }
 
+    /**
+     * Sets the recording the current history belongs to and stops any playback.
+     *
+     * @param file the recording, or null to hide the playback controls
+     */
+    public void setRecording(File file) {
+        player.stop();
+        recordingFile = file;
+        if (file != null) {
+            recordingLabel.setText("Recording: " + file.getName());
+        }
+        recordingPanel.setVisible(file != null);
+        updatePlayButton();
+        revalidate();
+        repaint();
+    }
+
+    /**
+     * Starts or stops playback of the session recording.
+     */
+    private void togglePlayback() {
+        if (player.getState() == RecordingPlayer.State.PLAYING) {
+            player.stop();
+            return;
+        }
+        try {
+            player.play(recordingFile);
+        } catch (IOException e) {
+            logger.warn("Could not play recording", e);
+            Notificationmanager.getInstance().showNotification(ToastNotification.Type.WARNING,
+                    recordingFile != null && !recordingFile.isFile()
+                            ? "Recording is no longer available."
+                            : "Could not play recording. See logs.");
+        }
+    }
+
+    private void updatePlayButton() {
+        boolean playing = player.getState() == RecordingPlayer.State.PLAYING;
+        playButton.setText(playing ? "\u25A0 Stop" : "\u25B6 Play");
+    }
+
     /**
      * Creates a panel for a single history result entry.
      */
@@ -125,7 +195,7 @@ public class HistoryPanel extends JPanel {
         pipelineLabel.setFont(pipelineLabel.getFont().deriveFont(Font.BOLD));
         headerPanel.add(pipelineLabel);
 
-        JLabel timeLabel = new JLabel(TIME_FORMAT.format(new Date(result.getTimestamp())));
+        JLabel timeLabel = new JLabel(DisplayFormat.shortTime(new Date(result.getTimestamp())));
         timeLabel.setForeground(Color.GRAY);
         timeLabel.setFont(timeLabel.getFont().deriveFont(Font.PLAIN, 11f));
         headerPanel.add(timeLabel);
@@ -137,6 +207,14 @@ public class HistoryPanel extends JPanel {
             headerPanel.add(durationLabel);
         }
 
+        if (result.getTokenUsage() != null) {
+            JLabel tokensLabel = new JLabel(result.getTokenUsage().getTotalTokens() + " tokens");
+            tokensLabel.setToolTipText(result.getTokenUsage().toString());
+            tokensLabel.setForeground(Color.GRAY);
+            tokensLabel.setFont(tokensLabel.getFont().deriveFont(Font.PLAIN, 10f));
+            headerPanel.add(tokensLabel);
+        }
+
         JButton copyButton = new JButton("Copy");
         copyButton.setFont(copyButton.getFont().deriveFont(Font.PLAIN, 10f));
         copyButton.setMargin(new Insets(2, 8, 2, 8));
@@ -0,0 +1,37 @@
+package org.whispercat.recording;
+
+import java.io.File;
+
+/**
+ * Where files created while preparing a recording for upload (converted, silence-removed,
+ * compressed or split audio) are written.
+ */
+public enum IntermediateFileLocation {
+    RECORDING_DIR("Next to the recording"),
+    TEMP_DIR("System temp folder");
+
+    private final String displayName;
+
+    IntermediateFileLocation(String displayName) {
+        this.displayName = displayName;
+    }
+
+    /**
+     * Resolves the directory for intermediate files of a recording.
+     *
+     * @param recording the recording being processed
+     * @return the directory to write to
+     */
+    public File directoryFor(File recording) {
+        File parent = recording.getAbsoluteFile().getParentFile();
+        if (this == TEMP_DIR || parent == null) {
+            return new File(System.getProperty("java.io.tmpdir"));
+        }
+        return parent;
+    }
+
+    @Override
+    public String toString() {
+        return displayName;
+    }
+}
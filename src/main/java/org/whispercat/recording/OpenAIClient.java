@@ -6,19 +6,32 @@ import com.fasterxml.jackson.databind.node.ArrayNode;
 import com.fasterxml.jackson.databind.node.ObjectNode;
 import org.apache.http.HttpEntity;
 import org.apache.http.client.methods.CloseableHttpResponse;
+import org.apache.http.client.methods.HttpGet;
 import org.apache.http.client.methods.HttpPost;
 import org.apache.http.entity.StringEntity;
 import org.apache.http.entity.ContentType;
 import org.apache.http.impl.client.CloseableHttpClient;
 import org.apache.http.impl.client.HttpClients;
 import org.whispercat.ConfigManager;
+import org.whispercat.HttpErrors;
+import org.whispercat.postprocessing.clients.ChatCompletion;
 
 import java.io.IOException;
 import java.nio.charset.StandardCharsets;
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.List;
+import java.util.Locale;
 
 public class OpenAIClient {
 
-    private static final String API_URL = "https://api.openai.com/v1/chat/completions";
+    /** Base URL of the official OpenAI API, used when no custom base URL is configured. */
+    public static final String DEFAULT_BASE_URL = "https://api.openai.com/v1";
+    // Model id fragments that identify non-chat models (audio, embeddings, images, moderation, legacy completions)
+    private static final String[] NON_CHAT_MODEL_MARKERS = {
+            "whisper", "embedding", "tts", "dall-e", "moderation", "transcribe", "realtime",
+            "audio", "image", "davinci", "babbage", "search"
+    };
     private final ConfigManager configManager;
 
     public OpenAIClient(ConfigManager configManager) {
@@ -36,32 +49,45 @@ public class OpenAIClient {
      * @throws IOException if an error occurs during the API call.
      */
     public String processText(String systemPrompt, String userPrompt, String model) throws IOException {
+        return processText(systemPrompt, userPrompt, model, false);
+    }
+
+    /**
+     * Processes the transcript like {@link #processText(String, String, String)}, optionally
+     * asking the API for a JSON object response.
+     *
+     * @param systemPrompt the system prompt.
+     * @param userPrompt   the user prompt.
+     * @param model        the model identifier.
+     * @param jsonMode     whether to send {@code response_format: {"type": "json_object"}}.
+     * @return the processed text returned by the API.
+     * @throws IOException if an error occurs during the API call.
+     */
+    public String processText(String systemPrompt, String userPrompt, String model, boolean jsonMode) throws IOException {
+        return complete(systemPrompt, userPrompt, model, jsonMode).getText();
+    }
+
+    /**
+     * Sends a chat completion request and returns the text together with the token usage.
+     * The configured max output tokens are sent if set.
+     *
+     * @param systemPrompt the system prompt.
+     * @param userPrompt   the user prompt.
+     * @param model        the model identifier.
+     * @param jsonMode     whether to send {@code response_format: {"type": "json_object"}}.
+     * @return the completion; its text is empty if the response has no choices.
+     * @throws IOException if an error occurs during the API call.
+     */
+    public ChatCompletion complete(String systemPrompt, String userPrompt, String model, boolean jsonMode) throws IOException {
         try (CloseableHttpClient httpClient = HttpClients.createDefault()) {
-            HttpPost httpPost = new HttpPost(API_URL);
+            String chatBaseUrl = configManager.getOpenAIChatBaseUrl();
+            HttpPost httpPost = new HttpPost(buildChatUrl(chatBaseUrl.isEmpty() ? configManager.getOpenAIBaseUrl() : chatBaseUrl));
             httpPost.setHeader("Authorization", "Bearer " + configManager.getApiKey());
             httpPost.setHeader("Content-Type", "application/json");
 
-            // Build the JSON payload using Jackson.
             ObjectMapper mapper = new ObjectMapper();
-            ObjectNode payload = mapper.createObjectNode();
-            payload.put("model", model);
-
-            // Build messages array:
-            ArrayNode messages = mapper.createArrayNode();
-
-            // System message.
-            ObjectNode systemMessage = mapper.createObjectNode();
-            systemMessage.put("role", "system");
-            systemMessage.put("content", systemPrompt);
-            messages.add(systemMessage);
-
-            // User message. We append the transcript to the user prompt.
-            ObjectNode userMessage = mapper.createObjectNode();
-            userMessage.put("role", "user");
-            userMessage.put("content", userPrompt);
-            messages.add(userMessage);
-
-            payload.set("messages", messages);
+            ObjectNode payload = buildChatPayload(mapper, systemPrompt, userPrompt, model, jsonMode,
+                    configManager.getChatMaxTokens());
 
             // Convert payload to JSON string.
             StringEntity entity = new StringEntity(payload.toString(), ContentType.APPLICATION_JSON);
@@ -72,23 +98,159 @@ public class OpenAIClient {
                 HttpEntity responseEntity = response.getEntity();
                 String responseString = new String(responseEntity.getContent().readAllBytes(), StandardCharsets.UTF_8);
 
-                if (statusCode != 200) {
-                    // Parse error message from response.
-                    JsonNode errorNode = mapper.readTree(responseString);
-                    String errorMessage = errorNode.path("error").path("message").asText();
-                    throw new IOException("Error from OpenAI API: " + errorMessage);
+                HttpErrors.checkStatus("OpenAI API", statusCode, responseString);
+
+                // Parse the successful response to get the completion text and usage.
+                return ChatCompletion.fromResponse(mapper.readTree(responseString));
+            }
+        }
+    }
+
+    /**
+     * Builds the body of an OpenAI-compatible chat completion request with a system and a
+     * user message. Shared by all chat providers, which may add their own fields.
+     *
+     * @param mapper       the mapper creating the nodes
+     * @param systemPrompt the system prompt
+     * @param userPrompt   the user prompt, including the transcript
+     * @param model        the model identifier
+     * @param jsonMode     whether to request a JSON object response
+     * @param maxTokens    the maximum output tokens, 0 or less to leave it to the server
+     * @return the request body
+     */
+    public static ObjectNode buildChatPayload(ObjectMapper mapper, String systemPrompt, String userPrompt, String model,
+                                              boolean jsonMode, int maxTokens) {
+        ObjectNode payload = mapper.createObjectNode();
+        payload.put("model", model);
+
+        ArrayNode messages = mapper.createArrayNode();
+        ObjectNode systemMessage = mapper.createObjectNode();
+        systemMessage.put("role", "system");
+        systemMessage.put("content", systemPrompt);
+        messages.add(systemMessage);
+        ObjectNode userMessage = mapper.createObjectNode();
+        userMessage.put("role", "user");
+        userMessage.put("content", userPrompt);
+        messages.add(userMessage);
+        payload.set("messages", messages);
+
+        if (jsonMode) {
+            payload.set("response_format", jsonObjectResponseFormat(mapper));
+        }
+        if (maxTokens > 0) {
+            payload.put("max_tokens", maxTokens);
+        }
+        return payload;
+    }
+
+    /**
+     * Builds the {@code response_format} value that makes chat completions return a JSON object.
+     *
+     * @param mapper the mapper creating the node
+     * @return the response format node
+     */
+    public static ObjectNode jsonObjectResponseFormat(ObjectMapper mapper) {
+        ObjectNode responseFormat = mapper.createObjectNode();
+        responseFormat.put("type", "json_object");
+        return responseFormat;
+    }
+
+    /**
+     * Builds the chat completions URL for the given base URL. The base URL is expected to
+     * include the API version path (e.g. http://localhost:1234/v1), like the OpenAI SDKs.
+     *
+     * @param baseUrl the configured base URL; blank for the official OpenAI API
+     * @return the full chat completions URL
+     */
+    static String buildChatUrl(String baseUrl) {
+        return resolveBaseUrl(baseUrl) + "/chat/completions";
+    }
+
+    /**
+     * Builds the models URL for the given base URL.
+     *
+     * @param baseUrl the configured base URL; blank for the official OpenAI API
+     * @return the full models URL
+     */
+    static String buildModelsUrl(String baseUrl) {
+        return resolveBaseUrl(baseUrl) + "/models";
+    }
+
+    /**
+     * Resolves a configured base URL, adding http:// if no scheme is given and removing
+     * trailing slashes.
+     *
+     * @param baseUrl the configured base URL; blank for the official OpenAI API
+     * @return the base URL including the version path, without a trailing slash
+     */
+    public static String resolveBaseUrl(String baseUrl) {
+        String url = (baseUrl == null || baseUrl.trim().isEmpty()) ? DEFAULT_BASE_URL : baseUrl.trim();
+        if (!url.toLowerCase(Locale.ROOT).startsWith("http://") && !url.toLowerCase(Locale.ROOT).startsWith("https://")) {
+            url = "http://" + url;
+        }
+        while (url.endsWith("/")) {
+            url = url.substring(0, url.length() - 1);
+        }
+        return url;
+    }
+
+    /**
+     * Fetches the models available for the configured API key and returns only
+     * those usable for chat completions (pipeline prompt units).
+     *
+     * @return sorted list of chat-capable model ids.
+     * @throws IOException if an error occurs during the API call.
+     */
+    public List<String> fetchChatModels() throws IOException {
+        try (CloseableHttpClient httpClient = HttpClients.createDefault()) {
+            HttpGet httpGet = new HttpGet(buildModelsUrl(configManager.getOpenAIBaseUrl()));
+            httpGet.setHeader("Authorization", "Bearer " + configManager.getApiKey());
+            httpGet.setHeader("Accept", "application/json");
+
+            try (CloseableHttpResponse response = httpClient.execute(httpGet)) {
+                int statusCode = response.getStatusLine().getStatusCode();
+                String responseString = new String(response.getEntity().getContent().readAllBytes(), StandardCharsets.UTF_8);
+                ObjectMapper mapper = new ObjectMapper();
+                HttpErrors.checkStatus("OpenAI API", statusCode, responseString);
+                List<String> modelIds = new ArrayList<>();
+                for (JsonNode model : mapper.readTree(responseString).path("data")) {
+                    modelIds.add(model.path("id").asText());
                 }
+                return filterChatModels(modelIds);
+            }
+        }
+    }
 
-                // Parse the successful response to get the completion text.
-                JsonNode jsonResponse = mapper.readTree(responseString);
-                // The response should include a "choices" array with at least one element.
-                JsonNode choices = jsonResponse.path("choices");
-                if (choices.isArray() && choices.size() > 0) {
-                    JsonNode messageNode = choices.get(0).path("message");
-                    return messageNode.path("content").asText();
+    /**
+     * Filters a list of OpenAI model ids down to chat-capable models.
+     *
+     * @param modelIds all model ids returned by the models endpoint.
+     * @return sorted chat model ids.
+     */
+    public static List<String> filterChatModels(List<String> modelIds) {
+        List<String> chatModels = new ArrayList<>();
+        for (String id : modelIds) {
+            if (id == null || id.isEmpty()) {
+                continue;
+            }
+            String lower = id.toLowerCase(Locale.ROOT);
+            boolean isChatFamily = lower.startsWith("gpt-") || lower.startsWith("chatgpt-")
+                    || lower.matches("^o\\d.*") || lower.startsWith("ft:gpt-");
+            if (!isChatFamily) {
+                continue;
+            }
+            boolean excluded = false;
+            for (String marker : NON_CHAT_MODEL_MARKERS) {
+                if (lower.contains(marker)) {
+                    excluded = true;
+                    break;
                 }
             }
+            if (!excluded) {
+                chatModels.add(id);
+            }
         }
-        return "";
+        Collections.sort(chatModels);
+        return chatModels;
     }
-}
\ No newline at end of file
+}
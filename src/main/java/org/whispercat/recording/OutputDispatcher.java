@@ -0,0 +1,131 @@
+package org.whispercat.recording;
+
+import com.fasterxml.jackson.databind.ObjectMapper;
+import com.fasterxml.jackson.databind.node.ObjectNode;
+import org.apache.http.client.methods.CloseableHttpResponse;
+import org.apache.http.client.methods.HttpPost;
+import org.apache.http.entity.ContentType;
+import org.apache.http.entity.StringEntity;
+import org.apache.http.impl.client.CloseableHttpClient;
+import org.apache.http.impl.client.HttpClients;
+import org.apache.logging.log4j.LogManager;
+import org.apache.logging.log4j.Logger;
+import org.whispercat.ConfigManager;
+import org.whispercat.ConsoleLogger;
+import org.whispercat.HttpErrors;
+import org.whispercat.Notificationmanager;
+import org.whispercat.ToastNotification;
+
+import java.io.File;
+import java.io.IOException;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Files;
+import java.nio.file.StandardOpenOption;
+import java.util.Set;
+
+/**
+ * Delivers final results to the non-clipboard output sinks (file and webhook).
+ * The clipboard sink is handled by the recorder screen, since it also drives auto-paste.
+ */
+public class OutputDispatcher {
+    private static final Logger logger = LogManager.getLogger(OutputDispatcher.class);
+    private static final ObjectMapper MAPPER = new ObjectMapper();
+
+    private final ConfigManager configManager;
+
+    public OutputDispatcher(ConfigManager configManager) {
+        this.configManager = configManager;
+    }
+
+    /**
+     * Sends the text to the configured file and webhook sinks on a background thread.
+     * Failures are logged and shown as a toast; they never block the UI.
+     *
+     * @param text the final result
+     */
+    public void dispatch(String text) {
+        Set<OutputSink> sinks = configManager.getOutputSinks();
+        if (text == null || text.isEmpty() || !(sinks.contains(OutputSink.FILE) || sinks.contains(OutputSink.WEBHOOK))) {
+            return;
+        }
+        Thread thread = new Thread(() -> deliverAll(text, sinks), "output-dispatch");
+        thread.setDaemon(true);
+        thread.start();
+    }
+
+    /**
+     * Delivers the text to each of the given file and webhook sinks on the calling thread.
+     *
+     * @param text  the final result
+     * @param sinks the enabled sinks; the clipboard sink is ignored here
+     */
+    void deliverAll(String text, Set<OutputSink> sinks) {
+        if (sinks.contains(OutputSink.FILE)) {
+            deliver(OutputSink.FILE, () -> appendToFile(text, configManager.getOutputFilePath()));
+        }
+        if (sinks.contains(OutputSink.WEBHOOK)) {
+            deliver(OutputSink.WEBHOOK, () -> postToWebhook(text, configManager.getOutputWebhookUrl()));
+        }
+    }
+
+    private interface Delivery {
+        void run() throws IOException;
+    }
+
+    private void deliver(OutputSink sink, Delivery delivery) {
+        try {
+            delivery.run();
+            logger.info("Result delivered to {}", sink);
+        } catch (IOException e) {
+            logger.error("Failed to deliver result to {}", sink, e);
+            ConsoleLogger.getInstance().logError("Output to " + sink.name().toLowerCase() + " failed: " + e.getMessage());
+            Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR,
+                    "Output to " + sink.name().toLowerCase() + " failed. See logs.");
+        }
+    }
+
+    /**
+     * Appends the text and a line break to the given file, creating it if needed.
+     */
+    static void appendToFile(String text, String path) throws IOException {
+        if (path == null || path.trim().isEmpty()) {
+            throw new IOException("No output file configured");
+        }
+        File file = new File(path.trim());
+        File parent = file.getAbsoluteFile().getParentFile();
+        if (parent != null && !parent.exists() && !parent.mkdirs()) {
+            throw new IOException("Could not create folder " + parent);
+        }
+        Files.write(file.toPath(), (text + System.lineSeparator()).getBytes(StandardCharsets.UTF_8),
+                StandardOpenOption.CREATE, StandardOpenOption.APPEND);
+    }
+
+    /**
+     * Builds the JSON body sent to the webhook.
+     *
+     * @param text the result text
+     * @return the payload, e.g. {"text": "..."}
+     */
+    static String buildWebhookPayload(String text) {
+        ObjectNode payload = MAPPER.createObjectNode();
+        payload.put("text", text);
+        return payload.toString();
+    }
+
+    private static void postToWebhook(String text, String url) throws IOException {
+        if (url == null || url.trim().isEmpty()) {
+            throw new IOException("No webhook URL configured");
+        }
+        try (CloseableHttpClient httpClient = HttpClients.createDefault()) {
+            HttpPost httpPost = new HttpPost(url.trim());
+            httpPost.setEntity(new StringEntity(buildWebhookPayload(text), ContentType.APPLICATION_JSON));
+            try (CloseableHttpResponse response = httpClient.execute(httpPost)) {
+                int statusCode = response.getStatusLine().getStatusCode();
+                String responseString = response.getEntity() != null
+                        ? new String(response.getEntity().getContent().readAllBytes(), StandardCharsets.UTF_8)
+                        : "";
+                HttpErrors.checkStatus("webhook", statusCode, responseString);
+            }
+        }
+    }
+}
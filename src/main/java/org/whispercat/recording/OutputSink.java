@@ -0,0 +1,10 @@
+package org.whispercat.recording;
+
+/**
+ * Destinations the final transcription or pipeline result can be delivered to.
+ */
+public enum OutputSink {
+    CLIPBOARD,  // Copy to the clipboard (and auto-paste if enabled)
+    FILE,       // Append to a text file
+    WEBHOOK     // POST as JSON to an HTTP endpoint
+}
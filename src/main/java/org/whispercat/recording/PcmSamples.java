@@ -0,0 +1,76 @@
+package org.whispercat.recording;
+
+/**
+ * Reads signed integer PCM samples of 8 to 32 bits, so that recordings from external
+ * recorders (often 24-bit) are analyzed with the right scale.
+ */
+final class PcmSamples {
+
+    private PcmSamples() {
+    }
+
+    /**
+     * Reads one signed sample.
+     *
+     * @param data        the audio bytes
+     * @param offset      offset of the sample's first byte
+     * @param sampleBytes bytes per sample, 1 to 4
+     * @param bigEndian   byte order
+     * @return the sample value, sign-extended
+     */
+    static int read(byte[] data, int offset, int sampleBytes, boolean bigEndian) {
+        int value = 0;
+        for (int i = 0; i < sampleBytes; i++) {
+            int b = data[offset + (bigEndian ? i : sampleBytes - 1 - i)] & 0xFF;
+            value = (value << 8) | b;
+        }
+        int shift = 32 - sampleBytes * 8;
+        return (value << shift) >> shift;
+    }
+
+    /**
+     * Writes one signed sample.
+     *
+     * @param data        the audio bytes
+     * @param offset      offset of the sample's first byte
+     * @param sampleBytes bytes per sample, 1 to 4
+     * @param value       the sample value, within the range of the sample size
+     * @param bigEndian   byte order
+     */
+    static void write(byte[] data, int offset, int sampleBytes, int value, boolean bigEndian) {
+        for (int i = 0; i < sampleBytes; i++) {
+            data[offset + (bigEndian ? sampleBytes - 1 - i : i)] = (byte) (value >> (8 * i));
+        }
+    }
+
+    /**
+     * Converts unsigned samples to signed ones in place by flipping the sign bit, e.g. the
+     * unsigned 16-bit values 0, 32768 and 65535 become -32768, 0 and 32767.
+     *
+     * @param data        the audio bytes
+     * @param length      number of bytes to convert, a multiple of the sample size
+     * @param sampleBytes bytes per sample, 1 to 4
+     * @param bigEndian   byte order
+     */
+    static void unsignedToSigned(byte[] data, int length, int sampleBytes, boolean bigEndian) {
+        int msb = bigEndian ? 0 : sampleBytes - 1;
+        for (int offset = 0; offset + sampleBytes <= length; offset += sampleBytes) {
+            data[offset + msb] ^= (byte) 0x80;
+        }
+    }
+
+    /**
+     * @param sampleBytes bytes per sample, 1 to 4
+     * @return the magnitude of the most negative sample, used to normalize to -1.0..1.0
+     */
+    static double fullScale(int sampleBytes) {
+        return Math.pow(2, sampleBytes * 8 - 1);
+    }
+
+    /**
+     * Reads one sample normalized to -1.0..1.0.
+     */
+    static double readNormalized(byte[] data, int offset, int sampleBytes, boolean bigEndian) {
+        return read(data, offset, sampleBytes, bigEndian) / fullScale(sampleBytes);
+    }
+}
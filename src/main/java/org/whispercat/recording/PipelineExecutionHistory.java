@@ -1,5 +1,8 @@
 package org.whispercat.recording;
 
+import org.whispercat.postprocessing.TokenUsage;
+
+import java.io.File;
 import java.util.ArrayList;
 import java.util.List;
 import java.util.UUID;
@@ -11,6 +14,7 @@ import java.util.UUID;
 public class PipelineExecutionHistory {
     private String recordingId;
     private String originalTranscription;
+    private File recordingFile;
     private final List<PipelineResult> results = new ArrayList<>();
 
     /**
@@ -22,6 +26,7 @@ public class PipelineExecutionHistory {
         private final String resultText;
         private final long timestamp;
         private int executionTimeMs;
+        private TokenUsage tokenUsage;
 
         public PipelineResult(String uuid, String name, String text) {
             this.pipelineUuid = uuid;
@@ -54,6 +59,13 @@ public class PipelineExecutionHistory {
         public int getExecutionTimeMs() {
             return executionTimeMs;
         }
+
+        /**
+         * @return the tokens the pipeline used, or null if not reported
+         */
+        public TokenUsage getTokenUsage() {
+            return tokenUsage;
+        }
     }
 
     /**
@@ -63,8 +75,19 @@ public class PipelineExecutionHistory {
      * @param transcription The original transcription text
      */
     public void startNewSession(String transcription) {
+        startNewSession(transcription, null);
+    }
+
+    /**
+     * Starts a new recording session and remembers the recording for playback.
+     *
+     * @param transcription The original transcription text
+     * @param recordingFile The transcribed recording, or null if not available
+     */
+    public void startNewSession(String transcription, File recordingFile) {
         this.recordingId = UUID.randomUUID().toString();
         this.originalTranscription = transcription;
+        this.recordingFile = recordingFile;
         this.results.clear();
     }
 
@@ -89,7 +112,23 @@ public class PipelineExecutionHistory {
      * @param executionTimeMs Time taken to execute in milliseconds
      */
     public void addResult(String pipelineUuid, String pipelineName, String resultText, int executionTimeMs) {
-        results.add(0, new PipelineResult(pipelineUuid, pipelineName, resultText, executionTimeMs));
+        addResult(pipelineUuid, pipelineName, resultText, executionTimeMs, null);
+    }
+
+    /**
+     * Adds a pipeline execution result with execution time and token usage.
+     *
+     * @param pipelineUuid    The pipeline's UUID
+     * @param pipelineName    The pipeline's display name
+     * @param resultText      The processed text result
+     * @param executionTimeMs Time taken to execute in milliseconds
+     * @param tokenUsage      Tokens used by the pipeline's API calls, or null if not reported
+     */
+    public void addResult(String pipelineUuid, String pipelineName, String resultText, int executionTimeMs,
+                          TokenUsage tokenUsage) {
+        PipelineResult result = new PipelineResult(pipelineUuid, pipelineName, resultText, executionTimeMs);
+        result.tokenUsage = tokenUsage;
+        results.add(0, result);
     }
 
     /**
@@ -120,6 +159,13 @@ public class PipelineExecutionHistory {
         return originalTranscription;
     }
 
+    /**
+     * Returns the recording of this session, or null if there is none.
+     */
+    public File getRecordingFile() {
+        return recordingFile;
+    }
+
     /**
      * Returns the recording session ID.
      */
@@ -133,6 +179,7 @@ public class PipelineExecutionHistory {
     public void clear() {
         this.recordingId = null;
         this.originalTranscription = null;
+        this.recordingFile = null;
         this.results.clear();
     }
 
@@ -0,0 +1,109 @@
+package org.whispercat.recording;
+
+import org.apache.http.conn.ConnectTimeoutException;
+import org.apache.logging.log4j.LogManager;
+import org.apache.logging.log4j.Logger;
+
+import java.net.ConnectException;
+import java.net.NoRouteToHostException;
+import java.net.UnknownHostException;
+import java.util.Map;
+import java.util.concurrent.ConcurrentHashMap;
+import java.util.function.LongSupplier;
+
+/**
+ * Remembers providers that could not be connected to, so that further attempts within a
+ * short window fail fast with the cached error instead of waiting for another timeout.
+ * Entries are keyed by provider URL and cleared by a successful call or {@link #clear()}.
+ */
+public class ProviderHealth {
+    private static final Logger logger = LogManager.getLogger(ProviderHealth.class);
+    private static ProviderHealth instance;
+
+    private final LongSupplier clock;
+    private final Map<String, Failure> failures = new ConcurrentHashMap<>();
+
+    private static class Failure {
+        final long failedAt;
+        final String message;
+
+        Failure(long failedAt, String message) {
+            this.failedAt = failedAt;
+            this.message = message;
+        }
+    }
+
+    ProviderHealth(LongSupplier clock) {
+        this.clock = clock;
+    }
+
+    public static synchronized ProviderHealth getInstance() {
+        if (instance == null) {
+            instance = new ProviderHealth(System::currentTimeMillis);
+        }
+        return instance;
+    }
+
+    /**
+     * Fails fast if the provider was unreachable within the window.
+     *
+     * @param provider display name used in the error
+     * @param url      the provider URL the failure was recorded for
+     * @param windowMs how long a failure is remembered; 0 or less disables the check
+     * @throws ProviderUnreachableException if a recent connection failure is cached
+     */
+    public void checkReachable(String provider, String url, long windowMs) throws ProviderUnreachableException {
+        Failure failure = failures.get(url);
+        if (failure == null || windowMs <= 0) {
+            return;
+        }
+        long elapsed = clock.getAsLong() - failure.failedAt;
+        if (elapsed >= windowMs) {
+            failures.remove(url, failure);
+            return;
+        }
+        throw new ProviderUnreachableException(provider, failure.message, windowMs - elapsed);
+    }
+
+    /**
+     * Records the outcome of a call: connection failures are cached, success clears the entry.
+     * Other errors (e.g. HTTP errors from a server that is up) leave the state unchanged.
+     *
+     * @param url   the provider URL
+     * @param error the exception the call failed with, or null on success
+     */
+    public void record(String url, Throwable error) {
+        if (error == null) {
+            if (failures.remove(url) != null) {
+                logger.info("Provider at {} is reachable again", url);
+            }
+        } else if (isConnectionFailure(error)) {
+            logger.warn("Provider at {} is unreachable, failing fast for further attempts", url);
+            failures.put(url, new Failure(clock.getAsLong(), error.getMessage()));
+        }
+    }
+
+    /**
+     * Forgets all cached failures, so the next attempt contacts the providers again.
+     */
+    public void clear() {
+        failures.clear();
+    }
+
+    /**
+     * Checks whether an exception (or one of its causes) means the server could not be
+     * connected to at all, as opposed to the server answering with an error.
+     */
+    static boolean isConnectionFailure(Throwable error) {
+        for (Throwable t = error; t != null; t = t.getCause()) {
+            if (t instanceof ConnectException || t instanceof ConnectTimeoutException
+                    || t instanceof NoRouteToHostException || t instanceof UnknownHostException) {
+                return true;
+            }
+            if (t.getCause() == t) {
+                break;
+            }
+        }
+        return false;
+    }
+}
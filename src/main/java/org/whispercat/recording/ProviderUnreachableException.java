@@ -0,0 +1,30 @@
+package org.whispercat.recording;
+
+import java.io.IOException;
+
+/**
+ * Thrown without contacting the provider when it failed to connect shortly before,
+ * so repeated attempts against a server that is down do not each wait for a timeout.
+ */
+public class ProviderUnreachableException extends IOException {
+    private final String provider;
+    private final long retryInMs;
+
+    public ProviderUnreachableException(String provider, String lastError, long retryInMs) {
+        super(provider + " was unreachable (" + lastError + "). Skipping until it is retried in "
+                + Math.max(1, (retryInMs + 999) / 1000) + " s.");
+        this.provider = provider;
+        this.retryInMs = retryInMs;
+    }
+
+    public String getProvider() {
+        return provider;
+    }
+
+    /**
+     * @return milliseconds until the provider is contacted again
+     */
+    public long getRetryInMs() {
+        return retryInMs;
+    }
+}
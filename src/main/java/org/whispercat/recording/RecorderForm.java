@@ -6,9 +6,8 @@ import org.whispercat.*;
 import org.whispercat.postprocessing.PostProcessingData;
 import org.whispercat.postprocessing.Pipeline;
 import org.whispercat.postprocessing.PostProcessingService;
-import org.whispercat.recording.clients.FasterWhisperTranscribeClient;
-import org.whispercat.recording.clients.OpenAITranscribeClient;
-import org.whispercat.recording.clients.OpenWebUITranscribeClient;
+import org.whispercat.recording.clients.AudioFileTooLargeException;
+import org.whispercat.recording.clients.TranscriptionConfidence;
 
 import javax.sound.sampled.AudioInputStream;
 import javax.sound.sampled.AudioSystem;
@@ -20,35 +19,68 @@ import javax.swing.event.DocumentListener;
 import java.awt.*;
 import java.awt.datatransfer.DataFlavor;
 import java.awt.datatransfer.StringSelection;
+import java.awt.event.HierarchyEvent;
+import java.awt.event.HierarchyListener;
 import java.awt.event.ItemEvent;
 import java.awt.event.KeyEvent;
 import java.awt.event.MouseAdapter;
 import java.awt.event.MouseEvent;
 import java.io.BufferedInputStream;
 import java.io.File;
+import java.io.IOException;
 import java.io.InputStream;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Files;
 import java.text.SimpleDateFormat;
+import java.time.LocalDateTime;
 import java.util.Date;
 import java.util.List;
 import java.util.Optional;
+import java.util.concurrent.ExecutionException;
+import java.util.function.Consumer;
 
 
 public class RecorderForm extends javax.swing.JPanel {
 
     private final JTextArea processedText = new JTextArea(3, 20);
     private final JCheckBox enablePostProcessingCheckBox = new JCheckBox("Enable Post Processing");
+    // Hands-free dictation: listen, record one utterance, transcribe, listen again
+    private final JCheckBox dictationModeCheckBox = new JCheckBox("Dictation mode");
+    // Speech must stay above the silence threshold this long to start an utterance
+    private static final long DICTATION_ONSET_MS = 150;
+    private static final int WAV_HEADER_BYTES = 44;
     private final JButton recordButton;
+    private final JButton pauseButton;
+    // Only visible while a transcription is waiting for the server
+    private final JButton cancelTranscriptionButton;
     private final int baseIconSize = 40;  // Reduced from 200 for status indicator
-    private final OpenAITranscribeClient whisperClient;
     private final ConfigManager configManager;
-    private final FasterWhisperTranscribeClient fasterWhisperTranscribeClient;
-    private final OpenWebUITranscribeClient openWebUITranscribeClient;
+    private final TranscriptionService transcriptionService;
+    private final OutputDispatcher outputDispatcher;
     private boolean isRecording = false;
     private boolean isTranscribing = false;  // Track transcription/conversion state
+    // Updates the record button with the time spent transcribing
+    private final javax.swing.Timer transcriptionTimer;
+    // Enforces the maximum recording length and shows the remaining time
+    private final javax.swing.Timer recordingLimitTimer;
+    // Live input level while recording, so users can see the microphone is capturing
+    private final JProgressBar levelMeter = new JProgressBar(0, 100);
+    private final javax.swing.Timer levelMeterTimer;
+    private long transcriptionStartMs;
     private AudioRecorder recorder;
+    private final TranscriptionQueue transcriptionQueue = new TranscriptionQueue(this::startTranscription);
+    private AudioTranscriptionWorker activeTranscription;
     private final JTextArea transcriptionTextArea;
     private final JPanel statusIndicatorPanel;  // Status circles instead of large logo
     private JButton copyButton;
+    private JButton copyWithMetadataButton;
+    // Metadata of the last transcription, null until one succeeded
+    private TranscriptionOutcome lastOutcome;
+    private final JLabel lowConfidenceLabel;
+    // While selected, new transcriptions go to latestTranscriptionArea instead of replacing the result
+    private JToggleButton pinButton;
+    private final JTextArea latestTranscriptionArea = new JTextArea(2, 20);
+    private JPanel latestTranscriptionPanel;
 
     private static final org.apache.logging.log4j.Logger logger = org.apache.logging.log4j.LogManager.getLogger(RecorderForm.class);
 
@@ -56,16 +88,23 @@ public class RecorderForm extends javax.swing.JPanel {
     private JComboBox<PostProcessingItem> postProcessingSelectComboBox;
     private List<Pipeline> pipelineList;
     private JTextArea consoleLogArea;
+    private JPanel consolePanel;
+    private JPanel consoleDock;
+    private JSpinner logHeightSpinner;
+    private JButton detachLogButton;
+    private JDialog logWindow;
     private JButton runPipelineButton;
+    private JLabel pipelineProblemLabel;
+    private JButton stopPipelineButton;
     private boolean isManualPipelineRunning = false;
+    private CancellationToken pipelineCancellationToken;
     private final PipelineExecutionHistory pipelineHistory = new PipelineExecutionHistory();
     private HistoryPanel historyPanel;
 
     public RecorderForm(ConfigManager configManager) {
         this.configManager = configManager;
-        this.whisperClient = new OpenAITranscribeClient(configManager);
-        this.fasterWhisperTranscribeClient = new FasterWhisperTranscribeClient(configManager);
-        this.openWebUITranscribeClient = new OpenWebUITranscribeClient(configManager);
+        this.transcriptionService = new TranscriptionService(configManager);
+        this.outputDispatcher = new OutputDispatcher(configManager);
 
 
         JPanel centerPanel = new JPanel();
@@ -86,7 +125,9 @@ public class RecorderForm extends javax.swing.JPanel {
 
                 // Status colors: green (ready), red (recording), blue (transcribing)
                 g2.setColor(new Color(144, 238, 144)); // Light green - ready/idle
-                if (isRecording) {
+                if (isRecording && recorder != null && recorder.isPaused()) {
+                    g2.setColor(new Color(255, 193, 7)); // Amber - recording paused
+                } else if (isRecording) {
                     g2.setColor(new Color(255, 99, 71)); // Tomato red - recording
                 } else if (isTranscribing) {
                     g2.setColor(new Color(100, 149, 237)); // Cornflower blue - transcribing/converting
@@ -106,9 +147,26 @@ public class RecorderForm extends javax.swing.JPanel {
         recordButton.addActionListener(e -> {
             toggleRecording();
         });
+        pauseButton = new JButton("Pause");
+        pauseButton.setToolTipText("Pause the recording without starting a new file");
+        pauseButton.setEnabled(false);
+        pauseButton.addActionListener(e -> togglePause());
+        cancelTranscriptionButton = new JButton("Cancel");
+        cancelTranscriptionButton.setToolTipText("Cancel the running transcription; queued recordings are still transcribed");
+        cancelTranscriptionButton.setVisible(false);
+        cancelTranscriptionButton.addActionListener(e -> cancelTranscription());
+        transcriptionTimer = new javax.swing.Timer(1000, e -> updateTranscriptionElapsed());
+        recordingLimitTimer = new javax.swing.Timer(1000, e -> checkRecordingLimit());
+        levelMeterTimer = new javax.swing.Timer(50, e -> updateLevelMeter());
 
         statusIndicatorPanel.add(statusCircle);
         statusIndicatorPanel.add(recordButton);
+        statusIndicatorPanel.add(pauseButton);
+        statusIndicatorPanel.add(cancelTranscriptionButton);
+        levelMeter.setPreferredSize(new Dimension(80, 10));
+        levelMeter.setToolTipText("Input level");
+        levelMeter.setVisible(false);
+        statusIndicatorPanel.add(levelMeter);
 
         JPanel transcriptionPanel = new JPanel();
         transcriptionPanel.setLayout(new BoxLayout(transcriptionPanel, BoxLayout.Y_AXIS));
@@ -134,10 +192,65 @@ public class RecorderForm extends javax.swing.JPanel {
 
         transcriptionPanel.add(transcriptionTextScrollPane);
 
-        copyButton = new JButton("Copy");
-        copyButton.setToolTipText("Copy transcription to clipboard");
-        copyButton.setAlignmentX(Component.CENTER_ALIGNMENT);
-        copyButton.addActionListener(e -> copyTranscriptionToClipboard(transcriptionTextArea.getText()));
+        // Warning shown when the transcription looks unreliable
+        lowConfidenceLabel = new JLabel("\u26A0 Low confidence \u2014 verify result");
+        lowConfidenceLabel.setForeground(new Color(230, 126, 34));
+        lowConfidenceLabel.setAlignmentX(Component.CENTER_ALIGNMENT);
+        lowConfidenceLabel.setVisible(false);
+        transcriptionPanel.add(lowConfidenceLabel);
+
+        // Transcriptions that arrived while the result above was pinned
+        latestTranscriptionArea.setLineWrap(true);
+        latestTranscriptionArea.setWrapStyleWord(true);
+        latestTranscriptionArea.setEditable(false);
+        JLabel latestTranscriptionLabel = new JLabel("Latest transcription (result above is pinned):");
+        latestTranscriptionLabel.setForeground(Color.GRAY);
+        latestTranscriptionPanel = new JPanel(new BorderLayout(0, 2));
+        latestTranscriptionPanel.add(latestTranscriptionLabel, BorderLayout.NORTH);
+        latestTranscriptionPanel.add(new JScrollPane(latestTranscriptionArea), BorderLayout.CENTER);
+        latestTranscriptionPanel.setVisible(false);
+        transcriptionPanel.add(latestTranscriptionPanel);
+
+        // The transcription area always holds the raw transcription; pipeline output goes to processedText
+        copyButton = new JButton("Copy Raw Transcription");
+        copyButton.setToolTipText("Copy the original transcription (without pipeline processing) to clipboard");
+        copyButton.addActionListener(e -> {
+            copyTranscriptionToClipboard(transcriptionTextArea.getText());
+            Notificationmanager.getInstance().showNotification(ToastNotification.Type.INFO,
+                    "Raw transcription copied");
+        });
+
+        copyWithMetadataButton = new JButton("Copy with Metadata");
+        copyWithMetadataButton.setToolTipText("Copy the raw transcription with a header line naming provider, model, language and duration");
+        copyWithMetadataButton.setEnabled(false);
+        copyWithMetadataButton.addActionListener(e -> {
+            if (lastOutcome == null) {
+                return;
+            }
+            copyTranscriptionToClipboard(TranscriptFormatter.withMetadataHeader(transcriptionTextArea.getText(), lastOutcome));
+            Notificationmanager.getInstance().showNotification(ToastNotification.Type.INFO,
+                    "Transcription copied with metadata");
+        });
+
+        pinButton = new JToggleButton("Pin");
+        pinButton.setToolTipText("Keep this transcription; new ones are shown below it instead of replacing it");
+        pinButton.addActionListener(e -> {
+            if (!pinButton.isSelected()) {
+                unpinResult();
+            }
+        });
+
+        JButton clearResultsButton = new JButton("Clear");
+        clearResultsButton.setToolTipText("Clear the transcription and pipeline result");
+        clearResultsButton.addActionListener(e -> clearResults());
+
+        JPanel transcriptionButtonPanel = new JPanel(new FlowLayout(FlowLayout.CENTER, 5, 0));
+        transcriptionButtonPanel.add(copyButton);
+        transcriptionButtonPanel.add(copyWithMetadataButton);
+        transcriptionButtonPanel.add(pinButton);
+        transcriptionButtonPanel.add(clearResultsButton);
+        transcriptionButtonPanel.setAlignmentX(Component.CENTER_ALIGNMENT);
+        transcriptionButtonPanel.setMaximumSize(new Dimension(Integer.MAX_VALUE, transcriptionButtonPanel.getPreferredSize().height));
 
         // Add components to center panel with proper spacing
         centerPanel.add(Box.createVerticalStrut(10));
@@ -145,7 +258,9 @@ public class RecorderForm extends javax.swing.JPanel {
         centerPanel.add(Box.createVerticalStrut(20));  // Section spacing
         centerPanel.add(transcriptionPanel);
         centerPanel.add(Box.createVerticalStrut(10));
-        centerPanel.add(copyButton);
+        centerPanel.add(transcriptionButtonPanel);
+        centerPanel.add(Box.createVerticalStrut(5));
+        centerPanel.add(createAdvancedPanel());
         centerPanel.add(Box.createVerticalStrut(15));
 
         // Drag & drop hint
@@ -205,6 +320,14 @@ public class RecorderForm extends javax.swing.JPanel {
             configManager.setAutoPasteEnabled(autoPasteCheckBox.isSelected());
         });
 
+        JCheckBox translateCheckBox = new JCheckBox("Translate to English");
+        translateCheckBox.setToolTipText("Translates speech in any language to English text (OpenAI only)");
+        translateCheckBox.setSelected(configManager.isTranslateToEnglish());
+        translateCheckBox.addActionListener(e -> configManager.setTranslateToEnglish(translateCheckBox.isSelected()));
+
+        dictationModeCheckBox.setToolTipText("Starts recording when you speak and transcribes after a pause, then listens again");
+        dictationModeCheckBox.addActionListener(e -> toggleDictationMode());
+
         // enablePostProcessingCheckBox uses default alignment (text on right)
 
         JCheckBox loadOnStartupCheckBox = new JCheckBox("Activate on startup");
@@ -226,6 +349,7 @@ public class RecorderForm extends javax.swing.JPanel {
                 PostProcessingItem selectedItem = (PostProcessingItem) e.getItem();
                 if (selectedItem != null) {
                     configManager.setLastUsedPostProcessingUUID(selectedItem.uuid);
+                    configManager.setLastUsedPipelineUUID(selectedItem.uuid);
                 }
                 updateRunPipelineButtonState();
             }
@@ -244,8 +368,23 @@ public class RecorderForm extends javax.swing.JPanel {
         runPipelineButton.addActionListener(e -> runManualPipeline());
         pipelineSelectionPanel.add(runPipelineButton);
 
+        // Stop button, only visible while a pipeline is executing
+        stopPipelineButton = new JButton("\u25A0 Stop Pipeline");
+        stopPipelineButton.setToolTipText("Stop the running pipeline after the current unit");
+        stopPipelineButton.setVisible(false);
+        stopPipelineButton.addActionListener(e -> stopRunningPipeline());
+        pipelineSelectionPanel.add(stopPipelineButton);
+
+        // Explains why the selected pipeline cannot run (e.g. missing API key)
+        pipelineProblemLabel = new JLabel();
+        pipelineProblemLabel.setForeground(new Color(230, 126, 34));
+        pipelineProblemLabel.setVisible(false);
+        pipelineSelectionPanel.add(pipelineProblemLabel);
+
         // Add controls to responsive options panel
         optionsPanel.add(autoPasteCheckBox);
+        optionsPanel.add(dictationModeCheckBox);
+        optionsPanel.add(translateCheckBox);
         optionsPanel.add(enablePostProcessingCheckBox);
         optionsPanel.add(loadOnStartupCheckBox);
 
@@ -273,11 +412,30 @@ public class RecorderForm extends javax.swing.JPanel {
         postProcessingContainerPanel.add(processedTextScrollPane);
 
 
-        JButton copyProcessedTextButton = new JButton("Copy");
+        JButton copyProcessedTextButton = new JButton("Copy Pipeline Result");
+        copyProcessedTextButton.setToolTipText("Copy the post-processed text to clipboard");
+        copyProcessedTextButton.setEnabled(false);
         JPanel copyButtonPanel = new JPanel(new FlowLayout(FlowLayout.CENTER));
         copyButtonPanel.add(copyProcessedTextButton);
         copyButtonPanel.setAlignmentX(Component.LEFT_ALIGNMENT);
-        copyProcessedTextButton.addActionListener(e -> copyTranscriptionToClipboard(processedText.getText()));
+        copyProcessedTextButton.addActionListener(e -> {
+            copyTranscriptionToClipboard(processedText.getText());
+            Notificationmanager.getInstance().showNotification(ToastNotification.Type.INFO,
+                    "Pipeline result copied");
+        });
+        // Only offer copying the pipeline result once there is one
+        processedText.getDocument().addDocumentListener(new DocumentListener() {
+            @Override
+            public void insertUpdate(DocumentEvent e) { updateCopyResultButton(); }
+            @Override
+            public void removeUpdate(DocumentEvent e) { updateCopyResultButton(); }
+            @Override
+            public void changedUpdate(DocumentEvent e) { updateCopyResultButton(); }
+
+            private void updateCopyResultButton() {
+                copyProcessedTextButton.setEnabled(!processedText.getText().trim().isEmpty());
+            }
+        });
         postProcessingContainerPanel.add(Box.createVerticalStrut(10));
         postProcessingContainerPanel.add(copyButtonPanel);
 
@@ -313,7 +471,7 @@ public class RecorderForm extends javax.swing.JPanel {
         }
 
         // Console log panel
-        JPanel consolePanel = new JPanel(new BorderLayout());
+        consolePanel = new JPanel(new BorderLayout());
         consolePanel.setBorder(BorderFactory.createTitledBorder("Execution Log"));
         consoleLogArea = new JTextArea(15, 20);  // Increased from 8 to 15 rows for better visibility
         consoleLogArea.setEditable(false);
@@ -326,15 +484,57 @@ public class RecorderForm extends javax.swing.JPanel {
 
         // Add clear button for console log
         JPanel consoleButtonPanel = new JPanel(new FlowLayout(FlowLayout.RIGHT, 5, 2));
+
+        // Log height spinner (persisted, only applies while the log is docked)
+        logHeightSpinner = new JSpinner(new SpinnerNumberModel(configManager.getLogPanelHeight(),
+                ConfigManager.MIN_LOG_PANEL_HEIGHT, ConfigManager.MAX_LOG_PANEL_HEIGHT, 50));
+        logHeightSpinner.setToolTipText("Height of the execution log panel in pixels");
+        logHeightSpinner.addChangeListener(e -> {
+            int height = (Integer) logHeightSpinner.getValue();
+            configManager.setLogPanelHeight(height);
+            applyLogPanelHeight(configManager.getLogPanelHeight());
+        });
+        consoleButtonPanel.add(new JLabel("Height:"));
+        consoleButtonPanel.add(logHeightSpinner);
+
+        detachLogButton = new JButton("Detach");
+        detachLogButton.setToolTipText("Show the execution log in a separate window");
+        detachLogButton.addActionListener(e -> {
+            if (logWindow == null) {
+                detachLog();
+            } else {
+                attachLog();
+            }
+        });
+        consoleButtonPanel.add(detachLogButton);
+
         JButton clearLogButton = new JButton("Clear Log");
         clearLogButton.setToolTipText("Clear the execution log");
         clearLogButton.addActionListener(e -> consoleLogArea.setText(""));
         consoleButtonPanel.add(clearLogButton);
         consolePanel.add(consoleButtonPanel, BorderLayout.SOUTH);
 
+        // The dock holds the log panel while attached so it can be moved into its own window
+        consoleDock = new JPanel(new BorderLayout());
+        consoleDock.add(consolePanel, BorderLayout.CENTER);
+        applyLogPanelHeight(configManager.getLogPanelHeight());
+
         // Register console with ConsoleLogger singleton
         ConsoleLogger.getInstance().setConsoleArea(consoleLogArea);
 
+        // Restore the detached log window once the form is on screen
+        if (configManager.isLogDetached()) {
+            addHierarchyListener(new HierarchyListener() {
+                @Override
+                public void hierarchyChanged(HierarchyEvent e) {
+                    if ((e.getChangeFlags() & HierarchyEvent.SHOWING_CHANGED) != 0 && isShowing()) {
+                        removeHierarchyListener(this);
+                        SwingUtilities.invokeLater(RecorderForm.this::detachLog);
+                    }
+                }
+            });
+        }
+
         checkSettings();
 
         javax.swing.GroupLayout layout = new javax.swing.GroupLayout(this);
@@ -344,7 +544,7 @@ public class RecorderForm extends javax.swing.JPanel {
                 layout.createParallelGroup(javax.swing.GroupLayout.Alignment.CENTER)
                         .addComponent(centerPanel)
                         .addComponent(postProcessingContainerPanel)
-                        .addComponent(consolePanel)
+                        .addComponent(consoleDock)
         );
 
         layout.setVerticalGroup(
@@ -354,12 +554,114 @@ public class RecorderForm extends javax.swing.JPanel {
                         .addGap(10)  // Reduced spacing between sections (was 20)
                         .addComponent(postProcessingContainerPanel)
                         .addGap(10)  // Reduced spacing between sections (was 20)
-                        .addComponent(consolePanel, 250, 300, 350)  // Increased height for taller log (was 150)
+                        .addComponent(consoleDock, GroupLayout.PREFERRED_SIZE, GroupLayout.PREFERRED_SIZE, GroupLayout.PREFERRED_SIZE)  // Height is user-configurable
                         .addContainerGap(10, Short.MAX_VALUE)  // Reduced bottom margin
         );
 
         // Enable drag & drop for audio files
         setupDragAndDrop(centerPanel);
+
+        setupRecordingShortcut();
+    }
+
+    /**
+     * Binds Space to toggle recording while the recorder screen has focus.
+     * Works independently of the global hotkeys.
+     */
+    private void setupRecordingShortcut() {
+        InputMap inputMap = getInputMap(JComponent.WHEN_ANCESTOR_OF_FOCUSED_COMPONENT);
+        inputMap.put(KeyStroke.getKeyStroke(KeyEvent.VK_SPACE, 0), "toggleRecording");
+        getActionMap().put("toggleRecording", new AbstractAction() {
+            @Override
+            public void actionPerformed(java.awt.event.ActionEvent e) {
+                Component focusOwner = KeyboardFocusManager.getCurrentKeyboardFocusManager().getFocusOwner();
+                if (shouldHandleRecordingShortcut(configManager.isInAppShortcutEnabled(), focusOwner)) {
+                    toggleRecording();
+                }
+            }
+        });
+    }
+
+    /**
+     * Decides whether the in-app recording shortcut should fire. It is ignored while
+     * the user is typing in an editable text component.
+     *
+     * @param enabled    whether the shortcut is enabled in the settings
+     * @param focusOwner the component that currently has keyboard focus, may be null
+     * @return true if recording should be toggled
+     */
+    static boolean shouldHandleRecordingShortcut(boolean enabled, Component focusOwner) {
+        if (!enabled) {
+            return false;
+        }
+        if (focusOwner instanceof javax.swing.text.JTextComponent) {
+            return !((javax.swing.text.JTextComponent) focusOwner).isEditable();
+        }
+        return true;
+    }
+
+    /**
+     * Applies the configured height to the docked execution log panel.
+     *
+     * @param height panel height in pixels
+     */
+    private void applyLogPanelHeight(int height) {
+        consoleDock.setPreferredSize(new Dimension(consolePanel.getPreferredSize().width, height));
+        consoleDock.revalidate();
+        revalidate();
+    }
+
+    /**
+     * Moves the execution log into a separate, non-modal window.
+     */
+    private void detachLog() {
+        if (logWindow != null) {
+            return;
+        }
+        Window owner = SwingUtilities.getWindowAncestor(this);
+        logWindow = new JDialog(owner, "Execution Log", Dialog.ModalityType.MODELESS);
+        logWindow.setDefaultCloseOperation(WindowConstants.DO_NOTHING_ON_CLOSE);
+        logWindow.addWindowListener(new java.awt.event.WindowAdapter() {
+            @Override
+            public void windowClosing(java.awt.event.WindowEvent e) {
+                attachLog();
+            }
+        });
+
+        consoleDock.remove(consolePanel);
+        consoleDock.setVisible(false);
+        logWindow.getContentPane().add(consolePanel, BorderLayout.CENTER);
+        logHeightSpinner.setEnabled(false);
+        detachLogButton.setText("Attach");
+        detachLogButton.setToolTipText("Dock the execution log back into the main window");
+
+        logWindow.setSize(700, Math.max(configManager.getLogPanelHeight(), 300));
+        logWindow.setLocationRelativeTo(owner);
+        logWindow.setVisible(true);
+        configManager.setLogDetached(true);
+        revalidate();
+        repaint();
+    }
+
+    /**
+     * Docks the execution log back into the recorder screen.
+     */
+    private void attachLog() {
+        if (logWindow == null) {
+            return;
+        }
+        logWindow.getContentPane().remove(consolePanel);
+        logWindow.dispose();
+        logWindow = null;
+
+        consoleDock.add(consolePanel, BorderLayout.CENTER);
+        consoleDock.setVisible(true);
+        logHeightSpinner.setEnabled(true);
+        detachLogButton.setText("Detach");
+        detachLogButton.setToolTipText("Show the execution log in a separate window");
+        applyLogPanelHeight(configManager.getLogPanelHeight());
+        configManager.setLogDetached(false);
+        repaint();
     }
 
     /**
@@ -453,7 +755,7 @@ public class RecorderForm extends javax.swing.JPanel {
         console.log("Audio file: " + fileToTranscribe.getName());
         Notificationmanager.getInstance().showNotification(ToastNotification.Type.INFO,
                 "Transcribing audio file...");
-        new AudioTranscriptionWorker(fileToTranscribe).execute();
+        transcriptionQueue.submit(fileToTranscribe);
     }
 
     /**
@@ -614,26 +916,317 @@ public class RecorderForm extends javax.swing.JPanel {
             updateUIForRecordingStart();
             updateTrayMenu();
 
+        } else if (recorder != null && !recorder.hasSpeech()) {
+            // Voice-activated start that never heard speech: nothing to transcribe
+            ConsoleLogger.getInstance().log("No speech detected, recording discarded");
+            stopRecording(true);
+            if (!transcriptionQueue.isTranscribing()) {
+                resetUIAfterTranscription();
+            }
+            updateTrayMenu();
         } else {
             stopRecording(false);
         }
     }
 
+    /**
+     * Pauses or resumes the running recording.
+     */
+    private void togglePause() {
+        if (!isRecording || recorder == null) {
+            return;
+        }
+        if (recorder.isPaused()) {
+            recorder.resume();
+            pauseButton.setText("Pause");
+            ConsoleLogger.getInstance().log("Recording resumed");
+        } else {
+            recorder.pause();
+            pauseButton.setText("Resume");
+            ConsoleLogger.getInstance().log("Recording paused");
+        }
+        statusIndicatorPanel.repaint();
+    }
+
+    /**
+     * Shows the time left before the maximum recording length and stops the recording
+     * once it is reached. Paused time does not count.
+     */
+    private void checkRecordingLimit() {
+        int maxSeconds = configManager.getMaxRecordingSeconds();
+        if (!isRecording || recorder == null || maxSeconds <= 0) {
+            recordingLimitTimer.stop();
+            return;
+        }
+        long remainingMs = remainingRecordingMillis(maxSeconds, recorder.getRecordedMillis());
+        if (remainingMs == 0) {
+            logger.warn("Recording reached the maximum length of {}s, stopping", maxSeconds);
+            ConsoleLogger.getInstance().logError("Recording stopped at the maximum length of " + formatElapsed(maxSeconds * 1000L));
+            Notificationmanager.getInstance().showNotification(ToastNotification.Type.WARNING,
+                    "Maximum recording length reached, transcribing");
+            stopRecording(false);
+            updateTrayMenu();
+            return;
+        }
+        if (!isToggleInProgress) {
+            recordButton.setText("Stop Recording (" + formatElapsed(remainingMs + 999) + " left)");
+        }
+    }
+
+    /**
+     * @param maxSeconds     the maximum recording length
+     * @param recordedMillis the recorded time, excluding pauses
+     * @return the time left before the recording is stopped, 0 once the limit is reached
+     */
+    static long remainingRecordingMillis(int maxSeconds, long recordedMillis) {
+        return Math.max(0, maxSeconds * 1000L - recordedMillis);
+    }
+
+    /**
+     * Shows the level of the audio being recorded and hides the meter once recording ends.
+     */
+    private void updateLevelMeter() {
+        if (!isRecording || recorder == null) {
+            levelMeterTimer.stop();
+            levelMeter.setVisible(false);
+            return;
+        }
+        levelMeter.setValue(Math.min(100, Math.round(recorder.getCurrentLevel() * 100)));
+    }
+
     private void startRecording() {
         try {
             isRecording = true;
             String timeStamp = new SimpleDateFormat("yyyyMMdd_HHmmss").format(new Date());
             File audioFile = new File(System.getProperty("java.io.tmpdir"), "record_" + timeStamp + ".wav");
             recorder = new AudioRecorder(audioFile, configManager);
+            AudioRecorder activeRecorder = recorder;
+            recorder.setDisconnectListener(() -> SwingUtilities.invokeLater(() -> handleMicrophoneDisconnect(activeRecorder)));
+            if (dictationModeCheckBox.isSelected()) {
+                VoiceActivityDetector detector = new VoiceActivityDetector(configManager.getSilenceThreshold(),
+                        DICTATION_ONSET_MS, configManager.getDictationTrailingSilenceMs());
+                recorder.enableDictation(detector,
+                        () -> ConsoleLogger.getInstance().log("Dictation: speech detected, recording"),
+                        () -> SwingUtilities.invokeLater(() -> handleUtteranceEnd(activeRecorder)));
+                ConsoleLogger.getInstance().log("Dictation: listening for speech...");
+            } else if (configManager.isVoiceActivatedStart()) {
+                // Same speech gate as dictation, but the recording runs until stopped
+                VoiceActivityDetector detector = new VoiceActivityDetector(configManager.getSilenceThreshold(),
+                        DICTATION_ONSET_MS, Long.MAX_VALUE);
+                recorder.enableVoiceActivatedStart(detector,
+                        () -> ConsoleLogger.getInstance().log("Speech detected, recording"));
+                ConsoleLogger.getInstance().log("Waiting for speech...");
+            }
             new Thread(recorder::start).start();
             logger.info("Recording started: " + audioFile.getPath());
             recordButton.setText("Stop Recording");
+            pauseButton.setText("Pause");
+            pauseButton.setEnabled(true);
+            if (configManager.getMaxRecordingSeconds() > 0) {
+                recordingLimitTimer.restart();
+            }
+            levelMeter.setValue(0);
+            levelMeter.setVisible(true);
+            levelMeterTimer.restart();
         } catch (Exception e) {
             logger.error("An error occurred while starting the recording", e);
             isRecording = false;
         }
     }
 
+    /**
+     * Stops the recording after the microphone failed, reports the reason and transcribes
+     * what was captured so far, if anything.
+     */
+    private void handleMicrophoneDisconnect(AudioRecorder disconnectedRecorder) {
+        if (disconnectedRecorder != recorder || !isRecording) {
+            return;
+        }
+        String error = disconnectedRecorder.getRecordingError();
+        String message = error != null ? error : "Microphone disconnected";
+        ConsoleLogger.getInstance().logError(message + " - recording stopped");
+        Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR, message);
+        File captured = disconnectedRecorder.getOutputFile();
+        boolean hasAudio = captured.isFile() && captured.length() > WAV_HEADER_BYTES;
+        if (hasAudio && configManager.isTranscribeInterruptedRecordings()) {
+            // Transcribe what was captured before the failure
+            stopRecording(false);
+        } else {
+            if (hasAudio) {
+                ConsoleLogger.getInstance().log("Audio captured before the failure was kept: " + captured.getAbsolutePath());
+            }
+            stopRecording(true);
+            if (!transcriptionQueue.isTranscribing()) {
+                resetUIAfterTranscription();
+            }
+        }
+        updateTrayMenu();
+    }
+
+    /**
+     * Starts listening when dictation mode is switched on while idle. When it is switched
+     * off before any speech was heard, the listening recording is discarded; an utterance
+     * in progress is still transcribed.
+     */
+    private void toggleDictationMode() {
+        if (dictationModeCheckBox.isSelected()) {
+            if (!isRecording && !isTranscribing) {
+                toggleRecording();
+            }
+        } else if (isRecording && recorder != null && !recorder.hasSpeech()) {
+            stopRecording(true);
+            if (!transcriptionQueue.isTranscribing()) {
+                resetUIAfterTranscription();
+            }
+            updateTrayMenu();
+        }
+    }
+
+    /**
+     * Transcribes the utterance that dictation mode just finished recording.
+     */
+    private void handleUtteranceEnd(AudioRecorder finishedRecorder) {
+        if (finishedRecorder != recorder || !isRecording) {
+            return;
+        }
+        ConsoleLogger.getInstance().log("Dictation: pause detected, transcribing");
+        stopRecording(false);
+        updateTrayMenu();
+    }
+
+    /**
+     * Listens for the next utterance once the previous one was handled, if dictation
+     * mode is still on.
+     */
+    private void continueDictation() {
+        if (dictationModeCheckBox.isSelected() && !isRecording && !isTranscribing) {
+            toggleRecording();
+        }
+    }
+
+    /**
+     * Releases the pinned result. A transcription that arrived while it was pinned
+     * takes its place.
+     */
+    private void unpinResult() {
+        if (moveLatestToResult(transcriptionTextArea, latestTranscriptionArea)) {
+            lowConfidenceLabel.setVisible(false);
+        }
+        latestTranscriptionPanel.setVisible(false);
+    }
+
+    /**
+     * Shows a finished transcription: in the result area, or in the latest-transcription
+     * area while the result is pinned.
+     *
+     * @return true if the result was replaced
+     */
+    static boolean showTranscript(String transcript, boolean pinned, JTextArea result, JTextArea latest) {
+        if (pinned) {
+            latest.setText(transcript);
+            return false;
+        }
+        result.setText(transcript);
+        return true;
+    }
+
+    /**
+     * Moves a transcription that arrived while the result was pinned into the result area.
+     *
+     * @return true if there was one to move
+     */
+    static boolean moveLatestToResult(JTextArea result, JTextArea latest) {
+        String text = latest.getText();
+        latest.setText("");
+        if (text.isEmpty()) {
+            return false;
+        }
+        result.setText(text);
+        return true;
+    }
+
+    /**
+     * Creates the collapsible "Advanced" section with the language, initial prompt and
+     * temperature sent with every transcription. Changes are saved right away.
+     */
+    private JPanel createAdvancedPanel() {
+        JTextField languageField = new JTextField(configManager.getTranscriptionLanguage(), 4);
+        languageField.setToolTipText("Language code such as en or de. Empty lets the server detect the language.");
+        languageField.getDocument().addDocumentListener(new DocumentListener() {
+            @Override
+            public void insertUpdate(DocumentEvent e) { configManager.setTranscriptionLanguage(languageField.getText()); }
+            @Override
+            public void removeUpdate(DocumentEvent e) { configManager.setTranscriptionLanguage(languageField.getText()); }
+            @Override
+            public void changedUpdate(DocumentEvent e) { configManager.setTranscriptionLanguage(languageField.getText()); }
+        });
+
+        JTextField promptField = new JTextField(configManager.getTranscriptionPrompt(), 30);
+        promptField.setToolTipText("Domain vocabulary and spellings, e.g. \"Kubernetes, kubectl, WhisperCat\"");
+        promptField.getDocument().addDocumentListener(new DocumentListener() {
+            @Override
+            public void insertUpdate(DocumentEvent e) { configManager.setTranscriptionPrompt(promptField.getText()); }
+            @Override
+            public void removeUpdate(DocumentEvent e) { configManager.setTranscriptionPrompt(promptField.getText()); }
+            @Override
+            public void changedUpdate(DocumentEvent e) { configManager.setTranscriptionPrompt(promptField.getText()); }
+        });
+
+        // The slider works in tenths, the temperature ranges from 0.0 to 1.0
+        JSlider temperatureSlider = new JSlider(0, 10, Math.round(configManager.getTranscriptionTemperature() * 10));
+        temperatureSlider.setPreferredSize(new Dimension(100, temperatureSlider.getPreferredSize().height));
+        temperatureSlider.setToolTipText("0 gives the most predictable result; raise it if the transcription repeats itself");
+        JLabel temperatureValue = new JLabel(DisplayFormat.number(temperatureSlider.getValue() / 10.0, 1));
+        temperatureSlider.addChangeListener(e -> {
+            temperatureValue.setText(DisplayFormat.number(temperatureSlider.getValue() / 10.0, 1));
+            if (!temperatureSlider.getValueIsAdjusting()) {
+                configManager.setTranscriptionTemperature(temperatureSlider.getValue() / 10.0f);
+            }
+        });
+
+        JPanel fieldsPanel = new JPanel(new FlowLayout(FlowLayout.CENTER, 5, 0));
+        fieldsPanel.add(new JLabel("Language:"));
+        fieldsPanel.add(languageField);
+        fieldsPanel.add(new JLabel("Prompt:"));
+        fieldsPanel.add(promptField);
+        fieldsPanel.add(new JLabel("Temperature:"));
+        fieldsPanel.add(temperatureSlider);
+        fieldsPanel.add(temperatureValue);
+        fieldsPanel.setVisible(false);
+
+        JToggleButton advancedToggle = new JToggleButton("Advanced \u25B8");
+        advancedToggle.setToolTipText("Language, prompt and temperature for the transcription");
+        advancedToggle.addActionListener(e -> {
+            fieldsPanel.setVisible(advancedToggle.isSelected());
+            advancedToggle.setText(advancedToggle.isSelected() ? "Advanced \u25BE" : "Advanced \u25B8");
+        });
+        JPanel togglePanel = new JPanel(new FlowLayout(FlowLayout.CENTER, 0, 0));
+        togglePanel.add(advancedToggle);
+
+        JPanel advancedPanel = new JPanel();
+        advancedPanel.setLayout(new BoxLayout(advancedPanel, BoxLayout.Y_AXIS));
+        advancedPanel.add(togglePanel);
+        advancedPanel.add(fieldsPanel);
+        advancedPanel.setAlignmentX(Component.CENTER_ALIGNMENT);
+        return advancedPanel;
+    }
+
+    /**
+     * Clears the transcription and pipeline result and drops the prompt context
+     * carried over from the previous transcription.
+     */
+    private void clearResults() {
+        pinButton.setSelected(false);
+        latestTranscriptionArea.setText("");
+        latestTranscriptionPanel.setVisible(false);
+        transcriptionTextArea.setText("");
+        processedText.setText("");
+        lowConfidenceLabel.setVisible(false);
+        lastOutcome = null;
+        copyWithMetadataButton.setEnabled(false);
+        transcriptionService.resetPromptContext();
+    }
+
     private boolean isStoppingInProgress = false;
 
     public void stopRecording(boolean cancelledRecording) {
@@ -643,19 +1236,51 @@ public class RecorderForm extends javax.swing.JPanel {
         //recordButton.setEnabled(false);
         if (recorder != null) {
             recorder.stop();
-            logger.info("Recording stopped");
+            logger.info("Recording stopped after {}", formatElapsed(recorder.getRecordedMillis()));
             if (!cancelledRecording) {
-                new RecorderForm.AudioTranscriptionWorker(recorder.getOutputFile()).execute();
+                transcriptionQueue.submit(recorder.getOutputFile());
+                if (transcriptionQueue.canStartRecording(configManager.isRecordDuringTranscription())) {
+                    // The transcription continues in the background, the recorder is free again
+                    isStoppingInProgress = false;
+                    recordButton.setEnabled(true);
+                    updateTranscriptionElapsed();
+                }
             } else {
                 logger.info("Recording cancelled");
-                // Reset transcribing state if cancelled
-                isTranscribing = false;
+                // Reset transcribing state if cancelled, unless an earlier recording is still transcribing
+                isTranscribing = transcriptionQueue.isTranscribing();
+                if (isTranscribing) {
+                    isStoppingInProgress = false;
+                    recordButton.setEnabled(true);
+                    updateTranscriptionElapsed();
+                }
                 statusIndicatorPanel.repaint();
                 updateTrayMenu();
             }
         }
     }
 
+    /**
+     * Starts the background transcription of a recording the queue hands over.
+     */
+    private void startTranscription(File audioFile) {
+        activeTranscription = new AudioTranscriptionWorker(audioFile);
+        activeTranscription.start();
+        cancelTranscriptionButton.setEnabled(true);
+        cancelTranscriptionButton.setVisible(true);
+    }
+
+    /**
+     * Cancels the running transcription, aborting its request if it is waiting for the
+     * server. The UI is reset once the worker has stopped.
+     */
+    private void cancelTranscription() {
+        if (activeTranscription != null) {
+            activeTranscription.cancelTranscription();
+            cancelTranscriptionButton.setEnabled(false);
+        }
+    }
+
     public void stopRecording(File audioFile) {
         isStoppingInProgress = true;
 
@@ -665,7 +1290,7 @@ public class RecorderForm extends javax.swing.JPanel {
 
         recordButton.setText("Converting. Please wait...");
         recordButton.setEnabled(false);
-        new RecorderForm.AudioTranscriptionWorker(audioFile).execute();
+        transcriptionQueue.submit(audioFile);
     }
 
     public void playFinishSound() {
@@ -690,10 +1315,67 @@ public class RecorderForm extends javax.swing.JPanel {
         Toolkit.getDefaultToolkit().getSystemClipboard().setContents(stringSelection, null);
     }
 
-    private void pasteFromClipboard() {
+    /**
+     * Copies a result to the clipboard if it is an output, and pastes it if auto-paste is
+     * enabled. Results of low-confidence transcriptions are only copied.
+     *
+     * @param text          the transcription or pipeline result
+     * @param confidence    the confidence of the transcription it came from
+     * @param focusedWindow the focused window from {@link #queryFocusedWindow()}
+     */
+    private void autoPaste(String text, double confidence, String focusedWindow) {
+        if (!configManager.getOutputSinks().contains(OutputSink.CLIPBOARD)) {
+            return;
+        }
+        copyTranscriptionToClipboard(text);
         if (!configManager.isAutoPasteEnabled()) {
             return;
         }
+        if (TranscriptionConfidence.allowsAutoPaste(confidence, configManager.getAutoPasteMinConfidence())) {
+            pasteFromClipboard(focusedWindow);
+        } else {
+            ConsoleLogger.getInstance().log(DisplayFormat.format("Auto-paste skipped: confidence %.0f%% is below the %.0f%% minimum",
+                    confidence * 100, configManager.getAutoPasteMinConfidence() * 100));
+            Notificationmanager.getInstance().showNotification(ToastNotification.Type.WARNING,
+                    "Low confidence - copied but not pasted. Please review the transcription.");
+        }
+    }
+
+    /**
+     * Queries the focused window for the auto-paste target app. Starts an external process
+     * that can take seconds, so it must not run on the event dispatch thread.
+     *
+     * @return the focused window, or null if no target app is set or it cannot be determined
+     */
+    private String queryFocusedWindow() {
+        if (!configManager.isAutoPasteEnabled() || configManager.getAutoPasteTargetApp().isEmpty()) {
+            return null;
+        }
+        return ForegroundWindow.query();
+    }
+
+    /**
+     * Pastes the clipboard with Ctrl+V if auto-paste is enabled and the focused window is the
+     * target app, if one is set.
+     *
+     * @param focusedWindow the focused window from {@link #queryFocusedWindow()}; if it could not
+     *                      be determined, the text is pasted without checking the target app
+     */
+    private void pasteFromClipboard(String focusedWindow) {
+        if (!configManager.isAutoPasteEnabled()) {
+            return;
+        }
+        String targetApp = configManager.getAutoPasteTargetApp();
+        if (!targetApp.isEmpty()) {
+            if (focusedWindow == null) {
+                ConsoleLogger.getInstance().log("Could not determine the focused window, pasting without checking for "
+                        + targetApp);
+            } else if (!ForegroundWindow.matches(targetApp, focusedWindow)) {
+                ConsoleLogger.getInstance().log("Auto-paste skipped: focused window '" + focusedWindow
+                        + "' is not " + targetApp + ". Text was copied.");
+                return;
+            }
+        }
         try {
             Robot robot = new Robot();
             robot.delay(500);
@@ -740,20 +1422,94 @@ public class RecorderForm extends javax.swing.JPanel {
         isRecording = false;  // Must set this BEFORE isTranscribing, or circle stays red!
         isTranscribing = true;
         statusIndicatorPanel.repaint();
+        pauseButton.setEnabled(false);
+        pauseButton.setText("Pause");
+        recordingLimitTimer.stop();
 
         recordButton.setText("Converting. Please wait...");
         recordButton.setEnabled(false);
     }
 
+    /**
+     * Shows "Transcribing... (0s)" on the record button and counts up every second.
+     */
+    private void startTranscriptionTimer() {
+        transcriptionStartMs = System.currentTimeMillis();
+        updateTranscriptionElapsed();
+        transcriptionTimer.restart();
+    }
+
+    private void stopTranscriptionTimer() {
+        transcriptionTimer.stop();
+    }
+
+    private void updateTranscriptionElapsed() {
+        if (isRecording) {
+            // A recording started during the transcription owns the button
+            return;
+        }
+        String elapsed = formatElapsed(System.currentTimeMillis() - transcriptionStartMs);
+        if (recordButton.isEnabled()) {
+            recordButton.setText("Start Recording (transcribing " + elapsed + ")");
+        } else {
+            recordButton.setText("Transcribing... (" + elapsed + ")");
+        }
+    }
+
+    /**
+     * Formats an elapsed time as "12s" or, from one minute on, "2m 05s".
+     *
+     * @param elapsedMs the elapsed time in milliseconds
+     * @return the formatted time
+     */
+    static String formatElapsed(long elapsedMs) {
+        long seconds = Math.max(0, elapsedMs) / 1000;
+        if (seconds < 60) {
+            return seconds + "s";
+        }
+        return String.format("%dm %02ds", seconds / 60, seconds % 60);
+    }
+
+    /**
+     * Reports a crashed transcription task. The task reports ordinary failures itself, so a
+     * failure reaching its {@link BackgroundTask} error handler is a crash (e.g. an Error in a
+     * decoder, which arrives wrapped in an {@link ExecutionException}).
+     *
+     * @param failure the failure from the task's error handler
+     * @param onCrash receives the message to show the user
+     */
+    static void reportCrash(Exception failure, Consumer<String> onCrash) {
+        Throwable cause = failure instanceof ExecutionException && failure.getCause() != null
+                ? failure.getCause() : failure;
+        logger.error("Transcription task crashed", cause);
+        ConsoleLogger.getInstance().logError("Transcription crashed: " + cause);
+        onCrash.accept("Transcription crashed. See logs.");
+    }
+
     private void resetUIAfterTranscription() {
-        isStoppingInProgress = false;
+        activeTranscription = null;
+        cancelTranscriptionButton.setVisible(false);
+        if (transcriptionQueue.finishActive() != null) {
+            return;
+        }
+
+        stopTranscriptionTimer();
         isTranscribing = false;  // Reset to idle state (green indicator)
 
         // Repaint status indicator to show ready state (green circle)
         statusIndicatorPanel.repaint();
+        if (isRecording) {
+            // Keep the Stop Recording button of a recording started during the transcription
+            return;
+        }
+        isStoppingInProgress = false;
 
         recordButton.setText("Start Recording");
         recordButton.setEnabled(true);
+
+        if (dictationModeCheckBox.isSelected()) {
+            SwingUtilities.invokeLater(this::continueDictation);
+        }
     }
 
     private boolean checkSettings() {
@@ -780,101 +1536,265 @@ public class RecorderForm extends javax.swing.JPanel {
     }
 
 
-    private class AudioTranscriptionWorker extends SwingWorker<String, Void> {
+    /**
+     * Applies the configured normalization and timestamp prefix to a raw transcription.
+     */
+    private String formatTranscript(String transcript) {
+        if (transcript != null && configManager.isNormalizeOutput()) {
+            transcript = TranscriptFormatter.normalizeText(transcript);
+        }
+        if (transcript != null && configManager.isPrependTimestamp() && !transcript.trim().isEmpty()) {
+            transcript = TranscriptFormatter.prependTimestamp(transcript, configManager.getTimestampFormat(), LocalDateTime.now());
+        }
+        return transcript;
+    }
+
+    /**
+     * Transcribes a recording in a {@link BackgroundTask}. If post-processing is enabled and
+     * the selected pipeline is ready, the pipeline runs right after the transcription in the
+     * same background task, and only its completion is announced.
+     */
+    private class AudioTranscriptionWorker {
         private final File audioFile;
+        private double confidence = TranscriptionConfidence.UNKNOWN;
+        private TranscriptionOutcome outcome;
+        private final PostProcessingWorker chainedPipeline;
+        private final CancellationToken cancellation = new CancellationToken();
+        private String processedResult;
+        private boolean pipelineFailed;
+        private String focusedWindow;
 
         public AudioTranscriptionWorker(File audioFile) {
             this.audioFile = audioFile;
+            this.chainedPipeline = createChainedPipeline();
+            startTranscriptionTimer();
         }
 
-        @Override
-        protected String doInBackground() {
-            ConsoleLogger console = ConsoleLogger.getInstance();
+        /**
+         * Starts the transcription in the background.
+         */
+        void start() {
+            BackgroundTask.run(this::transcribe, this::complete, this::crashed);
+        }
+
+        /**
+         * Stops the transcription; a pipeline already running with it is not affected.
+         */
+        void cancelTranscription() {
+            cancellation.cancel();
+        }
+
+        /**
+         * Writes the segment timestamps as an .srt file next to the recording, if the
+         * provider returned any. A failure is only logged; the transcription still counts.
+         */
+        private void writeSubtitles(File recording, TranscriptionOutcome result) {
+            String srt = TranscriptFormatter.toSrt(result.getSegments());
+            if (srt == null) {
+                return;
+            }
+            String name = recording.getName();
+            int dot = name.lastIndexOf('.');
+            File srtFile = new File(recording.getParentFile(), (dot > 0 ? name.substring(0, dot) : name) + ".srt");
             try {
-                // Apply silence removal if enabled
-                File fileToTranscribe = audioFile;
-                if (configManager.isSilenceRemovalEnabled()) {
-                    console.separator();
-                    fileToTranscribe = SilenceRemover.removeSilence(
-                        audioFile,
-                        configManager.getSilenceThreshold(),
-                        configManager.getMinSilenceDuration(),
-                        configManager.isKeepCompressedFile(),
-                        configManager.getMinRecordingDurationForSilenceRemoval()
-                    );
-                }
+                Files.write(srtFile.toPath(), srt.getBytes(StandardCharsets.UTF_8));
+                ConsoleLogger.getInstance().log("Subtitles saved: " + srtFile.getAbsolutePath());
+            } catch (IOException e) {
+                logger.warn("Could not write subtitles to {}", srtFile, e);
+                ConsoleLogger.getInstance().logError("Could not save subtitles: " + e.getMessage());
+            }
+        }
 
-                String server = configManager.getWhisperServer();
-                console.separator();
-                console.log("Starting transcription using " + server);
-                console.log("Audio file: " + fileToTranscribe.getName());
-
-                long transcriptionStartTime = System.currentTimeMillis();
-                String result = null;
-
-                if (server.equals("OpenAI")) {
-                    logger.info("Transcribing audio using OpenAI");
-                    result = whisperClient.transcribe(fileToTranscribe);
-                } else if (server.equals("Faster-Whisper")) {
-                    logger.info("Transcribing audio using Faster-Whisper");
-                    result = fasterWhisperTranscribeClient.transcribe(fileToTranscribe);
-                } else if (server.equals("Open WebUI")) {
-                    logger.info("Transcribing audio using Open WebUI");
-                    result = openWebUITranscribeClient.transcribeAudio(fileToTranscribe);
-                } else {
-                    logger.error("Unknown Whisper server: " + server);
-                    console.logError("Unknown Whisper server: " + server);
-                    Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR,
-                            "Unknown Whisper server: " + server);
-                    return null;
-                }
+        /**
+         * @return the run for the selected pipeline, or null if none should run automatically
+         */
+        private PostProcessingWorker createChainedPipeline() {
+            if (!enablePostProcessingCheckBox.isSelected()) {
+                return null;
+            }
+            PostProcessingItem selectedItem = (PostProcessingItem) postProcessingSelectComboBox.getSelectedItem();
+            if (selectedItem == null || selectedItem.uuid == null) {
+                return null;
+            }
+            Pipeline pipeline = configManager.getPipelineByUuid(selectedItem.uuid);
+            if (pipeline == null || PostProcessingService.checkPipelineReady(pipeline, configManager) != null) {
+                // deliver() reports the problem once the transcription is available
+                return null;
+            }
+            return new PostProcessingWorker(pipeline);
+        }
 
-                long transcriptionTime = System.currentTimeMillis() - transcriptionStartTime;
-                console.log(String.format("Transcription took %dms", transcriptionTime));
-                return result;
+        /**
+         * Transcribes the recording on the background thread. Failures are reported here.
+         *
+         * @return the transcript, or null if the transcription failed
+         */
+        private String transcribe() {
+            try {
+                ChainedTranscription result = ChainedTranscription.run(transcriptionService, audioFile, cancellation,
+                        RecorderForm.this::formatTranscript, chainedPipeline != null ? chainedPipeline::run : null);
+                outcome = result.getOutcome();
+                confidence = outcome.getConfidence();
+                writeSubtitles(audioFile, outcome);
+                processedResult = result.getProcessedResult();
+                if (result.getPipelineError() != null) {
+                    logger.error("Error during post-processing", result.getPipelineError());
+                    ConsoleLogger.getInstance().logError("Post-processing failed: " + result.getPipelineError().getMessage());
+                    pipelineFailed = true;
+                }
+                if (processedResult == null) {
+                    // The transcript itself may be pasted
+                    focusedWindow = queryFocusedWindow();
+                }
+                return result.getTranscript();
+            } catch (TranscriptionCancelledException e) {
+                logger.info("Transcription of {} cancelled", audioFile.getName());
+                return null;
+            } catch (TranslationNotSupportedException e) {
+                logger.warn("Transcription skipped: {}", e.getMessage());
+                ConsoleLogger.getInstance().logError("Transcription skipped: " + e.getMessage());
+                Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR, e.getMessage());
+                return null;
+            } catch (ProviderUnreachableException e) {
+                logger.warn("Transcription skipped: {}", e.getMessage());
+                ConsoleLogger.getInstance().logError("Transcription skipped: " + e.getMessage());
+                Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR,
+                        e.getProvider() + " is unreachable. Check the server, then save Settings to retry now.");
+                return null;
+            } catch (AudioFileTooLargeException e) {
+                logger.error("Recording too large for upload", e);
+                ConsoleLogger.getInstance().logError("Transcription skipped: " + e.getMessage());
+                Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR,
+                        "Recording too large to upload even after compression. Split it into shorter recordings.");
+                return null;
             } catch (Exception e) {
                 logger.error("Error during transcription", e);
                 ConsoleLogger.getInstance().logError("Transcription failed: " + e.getMessage());
+                ProviderError error = ProviderError.from(e);
                 Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR,
-                        "Error during transcription. See logs.");
+                        error == ProviderError.OTHER ? "Error during transcription. See logs." : error.describe());
                 return null;
             }
         }
 
-        @Override
-        protected void done() {
+        /**
+         * Shows the transcript and hands it on. Runs on the event dispatch thread.
+         *
+         * @param transcript the result of {@link #transcribe()}
+         */
+        private void complete(String transcript) {
+            stopTranscriptionTimer();
+            cancelTranscriptionButton.setVisible(false);
             ConsoleLogger console = ConsoleLogger.getInstance();
-            String transcript = null;
             try {
-                transcript = get();
                 if (transcript != null) {
                     logger.info("Transcribed text: " + transcript);
-                    transcriptionTextArea.setText(transcript);
+                    boolean pinned = !showTranscript(transcript, pinButton.isSelected(),
+                            transcriptionTextArea, latestTranscriptionArea);
+                    if (pinned) {
+                        latestTranscriptionPanel.setVisible(true);
+                        console.log("Result is pinned; the new transcription is shown below it");
+                    } else {
+                        lastOutcome = outcome;
+                        copyWithMetadataButton.setEnabled(outcome != null);
+                    }
+                    if (outcome != null && outcome.getWarning() != null) {
+                        Notificationmanager.getInstance().showNotification(ToastNotification.Type.WARNING,
+                                outcome.getWarning());
+                    }
+
+                    boolean lowConfidence = TranscriptionConfidence.isLow(confidence);
+                    if (!pinned) {
+                        lowConfidenceLabel.setVisible(lowConfidence);
+                    }
+                    if (lowConfidence) {
+                        console.log("WARNING: Low transcription confidence - verify the result");
+                        Notificationmanager.getInstance().showNotification(ToastNotification.Type.WARNING,
+                                "Low confidence transcription - please verify the result.");
+                    }
 
                     // Start new history session for this transcription
-                    pipelineHistory.startNewSession(transcript);
+                    pipelineHistory.startNewSession(transcript, audioFile);
+                    historyPanel.setRecording(audioFile);
                     processedText.setText("");  // Clear previous post-processed text
                     historyPanel.updateResults(pipelineHistory.getResults());  // Reset history panel
 
                     console.logSuccess("Transcription completed");
                     console.log("Transcript length: " + transcript.length() + " characters");
-                    // Show success notification
-                    Notificationmanager.getInstance().showNotification(ToastNotification.Type.SUCCESS,
-                            "Transcription completed!");
-                    // Show system-level notification
-                    TrayIconManager trayManager = AudioRecorderUI.getTrayIconManager();
-                    if (trayManager != null) {
-                        trayManager.showSystemNotification("WhisperCat", "Transcription completed");
+                    if (processedResult == null) {
+                        // Show success notification
+                        Notificationmanager.getInstance().showNotification(ToastNotification.Type.SUCCESS,
+                                "Transcription completed!");
+                        // Show system-level notification
+                        TrayIconManager trayManager = AudioRecorderUI.getTrayIconManager();
+                        if (trayManager != null) {
+                            trayManager.showSystemNotification("WhisperCat", "Transcription completed");
+                        }
                     }
+                } else if (cancellation.isCancelled()) {
+                    Notificationmanager.getInstance().showNotification(ToastNotification.Type.INFO,
+                            "Transcription cancelled.");
                 } else {
                     logger.warn("Transcription resulted in null");
                     console.logError("Transcription returned null");
                 }
-            } catch (Exception e) {
+            } catch (RuntimeException e) {
                 logger.error("An error occurred while finishing the transcription", e);
                 console.logError("Error finishing transcription: " + e.getMessage());
-            } finally {
-                isRecording = false;
+            }
+            deliverSafely(transcript);
+        }
+
+        /**
+         * Reports a crashed transcription and resets the UI.
+         *
+         * @param e the failure, see {@link #reportCrash(Exception, Consumer)}
+         */
+        private void crashed(Exception e) {
+            stopTranscriptionTimer();
+            cancelTranscriptionButton.setVisible(false);
+            reportCrash(e, message ->
+                    Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR, message));
+            deliverSafely(null);
+        }
+
+        /**
+         * Runs {@link #deliver(String)}, resetting the UI if it fails.
+         */
+        private void deliverSafely(String transcript) {
+            ConsoleLogger console = ConsoleLogger.getInstance();
+            try {
+                deliver(transcript);
+            } catch (RuntimeException e) {
+                // Never leave the record button stuck in "Transcribing..."
+                logger.error("An error occurred while delivering the transcription", e);
+                console.logError("Error delivering transcription: " + e.getMessage());
+                Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR,
+                        "Error delivering transcription. See logs.");
+                endPipelineRun();
+                resetUIAfterTranscription();
+                updateTrayMenu();
+            }
+        }
+
+        /**
+         * Hands the transcript to the chained pipeline, a post-processing run or the outputs,
+         * and resets the UI once nothing is left to do.
+         */
+        private void deliver(String transcript) {
+            ConsoleLogger console = ConsoleLogger.getInstance();
+            if (chainedPipeline != null && processedResult != null) {
+                // The pipeline already ran together with the transcription
+                chainedPipeline.complete(processedResult, confidence);
+                return;
+            }
+            if (chainedPipeline != null) {
+                chainedPipeline.discard();
+                if (pipelineFailed) {
+                    resetUIAfterTranscription();
+                    updateTrayMenu();
+                    return;
+                }
             }
 
             // Run post-processing asynchronously if enabled
@@ -883,9 +1803,16 @@ public class RecorderForm extends javax.swing.JPanel {
                     PostProcessingItem selectedItem = (PostProcessingItem) postProcessingSelectComboBox.getSelectedItem();
                     if (selectedItem != null && selectedItem.uuid != null) {
                         Pipeline pipeline = configManager.getPipelineByUuid(selectedItem.uuid);
-                        if (pipeline != null) {
+                        String pipelineProblem = PostProcessingService.checkPipelineReady(pipeline, configManager);
+                        if (pipeline != null && pipelineProblem != null) {
+                            console.logError("Pipeline skipped: " + pipelineProblem);
+                            Notificationmanager.getInstance().showNotification(ToastNotification.Type.WARNING,
+                                    "Pipeline skipped: " + pipelineProblem);
+                            resetUIAfterTranscription();
+                            updateTrayMenu();
+                        } else if (pipeline != null) {
                             // Run post-processing in separate worker to avoid blocking UI
-                            new PostProcessingWorker(transcript, pipeline).execute();
+                            new PostProcessingWorker(pipeline).start(transcript, confidence);
                         } else {
                             logger.error("Pipeline not found for UUID: " + selectedItem.uuid);
                             console.logError("Pipeline not found: " + selectedItem.uuid);
@@ -896,13 +1823,11 @@ public class RecorderForm extends javax.swing.JPanel {
                         resetUIAfterTranscription();
                     }
             } else if (transcript != null && !transcript.trim().isEmpty()) {
-                // No post-processing, just copy raw transcript if auto-paste enabled
-                if (configManager.isAutoPasteEnabled()) {
-                    // Remove focus from transcription area to prevent pasting into itself
-                    transcriptionTextArea.transferFocus();
-                    copyTranscriptionToClipboard(transcript);
-                    pasteFromClipboard();
-                }
+                // No post-processing, output the raw transcript. Remove focus from the
+                // transcription area to prevent pasting into itself
+                transcriptionTextArea.transferFocus();
+                autoPaste(transcript, confidence, focusedWindow);
+                outputDispatcher.dispatch(transcript);
                 playFinishSound();
                 resetUIAfterTranscription();
                 updateTrayMenu();
@@ -913,42 +1838,104 @@ public class RecorderForm extends javax.swing.JPanel {
     }
 
     /**
-     * Worker for running post-processing pipelines asynchronously
+     * Worker for running post-processing pipelines in a {@link BackgroundTask}. Can also run
+     * inside another background task via {@link #run(String)} and {@link #complete(String, double)}.
      */
-    private class PostProcessingWorker extends SwingWorker<String, Void> {
-        private final String inputText;
+    private class PostProcessingWorker {
+        private volatile String inputText;
+        private volatile String focusedWindow;
         private final Pipeline pipeline;
-        private final long startTime;
+        private volatile long startTime;
+        private final CancellationToken token;
+        private final PostProcessingService ppService = new PostProcessingService(configManager);
 
-        public PostProcessingWorker(String inputText, Pipeline pipeline) {
-            this.inputText = inputText;
+        public PostProcessingWorker(Pipeline pipeline) {
             this.pipeline = pipeline;
+            this.token = beginPipelineRun();
+        }
+
+        /**
+         * Runs the pipeline on the given text in the background.
+         *
+         * @param text       the transcription to process
+         * @param confidence the transcription's confidence
+         */
+        public void start(String text, double confidence) {
+            BackgroundTask.run(() -> run(text), result -> complete(result, confidence), this::failed);
+        }
+
+        /**
+         * Runs the pipeline on the calling (background) thread.
+         *
+         * @param text the transcription to process
+         * @return the pipeline result
+         */
+        public String run(String text) {
+            this.inputText = text;
             this.startTime = System.currentTimeMillis();
+            String result = ppService.applyPipeline(text, pipeline, token);
+            focusedWindow = queryFocusedWindow();
+            return result;
         }
 
-        @Override
-        protected String doInBackground() {
-            PostProcessingService ppService = new PostProcessingService(configManager);
-            return ppService.applyPipeline(inputText, pipeline);
+        /**
+         * Hides the stop button of a run that will not happen.
+         */
+        public void discard() {
+            endPipelineRun();
         }
 
-        @Override
-        protected void done() {
+        /**
+         * Reports a failed run started with {@link #start(String, double)} and resets the UI.
+         *
+         * @param e the failure
+         */
+        private void failed(Exception e) {
+            logger.error("Error during post-processing", e);
+            ConsoleLogger.getInstance().logError("Post-processing failed: " + e.getMessage());
+            endPipelineRun();
+            resetUIAfterTranscription();
+            updateTrayMenu();
+        }
+
+        /**
+         * Shows, stores and outputs the pipeline result. Must run on the event dispatch thread.
+         *
+         * @param processedResult the pipeline result
+         * @param confidence      confidence of the processed transcription, gates auto-paste
+         */
+        public void complete(String processedResult, double confidence) {
             try {
-                String processedResult = get();
+                if (token.isCancelled()) {
+                    // Keep the partial result visible but skip copy/paste and history
+                    RecorderForm.this.processedText.setText(processedResult);
+                    Notificationmanager.getInstance().showNotification(ToastNotification.Type.WARNING,
+                            "Pipeline stopped: " + pipeline.title);
+                    return;
+                }
                 int executionTime = (int) (System.currentTimeMillis() - startTime);
+                boolean emptyRun = PostProcessingService.isEmptyRun(inputText, processedResult, ppService.getLastUnitsRun());
 
-                // Add result to history
-                pipelineHistory.addResult(pipeline.uuid, pipeline.title, processedResult, executionTime);
+                if (emptyRun) {
+                    ConsoleLogger.getInstance().logError("Pipeline had no enabled units: " + pipeline.title);
+                    Notificationmanager.getInstance().showNotification(ToastNotification.Type.WARNING,
+                            "Pipeline had no enabled units");
+                } else {
+                    // Add result to history
+                    pipelineHistory.addResult(pipeline.uuid, pipeline.title, processedResult, executionTime,
+                            ppService.getLastTokenUsage());
 
-                // Update history panel
-                historyPanel.updateResults(pipelineHistory.getResults());
+                    // Update history panel
+                    historyPanel.updateResults(pipelineHistory.getResults());
+                }
 
                 RecorderForm.this.processedText.setText(processedResult);
 
-                // Show pipeline completion toast
-                Notificationmanager.getInstance().showNotification(ToastNotification.Type.SUCCESS,
-                        "Post-processing completed!");
+                if (!emptyRun) {
+                    // Show pipeline completion toast
+                    Notificationmanager.getInstance().showNotification(ToastNotification.Type.SUCCESS,
+                            "Post-processing completed!");
+                }
 
                 // Show system-level notification
                 TrayIconManager trayManager = AudioRecorderUI.getTrayIconManager();
@@ -962,8 +1949,10 @@ public class RecorderForm extends javax.swing.JPanel {
                 transcriptionTextArea.transferFocus();
                 RecorderForm.this.processedText.transferFocus();
 
-                copyTranscriptionToClipboard(processedResult);
-                pasteFromClipboard();
+                autoPaste(processedResult, confidence, focusedWindow);
+                if (!emptyRun) {
+                    outputDispatcher.dispatch(processedResult);
+                }
 
                 // Remember the last used pipeline
                 configManager.setLastUsedPipelineUUID(pipeline.uuid);
@@ -971,12 +1960,44 @@ public class RecorderForm extends javax.swing.JPanel {
                 logger.error("Error during post-processing", e);
                 ConsoleLogger.getInstance().logError("Post-processing failed: " + e.getMessage());
             } finally {
+                endPipelineRun();
                 resetUIAfterTranscription();
                 updateTrayMenu();
             }
         }
     }
 
+    /**
+     * Creates a new cancellation token for a pipeline run and shows the stop button.
+     *
+     * @return the token to pass to the pipeline execution
+     */
+    private CancellationToken beginPipelineRun() {
+        pipelineCancellationToken = new CancellationToken();
+        stopPipelineButton.setEnabled(true);
+        stopPipelineButton.setVisible(true);
+        return pipelineCancellationToken;
+    }
+
+    /**
+     * Hides the stop button once a pipeline run has finished or was stopped.
+     */
+    private void endPipelineRun() {
+        pipelineCancellationToken = null;
+        stopPipelineButton.setVisible(false);
+    }
+
+    /**
+     * Requests cancellation of the running pipeline. Units already finished keep their log output.
+     */
+    private void stopRunningPipeline() {
+        if (pipelineCancellationToken != null && !pipelineCancellationToken.isCancelled()) {
+            pipelineCancellationToken.cancel();
+            stopPipelineButton.setEnabled(false);
+            ConsoleLogger.getInstance().log("Stop requested - finishing current unit...");
+        }
+    }
+
     /**
      * Updates the Run Pipeline button enabled state based on:
      * - Transcription text field has content
@@ -984,19 +2005,25 @@ public class RecorderForm extends javax.swing.JPanel {
      * - No manual pipeline is currently running
      */
     private void updateRunPipelineButtonState() {
-        if (runPipelineButton == null) {
+        if (runPipelineButton == null || pipelineProblemLabel == null) {
             return; // Button not yet initialized
         }
 
         boolean hasTranscription = transcriptionTextArea.getText() != null
                 && !transcriptionTextArea.getText().trim().isEmpty();
         boolean hasPipelineSelected = postProcessingSelectComboBox.getSelectedItem() != null;
-        boolean canRun = hasTranscription && hasPipelineSelected && !isManualPipelineRunning && !isTranscribing;
+        String pipelineProblem = getSelectedPipelineProblem();
+        boolean canRun = hasTranscription && hasPipelineSelected && pipelineProblem == null
+                && !isManualPipelineRunning && !isTranscribing;
 
         runPipelineButton.setEnabled(canRun);
+        pipelineProblemLabel.setText(pipelineProblem != null ? "\u26A0 " + pipelineProblem : "");
+        pipelineProblemLabel.setVisible(pipelineProblem != null);
 
         // Update tooltip based on state
-        if (!hasTranscription) {
+        if (pipelineProblem != null) {
+            runPipelineButton.setToolTipText(pipelineProblem);
+        } else if (!hasTranscription) {
             runPipelineButton.setToolTipText("Record audio first");
         } else if (!hasPipelineSelected) {
             runPipelineButton.setToolTipText("Select a pipeline");
@@ -1007,6 +2034,68 @@ public class RecorderForm extends javax.swing.JPanel {
         }
     }
 
+    /**
+     * @return why the selected pipeline cannot run, or null if it is ready or none is selected
+     */
+    private String getSelectedPipelineProblem() {
+        PostProcessingItem selectedItem = (PostProcessingItem) postProcessingSelectComboBox.getSelectedItem();
+        if (selectedItem == null || selectedItem.uuid == null) {
+            return null;
+        }
+        return PostProcessingService.checkPipelineReady(configManager.getPipelineByUuid(selectedItem.uuid), configManager);
+    }
+
+    /**
+     * Runs the last used pipeline on the current transcription, for the pipeline hotkey.
+     * Tells the user with a toast why nothing happens when there is no transcription,
+     * no usable pipeline, or a transcription or pipeline is still running.
+     */
+    public void runLastPipeline() {
+        String uuid = configManager.getLastUsedPipelineUUID();
+        Pipeline pipeline = uuid.isEmpty() ? null : configManager.getPipelineByUuid(uuid);
+        String problem = lastPipelineProblem(transcriptionTextArea.getText(),
+                isManualPipelineRunning || isTranscribing, pipeline, configManager);
+        if (problem != null) {
+            Notificationmanager.getInstance().showNotification(ToastNotification.Type.WARNING, problem);
+            return;
+        }
+        for (int i = 0; i < postProcessingSelectComboBox.getItemCount(); i++) {
+            if (pipeline.uuid.equals(postProcessingSelectComboBox.getItemAt(i).uuid)) {
+                postProcessingSelectComboBox.setSelectedIndex(i);
+                break;
+            }
+        }
+        PostProcessingItem selectedItem = (PostProcessingItem) postProcessingSelectComboBox.getSelectedItem();
+        if (selectedItem == null || !pipeline.uuid.equals(selectedItem.uuid)) {
+            // Created since the list was loaded; reloading selects the last used pipeline
+            populatePostProcessingComboBox();
+        }
+        runManualPipeline();
+    }
+
+    /**
+     * Decides whether the pipeline hotkey can run the last used pipeline.
+     *
+     * @param transcript    the current transcription
+     * @param busy          whether a transcription or pipeline is running
+     * @param pipeline      the last used pipeline, or null if there is none
+     * @param configManager used to check the pipeline's providers
+     * @return the warning to show instead of running, or null if the pipeline can run
+     */
+    static String lastPipelineProblem(String transcript, boolean busy, Pipeline pipeline, ConfigManager configManager) {
+        if (transcript == null || transcript.trim().isEmpty()) {
+            return "No transcription to run the pipeline on";
+        }
+        if (busy) {
+            return "Busy - wait for the current run to finish";
+        }
+        if (pipeline == null || !pipeline.enabled) {
+            return "No pipeline to run. Select one on the recording screen first.";
+        }
+        String pipelineProblem = PostProcessingService.checkPipelineReady(pipeline, configManager);
+        return pipelineProblem != null ? "Pipeline skipped: " + pipelineProblem : null;
+    }
+
     /**
      * Runs the selected pipeline manually on the current transcription text.
      * Works regardless of "Enable Post Processing" checkbox state.
@@ -1044,82 +2133,82 @@ public class RecorderForm extends javax.swing.JPanel {
         ConsoleLogger.getInstance().log("Manual pipeline run: " + pipeline.title);
 
         // Run pipeline in worker
-        new ManualPipelineWorker(transcript, pipeline).execute();
+        startManualPipeline(transcript, pipeline);
     }
 
     /**
-     * Worker for running manual pipeline executions asynchronously
+     * Runs a pipeline on the given text in the background and shows the result, for
+     * manual runs.
      */
-    private class ManualPipelineWorker extends SwingWorker<String, Void> {
-        private final String inputText;
-        private final Pipeline pipeline;
-        private final long startTime;
-        private final String previousResult;  // Capture current result before running
-
-        public ManualPipelineWorker(String inputText, Pipeline pipeline) {
-            this.inputText = inputText;
-            this.pipeline = pipeline;
-            this.startTime = System.currentTimeMillis();
-            // Capture the current post-processed text before we run
-            this.previousResult = processedText.getText();
-        }
-
-        @Override
-        protected String doInBackground() {
-            PostProcessingService ppService = new PostProcessingService(configManager);
-            return ppService.applyPipeline(inputText, pipeline);
-        }
-
-        @Override
-        protected void done() {
-            try {
-                String result = get();
-                int executionTime = (int) (System.currentTimeMillis() - startTime);
-
-                // Result stacking: save previous result to history if it exists
-                if (previousResult != null && !previousResult.trim().isEmpty()) {
-                    // The previous result was from some pipeline run, we need to save it
-                    // Note: We don't have the previous pipeline info, so we mark it as "Previous result"
-                    // This is a limitation - in Phase 4 we'll track this properly
-                    ConsoleLogger.getInstance().log("Previous result saved to history");
-                    Notificationmanager.getInstance().showNotification(ToastNotification.Type.INFO,
-                            "Previous result saved to history");
-                }
+    private void startManualPipeline(String inputText, Pipeline pipeline) {
+        long startTime = System.currentTimeMillis();
+        // Capture the current post-processed text before we run
+        String previousResult = processedText.getText();
+        CancellationToken token = beginPipelineRun();
+        PostProcessingService ppService = new PostProcessingService(configManager);
+        BackgroundTask.run(() -> ppService.applyPipeline(inputText, pipeline, token),
+                result -> {
+                    if (token.isCancelled()) {
+                        processedText.setText(result);
+                        Notificationmanager.getInstance().showNotification(ToastNotification.Type.WARNING,
+                                "Pipeline stopped: " + pipeline.title);
+                        return;
+                    }
+                    int executionTime = (int) (System.currentTimeMillis() - startTime);
 
-                // Add new result to history
-                pipelineHistory.addResult(pipeline.uuid, pipeline.title, result, executionTime);
+                    if (PostProcessingService.isEmptyRun(inputText, result, ppService.getLastUnitsRun())) {
+                        ConsoleLogger.getInstance().logError("Pipeline had no enabled units: " + pipeline.title);
+                        Notificationmanager.getInstance().showNotification(ToastNotification.Type.WARNING,
+                                "Pipeline had no enabled units");
+                        return;
+                    }
 
-                // Update history panel
-                historyPanel.updateResults(pipelineHistory.getResults());
+                    // Result stacking: save previous result to history if it exists
+                    if (previousResult != null && !previousResult.trim().isEmpty()) {
+                        // The previous result was from some pipeline run, we need to save it
+                        // Note: We don't have the previous pipeline info, so we mark it as "Previous result"
+                        // This is a limitation - in Phase 4 we'll track this properly
+                        ConsoleLogger.getInstance().log("Previous result saved to history");
+                        Notificationmanager.getInstance().showNotification(ToastNotification.Type.INFO,
+                                "Previous result saved to history");
+                    }
 
-                // Update the display
-                processedText.setText(result);
+                    // Add new result to history
+                    pipelineHistory.addResult(pipeline.uuid, pipeline.title, result, executionTime,
+                            ppService.getLastTokenUsage());
 
-                // Post-processed text area is now always visible
+                    // Update history panel
+                    historyPanel.updateResults(pipelineHistory.getResults());
 
-                ConsoleLogger.getInstance().logSuccess("Manual pipeline completed: " + pipeline.title +
-                        " (" + executionTime + "ms)");
+                    // Update the display
+                    processedText.setText(result);
 
-                Notificationmanager.getInstance().showNotification(ToastNotification.Type.SUCCESS,
-                        "Pipeline completed: " + pipeline.title);
+                    // Post-processed text area is now always visible
 
-                // Remember the last used pipeline
-                configManager.setLastUsedPipelineUUID(pipeline.uuid);
+                    ConsoleLogger.getInstance().logSuccess("Manual pipeline completed: " + pipeline.title +
+                            " (" + executionTime + "ms)");
 
-            } catch (Exception e) {
-                logger.error("Error during manual pipeline execution", e);
-                ConsoleLogger.getInstance().logError("Pipeline failed: " + e.getMessage());
-                Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR,
-                        "Pipeline failed: " + e.getMessage());
-            } finally {
-                // Reset UI state
-                isManualPipelineRunning = false;
-                isTranscribing = false;
-                statusIndicatorPanel.repaint();
-                runPipelineButton.setText("\u25B6 Run Pipeline");
-                updateRunPipelineButtonState();
-            }
-        }
+                    Notificationmanager.getInstance().showNotification(ToastNotification.Type.SUCCESS,
+                            "Pipeline completed: " + pipeline.title);
+
+                    // Remember the last used pipeline
+                    configManager.setLastUsedPipelineUUID(pipeline.uuid);
+                },
+                e -> {
+                    logger.error("Error during manual pipeline execution", e);
+                    ConsoleLogger.getInstance().logError("Pipeline failed: " + e.getMessage());
+                    Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR,
+                            "Pipeline failed: " + e.getMessage());
+                },
+                () -> {
+                    // Reset UI state
+                    endPipelineRun();
+                    isManualPipelineRunning = false;
+                    isTranscribing = false;
+                    statusIndicatorPanel.repaint();
+                    runPipelineButton.setText("\u25B6 Run Pipeline");
+                    updateRunPipelineButtonState();
+                });
     }
 
 }
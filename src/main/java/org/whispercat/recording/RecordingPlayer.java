@@ -0,0 +1,132 @@
+package org.whispercat.recording;
+
+import org.apache.logging.log4j.LogManager;
+import org.apache.logging.log4j.Logger;
+
+import javax.sound.sampled.AudioInputStream;
+import javax.sound.sampled.AudioSystem;
+import javax.sound.sampled.Clip;
+import javax.sound.sampled.LineEvent;
+import javax.sound.sampled.LineUnavailableException;
+import javax.sound.sampled.UnsupportedAudioFileException;
+import java.io.File;
+import java.io.FileNotFoundException;
+import java.io.IOException;
+
+/**
+ * Plays back a recording from the history. Only one recording plays at a time; starting
+ * playback stops the previous one. The state listener is called on the audio thread
+ * when playback starts and when it stops, either by {@link #stop()} or at the end.
+ */
+public class RecordingPlayer {
+    private static final Logger logger = LogManager.getLogger(RecordingPlayer.class);
+
+    public enum State {
+        STOPPED,
+        PLAYING
+    }
+
+    /**
+     * Opens a file as a clip ready to start.
+     */
+    interface ClipOpener {
+        Clip open(File file) throws IOException;
+    }
+
+    private final ClipOpener clipOpener;
+    private State state = State.STOPPED;
+    private Clip clip;
+    private Runnable stateListener;
+
+    public RecordingPlayer() {
+        this(RecordingPlayer::openClip);
+    }
+
+    /**
+     * Creates a player that opens clips with the given opener, e.g. a fake in tests.
+     */
+    RecordingPlayer(ClipOpener clipOpener) {
+        this.clipOpener = clipOpener;
+    }
+
+    public void setStateListener(Runnable stateListener) {
+        this.stateListener = stateListener;
+    }
+
+    public synchronized State getState() {
+        return state;
+    }
+
+    /**
+     * Starts playing the given WAV file, stopping any current playback first.
+     *
+     * @param file the recording
+     * @throws FileNotFoundException if the recording no longer exists
+     * @throws IOException           if the file cannot be played
+     */
+    public void play(File file) throws IOException {
+        stop();
+        if (file == null || !file.isFile()) {
+            throw new FileNotFoundException("Recording not found: " + (file != null ? file.getName() : "none"));
+        }
+        Clip newClip = clipOpener.open(file);
+        newClip.addLineListener(event -> {
+            if (event.getType() == LineEvent.Type.STOP) {
+                onClipStopped(newClip);
+            }
+        });
+        synchronized (this) {
+            clip = newClip;
+            state = State.PLAYING;
+        }
+        logger.info("Playing recording {}", file.getName());
+        newClip.start();
+        notifyListener();
+    }
+
+    private static Clip openClip(File file) throws IOException {
+        try (AudioInputStream stream = AudioSystem.getAudioInputStream(file)) {
+            Clip clip = AudioSystem.getClip();
+            clip.open(stream);
+            return clip;
+        } catch (UnsupportedAudioFileException | LineUnavailableException e) {
+            throw new IOException("Cannot play " + file.getName() + ": " + e.getMessage(), e);
+        }
+    }
+
+    /**
+     * Stops playback. Does nothing if nothing is playing.
+     */
+    public void stop() {
+        Clip current;
+        synchronized (this) {
+            current = clip;
+        }
+        if (current != null) {
+            current.stop();
+            onClipStopped(current);
+        }
+    }
+
+    /**
+     * Releases the clip once, whichever of stop() or the end of the recording comes first.
+     */
+    private void onClipStopped(Clip stoppedClip) {
+        synchronized (this) {
+            if (clip != stoppedClip) {
+                return;
+            }
+            clip = null;
+            state = State.STOPPED;
+        }
+        stoppedClip.close();
+        notifyListener();
+    }
+
+    private void notifyListener() {
+        Runnable listener = stateListener;
+        if (listener != null) {
+            listener.run();
+        }
+    }
+}
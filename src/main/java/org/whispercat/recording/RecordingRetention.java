@@ -0,0 +1,94 @@
+package org.whispercat.recording;
+
+import org.apache.logging.log4j.LogManager;
+import org.apache.logging.log4j.Logger;
+import org.whispercat.ConsoleLogger;
+
+import java.io.File;
+import java.util.concurrent.TimeUnit;
+
+/**
+ * Deletes old recordings and their intermediate files (silence-removed and compressed copies)
+ * from the recordings folder. Only files created by WhisperCat are considered.
+ */
+public class RecordingRetention {
+    private static final Logger logger = LogManager.getLogger(RecordingRetention.class);
+
+    // Name prefixes of files written by the recorder and the transcription clients
+    private static final String[] RECORDING_PREFIXES = {"record_", "whispercat_compressed_", "whispercat_converted_"};
+    private static final String[] RECORDING_EXTENSIONS = {".wav", ".mp3", ".txt", ".srt"};
+
+    private RecordingRetention() {
+    }
+
+    /**
+     * Deletes recordings in the given folder that are older than the retention period.
+     *
+     * @param directory     the recordings folder
+     * @param retentionDays maximum age in days; 0 or less disables deletion
+     * @return the number of deleted files
+     */
+    public static int purge(File directory, int retentionDays) {
+        if (retentionDays <= 0 || directory == null || !directory.isDirectory()) {
+            return 0;
+        }
+        File[] files = directory.listFiles();
+        if (files == null) {
+            return 0;
+        }
+        long now = System.currentTimeMillis();
+        int deleted = 0;
+        for (File file : files) {
+            if (!file.isFile() || !isRecordingFile(file.getName())
+                    || !isExpired(file.lastModified(), retentionDays, now)) {
+                continue;
+            }
+            if (file.delete()) {
+                deleted++;
+                logger.info("Deleted old recording: {}", file.getName());
+            } else {
+                logger.warn("Could not delete old recording: {}", file.getAbsolutePath());
+            }
+        }
+        if (deleted > 0) {
+            ConsoleLogger.getInstance().log("Deleted " + deleted + " recording(s) older than " + retentionDays + " day(s)");
+        }
+        return deleted;
+    }
+
+    /**
+     * Checks whether a file name belongs to a recording or one of its intermediate files.
+     */
+    static boolean isRecordingFile(String name) {
+        String lower = name.toLowerCase();
+        boolean knownPrefix = false;
+        for (String prefix : RECORDING_PREFIXES) {
+            if (lower.startsWith(prefix)) {
+                knownPrefix = true;
+                break;
+            }
+        }
+        if (!knownPrefix) {
+            return false;
+        }
+        for (String extension : RECORDING_EXTENSIONS) {
+            if (lower.endsWith(extension)) {
+                return true;
+            }
+        }
+        return false;
+    }
+
+    /**
+     * Checks whether a file modified at the given time is older than the retention period.
+     *
+     * @param lastModified  modification time in epoch milliseconds
+     * @param retentionDays maximum age in days
+     * @param now           current time in epoch milliseconds
+     * @return true if the file should be deleted
+     */
+    static boolean isExpired(long lastModified, int retentionDays, long now) {
+        return retentionDays > 0 && lastModified > 0
+                && now - lastModified > TimeUnit.DAYS.toMillis(retentionDays);
+    }
+}
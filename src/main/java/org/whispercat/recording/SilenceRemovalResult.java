@@ -0,0 +1,50 @@
+package org.whispercat.recording;
+
+import java.io.File;
+
+/**
+ * Result of {@link SilenceRemover#removeSilence}: the file to transcribe, an optional
+ * warning for the user, e.g. when the whole recording was below the silence threshold,
+ * and the timeline of the removed regions.
+ */
+public class SilenceRemovalResult {
+    private final File file;
+    private final String warning;
+    private final SilenceTimeline timeline;
+
+    SilenceRemovalResult(File file, String warning) {
+        this(file, warning, null);
+    }
+
+    SilenceRemovalResult(File file, String warning, SilenceTimeline timeline) {
+        this.file = file;
+        this.warning = warning;
+        this.timeline = timeline;
+    }
+
+    static SilenceRemovalResult of(File file) {
+        return new SilenceRemovalResult(file, null);
+    }
+
+    /**
+     * @return the file to transcribe, never empty; the original if silence was not removed
+     */
+    public File getFile() {
+        return file;
+    }
+
+    /**
+     * @return a message for the user, or null if there is nothing to report
+     */
+    public String getWarning() {
+        return warning;
+    }
+
+    /**
+     * @return maps positions in {@link #getFile()} back to the original recording, or null
+     * if nothing was removed
+     */
+    public SilenceTimeline getTimeline() {
+        return timeline;
+    }
+}
@@ -1,6 +1,7 @@
 package org.whispercat.recording;
 
 import org.whispercat.ConsoleLogger;
+import org.whispercat.DisplayFormat;
 
 import javax.sound.sampled.AudioFileFormat;
 import javax.sound.sampled.AudioFormat;
@@ -21,10 +22,13 @@ public class SilenceRemover {
     private static final org.apache.logging.log4j.Logger logger =
         org.apache.logging.log4j.LogManager.getLogger(SilenceRemover.class);
 
+    /** Length of the linear fade applied on both sides of each cut, to avoid clicks. */
+    static final int FADE_MS = 10;
+
     /**
      * Represents a silent region in the audio.
      */
-    private static class SilenceRegion {
+    static class SilenceRegion {
         long startFrame;
         long endFrame;
 
@@ -39,18 +43,24 @@ public class SilenceRemover {
     }
 
     /**
-     * Removes silence from an audio file.
+     * Removes silence from an audio file, but only if the projected reduction is worth it.
      *
      * @param originalFile The original audio file
      * @param silenceThresholdRMS RMS threshold for silence detection (0.0-1.0, typically 0.01 = -40dB)
      * @param minSilenceDurationMs Minimum consecutive duration to consider as silence (milliseconds)
+     * @param windowMs Length of the analysis windows (milliseconds); smaller windows find shorter pauses
+     * @param paddingMs Audio kept on each side of a removed region, so word onsets and endings survive (milliseconds)
      * @param keepCompressed Whether to keep the compressed file after transcription
      * @param minRecordingDurationSec Minimum recording duration (seconds) to apply silence removal
-     * @return The compressed audio file, or original if no silence detected
+     * @param minReductionPercent Minimum projected reduction (percent) required to splice the audio
+     * @param outputDir Where to write the compressed file
+     * @return The compressed audio file, or original if removal was skipped or would leave
+     * no audio; with a warning if the recording contains no audible speech
      */
-    public static File removeSilence(File originalFile, float silenceThresholdRMS,
-                                     int minSilenceDurationMs, boolean keepCompressed,
-                                     int minRecordingDurationSec) {
+    public static SilenceRemovalResult removeSilence(File originalFile, float silenceThresholdRMS,
+                                     int minSilenceDurationMs, int windowMs, int paddingMs, boolean keepCompressed,
+                                     int minRecordingDurationSec, int minReductionPercent,
+                                     File outputDir) {
         ConsoleLogger console = ConsoleLogger.getInstance();
         long startTime = System.currentTimeMillis();
 
@@ -65,7 +75,7 @@ public class SilenceRemover {
             if (format.getEncoding() != AudioFormat.Encoding.PCM_SIGNED) {
                 console.log("Audio format not supported for silence removal (not PCM), skipping");
                 audioStream.close();
-                return originalFile;
+                return SilenceRemovalResult.of(originalFile);
             }
 
             // Read all audio data into memory
@@ -85,34 +95,49 @@ public class SilenceRemover {
             long totalFrames = audioData.length / frameSize;
             float originalDurationSec = totalFrames / sampleRate;
 
-            console.log(String.format("Original duration: %.1fs (%d frames)",
+            console.log(DisplayFormat.format("Original duration: %.1fs (%d frames)",
                 originalDurationSec, totalFrames));
 
             // Safety check: ensure original audio is long enough
             if (originalDurationSec < 1.0f) {
                 console.log("Original audio too short for silence removal (< 1s), skipping");
-                return originalFile;
+                return SilenceRemovalResult.of(originalFile);
             }
 
             // Check minimum recording duration threshold
             if (originalDurationSec < minRecordingDurationSec) {
-                console.log(String.format("Recording duration (%.1fs) below threshold (%ds), skipping silence removal",
+                console.log(DisplayFormat.format("Recording duration (%.1fs) below threshold (%ds), skipping silence removal",
                     originalDurationSec, minRecordingDurationSec));
                 console.log("Use longer recordings for silence removal to avoid unnecessary overhead");
-                return originalFile;
+                return SilenceRemovalResult.of(originalFile);
             }
 
             // Log detection parameters for transparency
-            console.log(String.format("Silence threshold: %.3f RMS | Min duration: %dms",
-                silenceThresholdRMS, minSilenceDurationMs));
+            console.log(DisplayFormat.format("Silence threshold: %.3f RMS | Min duration: %dms | Window: %dms | Padding: %dms",
+                silenceThresholdRMS, minSilenceDurationMs, windowMs, paddingMs));
+            if (minSilenceDurationMs < windowMs) {
+                console.log(DisplayFormat.format("Min duration is shorter than one window, pauses of %dms or more are detected", windowMs));
+            } else if (minSilenceDurationMs % windowMs != 0) {
+                console.log(DisplayFormat.format("Min duration is not a multiple of the window, pauses are detected from %dms",
+                    (minSilenceDurationMs / windowMs + 1) * windowMs));
+            }
 
             // Detect silence regions (with diagnostic logging)
             List<SilenceRegion> silences = detectSilence(audioData, format,
-                silenceThresholdRMS, minSilenceDurationMs, console);
+                silenceThresholdRMS, minSilenceDurationMs, windowMs, console);
+
+            // Everything below the threshold: splicing would leave nothing to transcribe
+            if (isEntirelySilent(silences, totalFrames)) {
+                console.logError("No audible speech detected - using original audio file");
+                logger.warn("Entire recording is below the silence threshold ({} RMS)", silenceThresholdRMS);
+                return new SilenceRemovalResult(originalFile,
+                    "No audible speech detected. Check that the microphone is not muted.");
+            }
 
+            silences = applyPadding(silences, (long) (paddingMs / 1000.0 * sampleRate), totalFrames);
             if (silences.isEmpty()) {
                 console.log("No significant silence detected");
-                return originalFile;
+                return SilenceRemovalResult.of(originalFile);
             }
 
             // Calculate statistics
@@ -122,39 +147,50 @@ public class SilenceRemover {
             float totalSilenceSec = totalSilenceFrames / sampleRate;
             float reductionPercent = (totalSilenceSec / originalDurationSec) * 100;
 
-            console.log(String.format("Detected %d silence region(s) (total: %.1fs)",
+            console.log(DisplayFormat.format("Detected %d silence region(s) (total: %.1fs)",
                 silences.size(), totalSilenceSec));
-            console.log(String.format("Reduction: %.1f%%", reductionPercent));
+            console.log(DisplayFormat.format("Reduction: %.1f%%", reductionPercent));
+
+            // Skip splicing when the savings are too small to be worth it
+            if (!isReductionWorthwhile(reductionPercent, minReductionPercent)) {
+                console.log(DisplayFormat.format("Reduction below %d%% threshold, skipping silence removal", minReductionPercent));
+                logger.info("Skipping silence removal: projected reduction {}% < {}%", reductionPercent, minReductionPercent);
+                return SilenceRemovalResult.of(originalFile);
+            }
 
             // Safety check: don't remove more than 90% of audio
             if (reductionPercent > 90.0f) {
                 console.log("⚠ Silence removal would reduce audio by >90%, skipping");
                 console.log("This may indicate overly aggressive settings");
                 console.log("Using original audio file");
-                return originalFile;
+                return SilenceRemovalResult.of(originalFile);
             }
 
             // Create compressed audio by removing silence
             byte[] compressedData = spliceAudio(audioData, format, silences);
+            if (compressedData.length == 0) {
+                console.log("⚠ Silence removal left no audio, using original audio file");
+                return SilenceRemovalResult.of(originalFile);
+            }
             long compressedFrames = compressedData.length / frameSize;
             float compressedDurationSec = compressedFrames / sampleRate;
 
-            console.log(String.format("Compressed duration: %.1fs (%d frames)",
+            console.log(DisplayFormat.format("Compressed duration: %.1fs (%d frames)",
                 compressedDurationSec, compressedFrames));
 
             // Safety check: ensure compressed audio is at least 0.5 seconds
             // (OpenAI requires 0.1s minimum, we use 0.5s for safety margin)
             if (compressedDurationSec < 0.5f) {
                 console.log("⚠ Compressed audio too short (" +
-                    String.format("%.2fs", compressedDurationSec) +
+                    DisplayFormat.format("%.2fs", compressedDurationSec) +
                     "), skipping silence removal");
                 console.log("Using original audio file");
-                return originalFile;
+                return SilenceRemovalResult.of(originalFile);
             }
 
             // Write compressed audio to file
             String compressedFileName = originalFile.getName().replace(".wav", "_nosilence.wav");
-            File compressedFile = new File(originalFile.getParent(), compressedFileName);
+            File compressedFile = new File(outputDir, compressedFileName);
 
             AudioInputStream compressedStream = new AudioInputStream(
                 new ByteArrayInputStream(compressedData),
@@ -167,7 +203,7 @@ public class SilenceRemover {
 
             long elapsedTime = System.currentTimeMillis() - startTime;
             console.logSuccess("Silence removed: " + compressedFile.getName());
-            console.log(String.format("Silence removal took %dms", elapsedTime));
+            console.log(DisplayFormat.format("Silence removal took %dms", elapsedTime));
 
             // Schedule deletion if configured (will be deleted when application closes)
             if (!keepCompressed) {
@@ -175,22 +211,96 @@ public class SilenceRemover {
                 console.log("Compressed file will be auto-deleted when application closes");
             }
 
-            return compressedFile;
+            List<long[]> removedFrames = new ArrayList<>();
+            for (SilenceRegion silence : silences) {
+                removedFrames.add(new long[]{silence.startFrame, silence.endFrame});
+            }
+            return new SilenceRemovalResult(compressedFile, null,
+                SilenceTimeline.fromRemovedFrames(removedFrames, sampleRate));
 
         } catch (Exception e) {
             logger.error("Error removing silence from audio", e);
             console.logError("Silence removal failed: " + e.getMessage());
             console.log("Using original audio file");
-            return originalFile;
+            return SilenceRemovalResult.of(originalFile);
+        }
+    }
+
+    /**
+     * Measures the RMS amplitude of a whole PCM recording.
+     *
+     * @param audioFile the recording
+     * @return the RMS (0.0-1.0), or 0 if the file cannot be read or is not signed PCM
+     */
+    public static float measureRms(File audioFile) {
+        try (AudioInputStream audioStream = AudioSystem.getAudioInputStream(audioFile)) {
+            AudioFormat format = audioStream.getFormat();
+            if (format.getEncoding() != AudioFormat.Encoding.PCM_SIGNED) {
+                return 0.0f;
+            }
+            byte[] audioData = audioStream.readAllBytes();
+            return calculateRMS(audioData, 0, audioData.length,
+                format.getSampleSizeInBits() / 8, format.isBigEndian());
+        } catch (Exception e) {
+            logger.warn("Could not measure audio level of {}", audioFile.getName(), e);
+            return 0.0f;
+        }
+    }
+
+    /**
+     * Decides whether the projected reduction justifies rewriting the audio file.
+     *
+     * @param reductionPercent Projected reduction of the recording length (percent)
+     * @param minReductionPercent Configured minimum reduction (percent), 0 or less always applies
+     * @return true if silence should be removed
+     */
+    static boolean isReductionWorthwhile(float reductionPercent, int minReductionPercent) {
+        return minReductionPercent <= 0 || reductionPercent >= minReductionPercent;
+    }
+
+    /**
+     * Checks whether the detected silence covers the whole recording.
+     *
+     * @param silences    the detected silence regions
+     * @param totalFrames number of frames in the recording
+     * @return true if no frame lies outside a silence region
+     */
+    static boolean isEntirelySilent(List<SilenceRegion> silences, long totalFrames) {
+        long silentFrames = silences.stream().mapToLong(SilenceRegion::getDurationFrames).sum();
+        return totalFrames > 0 && silentFrames >= totalFrames;
+    }
+
+    /**
+     * Shrinks silence regions by the padding on each side, so a little audio around speech
+     * is kept. Boundaries at the start or end of the recording are not moved, and regions
+     * that the padding consumes entirely are dropped.
+     *
+     * @param silences      the detected silence regions
+     * @param paddingFrames frames to keep on each side of a region
+     * @param totalFrames   number of frames in the recording
+     * @return the inset regions
+     */
+    static List<SilenceRegion> applyPadding(List<SilenceRegion> silences, long paddingFrames, long totalFrames) {
+        if (paddingFrames <= 0) {
+            return silences;
         }
+        List<SilenceRegion> padded = new ArrayList<>();
+        for (SilenceRegion silence : silences) {
+            long start = silence.startFrame == 0 ? 0 : silence.startFrame + paddingFrames;
+            long end = silence.endFrame >= totalFrames ? totalFrames : silence.endFrame - paddingFrames;
+            if (end > start) {
+                padded.add(new SilenceRegion(start, end));
+            }
+        }
+        return padded;
     }
 
     /**
      * Detects silence regions in audio data using RMS amplitude analysis.
      */
-    private static List<SilenceRegion> detectSilence(byte[] audioData, AudioFormat format,
-                                                     float silenceThresholdRMS, int minSilenceDurationMs,
-                                                     ConsoleLogger console) {
+    static List<SilenceRegion> detectSilence(byte[] audioData, AudioFormat format,
+                                             float silenceThresholdRMS, int minSilenceDurationMs,
+                                             int windowMs, ConsoleLogger console) {
         List<SilenceRegion> silences = new ArrayList<>();
 
         float sampleRate = format.getSampleRate();
@@ -198,13 +308,15 @@ public class SilenceRemover {
         int sampleSizeInBytes = format.getSampleSizeInBits() / 8;
         boolean isBigEndian = format.isBigEndian();
 
-        // Window size: 100ms
-        int windowFrames = (int) (sampleRate * 0.1); // 100ms windows
+        int windowFrames = Math.max(1, (int) (sampleRate * windowMs / 1000));
         int windowBytes = windowFrames * frameSize;
 
         // Minimum silence frames
         long minSilenceFrames = (long) ((minSilenceDurationMs / 1000.0) * sampleRate);
 
+        // All region boundaries are clamped to this, so a partial last window lines up with the data
+        long totalFrames = audioData.length / frameSize;
+
         long silenceStartFrame = -1;
 
         // Track RMS values for diagnostics
@@ -226,7 +338,7 @@ public class SilenceRemover {
             maxRMS = Math.max(maxRMS, rms);
             sumRMS += rms;
 
-            long currentFrame = offset / frameSize;
+            long currentFrame = Math.min(offset / frameSize, totalFrames);
 
             if (rms < silenceThresholdRMS) {
                 // Silence detected
@@ -248,16 +360,15 @@ public class SilenceRemover {
 
         // Handle silence at end of file
         if (silenceStartFrame != -1) {
-            long endFrame = audioData.length / frameSize;
-            long silenceDuration = endFrame - silenceStartFrame;
+            long silenceDuration = totalFrames - silenceStartFrame;
             if (silenceDuration >= minSilenceFrames) {
-                silences.add(new SilenceRegion(silenceStartFrame, endFrame));
+                silences.add(new SilenceRegion(silenceStartFrame, totalFrames));
             }
         }
 
         // Log RMS diagnostics to help debug silence detection issues
         float avgRMS = windowCount > 0 ? sumRMS / windowCount : 0.0f;
-        console.log(String.format("Audio RMS analysis: min=%.4f, max=%.4f, avg=%.4f (threshold=%.3f)",
+        console.log(DisplayFormat.format("Audio RMS analysis: min=%.4f, max=%.4f, avg=%.4f (threshold=%.3f)",
             minRMS, maxRMS, avgRMS, silenceThresholdRMS));
 
         if (maxRMS < silenceThresholdRMS) {
@@ -273,8 +384,8 @@ public class SilenceRemover {
      * Calculates RMS (Root Mean Square) amplitude for an audio segment.
      * Returns a value between 0.0 (silent) and 1.0 (maximum amplitude).
      */
-    private static float calculateRMS(byte[] audioData, int offset, int length,
-                                     int sampleSizeInBytes, boolean isBigEndian) {
+    static float calculateRMS(byte[] audioData, int offset, int length,
+                             int sampleSizeInBytes, boolean isBigEndian) {
         double sum = 0.0;
         int sampleCount = 0;
 
@@ -283,15 +394,9 @@ public class SilenceRemover {
 
             // Read sample value
             int sample = 0;
-            if (sampleSizeInBytes == 2) {
-                // 16-bit audio
-                if (isBigEndian) {
-                    sample = (audioData[i] << 8) | (audioData[i + 1] & 0xFF);
-                } else {
-                    sample = (audioData[i] & 0xFF) | (audioData[i + 1] << 8);
-                }
-                // Normalize to -1.0 to 1.0
-                double normalized = sample / 32768.0;
+            if (sampleSizeInBytes >= 2 && sampleSizeInBytes <= 4) {
+                // 16, 24 or 32-bit audio, normalized to -1.0 to 1.0
+                double normalized = PcmSamples.readNormalized(audioData, i, sampleSizeInBytes, isBigEndian);
                 sum += normalized * normalized;
                 sampleCount++;
             } else if (sampleSizeInBytes == 1) {
@@ -309,12 +414,14 @@ public class SilenceRemover {
     }
 
     /**
-     * Creates new audio data by removing silence regions.
+     * Creates new audio data by removing silence regions, fading out before and in after
+     * each cut.
      */
     private static byte[] spliceAudio(byte[] audioData, AudioFormat format,
                                      List<SilenceRegion> silences) {
         int frameSize = format.getFrameSize();
         ByteArrayOutputStream output = new ByteArrayOutputStream();
+        List<Integer> cuts = new ArrayList<>();
 
         long lastEndFrame = 0;
 
@@ -327,6 +434,9 @@ public class SilenceRemover {
                 int length = Math.min(endByte - startByte, audioData.length - startByte);
                 output.write(audioData, startByte, length);
             }
+            if (output.size() > 0) {
+                cuts.add(output.size());
+            }
 
             lastEndFrame = silence.endFrame;
         }
@@ -337,6 +447,50 @@ public class SilenceRemover {
             output.write(audioData, startByte, audioData.length - startByte);
         }
 
-        return output.toByteArray();
+        byte[] spliced = output.toByteArray();
+        int fadeFrames = (int) (format.getSampleRate() * FADE_MS / 1000);
+        for (int cut : cuts) {
+            if (cut < spliced.length) {
+                fadeAcrossCut(spliced, cut, fadeFrames, format);
+            }
+        }
+        return spliced;
+    }
+
+    /**
+     * Ramps the audio linearly to zero before a cut and back up after it.
+     *
+     * @param audioData  the spliced audio, modified in place
+     * @param cutByte    byte offset where two pieces of audio were joined
+     * @param fadeFrames frames to fade on each side
+     * @param format     the audio format
+     */
+    static void fadeAcrossCut(byte[] audioData, int cutByte, int fadeFrames, AudioFormat format) {
+        int frameSize = format.getFrameSize();
+        int sampleBytes = format.getSampleSizeInBits() / 8;
+        int channels = format.getChannels();
+        boolean bigEndian = format.isBigEndian();
+        int cutFrame = cutByte / frameSize;
+        int totalFrames = audioData.length / frameSize;
+        int before = Math.min(fadeFrames, cutFrame);
+        int after = Math.min(fadeFrames, totalFrames - cutFrame);
+
+        for (int i = 0; i < before; i++) {
+            scaleFrame(audioData, (cutFrame - 1 - i) * frameSize, channels, sampleBytes,
+                (double) i / before, bigEndian);
+        }
+        for (int i = 0; i < after; i++) {
+            scaleFrame(audioData, (cutFrame + i) * frameSize, channels, sampleBytes,
+                (double) i / after, bigEndian);
+        }
+    }
+
+    private static void scaleFrame(byte[] audioData, int offset, int channels, int sampleBytes,
+                                   double gain, boolean bigEndian) {
+        for (int c = 0; c < channels; c++) {
+            int sampleOffset = offset + c * sampleBytes;
+            int sample = PcmSamples.read(audioData, sampleOffset, sampleBytes, bigEndian);
+            PcmSamples.write(audioData, sampleOffset, sampleBytes, (int) Math.round(sample * gain), bigEndian);
+        }
     }
 }
@@ -0,0 +1,105 @@
+package org.whispercat.recording;
+
+import org.whispercat.recording.clients.TranscriptionSegment;
+
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.List;
+
+/**
+ * Maps positions in audio with the silence removed back to the original recording, so
+ * segment timestamps (and the subtitles built from them) line up with the original audio.
+ */
+public class SilenceTimeline {
+
+    /**
+     * A removed region: where the cut lies in the processed audio and how much was removed.
+     */
+    private static class Cut {
+        final double processedSeconds;
+        final double removedSeconds;
+
+        Cut(double processedSeconds, double removedSeconds) {
+            this.processedSeconds = processedSeconds;
+            this.removedSeconds = removedSeconds;
+        }
+    }
+
+    private final List<Cut> cuts;
+
+    private SilenceTimeline(List<Cut> cuts) {
+        this.cuts = Collections.unmodifiableList(cuts);
+    }
+
+    /**
+     * Builds the timeline from the removed regions.
+     *
+     * @param removedFrames start and end frame (exclusive) of each removed region in the
+     *                      original recording, in order and not overlapping
+     * @param sampleRate    frames per second
+     * @return the timeline
+     */
+    static SilenceTimeline fromRemovedFrames(List<long[]> removedFrames, float sampleRate) {
+        List<Cut> cuts = new ArrayList<>();
+        long removedSoFar = 0;
+        for (long[] region : removedFrames) {
+            long length = region[1] - region[0];
+            if (length <= 0) {
+                continue;
+            }
+            cuts.add(new Cut((region[0] - removedSoFar) / (double) sampleRate, length / (double) sampleRate));
+            removedSoFar += length;
+        }
+        return new SilenceTimeline(cuts);
+    }
+
+    /**
+     * Maps a start position: a position exactly at a cut maps to the end of the removed
+     * region, where the following speech begins.
+     *
+     * @param processedSeconds position in the processed audio
+     * @return the position in the original recording
+     */
+    public double toOriginalStart(double processedSeconds) {
+        double shift = 0;
+        for (Cut cut : cuts) {
+            if (processedSeconds < cut.processedSeconds) {
+                break;
+            }
+            shift += cut.removedSeconds;
+        }
+        return processedSeconds + shift;
+    }
+
+    /**
+     * Maps an end position: a position exactly at a cut maps to the start of the removed
+     * region, where the preceding speech ends.
+     *
+     * @param processedSeconds position in the processed audio
+     * @return the position in the original recording
+     */
+    public double toOriginalEnd(double processedSeconds) {
+        double shift = 0;
+        for (Cut cut : cuts) {
+            if (processedSeconds <= cut.processedSeconds) {
+                break;
+            }
+            shift += cut.removedSeconds;
+        }
+        return processedSeconds + shift;
+    }
+
+    /**
+     * Moves the segment timestamps from the processed audio to the original recording.
+     *
+     * @param segments the segments, modified in place
+     */
+    public void remap(List<TranscriptionSegment> segments) {
+        for (TranscriptionSegment segment : segments) {
+            double start = toOriginalStart(segment.getStart());
+            double end = toOriginalEnd(segment.getEnd());
+            segment.setStart(start);
+            segment.setEnd(Math.max(start, end));
+        }
+    }
+}
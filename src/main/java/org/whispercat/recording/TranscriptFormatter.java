@@ -0,0 +1,211 @@
+package org.whispercat.recording;
+
+import org.whispercat.recording.clients.TranscriptionSegment;
+
+import java.time.LocalDateTime;
+import java.time.format.DateTimeFormatter;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.Locale;
+import java.util.regex.Pattern;
+
+/**
+ * Helpers for formatting transcription results before they are displayed, copied or pasted.
+ */
+public class TranscriptFormatter {
+
+    public static final String DEFAULT_TIMESTAMP_FORMAT = "yyyy-MM-dd HH:mm";
+
+    // Runs of spaces/tabs (line breaks are kept)
+    private static final Pattern REPEATED_SPACES = Pattern.compile("[ \\t]{2,}");
+    // Whitespace directly before a comma or period, e.g. "word ." -> "word."
+    private static final Pattern SPACE_BEFORE_PUNCTUATION = Pattern.compile("[ \\t]+([,.])");
+
+    private TranscriptFormatter() {
+    }
+
+    /**
+     * Checks whether the given pattern is a usable {@link DateTimeFormatter} pattern.
+     * The pattern is also test-formatted, since some patterns only fail at format time.
+     *
+     * @param pattern the timestamp pattern
+     * @return true if the pattern can format a local date-time
+     */
+    public static boolean isValidTimestampFormat(String pattern) {
+        if (pattern == null || pattern.trim().isEmpty()) {
+            return false;
+        }
+        try {
+            DateTimeFormatter.ofPattern(pattern).format(LocalDateTime.now());
+            return true;
+        } catch (IllegalArgumentException | java.time.DateTimeException e) {
+            return false;
+        }
+    }
+
+    /**
+     * Prepends a formatted timestamp to the text, separated by a single space.
+     * Falls back to {@link #DEFAULT_TIMESTAMP_FORMAT} if the pattern is invalid.
+     *
+     * @param text    the transcription text
+     * @param pattern the timestamp pattern
+     * @param now     the time to format
+     * @return the text prefixed with the timestamp
+     */
+    public static String prependTimestamp(String text, String pattern, LocalDateTime now) {
+        String effectivePattern = isValidTimestampFormat(pattern) ? pattern : DEFAULT_TIMESTAMP_FORMAT;
+        String timestamp = DateTimeFormatter.ofPattern(effectivePattern).format(now);
+        return timestamp + " " + text;
+    }
+
+    /**
+     * Returns the end of a text for use as transcription prompt context. If the text is
+     * longer than the limit, the cut is moved forward to the next word boundary so the
+     * prompt does not start with a partial word.
+     *
+     * @param text     the previous transcription
+     * @param maxChars maximum length of the tail
+     * @return the tail, or an empty string if there is no text
+     */
+    public static String extractPromptTail(String text, int maxChars) {
+        if (text == null || maxChars <= 0) {
+            return "";
+        }
+        String trimmed = text.trim();
+        if (trimmed.length() <= maxChars) {
+            return trimmed;
+        }
+        String tail = trimmed.substring(trimmed.length() - maxChars);
+        if (Character.isLowSurrogate(tail.charAt(0))) {
+            // Don't start with the second half of an emoji or other supplementary character
+            tail = tail.substring(1);
+        }
+        if (!Character.isWhitespace(trimmed.charAt(trimmed.length() - maxChars - 1))) {
+            int firstSpace = tail.indexOf(' ');
+            if (firstSpace >= 0) {
+                tail = tail.substring(firstSpace + 1);
+            }
+        }
+        return tail.trim();
+    }
+
+    /**
+     * Conservatively normalizes whitespace in a transcription: trims the text, collapses
+     * runs of spaces within lines and removes spaces before commas and periods.
+     * Line breaks and all other punctuation are left untouched.
+     *
+     * @param text the transcription text
+     * @return the normalized text
+     */
+    public static String normalizeText(String text) {
+        if (text == null) {
+            return null;
+        }
+        String normalized = text.trim();
+        normalized = REPEATED_SPACES.matcher(normalized).replaceAll(" ");
+        normalized = SPACE_BEFORE_PUNCTUATION.matcher(normalized).replaceAll("$1");
+        return normalized;
+    }
+
+    /**
+     * Formats a header describing where a transcription came from, e.g.
+     * "[OpenAI whisper-1, en, 12.3s]". Unknown values are left out.
+     *
+     * @param outcome the transcription result
+     * @return the header, or "[]" if no metadata is known
+     */
+    public static String formatMetadataHeader(TranscriptionOutcome outcome) {
+        List<String> parts = new ArrayList<>();
+        String source = joinNonBlank(outcome.getProvider(), outcome.getModel());
+        if (!source.isEmpty()) {
+            parts.add(source);
+        }
+        if (outcome.getLanguage() != null && !outcome.getLanguage().trim().isEmpty()) {
+            parts.add(outcome.getLanguage().trim());
+        }
+        if (outcome.getDurationSeconds() >= 0) {
+            parts.add(String.format(Locale.ROOT, "%.1fs", outcome.getDurationSeconds()));
+        }
+        return "[" + String.join(", ", parts) + "]";
+    }
+
+    /**
+     * Puts the metadata header on its own line above the text.
+     *
+     * @param text    the transcription text
+     * @param outcome the transcription result the text came from
+     * @return the header followed by the text
+     */
+    public static String withMetadataHeader(String text, TranscriptionOutcome outcome) {
+        return formatMetadataHeader(outcome) + "\n" + (text != null ? text : "");
+    }
+
+    /**
+     * Formats segments as SubRip subtitles: numbered cues from 1 with
+     * "00:01:02,345 --> 00:01:04,000" time ranges.
+     *
+     * @param segments the transcription segments, in playback order
+     * @return the SRT text, or null if there are no segments
+     */
+    public static String toSrt(List<TranscriptionSegment> segments) {
+        if (segments == null || segments.isEmpty()) {
+            return null;
+        }
+        StringBuilder srt = new StringBuilder();
+        int index = 1;
+        for (TranscriptionSegment segment : segments) {
+            srt.append(index++).append('\n')
+                    .append(formatCueTime(segment.getStart(), ',')).append(" --> ")
+                    .append(formatCueTime(segment.getEnd(), ',')).append('\n')
+                    .append(cueText(segment)).append("\n\n");
+        }
+        return srt.toString();
+    }
+
+    /**
+     * Formats segments as WebVTT subtitles with "00:01:02.345 --> 00:01:04.000" time ranges.
+     *
+     * @param segments the transcription segments, in playback order
+     * @return the VTT text, or null if there are no segments
+     */
+    public static String toVtt(List<TranscriptionSegment> segments) {
+        if (segments == null || segments.isEmpty()) {
+            return null;
+        }
+        StringBuilder vtt = new StringBuilder("WEBVTT\n\n");
+        for (TranscriptionSegment segment : segments) {
+            vtt.append(formatCueTime(segment.getStart(), '.')).append(" --> ")
+                    .append(formatCueTime(segment.getEnd(), '.')).append('\n')
+                    .append(cueText(segment)).append("\n\n");
+        }
+        return vtt.toString();
+    }
+
+    /**
+     * Formats a time as HH:MM:SS followed by the separator and milliseconds.
+     *
+     * @param seconds         the time in seconds; negative values count as 0
+     * @param millisSeparator ',' for SRT, '.' for WebVTT
+     * @return e.g. "01:02:03,456"
+     */
+    static String formatCueTime(double seconds, char millisSeparator) {
+        long millis = Math.round(Math.max(0, seconds) * 1000);
+        return String.format(Locale.ROOT, "%02d:%02d:%02d%c%03d",
+                millis / 3_600_000, millis / 60_000 % 60, millis / 1000 % 60, millisSeparator, millis % 1000);
+    }
+
+    // A blank line ends a cue, so the text must not contain one
+    private static String cueText(TranscriptionSegment segment) {
+        String text = segment.getText() != null ? segment.getText().trim() : "";
+        return text.replaceAll("\\n\\s*\\n", "\n");
+    }
+
+    private static String joinNonBlank(String first, String second) {
+        String a = first != null ? first.trim() : "";
+        String b = second != null ? second.trim() : "";
+        if (a.isEmpty() || b.isEmpty()) {
+            return a + b;
+        }
+        return a + " " + b;
+    }
+}
@@ -0,0 +1,14 @@
+package org.whispercat.recording;
+
+import java.io.IOException;
+
+/**
+ * Thrown when the user cancelled a transcription, including one aborted while its
+ * request was still waiting for the server.
+ */
+public class TranscriptionCancelledException extends IOException {
+
+    public TranscriptionCancelledException() {
+        super("Transcription cancelled");
+    }
+}
@@ -0,0 +1,113 @@
+package org.whispercat.recording;
+
+import org.whispercat.recording.clients.TranscriptionConfidence;
+import org.whispercat.recording.clients.TranscriptionSegment;
+
+import java.util.Collections;
+import java.util.List;
+
+/**
+ * Result of a transcription including metadata about how it was produced.
+ * The GUI mostly uses the text; the remaining fields are available for callers that need them.
+ */
+public class TranscriptionOutcome {
+    private String text;
+    private String language;         // Language reported by the provider or configured, empty if unknown
+    private double durationSeconds;  // Duration of the submitted audio, -1 if unknown
+    private String provider;
+    private String model;
+    private long silenceRemovalMs;
+    private long transcriptionMs;
+    private double confidence = TranscriptionConfidence.UNKNOWN;
+    private List<TranscriptionSegment> segments = Collections.emptyList();
+    private String warning;          // Message for the user about the audio, null if none
+
+    public TranscriptionOutcome() {
+    }
+
+    public TranscriptionOutcome(String text, String provider, String model) {
+        this.text = text;
+        this.provider = provider;
+        this.model = model;
+    }
+
+    public String getText() {
+        return text;
+    }
+
+    public void setText(String text) {
+        this.text = text;
+    }
+
+    public String getLanguage() {
+        return language;
+    }
+
+    public void setLanguage(String language) {
+        this.language = language;
+    }
+
+    public double getDurationSeconds() {
+        return durationSeconds;
+    }
+
+    public void setDurationSeconds(double durationSeconds) {
+        this.durationSeconds = durationSeconds;
+    }
+
+    public String getProvider() {
+        return provider;
+    }
+
+    public void setProvider(String provider) {
+        this.provider = provider;
+    }
+
+    public String getModel() {
+        return model;
+    }
+
+    public void setModel(String model) {
+        this.model = model;
+    }
+
+    public long getSilenceRemovalMs() {
+        return silenceRemovalMs;
+    }
+
+    public void setSilenceRemovalMs(long silenceRemovalMs) {
+        this.silenceRemovalMs = silenceRemovalMs;
+    }
+
+    public long getTranscriptionMs() {
+        return transcriptionMs;
+    }
+
+    public void setTranscriptionMs(long transcriptionMs) {
+        this.transcriptionMs = transcriptionMs;
+    }
+
+    public double getConfidence() {
+        return confidence;
+    }
+
+    public void setConfidence(double confidence) {
+        this.confidence = confidence;
+    }
+
+    public List<TranscriptionSegment> getSegments() {
+        return segments;
+    }
+
+    public void setSegments(List<TranscriptionSegment> segments) {
+        this.segments = segments != null ? segments : Collections.emptyList();
+    }
+
+    public String getWarning() {
+        return warning;
+    }
+
+    public void setWarning(String warning) {
+        this.warning = warning;
+    }
+}
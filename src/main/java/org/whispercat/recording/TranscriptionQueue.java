@@ -0,0 +1,74 @@
+package org.whispercat.recording;
+
+import org.whispercat.ConsoleLogger;
+
+import java.io.File;
+import java.util.ArrayDeque;
+import java.util.Deque;
+import java.util.function.Consumer;
+
+/**
+ * Transcribes recordings one after another, in the order they were made. A recording
+ * finished while an earlier one is still being transcribed waits until that one is done.
+ * Used from the event dispatch thread only.
+ */
+class TranscriptionQueue {
+    private final Consumer<File> starter;
+    private final Deque<File> queuedRecordings = new ArrayDeque<>();
+    private boolean transcribing;
+
+    /**
+     * @param starter starts the background transcription of a recording
+     */
+    TranscriptionQueue(Consumer<File> starter) {
+        this.starter = starter;
+    }
+
+    /**
+     * Transcribes a finished recording now, or queues it if an earlier recording is
+     * still being transcribed.
+     *
+     * @return true if the transcription started, false if it was queued
+     */
+    boolean submit(File audioFile) {
+        if (transcribing) {
+            queuedRecordings.add(audioFile);
+            ConsoleLogger.getInstance().log("Transcription queued (" + queuedRecordings.size() + " waiting)");
+            return false;
+        }
+        transcribing = true;
+        starter.accept(audioFile);
+        return true;
+    }
+
+    /**
+     * Marks the running transcription as done and starts the next queued one.
+     *
+     * @return the recording now being transcribed, or null if the queue is idle
+     */
+    File finishActive() {
+        transcribing = false;
+        File next = queuedRecordings.poll();
+        if (next != null) {
+            ConsoleLogger.getInstance().log("Starting queued transcription: " + next.getName());
+            submit(next);
+        }
+        return next;
+    }
+
+    /**
+     * Whether a new recording can start. Without recording during transcription, the
+     * recorder stays blocked until the running transcription is done.
+     */
+    boolean canStartRecording(boolean recordDuringTranscription) {
+        return recordDuringTranscription || !transcribing;
+    }
+
+    boolean isTranscribing() {
+        return transcribing;
+    }
+
+    int getQueuedCount() {
+        return queuedRecordings.size();
+    }
+}
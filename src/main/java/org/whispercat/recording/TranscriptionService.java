@@ -0,0 +1,357 @@
+package org.whispercat.recording;
+
+import org.apache.logging.log4j.LogManager;
+import org.apache.logging.log4j.Logger;
+import org.whispercat.CancellationToken;
+import org.whispercat.ConfigManager;
+import org.whispercat.ConsoleLogger;
+import org.whispercat.DisplayFormat;
+import org.whispercat.recording.clients.AudioFileTooLargeException;
+import org.whispercat.recording.clients.FasterWhisperTranscribeClient;
+import org.whispercat.recording.clients.OpenAITranscribeClient;
+import org.whispercat.recording.clients.OpenWebUITranscribeClient;
+import org.whispercat.recording.clients.TempFile;
+import org.whispercat.recording.clients.TranscriptionConfidence;
+import org.whispercat.recording.clients.TranscriptionProviderSpec;
+import org.whispercat.recording.clients.TranscriptionProviders;
+import org.whispercat.recording.clients.TranscriptionSegment;
+
+import javax.sound.sampled.AudioFileFormat;
+import javax.sound.sampled.AudioSystem;
+import java.io.File;
+import java.io.IOException;
+import java.util.ArrayList;
+import java.util.List;
+
+/**
+ * Transcribes audio files with the configured Whisper server, independent of the UI.
+ * Applies silence removal if enabled and returns the text together with metadata.
+ */
+public class TranscriptionService {
+    private static final Logger logger = LogManager.getLogger(TranscriptionService.class);
+    private static final int PROMPT_CONTEXT_CHARS = 200;
+
+    private final ConfigManager configManager;
+    private final OpenAITranscribeClient openAIClient;
+    private final FasterWhisperTranscribeClient fasterWhisperClient;
+    private final OpenWebUITranscribeClient openWebUIClient;
+    private final ProviderHealth providerHealth = ProviderHealth.getInstance();
+    // Last transcription, used as prompt context for the next one
+    private volatile String previousText = "";
+
+    public TranscriptionService(ConfigManager configManager) {
+        this.configManager = configManager;
+        this.openAIClient = new OpenAITranscribeClient(configManager);
+        this.fasterWhisperClient = new FasterWhisperTranscribeClient(configManager);
+        this.openWebUIClient = new OpenWebUITranscribeClient(configManager);
+    }
+
+    /**
+     * Transcribes the given audio file using the configured server.
+     *
+     * @param audioFile the audio file to transcribe.
+     * @return the transcription result with metadata.
+     * @throws IOException if the transcription fails or the server is unknown.
+     */
+    public TranscriptionOutcome transcribe(File audioFile) throws IOException {
+        return transcribe(audioFile, new CancellationToken());
+    }
+
+    /**
+     * Transcribes the given audio file using the configured server, until cancelled.
+     *
+     * @param audioFile    the audio file to transcribe.
+     * @param cancellation cancels the transcription; a request waiting for the server is aborted.
+     * @return the transcription result with metadata.
+     * @throws TranscriptionCancelledException if the transcription was cancelled.
+     * @throws TranslationNotSupportedException if translation is enabled but the server cannot translate.
+     * @throws IOException if the transcription fails or the server is unknown.
+     */
+    public TranscriptionOutcome transcribe(File audioFile, CancellationToken cancellation) throws IOException {
+        String server = configManager.getWhisperServer();
+        TranscriptionProviderSpec spec = TranscriptionProviders.get(server);
+        if (spec == null) {
+            logger.error("Unknown Whisper server: " + server);
+            throw new IOException("Unknown Whisper server: " + server);
+        }
+        if (configManager.isTranslateToEnglish() && !spec.supportsTranslation()) {
+            throw new TranslationNotSupportedException(server);
+        }
+
+        // Convert 24/32-bit, stereo and other-rate recordings (e.g. dropped files) to the configured
+        // format; the converted copies are deleted once the transcription completes or fails
+        File intermediateDir = configManager.getIntermediateFileLocation().directoryFor(audioFile);
+        try (TempFile pcm16 = AudioResampler.toPcm16IfNeeded(audioFile, intermediateDir);
+             TempFile mono = AudioResampler.downmixIfNeeded(pcm16.getFile(), intermediateDir);
+             TempFile resampled = AudioResampler.resampleIfNeeded(mono.getFile(), configManager.getAudioBitrate(), intermediateDir);
+             TempFile normalized = configManager.isNormalizeAudio()
+                 ? AudioResampler.normalizePeakIfNeeded(resampled.getFile(), AudioResampler.NORMALIZE_TARGET_PEAK, intermediateDir)
+                 : TempFile.borrowed(resampled.getFile())) {
+            return transcribeConverted(spec, normalized.getFile(), intermediateDir, cancellation);
+        }
+    }
+
+    /**
+     * Transcribes a recording already converted to the configured format, removing silence
+     * first if enabled.
+     */
+    private TranscriptionOutcome transcribeConverted(TranscriptionProviderSpec spec, File fileToTranscribe,
+                                                     File intermediateDir, CancellationToken cancellation)
+            throws IOException {
+        ConsoleLogger console = ConsoleLogger.getInstance();
+        String server = spec.getName();
+
+        // Apply silence removal if enabled
+        long silenceRemovalMs = 0;
+        String audioWarning = null;
+        SilenceTimeline silenceTimeline = null;
+        File compressedFile = null;
+        checkCancelled(cancellation, null);
+        if (configManager.isSilenceRemovalEnabled()) {
+            console.separator();
+            long silenceStart = System.currentTimeMillis();
+            SilenceRemovalResult silenceRemoval = SilenceRemover.removeSilence(
+                fileToTranscribe,
+                configManager.getSilenceThreshold(),
+                configManager.getMinSilenceDuration(),
+                configManager.getSilenceWindowMs(),
+                configManager.getSilencePaddingMs(),
+                configManager.isKeepCompressedFile(),
+                configManager.getMinRecordingDurationForSilenceRemoval(),
+                configManager.getMinSilenceReductionPercent(),
+                intermediateDir
+            );
+            if (!silenceRemoval.getFile().equals(fileToTranscribe)) {
+                compressedFile = silenceRemoval.getFile();
+            }
+            fileToTranscribe = silenceRemoval.getFile();
+            audioWarning = silenceRemoval.getWarning();
+            silenceTimeline = silenceRemoval.getTimeline();
+            silenceRemovalMs = System.currentTimeMillis() - silenceStart;
+        }
+
+        console.separator();
+        console.log((configManager.isTranslateToEnglish() ? "Starting translation to English using " : "Starting transcription using ") + server);
+        console.log("Audio file: " + fileToTranscribe.getName());
+
+        long transcriptionStartTime = System.currentTimeMillis();
+        TranscriptionOutcome outcome;
+        String providerUrl = spec.resolveBaseUrl(configManager);
+        checkCancelled(cancellation, compressedFile);
+        providerHealth.checkReachable(server, providerUrl, configManager.getUnreachableRetrySeconds() * 1000L);
+        try {
+            outcome = transcribeWith(spec, fileToTranscribe, console, cancellation);
+            if (configManager.isRetryEmptyTranscription()
+                    && shouldRetryEmptyResult(outcome.getText(), SilenceRemover.measureRms(fileToTranscribe),
+                    configManager.getSilenceThreshold())) {
+                checkCancelled(cancellation, compressedFile);
+                logger.warn("Empty transcription for audible audio, retrying once");
+                console.log("Empty transcription although the audio is not silent - retrying once");
+                outcome = transcribeWith(spec, fileToTranscribe, console, cancellation);
+            }
+            providerHealth.record(providerUrl, null);
+        } catch (IOException | RuntimeException e) {
+            // An aborted request says nothing about the server
+            checkCancelled(cancellation, compressedFile);
+            providerHealth.record(providerUrl, e);
+            throw e;
+        }
+        checkCancelled(cancellation, compressedFile);
+
+        long transcriptionTime = System.currentTimeMillis() - transcriptionStartTime;
+        console.log(String.format("Transcription took %dms", transcriptionTime));
+
+        if (silenceTimeline != null) {
+            // Subtitles are saved next to the original recording, so they follow its timeline
+            silenceTimeline.remap(outcome.getSegments());
+        }
+        outcome.setTranscriptionMs(transcriptionTime);
+        outcome.setSilenceRemovalMs(silenceRemovalMs);
+        outcome.setWarning(audioWarning);
+        outcome.setDurationSeconds(getDurationSeconds(fileToTranscribe));
+        rememberForPrompt(outcome.getText());
+        return outcome;
+    }
+
+    /**
+     * Sends the file to the given provider. Recordings that are too large to upload even
+     * after compression are transcribed in overlapping chunks and merged.
+     */
+    private TranscriptionOutcome transcribeWith(TranscriptionProviderSpec spec, File fileToTranscribe, ConsoleLogger console,
+                                                CancellationToken cancellation) throws IOException {
+        try {
+            return transcribeOnce(spec, fileToTranscribe, console, cancellation);
+        } catch (AudioFileTooLargeException e) {
+            logger.warn("Audio exceeds the upload limit, transcribing in chunks", e);
+            return transcribeChunked(spec, fileToTranscribe, e.getMaxFileSize(), console, cancellation);
+        }
+    }
+
+    /**
+     * Stops the transcription if it was cancelled, deleting the file with the silence
+     * removed unless it is to be kept.
+     *
+     * @param cancellation   the token of the transcription
+     * @param compressedFile the file written by silence removal, or null
+     * @throws TranscriptionCancelledException if the transcription was cancelled
+     */
+    private void checkCancelled(CancellationToken cancellation, File compressedFile) throws TranscriptionCancelledException {
+        if (!cancellation.isCancelled()) {
+            return;
+        }
+        if (compressedFile != null && !configManager.isKeepCompressedFile() && compressedFile.delete()) {
+            logger.info("Deleted {} of the cancelled transcription", compressedFile.getName());
+        }
+        ConsoleLogger.getInstance().log("Transcription cancelled");
+        throw new TranscriptionCancelledException();
+    }
+
+    /**
+     * Splits the file into overlapping chunks under the size limit, transcribes them in order
+     * and merges the results. Segment times are shifted to positions in the whole recording.
+     */
+    private TranscriptionOutcome transcribeChunked(TranscriptionProviderSpec spec, File fileToTranscribe,
+                                                   long maxBytes, ConsoleLogger console,
+                                                   CancellationToken cancellation) throws IOException {
+        List<AudioChunker.Chunk> chunks = AudioChunker.split(fileToTranscribe, maxBytes,
+                configManager.getIntermediateFileLocation().directoryFor(fileToTranscribe));
+        console.log("Recording too large to upload, transcribing in " + chunks.size() + " parts");
+        List<String> texts = new ArrayList<>();
+        List<TranscriptionSegment> segments = new ArrayList<>();
+        TranscriptionOutcome first = null;
+        try {
+            for (int i = 0; i < chunks.size(); i++) {
+                if (cancellation.isCancelled()) {
+                    throw new TranscriptionCancelledException();
+                }
+                AudioChunker.Chunk chunk = chunks.get(i);
+                console.log("Transcribing part " + (i + 1) + " of " + chunks.size());
+                TranscriptionOutcome part;
+                try (TempFile chunkFile = TempFile.owned(chunk.getFile())) {
+                    part = transcribeOnce(spec, chunkFile.getFile(), console, cancellation);
+                }
+                if (first == null) {
+                    first = part;
+                }
+                texts.add(part.getText());
+                for (TranscriptionSegment segment : part.getSegments()) {
+                    segment.setStart(segment.getStart() + chunk.getStartSeconds());
+                    segment.setEnd(segment.getEnd() + chunk.getStartSeconds());
+                    segments.add(segment);
+                }
+            }
+        } finally {
+            // The parts not transcribed because one failed or the transcription was cancelled
+            AudioChunker.delete(chunks);
+        }
+        if (first == null) {
+            throw new IOException("Recording could not be split for transcription");
+        }
+        TranscriptionOutcome outcome = new TranscriptionOutcome(AudioChunker.mergeTexts(texts),
+                first.getProvider(), first.getModel());
+        outcome.setLanguage(first.getLanguage());
+        outcome.setSegments(segments);
+        outcome.setConfidence(TranscriptionConfidence.compute(segments));
+        return outcome;
+    }
+
+    /**
+     * Sends the file to the given provider in one request, which cancelling aborts. Providers
+     * without a dedicated client are called through their spec and must answer in the
+     * OpenAI-compatible format.
+     */
+    private TranscriptionOutcome transcribeOnce(TranscriptionProviderSpec spec, File fileToTranscribe, ConsoleLogger console,
+                                                CancellationToken cancellation) throws IOException {
+        String server = spec.getName();
+        TranscriptionOutcome outcome;
+        logger.info("Transcribing audio using " + server);
+        if (server.equals(TranscriptionProviders.OPENAI)) {
+            outcome = openAIClient.transcribe(fileToTranscribe, buildPrompt(), cancellation);
+            outcome.setConfidence(TranscriptionConfidence.compute(outcome.getSegments()));
+            if (outcome.getConfidence() != TranscriptionConfidence.UNKNOWN) {
+                console.log(DisplayFormat.format("Transcription confidence: %.0f%%", outcome.getConfidence() * 100));
+            }
+        } else if (server.equals(TranscriptionProviders.FASTER_WHISPER)) {
+            outcome = fasterWhisperClient.transcribe(fileToTranscribe, buildPrompt(), cancellation);
+        } else if (server.equals(TranscriptionProviders.OPEN_WEBUI)) {
+            String text = openWebUIClient.transcribeAudio(fileToTranscribe, cancellation);
+            outcome = new TranscriptionOutcome(text, server, "");
+            outcome.setLanguage("");
+        } else {
+            String responseBody = spec.buildRequest(configManager, fileToTranscribe, buildPrompt()).execute(cancellation);
+            outcome = new TranscriptionOutcome(TranscriptionProviders.parseText(responseBody), server, "");
+            outcome.setSegments(TranscriptionProviders.parseSegments(responseBody));
+            outcome.setLanguage("");
+        }
+        return outcome;
+    }
+
+    /**
+     * Keeps a transcription as prompt context for the next one; empty results are ignored.
+     */
+    void rememberForPrompt(String text) {
+        if (text != null && !text.trim().isEmpty()) {
+            previousText = text;
+        }
+    }
+
+    /**
+     * Decides whether an empty result is likely a server glitch worth one retry: the
+     * audio must be at least as loud as the silence threshold.
+     *
+     * @param text             the transcription
+     * @param audioRms         RMS of the transcribed audio (0.0-1.0)
+     * @param silenceThreshold RMS below which audio counts as silence
+     * @return true if the transcription should be repeated
+     */
+    static boolean shouldRetryEmptyResult(String text, float audioRms, float silenceThreshold) {
+        return (text == null || text.trim().isEmpty()) && audioRms >= silenceThreshold;
+    }
+
+    /**
+     * Forgets the previous transcription so the next one starts without prompt context.
+     */
+    public void resetPromptContext() {
+        previousText = "";
+    }
+
+    /**
+     * Builds the prompt from the configured initial prompt and, if enabled, the tail of the
+     * previous transcription.
+     *
+     * @return the prompt, or null if there is neither
+     */
+    String buildPrompt() {
+        String initialPrompt = configManager.getTranscriptionPrompt();
+        String context = configManager.isUsePreviousAsPrompt()
+                ? TranscriptFormatter.extractPromptTail(previousText, PROMPT_CONTEXT_CHARS)
+                : "";
+        if (!context.isEmpty()) {
+            logger.info("Using {} characters of the previous transcription as prompt", context.length());
+        }
+        if (initialPrompt.isEmpty()) {
+            return context.isEmpty() ? null : context;
+        }
+        return context.isEmpty() ? initialPrompt : initialPrompt + " " + context;
+    }
+
+    /**
+     * Determines the duration of an audio file.
+     *
+     * @param audioFile the audio file.
+     * @return the duration in seconds, or -1 if the format cannot be read.
+     */
+    private double getDurationSeconds(File audioFile) {
+        try {
+            AudioFileFormat fileFormat = AudioSystem.getAudioFileFormat(audioFile);
+            long frames = fileFormat.getFrameLength();
+            float frameRate = fileFormat.getFormat().getFrameRate();
+            if (frames > 0 && frameRate > 0) {
+                return frames / frameRate;
+            }
+        } catch (Exception e) {
+            logger.debug("Could not determine audio duration for {}", audioFile.getName(), e);
+        }
+        return -1;
+    }
+}
@@ -0,0 +1,20 @@
+package org.whispercat.recording;
+
+import java.io.IOException;
+
+/**
+ * Thrown without contacting the provider when translation to English is enabled but the
+ * selected provider has no translation endpoint.
+ */
+public class TranslationNotSupportedException extends IOException {
+    private final String provider;
+
+    public TranslationNotSupportedException(String provider) {
+        super(provider + " cannot translate to English. Switch to OpenAI or turn off \"Translate to English\".");
+        this.provider = provider;
+    }
+
+    public String getProvider() {
+        return provider;
+    }
+}
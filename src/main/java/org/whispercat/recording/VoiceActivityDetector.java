@@ -0,0 +1,73 @@
+package org.whispercat.recording;
+
+/**
+ * Detects the start and end of an utterance from a stream of RMS levels, for hands-free
+ * dictation. Speech starts once the level stays at or above the threshold for the onset
+ * time and ends once it stays below the threshold for the trailing silence time.
+ */
+public class VoiceActivityDetector {
+
+    public enum Event {
+        NONE,
+        SPEECH_STARTED,
+        SPEECH_ENDED
+    }
+
+    private final float threshold;
+    private final long onsetMs;
+    private final long trailingSilenceMs;
+    private boolean speaking = false;
+    private long loudMs = 0;
+    private long quietMs = 0;
+
+    /**
+     * @param threshold         RMS level (0.0-1.0) that counts as speech
+     * @param onsetMs           how long the level must stay above the threshold to start
+     * @param trailingSilenceMs how long the level must stay below the threshold to end
+     */
+    public VoiceActivityDetector(float threshold, long onsetMs, long trailingSilenceMs) {
+        this.threshold = threshold;
+        this.onsetMs = onsetMs;
+        this.trailingSilenceMs = trailingSilenceMs;
+    }
+
+    /**
+     * Feeds the level of the next chunk of audio.
+     *
+     * @param rms        the chunk's RMS level (0.0-1.0)
+     * @param durationMs the chunk's duration
+     * @return the transition caused by this chunk, {@link Event#NONE} if the state did not change
+     */
+    public Event process(float rms, long durationMs) {
+        boolean loud = rms >= threshold;
+        if (!speaking) {
+            loudMs = loud ? loudMs + durationMs : 0;
+            if (loudMs >= onsetMs) {
+                speaking = true;
+                quietMs = 0;
+                return Event.SPEECH_STARTED;
+            }
+        } else {
+            quietMs = loud ? 0 : quietMs + durationMs;
+            if (quietMs >= trailingSilenceMs) {
+                speaking = false;
+                loudMs = 0;
+                return Event.SPEECH_ENDED;
+            }
+        }
+        return Event.NONE;
+    }
+
+    /**
+     * @return true between {@link Event#SPEECH_STARTED} and {@link Event#SPEECH_ENDED}
+     */
+    public boolean isSpeaking() {
+        return speaking;
+    }
+
+    public void reset() {
+        speaking = false;
+        loudMs = 0;
+        quietMs = 0;
+    }
+}
@@ -0,0 +1,189 @@
+package org.whispercat.recording;
+
+import javax.sound.sampled.AudioFormat;
+import java.io.Closeable;
+import java.io.File;
+import java.io.IOException;
+import java.io.RandomAccessFile;
+
+/**
+ * WavStreamWriter writes 16-bit PCM WAV files incrementally.
+ *
+ * The header is written with placeholder sizes when the writer is created and patched
+ * in {@link #finish()}, so the recorder can feed captured chunks straight to disk
+ * without keeping a second copy of the recording in memory.
+ */
+public class WavStreamWriter implements Closeable {
+    private static final int HEADER_SIZE = 44;
+
+    private final RandomAccessFile out;
+    private final AudioFormat format;
+    private long dataBytes = 0;
+    private boolean finished = false;
+
+    /**
+     * Creates the WAV file and writes a header for the given format.
+     *
+     * @param file   the target WAV file (overwritten if it exists)
+     * @param format the PCM format of the samples; must be 16-bit signed
+     * @throws IOException if the file cannot be created
+     */
+    public WavStreamWriter(File file, AudioFormat format) throws IOException {
+        this(checkFormat(format), new RandomAccessFile(file, "rw"));
+    }
+
+    /**
+     * Writes the WAV header to an already opened file.
+     *
+     * @param format the PCM format of the samples
+     * @param out    the opened target file
+     * @throws IOException if the header cannot be written
+     */
+    WavStreamWriter(AudioFormat format, RandomAccessFile out) throws IOException {
+        this.format = format;
+        this.out = out;
+        out.setLength(0);
+        writeHeader();
+    }
+
+    private static AudioFormat checkFormat(AudioFormat format) throws IOException {
+        if (format.getSampleSizeInBits() != 16 || format.getEncoding() != AudioFormat.Encoding.PCM_SIGNED) {
+            throw new IOException("Only 16-bit signed PCM is supported, got: " + format);
+        }
+        return format;
+    }
+
+    /**
+     * Appends raw PCM bytes in the writer's format. Big-endian input is swapped to
+     * the little-endian byte order WAV requires.
+     *
+     * @param buffer the captured bytes
+     * @param offset start offset in the buffer
+     * @param length number of bytes to write (trailing partial frames are dropped)
+     * @throws IOException if writing fails
+     */
+    public void writePcm(byte[] buffer, int offset, int length) throws IOException {
+        ensureOpen();
+        int frameSize = format.getFrameSize();
+        int usable = length - (length % frameSize);
+        if (usable <= 0) {
+            return;
+        }
+        if (format.isBigEndian()) {
+            byte[] swapped = new byte[usable];
+            for (int i = 0; i < usable; i += 2) {
+                swapped[i] = buffer[offset + i + 1];
+                swapped[i + 1] = buffer[offset + i];
+            }
+            out.write(swapped, 0, usable);
+        } else {
+            out.write(buffer, offset, usable);
+        }
+        dataBytes += usable;
+    }
+
+    /**
+     * Appends normalized float samples (-1.0 to 1.0), clamping out-of-range values
+     * and converting them to 16-bit little-endian PCM.
+     *
+     * @param samples interleaved samples
+     * @throws IOException if writing fails
+     */
+    public void writeSamples(float[] samples) throws IOException {
+        ensureOpen();
+        byte[] bytes = new byte[samples.length * 2];
+        for (int i = 0; i < samples.length; i++) {
+            short value = toPcm16(samples[i]);
+            bytes[i * 2] = (byte) (value & 0xFF);
+            bytes[i * 2 + 1] = (byte) ((value >> 8) & 0xFF);
+        }
+        out.write(bytes);
+        dataBytes += bytes.length;
+    }
+
+    /**
+     * Converts a normalized float sample to a 16-bit PCM value, clamping to the valid range.
+     */
+    static short toPcm16(float sample) {
+        float clamped = Math.max(-1.0f, Math.min(1.0f, sample));
+        return (short) Math.round(clamped * Short.MAX_VALUE);
+    }
+
+    /**
+     * @return the number of PCM data bytes written so far
+     */
+    public long getDataBytes() {
+        return dataBytes;
+    }
+
+    /**
+     * Patches the RIFF and data chunk sizes and closes the file. Safe to call more than once.
+     * After a failed write (e.g. disk full) the file is cut back to the last complete chunk,
+     * so it still holds a valid WAV file with everything written before the failure.
+     *
+     * @throws IOException if the header cannot be updated
+     */
+    public void finish() throws IOException {
+        if (finished) {
+            return;
+        }
+        finished = true;
+        try {
+            // Shrinking needs no free space, so this also works on a full disk
+            out.setLength(HEADER_SIZE + dataBytes);
+            out.seek(4);
+            writeIntLE((int) (36 + dataBytes));
+            out.seek(40);
+            writeIntLE((int) dataBytes);
+        } finally {
+            out.close();
+        }
+    }
+
+    @Override
+    public void close() throws IOException {
+        finish();
+    }
+
+    private void ensureOpen() throws IOException {
+        if (finished) {
+            throw new IOException("WAV writer already finished");
+        }
+    }
+
+    private void writeHeader() throws IOException {
+        int channels = format.getChannels();
+        int sampleRate = (int) format.getSampleRate();
+        int blockAlign = format.getFrameSize();
+
+        out.writeBytes("RIFF");
+        writeIntLE(36); // patched in finish()
+        out.writeBytes("WAVE");
+        out.writeBytes("fmt ");
+        writeIntLE(16);
+        writeShortLE(1); // PCM
+        writeShortLE(channels);
+        writeIntLE(sampleRate);
+        writeIntLE(sampleRate * blockAlign);
+        writeShortLE(blockAlign);
+        writeShortLE(16);
+        out.writeBytes("data");
+        writeIntLE(0); // patched in finish()
+
+        if (out.getFilePointer() != HEADER_SIZE) {
+            throw new IOException("Unexpected WAV header size: " + out.getFilePointer());
+        }
+    }
+
+    private void writeIntLE(int value) throws IOException {
+        out.write(value & 0xFF);
+        out.write((value >> 8) & 0xFF);
+        out.write((value >> 16) & 0xFF);
+        out.write((value >> 24) & 0xFF);
+    }
+
+    private void writeShortLE(int value) throws IOException {
+        out.write(value & 0xFF);
+        out.write((value >> 8) & 0xFF);
+    }
+}
@@ -0,0 +1,28 @@
+package org.whispercat.recording.clients;
+
+import java.io.IOException;
+
+/**
+ * Thrown when an audio file is still larger than the provider's upload limit after compression,
+ * so uploading it would only fail on the server side.
+ */
+public class AudioFileTooLargeException extends IOException {
+    private final long fileSize;
+    private final long maxFileSize;
+
+    public AudioFileTooLargeException(long fileSize, long maxFileSize) {
+        super(String.format("Audio is %.1f MB after compression, which exceeds the %.0f MB upload limit. "
+                        + "Split the recording into shorter parts and transcribe them separately.",
+                fileSize / (1024.0 * 1024.0), maxFileSize / (1024.0 * 1024.0)));
+        this.fileSize = fileSize;
+        this.maxFileSize = maxFileSize;
+    }
+
+    public long getFileSize() {
+        return fileSize;
+    }
+
+    public long getMaxFileSize() {
+        return maxFileSize;
+    }
+}
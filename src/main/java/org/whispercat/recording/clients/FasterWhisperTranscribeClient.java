@@ -1,21 +1,13 @@
 package org.whispercat.recording.clients;
 
-import com.fasterxml.jackson.databind.JsonNode;
-import com.fasterxml.jackson.databind.ObjectMapper;
-import org.apache.http.HttpEntity;
-import org.apache.http.client.methods.CloseableHttpResponse;
-import org.apache.http.client.methods.HttpPost;
-import org.apache.http.entity.ContentType;
-import org.apache.http.entity.mime.MultipartEntityBuilder;
-import org.apache.http.impl.client.CloseableHttpClient;
-import org.apache.http.impl.client.HttpClients;
 import org.apache.logging.log4j.LogManager;
 import org.apache.logging.log4j.Logger;
+import org.whispercat.CancellationToken;
 import org.whispercat.ConfigManager;
+import org.whispercat.recording.TranscriptionOutcome;
 
 import java.io.File;
 import java.io.IOException;
-import java.nio.charset.StandardCharsets;
 
 /**
  * FasterWhisperClient communicates with the Faster-Whisper transcription API endpoint.
@@ -23,6 +15,8 @@ import java.nio.charset.StandardCharsets;
  */
 public class FasterWhisperTranscribeClient {
     private static final Logger logger = LogManager.getLogger(FasterWhisperTranscribeClient.class);
+    // Used when no model is configured (first entry of the bundled model list)
+    public static final String DEFAULT_MODEL = "Systran/faster-whisper-base";
     private final ConfigManager configManager;
 
     public FasterWhisperTranscribeClient(ConfigManager configManager) {
@@ -33,54 +27,73 @@ public class FasterWhisperTranscribeClient {
      * Transcribes the given audio file using the transcription API.
      *
      * @param audioFile the audio file to be transcribed.
-     * @return the transcription as returned by the API.
+     * @return the transcription with the segments the server sent.
      * @throws IOException if an error occurs during the API request.
      */
-    public String transcribe(File audioFile) throws IOException {
-        try (CloseableHttpClient httpClient = HttpClients.createDefault()) {
-            // Build URL from ConfigManager
-            String baseUrl = configManager.getFasterWhisperServerUrl().trim();
-
-            if (!baseUrl.toLowerCase().startsWith("http://") && !baseUrl.toLowerCase().startsWith("https://")) {
-                baseUrl = "http://" + baseUrl;
-            }
+    public TranscriptionOutcome transcribe(File audioFile) throws IOException {
+        return transcribe(audioFile, null);
+    }
 
-            if (baseUrl.endsWith("/")) {
-                baseUrl = baseUrl.substring(0, baseUrl.length() - 1);
-            }
+    /**
+     * Transcribes the given audio file, passing a prompt that gives the model context
+     * (e.g. the end of the previous transcription).
+     *
+     * @param audioFile the audio file to be transcribed.
+     * @param prompt    text to send as the prompt, or null for none.
+     * @return the transcription with the segments the server sent.
+     * @throws IOException if an error occurs during the API request.
+     */
+    public TranscriptionOutcome transcribe(File audioFile, String prompt) throws IOException {
+        return transcribe(audioFile, prompt, new CancellationToken());
+    }
 
-            String url = baseUrl + "/v1/audio/transcriptions";
-            HttpPost httpPost = new HttpPost(url);
-            httpPost.setHeader("Accept", "application/json");
+    /**
+     * Transcribes the given audio file with a prompt, until cancelled.
+     *
+     * @param audioFile    the audio file to be transcribed.
+     * @param prompt       text to send as the prompt, or null for none.
+     * @param cancellation aborts the request.
+     * @return the transcription with the segments the server sent.
+     * @throws IOException if an error occurs during the API request or it was aborted.
+     */
+    public TranscriptionOutcome transcribe(File audioFile, String prompt, CancellationToken cancellation) throws IOException {
+        TranscriptionRequest request = TranscriptionProviders.get(TranscriptionProviders.FASTER_WHISPER)
+                .buildRequest(configManager, audioFile, prompt);
+        logger.info("Transcribing audio file {} with model {} and language {}", audioFile.getName(),
+                request.getFields().get("model"), configManager.resolveFasterWhisperLanguage());
+        return parseResponse(request.execute(cancellation), configManager.getFasterWhisperModel(),
+                configManager.resolveFasterWhisperLanguage());
+    }
 
-            // Build multipart/form-data entity with the file and parameters.
-            MultipartEntityBuilder builder = MultipartEntityBuilder.create();
-            builder.addBinaryBody("file", audioFile, ContentType.create("audio/wav"), audioFile.getName());
-            builder.addTextBody("model", configManager.getFasterWhisperModel());
-            if (!configManager.getFasterWhisperLanguage().isEmpty()) {
-                builder.addTextBody("language", configManager.getFasterWhisperLanguage());
-            }
-            HttpEntity multipart = builder.build();
-            httpPost.setEntity(multipart);
-            logger.info("Transcribing audio file {} with model {} and language {}", audioFile.getName(), configManager.getFasterWhisperModel(), configManager.getFasterWhisperLanguage());
+    /**
+     * Parses a response into an outcome. The API may return a plain string or an object
+     * with a "text" field, and with verbose_json also the segments.
+     *
+     * @param responseBody the response body
+     * @param model        the configured model
+     * @param language     the configured language, empty for auto-detection
+     * @return the outcome; timings and confidence are left for the caller
+     * @throws IOException if the response cannot be parsed
+     */
+    static TranscriptionOutcome parseResponse(String responseBody, String model, String language) throws IOException {
+        TranscriptionOutcome outcome = new TranscriptionOutcome(TranscriptionProviders.parseText(responseBody),
+                TranscriptionProviders.FASTER_WHISPER, model);
+        outcome.setSegments(TranscriptionProviders.parseSegments(responseBody));
+        outcome.setLanguage(language);
+        return outcome;
+    }
 
-            try (CloseableHttpResponse response = httpClient.execute(httpPost)) {
-                int statusCode = response.getStatusLine().getStatusCode();
-                String responseString = new String(response.getEntity().getContent().readAllBytes(), StandardCharsets.UTF_8);
-                if (statusCode != 200) {
-                    logger.error("Error from transcription API. Status: {} Response: {}", statusCode, responseString);
-                    throw new IOException("Error from transcription API: " + responseString);
-                }
-                ObjectMapper objectMapper = new ObjectMapper();
-                JsonNode jsonNode = objectMapper.readTree(responseString);
-                // The API may return a plain string or an object with a "text" field.
-                if (jsonNode.isTextual()) {
-                    return jsonNode.asText();
-                } else {
-                    return jsonNode.path("text").asText();
-                }
-            }
+    /**
+     * Returns the configured model, or the default model if none is set.
+     *
+     * @param configuredModel the model from the configuration, may be blank.
+     * @return the model to send with the request.
+     */
+    static String resolveModel(String configuredModel) {
+        if (configuredModel == null || configuredModel.trim().isEmpty()) {
+            logger.warn("No Faster-Whisper model configured, using default: {}", DEFAULT_MODEL);
+            return DEFAULT_MODEL;
         }
+        return configuredModel.trim();
     }
-
-}
\ No newline at end of file
+}
@@ -0,0 +1,37 @@
+package org.whispercat.recording.clients;
+
+/**
+ * A Faster-Whisper model suggested for transcribing a specific language.
+ */
+public class LanguageModelRecommendation {
+    private String language;
+    private String model;
+    private String note;
+
+    public LanguageModelRecommendation() {
+    }
+
+    public String getLanguage() {
+        return language;
+    }
+
+    public void setLanguage(String language) {
+        this.language = language;
+    }
+
+    public String getModel() {
+        return model;
+    }
+
+    public void setModel(String model) {
+        this.model = model;
+    }
+
+    public String getNote() {
+        return note;
+    }
+
+    public void setNote(String note) {
+        this.note = note;
+    }
+}
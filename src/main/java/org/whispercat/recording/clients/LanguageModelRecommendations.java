@@ -0,0 +1,78 @@
+package org.whispercat.recording.clients;
+
+import com.fasterxml.jackson.databind.ObjectMapper;
+
+import java.io.IOException;
+import java.io.InputStream;
+import java.util.Collection;
+import java.util.Collections;
+import java.util.HashMap;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * Language-to-model suggestions loaded from languagemodelrecommendations.json.
+ * To add a suggestion, add an entry to the resource file; no code changes are needed.
+ */
+public class LanguageModelRecommendations {
+    private static final String RESOURCE = "/languagemodelrecommendations.json";
+
+    private List<LanguageModelRecommendation> recommendations;
+
+    public List<LanguageModelRecommendation> getRecommendations() {
+        return recommendations;
+    }
+
+    public void setRecommendations(List<LanguageModelRecommendation> recommendations) {
+        this.recommendations = recommendations;
+    }
+
+    /**
+     * Loads the bundled recommendations, keyed by language code.
+     *
+     * @return the recommendations per language, empty if the resource is missing
+     * @throws IOException if the resource cannot be parsed
+     */
+    public static Map<String, LanguageModelRecommendation> load() throws IOException {
+        try (InputStream is = LanguageModelRecommendations.class.getResourceAsStream(RESOURCE)) {
+            if (is == null) {
+                return Collections.emptyMap();
+            }
+            LanguageModelRecommendations parsed = new ObjectMapper().readValue(is, LanguageModelRecommendations.class);
+            Map<String, LanguageModelRecommendation> byLanguage = new HashMap<>();
+            if (parsed.getRecommendations() != null) {
+                for (LanguageModelRecommendation recommendation : parsed.getRecommendations()) {
+                    if (recommendation.getLanguage() != null && recommendation.getModel() != null) {
+                        byLanguage.put(recommendation.getLanguage(), recommendation);
+                    }
+                }
+            }
+            return byLanguage;
+        }
+    }
+
+    /**
+     * Looks up the model to suggest for a language. Nothing is suggested for unknown languages,
+     * for models that are not available, or if the suggested model is already selected.
+     *
+     * @param recommendations the recommendations per language, as returned by {@link #load()}
+     * @param language        the selected language code, may be null or empty
+     * @param selectedModel   the currently selected model
+     * @param availableModels the models that can be selected
+     * @return the recommendation, or null if there is nothing to suggest
+     */
+    public static LanguageModelRecommendation recommend(Map<String, LanguageModelRecommendation> recommendations,
+                                                        String language, String selectedModel,
+                                                        Collection<String> availableModels) {
+        if (language == null || language.isEmpty()) {
+            return null;
+        }
+        LanguageModelRecommendation recommendation = recommendations.get(language);
+        if (recommendation == null
+                || !availableModels.contains(recommendation.getModel())
+                || recommendation.getModel().equals(selectedModel)) {
+            return null;
+        }
+        return recommendation;
+    }
+}
@@ -2,30 +2,23 @@ package org.whispercat.recording.clients;
 
 import com.fasterxml.jackson.databind.JsonNode;
 import com.fasterxml.jackson.databind.ObjectMapper;
-import org.apache.http.HttpEntity;
-import org.apache.http.client.config.RequestConfig;
-import org.apache.http.client.methods.CloseableHttpResponse;
-import org.apache.http.client.methods.HttpPost;
-import org.apache.http.entity.ContentType;
-import org.apache.http.entity.mime.MultipartEntityBuilder;
-import org.apache.http.impl.client.CloseableHttpClient;
-import org.apache.http.impl.client.HttpClients;
 import org.apache.logging.log4j.LogManager;
 import org.apache.logging.log4j.Logger;
+import org.whispercat.CancellationToken;
 import org.whispercat.ConfigManager;
+import org.whispercat.recording.TranscriptionOutcome;
 
 import javax.sound.sampled.*;
 import java.io.File;
 import java.io.IOException;
-import java.nio.charset.StandardCharsets;
 import java.nio.file.Files;
+import java.util.ArrayList;
+import java.util.List;
 
 public class OpenAITranscribeClient {
     private static final Logger logger = LogManager.getLogger(OpenAITranscribeClient.class);
-    private static final String API_URL = "https://api.openai.com/v1/audio/transcriptions";
-    private static final long MAX_FILE_SIZE = 24 * 1024 * 1024; // 24 MB (leaving buffer under 25MB limit)
-    private static final int CONNECTION_TIMEOUT = 30000; // 30 seconds
-    private static final int SOCKET_TIMEOUT = 600000; // 10 minutes for large file processing
+    static final long MAX_FILE_SIZE = 24 * 1024 * 1024; // 24 MB (leaving buffer under 25MB limit)
+    private static final String MODEL = "whisper-1";
     private final ConfigManager configManager;
 
     public OpenAITranscribeClient(ConfigManager configManager) {
@@ -41,6 +34,7 @@ public class OpenAITranscribeClient {
      * @return The compressed MP3 file, or null if compression fails
      */
     private File compressAudioToMp3(File originalFile) {
+        File mp3File = null;
         try {
             logger.info("Compressing audio file to MP3: {} (size: {} MB)",
                 originalFile.getName(), originalFile.length() / (1024.0 * 1024.0));
@@ -49,26 +43,12 @@ public class OpenAITranscribeClient {
                 originalFile.getName(), originalFile.length() / (1024.0 * 1024.0)));
 
             // Create temporary MP3 file
-            File mp3File = File.createTempFile("whispercat_compressed_", ".mp3");
+            mp3File = File.createTempFile("whispercat_compressed_", ".mp3",
+                configManager.getIntermediateFileLocation().directoryFor(originalFile));
             mp3File.deleteOnExit();
 
-            // Use ffmpeg to convert to MP3 with good compression
-            // -y = overwrite output file
-            // -i = input file
-            // -codec:a libmp3lame = use LAME MP3 encoder
-            // -q:a 4 = VBR quality (0-9, where 0 is best, 9 is worst; 4 is good for speech ~140kbps)
-            // -ac 1 = mono audio (speech doesn't need stereo, halves file size)
-            // -ar 16000 = 16kHz sample rate (good for speech recognition)
-            ProcessBuilder pb = new ProcessBuilder(
-                "ffmpeg",
-                "-y",
-                "-i", originalFile.getAbsolutePath(),
-                "-codec:a", "libmp3lame",
-                "-q:a", "4",
-                "-ac", "1",
-                "-ar", "16000",
-                mp3File.getAbsolutePath()
-            );
+            ProcessBuilder pb = new ProcessBuilder(buildMp3Command(originalFile, mp3File,
+                configManager.getMp3VbrQuality(), configManager.getMp3SampleRate(), configManager.isMp3Mono()));
 
             pb.redirectErrorStream(true);
             Process process = pb.start();
@@ -96,14 +76,46 @@ public class OpenAITranscribeClient {
                 return mp3File;
             } else {
                 logger.error("ffmpeg conversion failed with exit code: {}. Output: {}", exitCode, output);
+                TempFile.owned(mp3File).close();
                 return null;
             }
         } catch (Exception e) {
             logger.error("Failed to compress audio file to MP3", e);
+            TempFile.owned(mp3File).close();
             return null;
         }
     }
 
+    /**
+     * Builds the ffmpeg command line for compressing a recording to MP3.
+     *
+     * @param input      the recording
+     * @param output     the MP3 file to write
+     * @param vbrQuality LAME VBR quality, 0 (best) to 9 (smallest); 4 is good for speech (~140kbps)
+     * @param sampleRate output sample rate; 16 kHz is enough for speech recognition
+     * @param mono       whether to mix down to one channel, which halves the file size
+     * @return the command and its arguments
+     */
+    static List<String> buildMp3Command(File input, File output, int vbrQuality, int sampleRate, boolean mono) {
+        List<String> command = new ArrayList<>();
+        command.add("ffmpeg");
+        command.add("-y"); // overwrite output file
+        command.add("-i");
+        command.add(input.getAbsolutePath());
+        command.add("-codec:a");
+        command.add("libmp3lame");
+        command.add("-q:a");
+        command.add(String.valueOf(vbrQuality));
+        if (mono) {
+            command.add("-ac");
+            command.add("1");
+        }
+        command.add("-ar");
+        command.add(String.valueOf(sampleRate));
+        command.add(output.getAbsolutePath());
+        return command;
+    }
+
     /**
      * Legacy compression method using downsampling.
      * Kept as fallback if ffmpeg is not available.
@@ -136,7 +148,8 @@ public class OpenAITranscribeClient {
             AudioInputStream convertedStream = AudioSystem.getAudioInputStream(targetFormat, originalStream);
 
             // Create a temporary file for the compressed audio
-            File compressedFile = File.createTempFile("whispercat_compressed_", ".wav");
+            File compressedFile = File.createTempFile("whispercat_compressed_", ".wav",
+                configManager.getIntermediateFileLocation().directoryFor(originalFile));
             compressedFile.deleteOnExit();
 
             // Write the converted audio to the temporary file
@@ -161,108 +174,135 @@ public class OpenAITranscribeClient {
      * First tries MP3 compression via ffmpeg (10x+ compression).
      * Falls back to downsampling if ffmpeg is not available.
      *
+     * The compressed file is returned in a guard that deletes it once the upload is done;
+     * if the original is returned, the guard leaves it in place.
+     *
      * @param originalFile The original audio file
      * @return The compressed audio file, or the original if compression fails
+     * @throws AudioFileTooLargeException if the compressed file still exceeds the upload limit
      */
-    private File compressAudioFile(File originalFile) {
+    private TempFile compressAudioFile(File originalFile) throws AudioFileTooLargeException {
         // Try MP3 compression first (much better compression)
-        File mp3File = compressAudioToMp3(originalFile);
-        if (mp3File != null && mp3File.length() < originalFile.length()) {
-            // Check if MP3 is still too large
-            if (mp3File.length() > MAX_FILE_SIZE) {
-                logger.warn("MP3 file still exceeds size limit ({} MB). File may be too long for OpenAI.",
-                    mp3File.length() / (1024.0 * 1024.0));
-            }
-            return mp3File;
+        TempFile mp3 = TempFile.owned(compressAudioToMp3(originalFile));
+        if (mp3.getFile() != null && mp3.getFile().length() < originalFile.length()) {
+            return verifyUploadSize(mp3);
         }
+        mp3.close();
 
         // Fall back to downsampling if ffmpeg failed or is not available
         logger.warn("MP3 compression failed or not available. Falling back to downsampling.");
-        return compressAudioFileByDownsampling(originalFile);
+        File downsampled = compressAudioFileByDownsampling(originalFile);
+        return verifyUploadSize(downsampled.equals(originalFile)
+                ? TempFile.borrowed(originalFile) : TempFile.owned(downsampled));
     }
 
-    public String transcribe(File audioFile) throws IOException {
-        // Check if file size exceeds limit and compress if necessary
-        File fileToTranscribe = audioFile;
-        if (audioFile.length() > MAX_FILE_SIZE) {
-            logger.warn("Audio file size ({} MB) exceeds OpenAI limit (25 MB). Compressing...",
-                audioFile.length() / (1024.0 * 1024.0));
-            fileToTranscribe = compressAudioFile(audioFile);
+    /**
+     * Checks the upload size, deleting the compressed file before the error propagates.
+     */
+    static TempFile verifyUploadSize(TempFile upload) throws AudioFileTooLargeException {
+        try {
+            checkUploadSize(upload.getFile());
+            return upload;
+        } catch (AudioFileTooLargeException e) {
+            upload.close();
+            throw e;
         }
+    }
 
-        // Configure timeouts to prevent indefinite hanging
-        RequestConfig requestConfig = RequestConfig.custom()
-            .setConnectTimeout(CONNECTION_TIMEOUT)
-            .setSocketTimeout(SOCKET_TIMEOUT)
-            .setConnectionRequestTimeout(CONNECTION_TIMEOUT)
-            .build();
-
-        try (CloseableHttpClient httpClient = HttpClients.custom()
-                .setDefaultRequestConfig(requestConfig)
-                .build()) {
-            HttpPost httpPost = new HttpPost(API_URL);
-            httpPost.setHeader("Authorization", "Bearer " + configManager.getApiKey());
+    /**
+     * Rejects files that are still over the upload limit after compression.
+     *
+     * @param file the file that would be uploaded
+     * @throws AudioFileTooLargeException if the file exceeds {@link #MAX_FILE_SIZE}
+     */
+    static void checkUploadSize(File file) throws AudioFileTooLargeException {
+        if (file.length() > MAX_FILE_SIZE) {
+            logger.error("Compressed file still exceeds size limit ({} MB), not uploading",
+                file.length() / (1024.0 * 1024.0));
+            throw new AudioFileTooLargeException(file.length(), MAX_FILE_SIZE);
+        }
+    }
 
-            // Determine content type based on file extension
-            String fileName = fileToTranscribe.getName().toLowerCase();
-            String contentType = "audio/wav"; // default
-            if (fileName.endsWith(".mp3")) {
-                contentType = "audio/mpeg";
-            } else if (fileName.endsWith(".m4a")) {
-                contentType = "audio/mp4";
-            } else if (fileName.endsWith(".ogg")) {
-                contentType = "audio/ogg";
-            } else if (fileName.endsWith(".flac")) {
-                contentType = "audio/flac";
+    /**
+     * Parses a successful verbose_json response into an outcome with the text,
+     * the detected language and the segments.
+     *
+     * @param responseString the response body
+     * @return the outcome; timings and confidence are left for the caller
+     * @throws IOException if the response cannot be parsed or contains no text
+     */
+    static TranscriptionOutcome parseResponse(String responseString) throws IOException {
+        try {
+            ObjectMapper objectMapper = new ObjectMapper();
+            JsonNode jsonNode = objectMapper.readTree(responseString);
+            String transcription = jsonNode.path("text").asText();
+            if (transcription == null || transcription.isEmpty()) {
+                logger.warn("OpenAI returned empty transcription");
+                throw new IOException("OpenAI returned empty transcription");
             }
+            TranscriptionOutcome outcome = new TranscriptionOutcome(transcription, "OpenAI", MODEL);
+            outcome.setSegments(TranscriptionProviders.parseSegments(responseString));
+            outcome.setLanguage(jsonNode.path("language").asText(""));
+            return outcome;
+        } catch (Exception jsonException) {
+            logger.error("Failed to parse successful response as JSON. Response: {}", responseString, jsonException);
+            throw new IOException("Failed to parse OpenAI response: " + jsonException.getMessage());
+        }
+    }
 
-            MultipartEntityBuilder builder = MultipartEntityBuilder.create();
-            builder.addBinaryBody("file", fileToTranscribe, ContentType.create(contentType), fileToTranscribe.getName());
-            builder.addTextBody("model", "whisper-1");
-
-            HttpEntity multipart = builder.build();
-            httpPost.setEntity(multipart);
+    /**
+     * Transcribes the audio file, compressing it first if it exceeds the upload limit.
+     *
+     * @param audioFile the audio file to transcribe
+     * @return the text together with the detected language and segments
+     * @throws IOException if the request fails
+     */
+    public TranscriptionOutcome transcribe(File audioFile) throws IOException {
+        return transcribe(audioFile, null);
+    }
 
-            try (CloseableHttpResponse response = httpClient.execute(httpPost)) {
-                int statusCode = response.getStatusLine().getStatusCode();
-                HttpEntity responseEntity = response.getEntity();
-                String responseString = new String(responseEntity.getContent().readAllBytes(), StandardCharsets.UTF_8);
+    /**
+     * Transcribes the audio file, passing a prompt that gives Whisper context
+     * (e.g. the end of the previous transcription).
+     *
+     * @param audioFile the audio file to transcribe
+     * @param prompt    text to send as the prompt, or null for none
+     * @return the text together with the detected language and segments
+     * @throws IOException if the request fails
+     */
+    public TranscriptionOutcome transcribe(File audioFile, String prompt) throws IOException {
+        return transcribe(audioFile, prompt, new CancellationToken());
+    }
 
-                if (statusCode != 200) {
-                    logger.error("OpenAI API returned status code: {}. Response: {}", statusCode, responseString);
+    /**
+     * Transcribes the audio file with a prompt, until cancelled.
+     *
+     * @param audioFile    the audio file to transcribe
+     * @param prompt       text to send as the prompt, or null for none
+     * @param cancellation aborts the request
+     * @return the text together with the detected language and segments
+     * @throws IOException if the request fails or was aborted
+     */
+    public TranscriptionOutcome transcribe(File audioFile, String prompt, CancellationToken cancellation) throws IOException {
+        // Check if file size exceeds limit and compress if necessary
+        TempFile upload = TempFile.borrowed(audioFile);
+        if (audioFile.length() > MAX_FILE_SIZE) {
+            logger.warn("Audio file size ({} MB) exceeds OpenAI limit (25 MB). Compressing...",
+                audioFile.length() / (1024.0 * 1024.0));
+            upload = compressAudioFile(audioFile);
+        }
+        File fileToTranscribe = upload.getFile();
 
-                    // Try to parse as JSON to get error message
-                    try {
-                        ObjectMapper objectMapper = new ObjectMapper();
-                        JsonNode jsonNode = objectMapper.readTree(responseString);
-                        String errorMessage = jsonNode.path("error").path("message").asText("Unknown error");
-                        throw new IOException("Error from OpenAI API (HTTP " + statusCode + "): " + errorMessage);
-                    } catch (Exception jsonException) {
-                        // Response is not valid JSON, use raw response
-                        logger.error("Failed to parse error response as JSON", jsonException);
-                        // Truncate very long responses
-                        String truncatedResponse = responseString.length() > 500
-                            ? responseString.substring(0, 500) + "..."
-                            : responseString;
-                        throw new IOException("Error from OpenAI API (HTTP " + statusCode + "): " + truncatedResponse);
-                    }
-                }
+        TranscriptionRequest request = TranscriptionProviders.get(TranscriptionProviders.OPENAI)
+                .buildRequest(configManager, fileToTranscribe, prompt);
 
-                // Parse successful response
-                try {
-                    ObjectMapper objectMapper = new ObjectMapper();
-                    JsonNode jsonNode = objectMapper.readTree(responseString);
-                    String transcription = jsonNode.path("text").asText();
-                    if (transcription == null || transcription.isEmpty()) {
-                        logger.warn("OpenAI returned empty transcription");
-                        throw new IOException("OpenAI returned empty transcription");
-                    }
-                    return transcription;
-                } catch (Exception jsonException) {
-                    logger.error("Failed to parse successful response as JSON. Response: {}", responseString, jsonException);
-                    throw new IOException("Failed to parse OpenAI response: " + jsonException.getMessage());
-                }
-            }
+        // The compressed upload (if any) is deleted however the request ends
+        String responseString;
+        try (TempFile uploadGuard = upload) {
+            responseString = request.execute(cancellation);
         }
+
+        // Parse successful response
+        return parseResponse(responseString);
     }
-}
\ No newline at end of file
+}
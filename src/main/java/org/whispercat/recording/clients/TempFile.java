@@ -0,0 +1,67 @@
+package org.whispercat.recording.clients;
+
+import org.apache.logging.log4j.LogManager;
+import org.apache.logging.log4j.Logger;
+
+import java.io.File;
+import java.io.IOException;
+import java.nio.file.Files;
+
+/**
+ * Holds a file that is only needed for the duration of one operation, such as a
+ * compressed upload. Used with try-with-resources, the file is deleted on every exit
+ * path, including exceptions. A guard can also wrap a file it does not own (e.g. the
+ * original recording when no compression was needed); such files are never deleted.
+ */
+public class TempFile implements AutoCloseable {
+    private static final Logger logger = LogManager.getLogger(TempFile.class);
+
+    private final File file;
+    private final boolean owned;
+
+    private TempFile(File file, boolean owned) {
+        this.file = file;
+        this.owned = owned;
+    }
+
+    /**
+     * Wraps a temporary file that is deleted when the guard is closed.
+     *
+     * @param file the temporary file, may be null
+     * @return the guard
+     */
+    public static TempFile owned(File file) {
+        return new TempFile(file, true);
+    }
+
+    /**
+     * Wraps a file that must survive the guard, e.g. the original recording.
+     *
+     * @param file the file
+     * @return the guard
+     */
+    public static TempFile borrowed(File file) {
+        return new TempFile(file, false);
+    }
+
+    public File getFile() {
+        return file;
+    }
+
+    /**
+     * Deletes the file if it is owned by this guard. Failures are only logged.
+     */
+    @Override
+    public void close() {
+        if (!owned || file == null) {
+            return;
+        }
+        try {
+            if (Files.deleteIfExists(file.toPath())) {
+                logger.debug("Deleted temporary file {}", file.getAbsolutePath());
+            }
+        } catch (IOException e) {
+            logger.warn("Failed to delete temporary file {}", file.getAbsolutePath(), e);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+package org.whispercat.recording.clients;
+
+import java.util.List;
+
+/**
+ * Computes an overall confidence heuristic from transcription segments, used to flag
+ * results that are likely hallucinated (e.g. text produced from silence or noise).
+ */
+public class TranscriptionConfidence {
+
+    // Results below this confidence are flagged for the user to verify
+    public static final double LOW_CONFIDENCE_THRESHOLD = 0.4;
+    // Returned when no segment information is available
+    public static final double UNKNOWN = -1.0;
+
+    private TranscriptionConfidence() {
+    }
+
+    /**
+     * Computes the duration-weighted average confidence of all segments.
+     *
+     * @param segments the transcription segments, may be null or empty
+     * @return confidence between 0 and 1, or {@link #UNKNOWN} if there are no segments
+     */
+    public static double compute(List<TranscriptionSegment> segments) {
+        if (segments == null || segments.isEmpty()) {
+            return UNKNOWN;
+        }
+        double weightedSum = 0.0;
+        double totalWeight = 0.0;
+        for (TranscriptionSegment segment : segments) {
+            // Zero-length segments still count, so the average is defined for them
+            double weight = Math.max(segment.getEnd() - segment.getStart(), 0.01);
+            weightedSum += segment.getConfidence() * weight;
+            totalWeight += weight;
+        }
+        return weightedSum / totalWeight;
+    }
+
+    /**
+     * @param confidence a value returned by {@link #compute(List)}
+     * @return true if the confidence is known and below the warning threshold
+     */
+    public static boolean isLow(double confidence) {
+        return confidence != UNKNOWN && confidence < LOW_CONFIDENCE_THRESHOLD;
+    }
+
+    /**
+     * Decides whether a transcription is confident enough to be auto-pasted.
+     * Results without confidence information are always pasted.
+     *
+     * @param confidence    a value returned by {@link #compute(List)}
+     * @param minConfidence the configured threshold; 0 disables the gate
+     * @return true if the result may be pasted without review
+     */
+    public static boolean allowsAutoPaste(double confidence, double minConfidence) {
+        return confidence == UNKNOWN || minConfidence <= 0.0 || confidence >= minConfidence;
+    }
+}
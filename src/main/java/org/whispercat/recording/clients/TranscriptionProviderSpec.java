@@ -0,0 +1,220 @@
+package org.whispercat.recording.clients;
+
+import org.apache.http.conn.ssl.SSLConnectionSocketFactory;
+import org.whispercat.ConfigManager;
+
+import java.io.File;
+import java.io.IOException;
+import java.util.LinkedHashMap;
+import java.util.Map;
+import java.util.function.Function;
+
+/**
+ * Describes how to talk to a transcription provider: where its endpoint is, how it
+ * authenticates and which form fields it expects. Providers are registered in
+ * {@link TranscriptionProviders}; adding an OpenAI-compatible provider only takes a new spec.
+ */
+public class TranscriptionProviderSpec {
+
+    public enum AuthStyle {
+        NONE,
+        BEARER
+    }
+
+    /**
+     * Builds the text form fields sent next to the audio file.
+     */
+    public interface FormFields {
+        /**
+         * @param configManager the configuration
+         * @param prompt        context prompt, or null
+         * @return the fields in the order they should be sent
+         */
+        Map<String, String> build(ConfigManager configManager, String prompt);
+    }
+
+    /**
+     * Creates the TLS socket factory for the provider's connections.
+     */
+    public interface TlsSettings {
+        /**
+         * @param configManager the configuration
+         * @return the socket factory, or null for the default certificate verification
+         * @throws IOException if the TLS settings cannot be applied
+         */
+        SSLConnectionSocketFactory socketFactory(ConfigManager configManager) throws IOException;
+    }
+
+    private final String name;
+    private final Function<ConfigManager, String> baseUrl;
+    private final String defaultScheme;
+    private final Function<ConfigManager, String> path;
+    private final String translationPath;
+    private final AuthStyle authStyle;
+    private final Function<ConfigManager, String> apiKey;
+    private final FormFields formFields;
+    private final TlsSettings tlsSettings;
+    private final int connectTimeoutMs;
+
+    private TranscriptionProviderSpec(Builder builder) {
+        this.name = builder.name;
+        this.baseUrl = builder.baseUrl;
+        this.defaultScheme = builder.defaultScheme;
+        this.path = builder.path;
+        this.translationPath = builder.translationPath;
+        this.authStyle = builder.authStyle;
+        this.apiKey = builder.apiKey;
+        this.formFields = builder.formFields;
+        this.tlsSettings = builder.tlsSettings;
+        this.connectTimeoutMs = builder.connectTimeoutMs;
+    }
+
+    public static Builder builder(String name) {
+        return new Builder(name);
+    }
+
+    public String getName() {
+        return name;
+    }
+
+    public AuthStyle getAuthStyle() {
+        return authStyle;
+    }
+
+    /**
+     * @return whether the provider can translate speech to English
+     */
+    public boolean supportsTranslation() {
+        return translationPath != null;
+    }
+
+    public int getConnectTimeoutMs() {
+        return connectTimeoutMs;
+    }
+
+    /**
+     * Resolves the configured base URL, adding the default scheme if none is given and
+     * removing trailing slashes.
+     *
+     * @param configManager the configuration
+     * @return the base URL, or an empty string if none is configured
+     */
+    public String resolveBaseUrl(ConfigManager configManager) {
+        String url = baseUrl.apply(configManager);
+        url = url != null ? url.trim() : "";
+        if (url.isEmpty()) {
+            return "";
+        }
+        String lower = url.toLowerCase();
+        if (!lower.startsWith("http://") && !lower.startsWith("https://")) {
+            url = defaultScheme + "://" + url;
+        }
+        while (url.endsWith("/")) {
+            url = url.substring(0, url.length() - 1);
+        }
+        return url;
+    }
+
+    /**
+     * Builds the request for transcribing a file with this provider, or for translating it
+     * to English if that is enabled and the provider supports it.
+     *
+     * @param configManager the configuration
+     * @param audioFile     the file to upload
+     * @param prompt        context prompt, or null
+     * @return the resolved request
+     * @throws IOException if the TLS settings cannot be applied
+     */
+    public TranscriptionRequest buildRequest(ConfigManager configManager, File audioFile, String prompt) throws IOException {
+        Map<String, String> headers = new LinkedHashMap<>();
+        headers.put("Accept", "application/json");
+        if (authStyle == AuthStyle.BEARER) {
+            String key = apiKey.apply(configManager);
+            headers.put("Authorization", "Bearer " + (key != null ? key : ""));
+        }
+        Map<String, String> fields = new LinkedHashMap<>(formFields.build(configManager, prompt));
+        // Extra fields from the settings never replace the ones the response parsing relies on
+        configManager.getExtraFormFields(name).forEach(fields::putIfAbsent);
+        String requestPath = path.apply(configManager);
+        if (configManager.isTranslateToEnglish() && supportsTranslation()) {
+            requestPath = translationPath;
+            // The output language is always English, the endpoint does not take one
+            fields.remove("language");
+        }
+        return new TranscriptionRequest(this, resolveBaseUrl(configManager) + requestPath, headers, fields, audioFile,
+                tlsSettings.socketFactory(configManager), configManager.getTranscriptionTimeoutSeconds());
+    }
+
+    public static class Builder {
+        private final String name;
+        private Function<ConfigManager, String> baseUrl = c -> "";
+        private String defaultScheme = "http";
+        private Function<ConfigManager, String> path = c -> "/v1/audio/transcriptions";
+        private String translationPath = null;
+        private AuthStyle authStyle = AuthStyle.NONE;
+        private Function<ConfigManager, String> apiKey = c -> "";
+        private FormFields formFields = (c, prompt) -> new LinkedHashMap<>();
+        private TlsSettings tlsSettings = c -> null;
+        private int connectTimeoutMs = 0;
+
+        private Builder(String name) {
+            this.name = name;
+        }
+
+        public Builder baseUrl(Function<ConfigManager, String> baseUrl, String defaultScheme) {
+            this.baseUrl = baseUrl;
+            this.defaultScheme = defaultScheme;
+            return this;
+        }
+
+        public Builder path(String path) {
+            this.path = c -> path;
+            return this;
+        }
+
+        /**
+         * Sets a path that depends on the server, e.g. on its detected version.
+         */
+        public Builder path(Function<ConfigManager, String> path) {
+            this.path = path;
+            return this;
+        }
+
+        /**
+         * Sets the endpoint that translates speech to English, for providers that have one.
+         */
+        public Builder translationPath(String translationPath) {
+            this.translationPath = translationPath;
+            return this;
+        }
+
+        public Builder bearerAuth(Function<ConfigManager, String> apiKey) {
+            this.authStyle = AuthStyle.BEARER;
+            this.apiKey = apiKey;
+            return this;
+        }
+
+        public Builder formFields(FormFields formFields) {
+            this.formFields = formFields;
+            return this;
+        }
+
+        public Builder tls(TlsSettings tlsSettings) {
+            this.tlsSettings = tlsSettings;
+            return this;
+        }
+
+        /**
+         * Sets how long to wait for the connection. How long to wait for the answer is the
+         * request timeout from the settings.
+         */
+        public Builder connectTimeout(int connectTimeoutMs) {
+            this.connectTimeoutMs = connectTimeoutMs;
+            return this;
+        }
+
+        public TranscriptionProviderSpec build() {
+            return new TranscriptionProviderSpec(this);
+        }
+    }
+}
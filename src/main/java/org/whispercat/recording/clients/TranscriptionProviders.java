@@ -0,0 +1,174 @@
+package org.whispercat.recording.clients;
+
+import com.fasterxml.jackson.core.type.TypeReference;
+import com.fasterxml.jackson.databind.JsonNode;
+import com.fasterxml.jackson.databind.ObjectMapper;
+import org.apache.logging.log4j.LogManager;
+import org.apache.logging.log4j.Logger;
+import org.whispercat.ConfigManager;
+import org.whispercat.OpenWebUIPaths;
+import org.whispercat.OpenWebUITls;
+import org.whispercat.recording.OpenAIClient;
+
+import java.io.IOException;
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Locale;
+import java.util.Map;
+
+/**
+ * Registry of the transcription providers WhisperCat can talk to, keyed by the name shown
+ * in the settings ("OpenAI", "Faster-Whisper", "Open WebUI", "whisper.cpp").
+ */
+public class TranscriptionProviders {
+
+    public static final String OPENAI = "OpenAI";
+    public static final String FASTER_WHISPER = "Faster-Whisper";
+    public static final String OPEN_WEBUI = "Open WebUI";
+    public static final String WHISPER_CPP = "whisper.cpp";
+
+    private static final Map<String, TranscriptionProviderSpec> SPECS = new LinkedHashMap<>();
+    private static final ObjectMapper MAPPER = new ObjectMapper();
+    private static final Logger logger = LogManager.getLogger(TranscriptionProviders.class);
+
+    static {
+        register(TranscriptionProviderSpec.builder(OPENAI)
+                // The base URL includes the version path, like the chat base URL
+                .baseUrl(c -> OpenAIClient.resolveBaseUrl(c.getOpenAIBaseUrl()), "https")
+                .path("/audio/transcriptions")
+                .translationPath("/audio/translations")
+                .bearerAuth(c -> c.getApiKey() != null ? c.getApiKey().toString() : "")
+                .formFields((c, prompt) -> {
+                    Map<String, String> fields = new LinkedHashMap<>();
+                    fields.put("model", "whisper-1");
+                    // verbose_json includes per-segment confidence data
+                    fields.put("response_format", "verbose_json");
+                    putIfPresent(fields, "language", c.getTranscriptionLanguage());
+                    putIfPresent(fields, "prompt", prompt);
+                    putTemperature(fields, c);
+                    return fields;
+                })
+                .connectTimeout(30000)
+                .build());
+        register(TranscriptionProviderSpec.builder(FASTER_WHISPER)
+                .baseUrl(c -> c.getFasterWhisperServerUrl(), "http")
+                .formFields((c, prompt) -> {
+                    Map<String, String> fields = new LinkedHashMap<>();
+                    fields.put("model", FasterWhisperTranscribeClient.resolveModel(c.getFasterWhisperModel()));
+                    // Adds segment timestamps; servers without it still answer with the text
+                    fields.put("response_format", "verbose_json");
+                    putIfPresent(fields, "language", c.resolveFasterWhisperLanguage());
+                    putIfPresent(fields, "prompt", prompt);
+                    putTemperature(fields, c);
+                    return fields;
+                })
+                .build());
+        register(TranscriptionProviderSpec.builder(OPEN_WEBUI)
+                .baseUrl(c -> c.getOpenWebUIServerUrl(), "https")
+                .path(c -> OpenWebUIPaths.detect(c).getTranscriptionPath())
+                .bearerAuth(c -> c.getOpenWebUIApiKey())
+                .tls(OpenWebUITls::socketFactory)
+                .build());
+        register(TranscriptionProviderSpec.builder(WHISPER_CPP)
+                .baseUrl(c -> c.getWhisperCppServerUrl(), "http")
+                .path("/inference")
+                .formFields((c, prompt) -> {
+                    Map<String, String> fields = new LinkedHashMap<>();
+                    fields.put("response_format", "json");
+                    putIfPresent(fields, "language", c.getTranscriptionLanguage());
+                    putIfPresent(fields, "prompt", prompt);
+                    putTemperature(fields, c);
+                    return fields;
+                })
+                .build());
+    }
+
+    private TranscriptionProviders() {
+    }
+
+    /**
+     * Adds or replaces a provider.
+     *
+     * @param spec the provider description
+     */
+    public static synchronized void register(TranscriptionProviderSpec spec) {
+        SPECS.put(spec.getName(), spec);
+    }
+
+    /**
+     * @param name the provider name
+     * @return the provider, or null if none is registered under this name
+     */
+    public static synchronized TranscriptionProviderSpec get(String name) {
+        return SPECS.get(name);
+    }
+
+    /**
+     * @return all registered providers in registration order
+     */
+    public static synchronized List<TranscriptionProviderSpec> all() {
+        return new ArrayList<>(SPECS.values());
+    }
+
+    /**
+     * Reads the transcription from a response that is either a JSON string or an object
+     * with a "text" field, as returned by OpenAI-compatible servers. Bodies that are not
+     * JSON at all (e.g. whisper.cpp with response_format=text) are taken as the text.
+     *
+     * @param responseBody the response body
+     * @return the text, empty if the response contains none
+     * @throws IOException if the body looks like JSON but is not valid
+     */
+    public static String parseText(String responseBody) throws IOException {
+        String body = responseBody != null ? responseBody.trim() : "";
+        if (!body.startsWith("{") && !body.startsWith("[") && !body.startsWith("\"")) {
+            return body;
+        }
+        JsonNode json = MAPPER.readTree(body);
+        if (json.isTextual()) {
+            return json.asText();
+        }
+        return json.path("text").asText("");
+    }
+
+    /**
+     * Reads the "segments" array of a verbose_json response, with the start and end time
+     * of each segment in seconds.
+     *
+     * @param responseBody the response body
+     * @return the segments, or an empty list if the response contains none or is not a JSON object
+     */
+    public static List<TranscriptionSegment> parseSegments(String responseBody) {
+        String body = responseBody != null ? responseBody.trim() : "";
+        if (!body.startsWith("{")) {
+            // Plain text answers (whisper.cpp, response_format=text) carry no timestamps
+            logger.debug("Response is not a JSON object, no segments to parse");
+            return Collections.emptyList();
+        }
+        try {
+            JsonNode segmentsNode = MAPPER.readTree(body).path("segments");
+            if (!segmentsNode.isArray()) {
+                return Collections.emptyList();
+            }
+            return MAPPER.convertValue(segmentsNode, new TypeReference<List<TranscriptionSegment>>() {});
+        } catch (IOException | IllegalArgumentException e) {
+            logger.warn("Failed to parse transcription segments: {}", e.getMessage());
+            return Collections.emptyList();
+        }
+    }
+
+    private static void putIfPresent(Map<String, String> fields, String name, String value) {
+        if (value != null && !value.isEmpty()) {
+            fields.put(name, value);
+        }
+    }
+
+    private static void putTemperature(Map<String, String> fields, ConfigManager c) {
+        float temperature = c.getTranscriptionTemperature();
+        if (temperature > 0.0f) {
+            fields.put("temperature", String.format(Locale.ROOT, "%.1f", temperature));
+        }
+    }
+}
@@ -0,0 +1,179 @@
+package org.whispercat.recording.clients;
+
+import org.apache.http.HttpEntity;
+import org.apache.http.client.config.RequestConfig;
+import org.apache.http.client.methods.CloseableHttpResponse;
+import org.apache.http.client.methods.HttpPost;
+import org.apache.http.conn.ConnectTimeoutException;
+import org.apache.http.conn.ssl.SSLConnectionSocketFactory;
+import org.apache.http.entity.ContentType;
+import org.apache.http.entity.mime.HttpMultipartMode;
+import org.apache.http.entity.mime.MultipartEntityBuilder;
+import org.apache.http.impl.client.CloseableHttpClient;
+import org.apache.http.impl.client.HttpClientBuilder;
+import org.apache.http.impl.client.HttpClients;
+import org.whispercat.CancellationToken;
+import org.whispercat.HttpErrors;
+
+import java.io.File;
+import java.io.IOException;
+import java.net.SocketTimeoutException;
+import java.nio.charset.StandardCharsets;
+import java.util.Collections;
+import java.util.Map;
+
+/**
+ * A fully resolved transcription request, built by {@link TranscriptionProviderSpec#buildRequest}:
+ * the endpoint URL, headers and multipart form fields that will be sent with the audio file.
+ */
+public class TranscriptionRequest {
+    private final TranscriptionProviderSpec spec;
+    private final String url;
+    private final Map<String, String> headers;
+    private final Map<String, String> fields;
+    private final File audioFile;
+    private final SSLConnectionSocketFactory socketFactory;
+    private final int timeoutSeconds;
+
+    TranscriptionRequest(TranscriptionProviderSpec spec, String url, Map<String, String> headers,
+                         Map<String, String> fields, File audioFile, SSLConnectionSocketFactory socketFactory,
+                         int timeoutSeconds) {
+        this.spec = spec;
+        this.url = url;
+        this.headers = Collections.unmodifiableMap(headers);
+        this.fields = Collections.unmodifiableMap(fields);
+        this.audioFile = audioFile;
+        this.socketFactory = socketFactory;
+        this.timeoutSeconds = timeoutSeconds;
+    }
+
+    public String getUrl() {
+        return url;
+    }
+
+    public Map<String, String> getHeaders() {
+        return headers;
+    }
+
+    /**
+     * @return the text form fields in the order they are sent (the file is sent first)
+     */
+    public Map<String, String> getFields() {
+        return fields;
+    }
+
+    public File getAudioFile() {
+        return audioFile;
+    }
+
+    /**
+     * @return the TLS socket factory, or null for the HTTP client's default verification
+     */
+    SSLConnectionSocketFactory getSocketFactory() {
+        return socketFactory;
+    }
+
+    /**
+     * @return how long to wait for the server to answer, 0 for no limit
+     */
+    public int getTimeoutSeconds() {
+        return timeoutSeconds;
+    }
+
+    /**
+     * Builds the HTTP request with the audio file and form fields as multipart/form-data.
+     */
+    public HttpPost toHttpPost() {
+        HttpPost httpPost = new HttpPost(url);
+        headers.forEach(httpPost::setHeader);
+        MultipartEntityBuilder builder = MultipartEntityBuilder.create();
+        builder.setMode(HttpMultipartMode.BROWSER_COMPATIBLE);
+        builder.addBinaryBody("file", audioFile, ContentType.create(contentTypeFor(audioFile)), audioFile.getName());
+        ContentType textType = ContentType.create("text/plain", StandardCharsets.UTF_8);
+        fields.forEach((name, value) -> builder.addTextBody(name, value, textType));
+        HttpEntity multipart = builder.build();
+        httpPost.setEntity(multipart);
+        return httpPost;
+    }
+
+    /**
+     * Sends the request and returns the response body.
+     *
+     * @return the body of a successful response
+     * @throws IOException if the request fails or the server answers with an error status; a
+     *                     {@link SocketTimeoutException} if the server did not answer within the
+     *                     request timeout, a {@link ConnectTimeoutException} if it could not be reached
+     */
+    public String execute() throws IOException {
+        return execute(new CancellationToken());
+    }
+
+    /**
+     * Sends the request and returns the response body. Cancelling the token aborts this
+     * request only.
+     *
+     * @param cancellation aborts the request while it is being sent or waiting for an answer
+     * @return the body of a successful response
+     * @throws IOException if the request fails, is aborted or the server answers with an error
+     *                     status; see {@link #execute()}
+     */
+    public String execute(CancellationToken cancellation) throws IOException {
+        HttpPost httpPost = toHttpPost();
+        Runnable abort = httpPost::abort;
+        cancellation.onCancel(abort);
+        try (CloseableHttpClient httpClient = createHttpClient();
+             CloseableHttpResponse response = httpClient.execute(httpPost)) {
+            int statusCode = response.getStatusLine().getStatusCode();
+            String responseString = response.getEntity() != null
+                    ? new String(response.getEntity().getContent().readAllBytes(), StandardCharsets.UTF_8)
+                    : "";
+            HttpErrors.checkStatus(spec.getName() + " transcription API", statusCode, responseString);
+            return responseString;
+        } catch (ConnectTimeoutException e) {
+            ConnectTimeoutException timeout = new ConnectTimeoutException("Could not connect to " + spec.getName()
+                    + " at " + url + " within " + spec.getConnectTimeoutMs() / 1000 + " s");
+            timeout.initCause(e);
+            throw timeout;
+        } catch (SocketTimeoutException e) {
+            SocketTimeoutException timeout = new SocketTimeoutException(spec.getName()
+                    + " did not answer within " + timeoutSeconds + " s. Raise the request timeout in Settings"
+                    + " for long recordings or slow servers.");
+            timeout.initCause(e);
+            throw timeout;
+        } finally {
+            cancellation.removeOnCancel(abort);
+        }
+    }
+
+    private CloseableHttpClient createHttpClient() {
+        HttpClientBuilder builder = HttpClients.custom();
+        RequestConfig.Builder config = RequestConfig.custom()
+                .setSocketTimeout(timeoutSeconds * 1000);
+        if (spec.getConnectTimeoutMs() > 0) {
+            config.setConnectTimeout(spec.getConnectTimeoutMs())
+                    .setConnectionRequestTimeout(spec.getConnectTimeoutMs());
+        }
+        builder.setDefaultRequestConfig(config.build());
+        if (socketFactory != null) {
+            builder.setSSLSocketFactory(socketFactory);
+        }
+        return builder.build();
+    }
+
+    /**
+     * Determines the upload content type from the file extension.
+     */
+    static String contentTypeFor(File file) {
+        String fileName = file.getName().toLowerCase();
+        if (fileName.endsWith(".mp3")) {
+            return "audio/mpeg";
+        } else if (fileName.endsWith(".m4a")) {
+            return "audio/mp4";
+        } else if (fileName.endsWith(".ogg")) {
+            return "audio/ogg";
+        } else if (fileName.endsWith(".flac")) {
+            return "audio/flac";
+        }
+        return "audio/wav";
+    }
+}
@@ -0,0 +1,73 @@
+package org.whispercat.recording.clients;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonProperty;
+
+/**
+ * Data model representing a single segment of a verbose_json transcription response.
+ */
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class TranscriptionSegment {
+    private double start;
+    private double end;
+    private String text;
+    @JsonProperty("avg_logprob")
+    private double avgLogprob;
+    @JsonProperty("no_speech_prob")
+    private double noSpeechProb;
+
+    public TranscriptionSegment() {
+    }
+
+    public double getStart() {
+        return start;
+    }
+
+    public void setStart(double start) {
+        this.start = start;
+    }
+
+    public double getEnd() {
+        return end;
+    }
+
+    public void setEnd(double end) {
+        this.end = end;
+    }
+
+    public String getText() {
+        return text;
+    }
+
+    public void setText(String text) {
+        this.text = text;
+    }
+
+    public double getAvgLogprob() {
+        return avgLogprob;
+    }
+
+    public void setAvgLogprob(double avgLogprob) {
+        this.avgLogprob = avgLogprob;
+    }
+
+    public double getNoSpeechProb() {
+        return noSpeechProb;
+    }
+
+    public void setNoSpeechProb(double noSpeechProb) {
+        this.noSpeechProb = noSpeechProb;
+    }
+
+    /**
+     * Confidence of this segment between 0 and 1: the average token probability,
+     * reduced by the probability that the segment contains no speech at all.
+     *
+     * @return the segment confidence
+     */
+    public double getConfidence() {
+        double tokenProbability = Math.exp(Math.min(0.0, avgLogprob));
+        double speechProbability = 1.0 - Math.max(0.0, Math.min(1.0, noSpeechProb));
+        return tokenProbability * speechProbability;
+    }
+}
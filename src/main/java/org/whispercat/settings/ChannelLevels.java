@@ -0,0 +1,99 @@
+package org.whispercat.settings;
+
+import javax.sound.sampled.AudioFormat;
+
+/**
+ * Computes RMS levels per channel from interleaved 16-bit PCM, so the microphone
+ * test can show left and right separately on stereo devices.
+ */
+public final class ChannelLevels {
+    // Lowest level shown; digital silence would otherwise be negative infinity
+    public static final double MIN_DECIBELS = -90.0;
+    // Samples at or above this fraction of full scale count as clipping
+    public static final double CLIP_THRESHOLD = 0.999;
+
+    private ChannelLevels() {
+    }
+
+    /**
+     * De-interleaves the samples by channel and computes the RMS of each channel.
+     * Incomplete trailing frames are ignored.
+     *
+     * @param audioData the captured bytes
+     * @param length    number of valid bytes in {@code audioData}
+     * @param channels  number of interleaved channels
+     * @param bigEndian byte order of the samples
+     * @return the RMS of each channel, 0.0-1.0
+     */
+    public static double[] rmsPerChannel(byte[] audioData, int length, int channels, boolean bigEndian) {
+        if (channels <= 0) {
+            throw new IllegalArgumentException("Channel count must be positive: " + channels);
+        }
+        int frameSize = 2 * channels;
+        int frames = length / frameSize;
+        double[] sums = new double[channels];
+        for (int frame = 0; frame < frames; frame++) {
+            for (int channel = 0; channel < channels; channel++) {
+                int i = frame * frameSize + channel * 2;
+                int sample = bigEndian
+                        ? (audioData[i] << 8) | (audioData[i + 1] & 0xFF)
+                        : (audioData[i + 1] << 8) | (audioData[i] & 0xFF);
+                sums[channel] += (double) sample * sample;
+            }
+        }
+        double[] levels = new double[channels];
+        if (frames == 0) {
+            return levels;
+        }
+        for (int channel = 0; channel < channels; channel++) {
+            levels[channel] = Math.min(Math.sqrt(sums[channel] / frames) / 32768.0, 1.0);
+        }
+        return levels;
+    }
+
+    /**
+     * Computes the per-channel RMS of 16-bit PCM captured in the given format.
+     *
+     * @param audioData the captured bytes
+     * @param length    number of valid bytes in {@code audioData}
+     * @param format    the capture format
+     * @return the RMS of each channel, 0.0-1.0
+     */
+    public static double[] rmsPerChannel(byte[] audioData, int length, AudioFormat format) {
+        return rmsPerChannel(audioData, length, format.getChannels(), format.isBigEndian());
+    }
+
+    /**
+     * Converts a linear level to decibels relative to full scale (20 * log10(rms)).
+     *
+     * @param rms the level, 0.0-1.0
+     * @return the level in dBFS, at least {@link #MIN_DECIBELS}
+     */
+    public static double toDecibels(double rms) {
+        if (rms <= 0) {
+            return MIN_DECIBELS;
+        }
+        return Math.max(MIN_DECIBELS, 20 * Math.log10(rms));
+    }
+
+    /**
+     * Checks whether any 16-bit sample reaches {@link #CLIP_THRESHOLD} of full scale.
+     *
+     * @param audioData the captured bytes
+     * @param length    number of valid bytes in {@code audioData}
+     * @param bigEndian byte order of the samples
+     * @return true if the buffer contains a clipped sample
+     */
+    public static boolean isClipping(byte[] audioData, int length, boolean bigEndian) {
+        int limit = (int) Math.ceil(CLIP_THRESHOLD * 32767);
+        for (int i = 0; i + 1 < length; i += 2) {
+            int sample = bigEndian
+                    ? (audioData[i] << 8) | (audioData[i + 1] & 0xFF)
+                    : (audioData[i + 1] << 8) | (audioData[i] & 0xFF);
+            if (Math.abs(sample) >= limit) {
+                return true;
+            }
+        }
+        return false;
+    }
+}
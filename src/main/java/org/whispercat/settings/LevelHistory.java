@@ -0,0 +1,61 @@
+package org.whispercat.settings;
+
+import java.util.Arrays;
+
+/**
+ * Fixed-size ring buffer of recent audio levels (0-100), oldest first when read.
+ * Used to draw a short scrolling level history during the microphone test.
+ */
+public class LevelHistory {
+    private final int[] levels;
+    private int start = 0;
+    private int size = 0;
+
+    public LevelHistory(int capacity) {
+        if (capacity <= 0) {
+            throw new IllegalArgumentException("Capacity must be positive: " + capacity);
+        }
+        this.levels = new int[capacity];
+    }
+
+    /**
+     * Adds a level, overwriting the oldest one once the buffer is full.
+     *
+     * @param level the level; clamped to 0-100
+     */
+    public synchronized void push(int level) {
+        int clamped = Math.max(0, Math.min(100, level));
+        if (size < levels.length) {
+            levels[(start + size) % levels.length] = clamped;
+            size++;
+        } else {
+            levels[start] = clamped;
+            start = (start + 1) % levels.length;
+        }
+    }
+
+    /**
+     * @return the stored levels, oldest first
+     */
+    public synchronized int[] toArray() {
+        int[] result = new int[size];
+        for (int i = 0; i < size; i++) {
+            result[i] = levels[(start + i) % levels.length];
+        }
+        return result;
+    }
+
+    public synchronized void clear() {
+        Arrays.fill(levels, 0);
+        start = 0;
+        size = 0;
+    }
+
+    public synchronized int size() {
+        return size;
+    }
+
+    public int capacity() {
+        return levels.length;
+    }
+}
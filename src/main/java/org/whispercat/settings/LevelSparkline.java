@@ -0,0 +1,70 @@
+package org.whispercat.settings;
+
+import javax.swing.*;
+import java.awt.*;
+
+/**
+ * Small scrolling line chart of the levels in a {@link LevelHistory}, with the newest
+ * level on the right. An optional threshold is drawn as a dashed line.
+ */
+public class LevelSparkline extends JComponent {
+    private final LevelHistory history;
+    private int thresholdValue = 0;
+    private final Color lineColor = new Color(52, 152, 219);
+    private final Color thresholdColor = new Color(255, 100, 100);
+
+    public LevelSparkline(LevelHistory history) {
+        this.history = history;
+        setPreferredSize(new Dimension(200, 40));
+        setToolTipText("Level history of the last few seconds");
+    }
+
+    /**
+     * @param thresholdValue Value between 0-100 to draw as the silence threshold line
+     */
+    public void setThreshold(int thresholdValue) {
+        this.thresholdValue = Math.max(0, Math.min(100, thresholdValue));
+        repaint();
+    }
+
+    @Override
+    protected void paintComponent(Graphics g) {
+        super.paintComponent(g);
+        Graphics2D g2d = (Graphics2D) g.create();
+        g2d.setRenderingHint(RenderingHints.KEY_ANTIALIASING, RenderingHints.VALUE_ANTIALIAS_ON);
+
+        int width = getWidth();
+        int height = getHeight();
+        g2d.setColor(UIManager.getColor("ProgressBar.background") != null
+                ? UIManager.getColor("ProgressBar.background") : Color.LIGHT_GRAY);
+        g2d.fillRect(0, 0, width, height);
+
+        if (thresholdValue > 0) {
+            int y = levelToY(thresholdValue, height);
+            g2d.setColor(thresholdColor);
+            g2d.setStroke(new BasicStroke(1, BasicStroke.CAP_BUTT, BasicStroke.JOIN_MITER, 10, new float[]{4, 4}, 0));
+            g2d.drawLine(0, y, width, y);
+        }
+
+        int[] levels = history.toArray();
+        if (levels.length > 1) {
+            // Fixed spacing per sample so the line scrolls left as new levels arrive
+            double step = (double) width / (history.capacity() - 1);
+            double x = width - step * (levels.length - 1);
+            int[] xPoints = new int[levels.length];
+            int[] yPoints = new int[levels.length];
+            for (int i = 0; i < levels.length; i++) {
+                xPoints[i] = (int) Math.round(x + step * i);
+                yPoints[i] = levelToY(levels[i], height);
+            }
+            g2d.setColor(lineColor);
+            g2d.setStroke(new BasicStroke(1.5f));
+            g2d.drawPolyline(xPoints, yPoints, levels.length);
+        }
+        g2d.dispose();
+    }
+
+    private static int levelToY(int level, int height) {
+        return height - 1 - (int) Math.round(level / 100.0 * (height - 2));
+    }
+}
@@ -1,17 +1,32 @@
 package org.whispercat.settings;
 
 import com.fasterxml.jackson.databind.ObjectMapper;
-import com.github.kwhat.jnativehook.keyboard.NativeKeyEvent;
 import org.apache.logging.log4j.LogManager;
 import org.apache.logging.log4j.Logger;
+import org.whispercat.ClipboardCheck;
 import org.whispercat.ConfigManager;
+import org.whispercat.ConsoleLogger;
+import org.whispercat.DisplayFormat;
+import org.whispercat.Hotkey;
 import org.whispercat.Notificationmanager;
+import org.whispercat.OpenWebUIPaths;
 import org.whispercat.ToastNotification;
+import org.whispercat.recording.AudioDeviceKind;
+import org.whispercat.recording.IntermediateFileLocation;
+import org.whispercat.recording.OutputSink;
+import org.whispercat.recording.ProviderHealth;
+import org.whispercat.recording.TranscriptFormatter;
 import org.whispercat.recording.clients.FasterWhisperModel;
 import org.whispercat.recording.clients.FasterWhisperModelsResponse;
+import org.whispercat.recording.clients.LanguageModelRecommendation;
+import org.whispercat.recording.clients.LanguageModelRecommendations;
+import org.whispercat.recording.clients.TranscriptionProviderSpec;
+import org.whispercat.recording.clients.TranscriptionProviders;
 
 import javax.sound.sampled.*;
 import javax.swing.*;
+import javax.swing.event.DocumentEvent;
+import javax.swing.event.DocumentListener;
 import java.awt.*;
 import java.awt.event.ActionEvent;
 import java.awt.event.ItemEvent;
@@ -25,14 +40,28 @@ public class SettingsForm extends JPanel {
     private static final Logger logger = LogManager.getLogger(SettingsForm.class);
     // Existing components
     private final KeyCombinationTextField keyCombinationTextField;
+    private final KeyCombinationTextField pipelineKeyTextField;
     private final JButton clearKeybindButton;
     private final KeySequenceTextField keySequenceTextField;
     private final JButton clearKeySequenceButton;
+    private final JCheckBox inAppShortcutSwitch;
+    private final JCheckBox localServerSwitch;
+    private final JSpinner localServerPortSpinner;
+    private final JTextField localServerTokenField;
     private final JComboBox<String> microphoneComboBox;
     private final JComboBox<Integer> bitrateComboBox;
     private final ConfigManager configManager;
     private final JCheckBox stopSoundSwitch;
     private final ThresholdProgressBar volumeBar;
+    // Right channel level, only shown when the test microphone delivers stereo
+    private final ThresholdProgressBar rightVolumeBar;
+    // A few seconds of levels at the test worker's read rate (one level per 1 KB buffer)
+    private final LevelHistory levelHistory = new LevelHistory(200);
+    private final LevelSparkline levelSparkline;
+    // Numeric level and clip indicator of the mic test
+    private final JLabel levelReadoutLabel;
+    private final JLabel clipLabel;
+    private long lastClipMs;
     private final JButton stopTestButton;
     private final JButton testMicrophoneButton;
 
@@ -41,10 +70,56 @@ public class SettingsForm extends JPanel {
 
     // Silence removal settings
     private JCheckBox silenceRemovalSwitch;
+    private JCheckBox forceMonoOutputSwitch;
+    private JCheckBox normalizeAudioSwitch;
     private JSlider silenceThresholdSlider;
     private JSlider minSilenceDurationSlider;
+    private JSlider silencePaddingSlider;
+    private JSpinner silenceWindowSpinner;
     private JSlider minRecordingDurationSlider;
+    private JSlider minReductionSlider;
     private JCheckBox keepCompressedSwitch;
+    private JComboBox<IntermediateFileLocation> intermediateLocationComboBox;
+    private JComboBox<Integer> mp3SampleRateComboBox;
+    private JCheckBox mp3MonoSwitch;
+    private JSpinner retentionDaysSpinner;
+    private JSpinner autoPasteMinConfidenceSpinner;
+    private JTextField autoPasteTargetAppField;
+    private JSpinner unreachableRetrySpinner;
+    private JSpinner transcriptionTimeoutSpinner;
+    private JSpinner dictationSilenceSpinner;
+    private JCheckBox recordDuringTranscriptionSwitch;
+    private JSpinner maxRecordingSecondsSpinner;
+    private JCheckBox transcribeInterruptedSwitch;
+    private JCheckBox voiceActivatedStartSwitch;
+    private JTextArea chainedMetaPromptArea;
+    private JSpinner chatMaxTokensSpinner;
+    private JTextArea extraFormFieldsArea;
+    private String extraFormFieldsProvider;
+    private final Map<String, String> extraFormFieldsByProvider = new HashMap<>();
+    private JCheckBox normalizeOutputSwitch;
+    private JCheckBox usePreviousAsPromptSwitch;
+    private JCheckBox retryEmptyTranscriptionSwitch;
+    private JCheckBox outputClipboardSwitch;
+    private JCheckBox outputFileSwitch;
+    private JCheckBox outputWebhookSwitch;
+    private JTextField outputFilePathField;
+    private JTextField outputWebhookUrlField;
+    private JCheckBox prependTimestampSwitch;
+    private JTextField timestampFormatField;
+    private JComboBox<String> displayLocaleComboBox;
+    // Language tag and name of each display locale choice; the empty tag is the system locale
+    private static final String[][] DISPLAY_LOCALES = {
+            {"", "System default"},
+            {"en-US", "English (US) - 1,234.5 / 3:04 PM"},
+            {"en-GB", "English (UK) - 1,234.5 / 15:04"},
+            {"de-DE", "German - 1.234,5 / 15:04"},
+            {"fr-FR", "French - 1 234,5 / 15:04"},
+            {"es-ES", "Spanish - 1.234,5 / 15:04"},
+            {"it-IT", "Italian - 1.234,5 / 15:04"},
+            {"nl-NL", "Dutch - 1.234,5 / 15:04"}
+    };
+    private JPanel levelReadoutPanel;
     private AudioFormat format;
     private TargetDataLine line;
     private TestWorker testWorker;
@@ -56,21 +131,29 @@ public class SettingsForm extends JPanel {
     private final JPanel groqPanel;
     private final JPanel openaiPanel;
     private final JPanel openWebUIPanel;
+    private final JPanel whisperCppPanel;
 
     private final JTextField whisperServerUrlField;
+    private final JTextField whisperCppServerUrlField;
     private final JComboBox<String> fasterWhisperModelComboBox;
     private final JComboBox<String> fasterWhisperLanguageComboBox;
+    private final JLabel languageRecommendationLabel;
+    private final JButton useRecommendedModelButton;
 
     private final JTextField groqApiKeyField;
     private final JComboBox<String> groqModelComboBox;
 
     private JTextField openaiApiKeyField;
     private JTextField customOpenAIModelsField;
+    private JTextField openaiBaseUrlField;
+    private JTextField openaiChatBaseUrlField;
 
     private JTextField grokApiKeyField;
 
     private JTextField openwebUIApiKeyField;
     private JTextField openwebUIApiURLField;
+    private JTextField openwebUICaCertField;
+    private JCheckBox openwebUIAcceptInvalidCertsSwitch;
 
     private static final String SERVER_FASTER_WHISPER = "Faster-Whisper";
     private static final String OPEN_WEB_UI = "Open WebUI";
@@ -78,6 +161,7 @@ public class SettingsForm extends JPanel {
     private static final String SERVER_OPENAI = "OpenAI";
 
     private final Map<String, List<String>> fastModelLanguages;
+    private final Map<String, LanguageModelRecommendation> languageRecommendations;
 
     public SettingsForm(ConfigManager configManager) {
         this.configManager = configManager;
@@ -88,6 +172,19 @@ public class SettingsForm extends JPanel {
         // Set initial threshold from config (threshold is 0.0-1.0, bar is 0-100)
         float initialThreshold = configManager.getSilenceThreshold();
         volumeBar.setThreshold((int)(initialThreshold * 100));
+        rightVolumeBar = new ThresholdProgressBar(0, 100);
+        rightVolumeBar.setStringPainted(true);
+        rightVolumeBar.setVisible(false);
+        rightVolumeBar.setThreshold((int)(initialThreshold * 100));
+        levelSparkline = new LevelSparkline(levelHistory);
+        levelSparkline.setVisible(false);
+        levelSparkline.setThreshold((int)(initialThreshold * 100));
+        levelReadoutLabel = new JLabel();
+        levelReadoutLabel.setFont(new Font(Font.MONOSPACED, Font.PLAIN, 12));
+        clipLabel = new JLabel("CLIP");
+        clipLabel.setFont(clipLabel.getFont().deriveFont(Font.BOLD));
+        clipLabel.setForeground(Color.LIGHT_GRAY);
+        clipLabel.setToolTipText("Lights up when the signal reaches full scale. Lower the input gain.");
         stopTestButton = new JButton("Stop Test");
         stopTestButton.setVisible(false);
         stopTestButton.addActionListener(e -> stopAudioTest());
@@ -112,6 +209,15 @@ public class SettingsForm extends JPanel {
             throw new RuntimeException("Failed to load fasterwhispermodels.json", e);
         }
 
+        Map<String, LanguageModelRecommendation> recommendations;
+        try {
+            recommendations = LanguageModelRecommendations.load();
+        } catch (IOException e) {
+            logger.warn("Failed to load language model recommendations", e);
+            recommendations = Collections.emptyMap();
+        }
+        languageRecommendations = recommendations;
+
         JPanel contentPanel = new JPanel(new GridBagLayout());
         setBorder(BorderFactory.createEmptyBorder(60, 20, 10, 10));
 
@@ -171,6 +277,104 @@ public class SettingsForm extends JPanel {
             keySequenceTextField.setKeysDisplayed(new ArrayList<>());
         });
 
+        // Row: Pipeline key combination
+        row++;
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Run pipeline key combination:"), gbc);
+        pipelineKeyTextField = new KeyCombinationTextField();
+        pipelineKeyTextField.setToolTipText("Runs the last used pipeline on the current transcription, e.g. Ctrl+Shift+P");
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(pipelineKeyTextField, gbc);
+        JButton clearPipelineKeyButton = new JButton("Delete");
+        gbc.gridx = 2;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        contentPanel.add(clearPipelineKeyButton, gbc);
+        clearPipelineKeyButton.addActionListener(e -> {
+            pipelineKeyTextField.setText("");
+            pipelineKeyTextField.setKeysDisplayed(new HashSet<>());
+        });
+
+        // Row: In-app recording shortcut
+        row++;
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("In-app shortcut (Space):"), gbc);
+        inAppShortcutSwitch = new JCheckBox("Toggle recording with Space on the recording screen");
+        inAppShortcutSwitch.setSelected(configManager.isInAppShortcutEnabled());
+        inAppShortcutSwitch.addActionListener(e -> settingsDirty = true);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(inAppShortcutSwitch, gbc);
+
+        // Row: Local API server
+        row++;
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Local API server:"), gbc);
+        JPanel localServerPanel = new JPanel(new FlowLayout(FlowLayout.LEFT, 0, 0));
+        localServerSwitch = new JCheckBox("Enable on port");
+        localServerSwitch.setSelected(configManager.isLocalServerEnabled());
+        localServerSwitch.addActionListener(e -> settingsDirty = true);
+        localServerPortSpinner = new JSpinner(new SpinnerNumberModel(configManager.getLocalServerPort(), 1024, 65535, 1));
+        localServerPortSpinner.setEditor(new JSpinner.NumberEditor(localServerPortSpinner, "#"));
+        localServerPortSpinner.addChangeListener(e -> settingsDirty = true);
+        localServerPanel.add(localServerSwitch);
+        localServerPanel.add(Box.createHorizontalStrut(5));
+        localServerPanel.add(localServerPortSpinner);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(localServerPanel, gbc);
+
+        row++;
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("API token:"), gbc);
+        localServerTokenField = new JTextField(configManager.getLocalServerToken(), 30);
+        localServerTokenField.setEditable(false);
+        localServerTokenField.setToolTipText("Send as \"Authorization: Bearer <token>\" with every request");
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(localServerTokenField, gbc);
+
+        row++;
+        JLabel localServerHint = new JLabel("<html><i>POST /transcribe and /record/toggle on 127.0.0.1 only, with the header Authorization: Bearer &lt;token&gt;. Takes effect after restart.</i></html>");
+        localServerHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        localServerHint.setForeground(Color.GRAY);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(localServerHint, gbc);
+
         // Row: Microphone selection
         row++;
         gbc.gridx = 0;
@@ -180,6 +384,17 @@ public class SettingsForm extends JPanel {
         gbc.anchor = GridBagConstraints.EAST;
         contentPanel.add(new JLabel("Microphone:"), gbc);
         microphoneComboBox = new JComboBox<>(getAvailableMicrophones());
+        microphoneComboBox.setRenderer(new DefaultListCellRenderer() {
+            @Override
+            public Component getListCellRendererComponent(JList<?> list, Object value, int index,
+                                                          boolean isSelected, boolean cellHasFocus) {
+                Object label = value;
+                if (value != null && isLoopbackDevice(value.toString())) {
+                    label = "System audio: " + value;
+                }
+                return super.getListCellRendererComponent(list, label, index, isSelected, cellHasFocus);
+            }
+        });
         microphoneComboBox.addItemListener(e -> {
             if (e.getStateChange() == ItemEvent.SELECTED) {
                 settingsDirty = true;
@@ -199,16 +414,37 @@ public class SettingsForm extends JPanel {
         gbc.weightx = 0;
         contentPanel.add(testMicrophoneButton, gbc);
         testMicrophoneButton.addActionListener(e -> {
+            if (line != null) {
+                // A second click ends the test
+                stopAudioTest();
+                return;
+            }
             String selectedMicrophone = (String) microphoneComboBox.getSelectedItem();
             if (selectedMicrophone != null && !selectedMicrophone.isEmpty()) {
-                startAudioTest(selectedMicrophone);
-                volumeBar.setVisible(true);
-                stopTestButton.setVisible(true);
+                if (startAudioTest(selectedMicrophone)) {
+                    testMicrophoneButton.setText("Stop");
+                    volumeBar.setVisible(true);
+                    levelSparkline.setVisible(true);
+                    levelReadoutPanel.setVisible(true);
+                    stopTestButton.setVisible(true);
+                }
             } else {
                 JOptionPane.showMessageDialog(this, "No Mic selected. Please select Mic.", "Error", JOptionPane.ERROR_MESSAGE);
             }
         });
 
+        row++;
+        JLabel inputDeviceHint = new JLabel("<html><i>\"System audio\" devices record what your computer plays, e.g. the other side of a meeting. "
+                + "On Windows enable \"Stereo Mix\" in the sound settings; on Linux use a \"Monitor of\" source.</i></html>");
+        inputDeviceHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        inputDeviceHint.setForeground(Color.GRAY);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(inputDeviceHint, gbc);
+
         // Row: Volume bar and Stop Test button
         row++;
         gbc.gridx = 1;
@@ -223,6 +459,37 @@ public class SettingsForm extends JPanel {
         gbc.weightx = 0;
         contentPanel.add(stopTestButton, gbc);
 
+        // Row: Right channel level (stereo devices only)
+        row++;
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.fill = GridBagConstraints.HORIZONTAL;
+        contentPanel.add(rightVolumeBar, gbc);
+
+        // Row: Level history of the mic test
+        row++;
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.fill = GridBagConstraints.HORIZONTAL;
+        contentPanel.add(levelSparkline, gbc);
+
+        // Row: Numeric level and clip indicator
+        row++;
+        levelReadoutPanel = new JPanel(new FlowLayout(FlowLayout.LEFT, 10, 0));
+        levelReadoutPanel.add(levelReadoutLabel);
+        levelReadoutPanel.add(clipLabel);
+        levelReadoutPanel.setVisible(false);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.fill = GridBagConstraints.HORIZONTAL;
+        contentPanel.add(levelReadoutPanel, gbc);
+
         // Row: Bitrate selection
         row++;
         gbc.gridx = 0;
@@ -245,6 +512,25 @@ public class SettingsForm extends JPanel {
         gbc.anchor = GridBagConstraints.WEST;
         contentPanel.add(bitrateComboBox, gbc);
 
+        // Row: Mono output
+        row++;
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Mono output:"), gbc);
+        forceMonoOutputSwitch = new JCheckBox("Save stereo-only devices as mono");
+        forceMonoOutputSwitch.setToolTipText("Some devices, e.g. system audio loopbacks, only record in stereo. Mono files work with every provider.");
+        forceMonoOutputSwitch.setSelected(configManager.isForceMonoOutput());
+        forceMonoOutputSwitch.addActionListener(e -> settingsDirty = true);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(forceMonoOutputSwitch, gbc);
+
         // Row: Enable Finish Sound
         row++;
         gbc.gridx = 0;
@@ -283,6 +569,32 @@ public class SettingsForm extends JPanel {
 
         row++;
 
+        // Peak normalization
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Normalize volume:"), gbc);
+        normalizeAudioSwitch = new JCheckBox();
+        normalizeAudioSwitch.setSelected(configManager.isNormalizeAudio());
+        normalizeAudioSwitch.addActionListener(e -> settingsDirty = true);
+        JPanel normalizeAudioPanel = new JPanel(new FlowLayout(FlowLayout.LEFT, 0, 0));
+        normalizeAudioPanel.add(normalizeAudioSwitch);
+        JLabel normalizeAudioHint = new JLabel("<html><i>Raise quiet recordings to a fixed peak before silence removal</i></html>");
+        normalizeAudioHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        normalizeAudioHint.setForeground(Color.GRAY);
+        normalizeAudioPanel.add(Box.createHorizontalStrut(8));
+        normalizeAudioPanel.add(normalizeAudioHint);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(normalizeAudioPanel, gbc);
+
+        row++;
+
         // Silence threshold slider
         gbc.gridx = 0;
         gbc.gridy = row;
@@ -297,15 +609,17 @@ public class SettingsForm extends JPanel {
         silenceThresholdSlider.setMajorTickSpacing(10);
         silenceThresholdSlider.setMinorTickSpacing(5);
         silenceThresholdSlider.setPaintTicks(true);
-        JLabel thresholdValueLabel = new JLabel(String.format("%.3f", configManager.getSilenceThreshold()));
+        JLabel thresholdValueLabel = new JLabel(DisplayFormat.number(configManager.getSilenceThreshold(), 3));
         thresholdPanel.add(silenceThresholdSlider, BorderLayout.CENTER);
         thresholdPanel.add(thresholdValueLabel, BorderLayout.EAST);
 
         silenceThresholdSlider.addChangeListener(e -> {
             float value = silenceThresholdSlider.getValue() / 1000.0f;
-            thresholdValueLabel.setText(String.format("%.3f", value));
+            thresholdValueLabel.setText(DisplayFormat.number(value, 3));
             // Update threshold indicator on volume bar
             volumeBar.setThreshold((int)(value * 100));
+            rightVolumeBar.setThreshold((int)(value * 100));
+            levelSparkline.setThreshold((int)(value * 100));
             // Mark settings as dirty when user interacts
             settingsDirty = true;
             // Auto-save when slider stops moving (not dragging)
@@ -319,135 +633,954 @@ public class SettingsForm extends JPanel {
         gbc.gridwidth = 2;
         gbc.weightx = 1.0;
         gbc.anchor = GridBagConstraints.WEST;
-        contentPanel.add(thresholdPanel, gbc);
+        contentPanel.add(thresholdPanel, gbc);
+
+        row++;
+
+        // Hint for threshold slider
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        JLabel thresholdHint = new JLabel("<html><i>Lower = conservative (removes less), Higher = aggressive (removes more)</i></html>");
+        thresholdHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        thresholdHint.setForeground(Color.GRAY);
+        contentPanel.add(thresholdHint, gbc);
+
+        row++;
+
+        // Minimum silence duration slider
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        JLabel durationLabel = new JLabel("Min silence duration:");
+        contentPanel.add(durationLabel, gbc);
+
+        JPanel durationPanel = new JPanel(new BorderLayout(5, 0));
+        minSilenceDurationSlider = new JSlider(500, 10000, configManager.getMinSilenceDuration());
+        minSilenceDurationSlider.setMajorTickSpacing(1000);
+        minSilenceDurationSlider.setMinorTickSpacing(500);
+        minSilenceDurationSlider.setPaintTicks(true);
+        JLabel durationValueLabel = new JLabel(configManager.getMinSilenceDuration() + "ms");
+        durationPanel.add(minSilenceDurationSlider, BorderLayout.CENTER);
+        durationPanel.add(durationValueLabel, BorderLayout.EAST);
+
+        minSilenceDurationSlider.addChangeListener(e -> {
+            durationValueLabel.setText(minSilenceDurationSlider.getValue() + "ms");
+            // Auto-save when slider stops moving
+            if (!minSilenceDurationSlider.getValueIsAdjusting()) {
+                configManager.setMinSilenceDuration(minSilenceDurationSlider.getValue());
+            }
+        });
+
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(durationPanel, gbc);
+
+        row++;
+
+        // Hint for duration slider
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        JLabel durationHint = new JLabel("<html><i>Minimum consecutive silence to remove (higher = more conservative)</i></html>");
+        durationHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        durationHint.setForeground(Color.GRAY);
+        contentPanel.add(durationHint, gbc);
+
+        row++;
+
+        // Silence padding slider
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Silence padding:"), gbc);
+
+        JPanel paddingPanel = new JPanel(new BorderLayout(5, 0));
+        silencePaddingSlider = new JSlider(0, 500, configManager.getSilencePaddingMs());
+        silencePaddingSlider.setMajorTickSpacing(100);
+        silencePaddingSlider.setMinorTickSpacing(50);
+        silencePaddingSlider.setPaintTicks(true);
+        JLabel paddingValueLabel = new JLabel(configManager.getSilencePaddingMs() + "ms");
+        paddingPanel.add(silencePaddingSlider, BorderLayout.CENTER);
+        paddingPanel.add(paddingValueLabel, BorderLayout.EAST);
+
+        silencePaddingSlider.addChangeListener(e -> {
+            paddingValueLabel.setText(silencePaddingSlider.getValue() + "ms");
+            // Auto-save when slider stops moving
+            if (!silencePaddingSlider.getValueIsAdjusting()) {
+                configManager.setSilencePaddingMs(silencePaddingSlider.getValue());
+            }
+        });
+
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(paddingPanel, gbc);
+
+        row++;
+
+        // Hint for padding slider
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        JLabel paddingHint = new JLabel("<html><i>Audio kept around each removed pause so word starts and endings are not clipped</i></html>");
+        paddingHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        paddingHint.setForeground(Color.GRAY);
+        contentPanel.add(paddingHint, gbc);
+
+        row++;
+
+        // Analysis window
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Analysis window (ms):"), gbc);
+        silenceWindowSpinner = new JSpinner(new SpinnerNumberModel(configManager.getSilenceWindowMs(), 10, 500, 10));
+        silenceWindowSpinner.addChangeListener(e -> settingsDirty = true);
+        JPanel silenceWindowPanel = new JPanel(new FlowLayout(FlowLayout.LEFT, 0, 0));
+        silenceWindowPanel.add(silenceWindowSpinner);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(silenceWindowPanel, gbc);
+
+        row++;
+
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        JLabel silenceWindowHint = new JLabel("<html><i>Smaller windows (20-50ms) catch the short pauses of fast speech. Default 100ms.</i></html>");
+        silenceWindowHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        silenceWindowHint.setForeground(Color.GRAY);
+        contentPanel.add(silenceWindowHint, gbc);
+
+        row++;
+
+        // Minimum recording duration slider
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        JLabel minRecDurationLabel = new JLabel("Min recording for removal:");
+        contentPanel.add(minRecDurationLabel, gbc);
+
+        JPanel minRecDurationPanel = new JPanel(new BorderLayout(5, 0));
+        minRecordingDurationSlider = new JSlider(0, 60, configManager.getMinRecordingDurationForSilenceRemoval());
+        minRecordingDurationSlider.setMajorTickSpacing(10);
+        minRecordingDurationSlider.setMinorTickSpacing(5);
+        minRecordingDurationSlider.setPaintTicks(true);
+        JLabel minRecDurationValueLabel = new JLabel(configManager.getMinRecordingDurationForSilenceRemoval() + "s");
+        minRecDurationPanel.add(minRecordingDurationSlider, BorderLayout.CENTER);
+        minRecDurationPanel.add(minRecDurationValueLabel, BorderLayout.EAST);
+
+        minRecordingDurationSlider.addChangeListener(e -> {
+            int value = minRecordingDurationSlider.getValue();
+            minRecDurationValueLabel.setText(value + "s");
+            // Auto-save when slider stops moving
+            if (!minRecordingDurationSlider.getValueIsAdjusting()) {
+                configManager.setMinRecordingDurationForSilenceRemoval(value);
+            }
+        });
+
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(minRecDurationPanel, gbc);
+
+        row++;
+
+        // Hint for minimum recording duration
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        JLabel minRecDurationHint = new JLabel("<html><i>Only apply silence removal to recordings longer than this (0 = always apply)</i></html>");
+        minRecDurationHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        minRecDurationHint.setForeground(Color.GRAY);
+        contentPanel.add(minRecDurationHint, gbc);
+
+        row++;
+
+        // Minimum reduction slider
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        JLabel minReductionLabel = new JLabel("Min reduction for removal:");
+        contentPanel.add(minReductionLabel, gbc);
+
+        JPanel minReductionPanel = new JPanel(new BorderLayout(5, 0));
+        minReductionSlider = new JSlider(0, 50, configManager.getMinSilenceReductionPercent());
+        minReductionSlider.setMajorTickSpacing(10);
+        minReductionSlider.setMinorTickSpacing(5);
+        minReductionSlider.setPaintTicks(true);
+        JLabel minReductionValueLabel = new JLabel(configManager.getMinSilenceReductionPercent() + "%");
+        minReductionPanel.add(minReductionSlider, BorderLayout.CENTER);
+        minReductionPanel.add(minReductionValueLabel, BorderLayout.EAST);
+
+        minReductionSlider.addChangeListener(e -> {
+            int value = minReductionSlider.getValue();
+            minReductionValueLabel.setText(value + "%");
+            // Auto-save when slider stops moving
+            if (!minReductionSlider.getValueIsAdjusting()) {
+                configManager.setMinSilenceReductionPercent(value);
+            }
+        });
+
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(minReductionPanel, gbc);
+
+        row++;
+
+        // Hint for minimum reduction
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        JLabel minReductionHint = new JLabel("<html><i>Skip silence removal when it would shorten the recording by less than this (0 = always apply)</i></html>");
+        minReductionHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        minReductionHint.setForeground(Color.GRAY);
+        contentPanel.add(minReductionHint, gbc);
+
+        row++;
+
+        // Keep compressed files checkbox
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Keep compressed files:"), gbc);
+        keepCompressedSwitch = new JCheckBox();
+        keepCompressedSwitch.setSelected(configManager.isKeepCompressedFile());
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(keepCompressedSwitch, gbc);
+
+        row++;
+
+        // Location of converted/compressed audio
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Intermediate files:"), gbc);
+        intermediateLocationComboBox = new JComboBox<>(IntermediateFileLocation.values());
+        intermediateLocationComboBox.setSelectedItem(configManager.getIntermediateFileLocation());
+        intermediateLocationComboBox.addActionListener(e -> settingsDirty = true);
+        JPanel intermediateLocationPanel = new JPanel(new FlowLayout(FlowLayout.LEFT, 0, 0));
+        intermediateLocationPanel.add(intermediateLocationComboBox);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(intermediateLocationPanel, gbc);
+
+        row++;
+        JLabel intermediateLocationHint = new JLabel("<html><i>Where converted, silence-removed, compressed and split audio is written. Use the temp folder if the recording's folder is read-only.</i></html>");
+        intermediateLocationHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        intermediateLocationHint.setForeground(Color.GRAY);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(intermediateLocationHint, gbc);
+
+        row++;
+
+        // MP3 quality slider (compression of uploads above the size limit)
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("MP3 quality:"), gbc);
+
+        JPanel mp3QualityPanel = new JPanel(new BorderLayout(5, 0));
+        JSlider mp3QualitySlider = new JSlider(0, 9, configManager.getMp3VbrQuality());
+        mp3QualitySlider.setMajorTickSpacing(1);
+        mp3QualitySlider.setSnapToTicks(true);
+        mp3QualitySlider.setPaintTicks(true);
+        JLabel mp3QualityValueLabel = new JLabel("V" + configManager.getMp3VbrQuality());
+        mp3QualityPanel.add(mp3QualitySlider, BorderLayout.CENTER);
+        mp3QualityPanel.add(mp3QualityValueLabel, BorderLayout.EAST);
+
+        mp3QualitySlider.addChangeListener(e -> {
+            int value = mp3QualitySlider.getValue();
+            mp3QualityValueLabel.setText("V" + value);
+            // Auto-save when slider stops moving
+            if (!mp3QualitySlider.getValueIsAdjusting()) {
+                configManager.setMp3VbrQuality(value);
+            }
+        });
+
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(mp3QualityPanel, gbc);
+
+        row++;
+
+        // MP3 sample rate and channels
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("MP3 sample rate:"), gbc);
+        JPanel mp3FormatPanel = new JPanel(new FlowLayout(FlowLayout.LEFT, 0, 0));
+        mp3SampleRateComboBox = new JComboBox<>(Arrays.stream(ConfigManager.MP3_SAMPLE_RATES).boxed().toArray(Integer[]::new));
+        mp3SampleRateComboBox.setSelectedItem(configManager.getMp3SampleRate());
+        mp3SampleRateComboBox.addActionListener(e -> settingsDirty = true);
+        mp3FormatPanel.add(mp3SampleRateComboBox);
+        mp3FormatPanel.add(new JLabel(" Hz"));
+        mp3FormatPanel.add(Box.createHorizontalStrut(15));
+        mp3MonoSwitch = new JCheckBox("Mono");
+        mp3MonoSwitch.setSelected(configManager.isMp3Mono());
+        mp3MonoSwitch.addActionListener(e -> settingsDirty = true);
+        mp3FormatPanel.add(mp3MonoSwitch);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(mp3FormatPanel, gbc);
+
+        row++;
+        JLabel mp3Hint = new JLabel("<html><i>Used when a recording is too large to upload as WAV. Lower V values sound better but produce larger files.</i></html>");
+        mp3Hint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        mp3Hint.setForeground(Color.GRAY);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(mp3Hint, gbc);
+
+        row++;
+
+        // Recording retention
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Delete recordings after (days):"), gbc);
+        retentionDaysSpinner = new JSpinner(new SpinnerNumberModel(configManager.getRecordingRetentionDays(), 0, 3650, 1));
+        retentionDaysSpinner.addChangeListener(e -> settingsDirty = true);
+        JPanel retentionPanel = new JPanel(new FlowLayout(FlowLayout.LEFT, 0, 0));
+        retentionPanel.add(retentionDaysSpinner);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(retentionPanel, gbc);
+
+        row++;
+        JLabel retentionHint = new JLabel("<html><i>0 keeps recordings forever. Older recordings are deleted on startup.</i></html>");
+        retentionHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        retentionHint.setForeground(Color.GRAY);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(retentionHint, gbc);
+
+        row++;
+
+        // Auto-paste confidence gate
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Auto-paste min confidence (%):"), gbc);
+        autoPasteMinConfidenceSpinner = new JSpinner(new SpinnerNumberModel(
+                (int) Math.round(configManager.getAutoPasteMinConfidence() * 100), 0, 100, 5));
+        autoPasteMinConfidenceSpinner.addChangeListener(e -> settingsDirty = true);
+        JPanel autoPasteConfidencePanel = new JPanel(new FlowLayout(FlowLayout.LEFT, 0, 0));
+        autoPasteConfidencePanel.add(autoPasteMinConfidenceSpinner);
+        autoPasteConfidencePanel.add(Box.createHorizontalStrut(15));
+        JButton testClipboardButton = new JButton("Test clipboard");
+        testClipboardButton.setToolTipText("Write a test text to the clipboard and read it back");
+        testClipboardButton.addActionListener(e -> {
+            ClipboardCheck.Result result = ClipboardCheck.run();
+            String message = result.getMessage() + " - " + result.getBackend();
+            if (result.isSuccess()) {
+                ConsoleLogger.getInstance().logSuccess("Clipboard test: " + message);
+            } else {
+                ConsoleLogger.getInstance().logError("Clipboard test: " + message);
+            }
+            Notificationmanager.getInstance().showNotification(
+                    result.isSuccess() ? ToastNotification.Type.SUCCESS : ToastNotification.Type.ERROR, message);
+        });
+        autoPasteConfidencePanel.add(testClipboardButton);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(autoPasteConfidencePanel, gbc);
+
+        row++;
+        JLabel autoPasteConfidenceHint = new JLabel("<html><i>Less confident transcriptions are only copied, not pasted. 0 always pastes.</i></html>");
+        autoPasteConfidenceHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        autoPasteConfidenceHint.setForeground(Color.GRAY);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(autoPasteConfidenceHint, gbc);
+
+        row++;
+
+        // Auto-paste target app
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Auto-paste only into:"), gbc);
+        autoPasteTargetAppField = new JTextField(configManager.getAutoPasteTargetApp(), 20);
+        autoPasteTargetAppField.getDocument().addDocumentListener(new DocumentListener() {
+            @Override
+            public void insertUpdate(DocumentEvent e) { settingsDirty = true; }
+            @Override
+            public void removeUpdate(DocumentEvent e) { settingsDirty = true; }
+            @Override
+            public void changedUpdate(DocumentEvent e) { settingsDirty = true; }
+        });
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(autoPasteTargetAppField, gbc);
+
+        row++;
+        JLabel autoPasteTargetHint = new JLabel("<html><i>App or window name, e.g. obsidian. Other windows only get the text copied. "
+                + "Empty pastes everywhere. Needs xdotool on Linux; if the window cannot be determined, the text is pasted.</i></html>");
+        autoPasteTargetHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        autoPasteTargetHint.setForeground(Color.GRAY);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(autoPasteTargetHint, gbc);
+
+        row++;
+
+        // Fail fast for unreachable servers
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Skip unreachable server for (s):"), gbc);
+        unreachableRetrySpinner = new JSpinner(new SpinnerNumberModel(configManager.getUnreachableRetrySeconds(), 0, 3600, 5));
+        unreachableRetrySpinner.addChangeListener(e -> settingsDirty = true);
+        JPanel unreachableRetryPanel = new JPanel(new FlowLayout(FlowLayout.LEFT, 0, 0));
+        unreachableRetryPanel.add(unreachableRetrySpinner);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(unreachableRetryPanel, gbc);
+
+        row++;
+        JLabel unreachableRetryHint = new JLabel("<html><i>After a connection failure, transcriptions fail immediately for this long. 0 always retries. Saving settings retries right away.</i></html>");
+        unreachableRetryHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        unreachableRetryHint.setForeground(Color.GRAY);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(unreachableRetryHint, gbc);
+
+        row++;
+
+        // Transcription request timeout
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Request timeout (s):"), gbc);
+        transcriptionTimeoutSpinner = new JSpinner(new SpinnerNumberModel(configManager.getTranscriptionTimeoutSeconds(), 0, 3600, 30));
+        transcriptionTimeoutSpinner.addChangeListener(e -> settingsDirty = true);
+        JPanel transcriptionTimeoutPanel = new JPanel(new FlowLayout(FlowLayout.LEFT, 0, 0));
+        transcriptionTimeoutPanel.add(transcriptionTimeoutSpinner);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(transcriptionTimeoutPanel, gbc);
+
+        row++;
+        JLabel transcriptionTimeoutHint = new JLabel("<html><i>How long to wait for the transcription server to answer. Raise it for long recordings or slow local servers. 0 waits forever.</i></html>");
+        transcriptionTimeoutHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        transcriptionTimeoutHint.setForeground(Color.GRAY);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(transcriptionTimeoutHint, gbc);
+
+        row++;
+
+        // Dictation mode: end of utterance
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Dictation stop after silence (ms):"), gbc);
+        dictationSilenceSpinner = new JSpinner(new SpinnerNumberModel(configManager.getDictationTrailingSilenceMs(), 300, 10000, 100));
+        dictationSilenceSpinner.addChangeListener(e -> settingsDirty = true);
+        JPanel dictationSilencePanel = new JPanel(new FlowLayout(FlowLayout.LEFT, 0, 0));
+        dictationSilencePanel.add(dictationSilenceSpinner);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(dictationSilencePanel, gbc);
+
+        row++;
+        JLabel dictationSilenceHint = new JLabel("<html><i>In dictation mode, recording starts when you speak and stops after this much silence. Speech is detected with the silence threshold.</i></html>");
+        dictationSilenceHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        dictationSilenceHint.setForeground(Color.GRAY);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(dictationSilenceHint, gbc);
+
+        row++;
+
+        // Voice-activated start
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Voice-activated start:"), gbc);
+        voiceActivatedStartSwitch = new JCheckBox("Keep audio only from the first speech on");
+        voiceActivatedStartSwitch.setToolTipText("Recordings started by hand drop the silence before you speak and run until stopped. Speech is detected with the silence threshold.");
+        voiceActivatedStartSwitch.setSelected(configManager.isVoiceActivatedStart());
+        voiceActivatedStartSwitch.addActionListener(e -> settingsDirty = true);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(voiceActivatedStartSwitch, gbc);
+
+        row++;
+
+        // Maximum recording length
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Max recording length (s):"), gbc);
+        maxRecordingSecondsSpinner = new JSpinner(new SpinnerNumberModel(configManager.getMaxRecordingSeconds(), 0, 36000, 60));
+        maxRecordingSecondsSpinner.addChangeListener(e -> settingsDirty = true);
+        JPanel maxRecordingPanel = new JPanel(new FlowLayout(FlowLayout.LEFT, 0, 0));
+        maxRecordingPanel.add(maxRecordingSecondsSpinner);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(maxRecordingPanel, gbc);
+
+        row++;
+
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        JLabel maxRecordingHint = new JLabel("<html><i>Recordings are stopped and transcribed after this long. Pauses do not count. 0 is unlimited.</i></html>");
+        maxRecordingHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        maxRecordingHint.setForeground(Color.GRAY);
+        contentPanel.add(maxRecordingHint, gbc);
+
+        row++;
+
+        // Recordings cut short by a device or disk failure
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Interrupted recording:"), gbc);
+        transcribeInterruptedSwitch = new JCheckBox("Transcribe what was captured");
+        transcribeInterruptedSwitch.setToolTipText("When the microphone disconnects or the disk fills up, the audio recorded so far is saved. Unchecked, it is only kept as a file.");
+        transcribeInterruptedSwitch.setSelected(configManager.isTranscribeInterruptedRecordings());
+        transcribeInterruptedSwitch.addActionListener(e -> settingsDirty = true);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(transcribeInterruptedSwitch, gbc);
+
+        row++;
+
+        // Back-to-back recording
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Record during transcription:"), gbc);
+        recordDuringTranscriptionSwitch = new JCheckBox("Allow a new recording while the previous one is transcribed");
+        recordDuringTranscriptionSwitch.setToolTipText("Recordings are queued and transcribed one after another, in order");
+        recordDuringTranscriptionSwitch.setSelected(configManager.isRecordDuringTranscription());
+        recordDuringTranscriptionSwitch.addActionListener(e -> settingsDirty = true);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(recordDuringTranscriptionSwitch, gbc);
+
+        row++;
+
+        // Normalize output checkbox
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Normalize output:"), gbc);
+        normalizeOutputSwitch = new JCheckBox("Trim and fix spacing around punctuation");
+        normalizeOutputSwitch.setSelected(configManager.isNormalizeOutput());
+        normalizeOutputSwitch.addActionListener(e -> settingsDirty = true);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(normalizeOutputSwitch, gbc);
+
+        row++;
+
+        // Previous transcription as prompt context
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Continuous dictation:"), gbc);
+        usePreviousAsPromptSwitch = new JCheckBox("Use end of previous transcription as prompt");
+        usePreviousAsPromptSwitch.setToolTipText("Sends the last ~200 characters to Whisper for context (OpenAI and Faster-Whisper). Reset with Clear.");
+        usePreviousAsPromptSwitch.setSelected(configManager.isUsePreviousAsPrompt());
+        usePreviousAsPromptSwitch.addActionListener(e -> settingsDirty = true);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(usePreviousAsPromptSwitch, gbc);
+
+        row++;
+
+        // Retry when the server returns nothing for audible audio
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Empty result:"), gbc);
+        retryEmptyTranscriptionSwitch = new JCheckBox("Retry once if the recording is not silent");
+        retryEmptyTranscriptionSwitch.setToolTipText("Transcribes again when the server returns no text although the audio is above the silence threshold");
+        retryEmptyTranscriptionSwitch.setSelected(configManager.isRetryEmptyTranscription());
+        retryEmptyTranscriptionSwitch.addActionListener(e -> settingsDirty = true);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(retryEmptyTranscriptionSwitch, gbc);
+
+        row++;
+
+        // Output sinks
+        Set<OutputSink> outputSinks = configManager.getOutputSinks();
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Send results to:"), gbc);
+        JPanel outputSinkPanel = new JPanel(new FlowLayout(FlowLayout.LEFT, 5, 0));
+        outputClipboardSwitch = new JCheckBox("Clipboard", outputSinks.contains(OutputSink.CLIPBOARD));
+        outputFileSwitch = new JCheckBox("File", outputSinks.contains(OutputSink.FILE));
+        outputWebhookSwitch = new JCheckBox("Webhook", outputSinks.contains(OutputSink.WEBHOOK));
+        outputClipboardSwitch.addActionListener(e -> settingsDirty = true);
+        outputFileSwitch.addActionListener(e -> settingsDirty = true);
+        outputWebhookSwitch.addActionListener(e -> settingsDirty = true);
+        outputSinkPanel.add(outputClipboardSwitch);
+        outputSinkPanel.add(outputFileSwitch);
+        outputSinkPanel.add(outputWebhookSwitch);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(outputSinkPanel, gbc);
+
+        row++;
+
+        // Output file path
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Output file:"), gbc);
+        outputFilePathField = new JTextField(configManager.getOutputFilePath(), 30);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(outputFilePathField, gbc);
+
+        row++;
+
+        // Webhook URL
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Webhook URL:"), gbc);
+        outputWebhookUrlField = new JTextField(configManager.getOutputWebhookUrl(), 30);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(outputWebhookUrlField, gbc);
 
         row++;
 
-        // Hint for threshold slider
+        JLabel outputSinkHint = new JLabel("<html><i>Results are appended to the file and POSTed as JSON {\"text\": ...} to the webhook.</i></html>");
+        outputSinkHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        outputSinkHint.setForeground(Color.GRAY);
         gbc.gridx = 1;
         gbc.gridy = row;
         gbc.gridwidth = 2;
         gbc.weightx = 1.0;
         gbc.anchor = GridBagConstraints.WEST;
-        JLabel thresholdHint = new JLabel("<html><i>Lower = conservative (removes less), Higher = aggressive (removes more)</i></html>");
-        thresholdHint.setFont(new Font("Dialog", Font.PLAIN, 10));
-        thresholdHint.setForeground(Color.GRAY);
-        contentPanel.add(thresholdHint, gbc);
+        contentPanel.add(outputSinkHint, gbc);
 
         row++;
 
-        // Minimum silence duration slider
+        // Prepend timestamp checkbox
         gbc.gridx = 0;
         gbc.gridy = row;
         gbc.gridwidth = 1;
         gbc.weightx = 0;
         gbc.anchor = GridBagConstraints.EAST;
-        JLabel durationLabel = new JLabel("Min silence duration:");
-        contentPanel.add(durationLabel, gbc);
+        contentPanel.add(new JLabel("Prepend timestamp:"), gbc);
+        prependTimestampSwitch = new JCheckBox();
+        prependTimestampSwitch.setSelected(configManager.isPrependTimestamp());
+        prependTimestampSwitch.addActionListener(e -> settingsDirty = true);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(prependTimestampSwitch, gbc);
 
-        JPanel durationPanel = new JPanel(new BorderLayout(5, 0));
-        minSilenceDurationSlider = new JSlider(500, 10000, configManager.getMinSilenceDuration());
-        minSilenceDurationSlider.setMajorTickSpacing(1000);
-        minSilenceDurationSlider.setMinorTickSpacing(500);
-        minSilenceDurationSlider.setPaintTicks(true);
-        JLabel durationValueLabel = new JLabel(configManager.getMinSilenceDuration() + "ms");
-        durationPanel.add(minSilenceDurationSlider, BorderLayout.CENTER);
-        durationPanel.add(durationValueLabel, BorderLayout.EAST);
+        row++;
 
-        minSilenceDurationSlider.addChangeListener(e -> {
-            durationValueLabel.setText(minSilenceDurationSlider.getValue() + "ms");
-            // Auto-save when slider stops moving
-            if (!minSilenceDurationSlider.getValueIsAdjusting()) {
-                configManager.setMinSilenceDuration(minSilenceDurationSlider.getValue());
-            }
+        // Timestamp format
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.EAST;
+        contentPanel.add(new JLabel("Timestamp format:"), gbc);
+        timestampFormatField = new JTextField(configManager.getTimestampFormat(), 20);
+        timestampFormatField.getDocument().addDocumentListener(new DocumentListener() {
+            @Override
+            public void insertUpdate(DocumentEvent e) { settingsDirty = true; }
+            @Override
+            public void removeUpdate(DocumentEvent e) { settingsDirty = true; }
+            @Override
+            public void changedUpdate(DocumentEvent e) { settingsDirty = true; }
         });
-
         gbc.gridx = 1;
         gbc.gridy = row;
         gbc.gridwidth = 2;
         gbc.weightx = 1.0;
         gbc.anchor = GridBagConstraints.WEST;
-        contentPanel.add(durationPanel, gbc);
+        contentPanel.add(timestampFormatField, gbc);
 
         row++;
 
-        // Hint for duration slider
+        // Hint for timestamp format
         gbc.gridx = 1;
         gbc.gridy = row;
         gbc.gridwidth = 2;
         gbc.weightx = 1.0;
         gbc.anchor = GridBagConstraints.WEST;
-        JLabel durationHint = new JLabel("<html><i>Minimum consecutive silence to remove (higher = more conservative)</i></html>");
-        durationHint.setFont(new Font("Dialog", Font.PLAIN, 10));
-        durationHint.setForeground(Color.GRAY);
-        contentPanel.add(durationHint, gbc);
+        JLabel timestampHint = new JLabel("<html><i>Java date pattern, e.g. yyyy-MM-dd HH:mm or [dd.MM.yyyy HH:mm]</i></html>");
+        timestampHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        timestampHint.setForeground(Color.GRAY);
+        contentPanel.add(timestampHint, gbc);
 
         row++;
 
-        // Minimum recording duration slider
+        // Number and time format of the UI
         gbc.gridx = 0;
         gbc.gridy = row;
         gbc.gridwidth = 1;
         gbc.weightx = 0;
         gbc.anchor = GridBagConstraints.EAST;
-        JLabel minRecDurationLabel = new JLabel("Min recording for removal:");
-        contentPanel.add(minRecDurationLabel, gbc);
+        contentPanel.add(new JLabel("Number format:"), gbc);
+        displayLocaleComboBox = new JComboBox<>();
+        for (String[] displayLocale : DISPLAY_LOCALES) {
+            displayLocaleComboBox.addItem(displayLocale[1]);
+        }
+        Locale configuredLocale = configManager.getDisplayLocale();
+        for (int i = 1; i < DISPLAY_LOCALES.length; i++) {
+            if (configuredLocale != null && DISPLAY_LOCALES[i][0].equals(configuredLocale.toLanguageTag())) {
+                displayLocaleComboBox.setSelectedIndex(i);
+            }
+        }
+        displayLocaleComboBox.setToolTipText("Decimal separator and time format for durations, levels and percentages shown in the app");
+        displayLocaleComboBox.addActionListener(e -> settingsDirty = true);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(displayLocaleComboBox, gbc);
 
-        JPanel minRecDurationPanel = new JPanel(new BorderLayout(5, 0));
-        minRecordingDurationSlider = new JSlider(0, 60, configManager.getMinRecordingDurationForSilenceRemoval());
-        minRecordingDurationSlider.setMajorTickSpacing(10);
-        minRecordingDurationSlider.setMinorTickSpacing(5);
-        minRecordingDurationSlider.setPaintTicks(true);
-        JLabel minRecDurationValueLabel = new JLabel(configManager.getMinRecordingDurationForSilenceRemoval() + "s");
-        minRecDurationPanel.add(minRecordingDurationSlider, BorderLayout.CENTER);
-        minRecDurationPanel.add(minRecDurationValueLabel, BorderLayout.EAST);
+        row++;
 
-        minRecordingDurationSlider.addChangeListener(e -> {
-            int value = minRecordingDurationSlider.getValue();
-            minRecDurationValueLabel.setText(value + "s");
-            // Auto-save when slider stops moving
-            if (!minRecordingDurationSlider.getValueIsAdjusting()) {
-                configManager.setMinRecordingDurationForSilenceRemoval(value);
-            }
+        // Meta prompt for optimized (chained) pipeline calls
+        gbc.gridx = 0;
+        gbc.gridy = row;
+        gbc.gridwidth = 1;
+        gbc.weightx = 0;
+        gbc.anchor = GridBagConstraints.NORTHEAST;
+        contentPanel.add(new JLabel("Chained prompt instruction:"), gbc);
+        chainedMetaPromptArea = new JTextArea(configManager.getChainedMetaPrompt(), 3, 30);
+        chainedMetaPromptArea.setLineWrap(true);
+        chainedMetaPromptArea.setWrapStyleWord(true);
+        chainedMetaPromptArea.getDocument().addDocumentListener(new DocumentListener() {
+            @Override
+            public void insertUpdate(DocumentEvent e) { settingsDirty = true; }
+            @Override
+            public void removeUpdate(DocumentEvent e) { settingsDirty = true; }
+            @Override
+            public void changedUpdate(DocumentEvent e) { settingsDirty = true; }
         });
-
         gbc.gridx = 1;
         gbc.gridy = row;
         gbc.gridwidth = 2;
         gbc.weightx = 1.0;
         gbc.anchor = GridBagConstraints.WEST;
-        contentPanel.add(minRecDurationPanel, gbc);
+        contentPanel.add(new JScrollPane(chainedMetaPromptArea), gbc);
 
         row++;
-
-        // Hint for minimum recording duration
+        JLabel chainedMetaPromptHint = new JLabel("<html><i>Opens the system prompt when several prompt units are combined into one API call. Empty restores the default.</i></html>");
+        chainedMetaPromptHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        chainedMetaPromptHint.setForeground(Color.GRAY);
         gbc.gridx = 1;
         gbc.gridy = row;
         gbc.gridwidth = 2;
         gbc.weightx = 1.0;
         gbc.anchor = GridBagConstraints.WEST;
-        JLabel minRecDurationHint = new JLabel("<html><i>Only apply silence removal to recordings longer than this (0 = always apply)</i></html>");
-        minRecDurationHint.setFont(new Font("Dialog", Font.PLAIN, 10));
-        minRecDurationHint.setForeground(Color.GRAY);
-        contentPanel.add(minRecDurationHint, gbc);
+        contentPanel.add(chainedMetaPromptHint, gbc);
 
         row++;
 
-        // Keep compressed files checkbox
+        // Output length of chat completions
         gbc.gridx = 0;
         gbc.gridy = row;
         gbc.gridwidth = 1;
         gbc.weightx = 0;
         gbc.anchor = GridBagConstraints.EAST;
-        contentPanel.add(new JLabel("Keep compressed files:"), gbc);
-        keepCompressedSwitch = new JCheckBox();
-        keepCompressedSwitch.setSelected(configManager.isKeepCompressedFile());
+        contentPanel.add(new JLabel("Max output tokens:"), gbc);
+        chatMaxTokensSpinner = new JSpinner(new SpinnerNumberModel(configManager.getChatMaxTokens(), 0, 128000, 256));
+        chatMaxTokensSpinner.addChangeListener(e -> settingsDirty = true);
+        JPanel chatMaxTokensPanel = new JPanel(new FlowLayout(FlowLayout.LEFT, 0, 0));
+        chatMaxTokensPanel.add(chatMaxTokensSpinner);
         gbc.gridx = 1;
         gbc.gridy = row;
         gbc.gridwidth = 2;
         gbc.weightx = 1.0;
         gbc.anchor = GridBagConstraints.WEST;
-        contentPanel.add(keepCompressedSwitch, gbc);
+        contentPanel.add(chatMaxTokensPanel, gbc);
+
+        row++;
+        JLabel chatMaxTokensHint = new JLabel("<html><i>Sent as max_tokens with every pipeline API call. 0 uses the provider's default. Raise it if long outputs are cut off.</i></html>");
+        chatMaxTokensHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        chatMaxTokensHint.setForeground(Color.GRAY);
+        gbc.gridx = 1;
+        gbc.gridy = row;
+        gbc.gridwidth = 2;
+        gbc.weightx = 1.0;
+        gbc.anchor = GridBagConstraints.WEST;
+        contentPanel.add(chatMaxTokensHint, gbc);
 
         row++;
 
@@ -502,6 +1635,62 @@ public class SettingsForm extends JPanel {
         apiGbc.anchor = GridBagConstraints.WEST;
         apiSettingsPanel.add(modelsHintLabel, apiGbc);
 
+        apiRow++;
+
+// ----- OpenAI base URL (proxy or gateway) -----
+        apiGbc.gridx = 0;
+        apiGbc.gridy = apiRow;
+        apiGbc.gridwidth = 1;
+        apiGbc.weightx = 0;
+        apiGbc.anchor = GridBagConstraints.EAST;
+        apiSettingsPanel.add(new JLabel("OpenAI Base URL:"), apiGbc);
+        openaiBaseUrlField = new JTextField(20);
+        apiGbc.gridx = 1;
+        apiGbc.gridy = apiRow;
+        apiGbc.gridwidth = 2;
+        apiGbc.weightx = 1.0;
+        apiGbc.anchor = GridBagConstraints.WEST;
+        apiSettingsPanel.add(openaiBaseUrlField, apiGbc);
+
+        apiRow++;
+
+        JLabel baseUrlHintLabel = new JLabel("Optional proxy or gateway for all OpenAI calls (e.g., https://litellm.example.com/v1). Empty uses api.openai.com");
+        baseUrlHintLabel.setFont(new Font("Dialog", Font.ITALIC, 10));
+        baseUrlHintLabel.setForeground(Color.GRAY);
+        apiGbc.gridx = 1;
+        apiGbc.gridy = apiRow;
+        apiGbc.gridwidth = 2;
+        apiGbc.anchor = GridBagConstraints.WEST;
+        apiSettingsPanel.add(baseUrlHintLabel, apiGbc);
+
+        apiRow++;
+
+// ----- OpenAI-compatible chat base URL -----
+        apiGbc.gridx = 0;
+        apiGbc.gridy = apiRow;
+        apiGbc.gridwidth = 1;
+        apiGbc.weightx = 0;
+        apiGbc.anchor = GridBagConstraints.EAST;
+        apiSettingsPanel.add(new JLabel("Chat Base URL:"), apiGbc);
+        openaiChatBaseUrlField = new JTextField(20);
+        apiGbc.gridx = 1;
+        apiGbc.gridy = apiRow;
+        apiGbc.gridwidth = 2;
+        apiGbc.weightx = 1.0;
+        apiGbc.anchor = GridBagConstraints.WEST;
+        apiSettingsPanel.add(openaiChatBaseUrlField, apiGbc);
+
+        apiRow++;
+
+        JLabel chatBaseUrlHintLabel = new JLabel("Optional OpenAI-compatible endpoint for pipelines (e.g., http://localhost:1234/v1). Empty uses the OpenAI Base URL");
+        chatBaseUrlHintLabel.setFont(new Font("Dialog", Font.ITALIC, 10));
+        chatBaseUrlHintLabel.setForeground(Color.GRAY);
+        apiGbc.gridx = 1;
+        apiGbc.gridy = apiRow;
+        apiGbc.gridwidth = 2;
+        apiGbc.anchor = GridBagConstraints.WEST;
+        apiSettingsPanel.add(chatBaseUrlHintLabel, apiGbc);
+
         apiRow++;
 // ----- Separator between OpenAI and Grok -----
         JSeparator grokSeperator = new JSeparator();
@@ -578,6 +1767,48 @@ public class SettingsForm extends JPanel {
         apiGbc.anchor = GridBagConstraints.WEST;
         apiSettingsPanel.add(openwebUIApiURLField, apiGbc);
 
+        apiRow++;
+
+// ----- OpenWebUI CA certificate -----
+        apiGbc.gridx = 0;
+        apiGbc.gridy = apiRow;
+        apiGbc.gridwidth = 1;
+        apiGbc.weightx = 0;
+        apiGbc.anchor = GridBagConstraints.EAST;
+        apiSettingsPanel.add(new JLabel("OpenWebUI CA certificate:"), apiGbc);
+        openwebUICaCertField = new JTextField(20);
+        openwebUICaCertField.setToolTipText("Path to a PEM file with the CA or self-signed certificate of your server");
+        apiGbc.gridx = 1;
+        apiGbc.gridy = apiRow;
+        apiGbc.gridwidth = 2;
+        apiGbc.weightx = 1.0;
+        apiGbc.anchor = GridBagConstraints.WEST;
+        apiSettingsPanel.add(openwebUICaCertField, apiGbc);
+
+        apiRow++;
+
+        openwebUIAcceptInvalidCertsSwitch = new JCheckBox("Accept invalid certificates");
+        openwebUIAcceptInvalidCertsSwitch.setToolTipText("Insecure: skips certificate verification entirely. Only for self-signed servers; ignored when a CA certificate is set.");
+        openwebUIAcceptInvalidCertsSwitch.addActionListener(e -> settingsDirty = true);
+        apiGbc.gridx = 1;
+        apiGbc.gridy = apiRow;
+        apiGbc.gridwidth = 2;
+        apiGbc.weightx = 1.0;
+        apiGbc.anchor = GridBagConstraints.WEST;
+        apiSettingsPanel.add(openwebUIAcceptInvalidCertsSwitch, apiGbc);
+
+        apiRow++;
+
+        JLabel openwebUICertHint = new JLabel("<html><i>With a CA certificate, connections are fully verified against it and the system CAs. Prefer it over accepting invalid certificates.</i></html>");
+        openwebUICertHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        openwebUICertHint.setForeground(Color.GRAY);
+        apiGbc.gridx = 1;
+        apiGbc.gridy = apiRow;
+        apiGbc.gridwidth = 2;
+        apiGbc.weightx = 1.0;
+        apiGbc.anchor = GridBagConstraints.WEST;
+        apiSettingsPanel.add(openwebUICertHint, apiGbc);
+
 // Add the API Settings panel to the content panel
         gbc.gridx = 0;
         gbc.gridy = row;
@@ -597,7 +1828,9 @@ public class SettingsForm extends JPanel {
         gbc.weightx = 0;
         gbc.anchor = GridBagConstraints.EAST;
         contentPanel.add(whisperServerLabel, gbc);
-        String[] whisperServers = {SERVER_OPENAI, SERVER_FASTER_WHISPER, OPEN_WEB_UI}; // TODO: Add GROQ
+        String[] whisperServers = TranscriptionProviders.all().stream()
+                .map(TranscriptionProviderSpec::getName)
+                .toArray(String[]::new);
         whisperServerComboBox = new JComboBox<>(whisperServers);
         gbc.gridx = 1;
         gbc.gridy = row;
@@ -674,9 +1907,30 @@ public class SettingsForm extends JPanel {
         fwGbc.weightx = 1.0;
         fwGbc.anchor = GridBagConstraints.WEST;
         fasterWhispererPanel.add(fasterWhisperLanguageComboBox, fwGbc);
+        fwRow++;
+
+        // Suggested model for the selected language (only shown if a better fit exists)
+        JPanel recommendationPanel = new JPanel(new FlowLayout(FlowLayout.LEFT, 0, 0));
+        languageRecommendationLabel = new JLabel();
+        languageRecommendationLabel.setFont(new Font("Dialog", Font.PLAIN, 10));
+        languageRecommendationLabel.setForeground(Color.GRAY);
+        useRecommendedModelButton = new JButton("Use");
+        useRecommendedModelButton.setMargin(new Insets(0, 6, 0, 6));
+        useRecommendedModelButton.addActionListener(e -> applyRecommendedModel());
+        recommendationPanel.add(languageRecommendationLabel);
+        recommendationPanel.add(Box.createHorizontalStrut(8));
+        recommendationPanel.add(useRecommendedModelButton);
+        recommendationPanel.setVisible(false);
+        fwGbc.gridx = 1;
+        fwGbc.gridy = fwRow;
+        fwGbc.gridwidth = 2;
+        fwGbc.weightx = 1.0;
+        fwGbc.anchor = GridBagConstraints.WEST;
+        fasterWhispererPanel.add(recommendationPanel, fwGbc);
 
         // Action listener to update available languages whenever the model selection changes.
         fasterWhisperModelComboBox.addActionListener(e -> updateFasterWhisperLanguages());
+        fasterWhisperLanguageComboBox.addActionListener(e -> updateLanguageRecommendation());
 
         // ----- Initialize Groq Panel -----
         groqPanel = new JPanel(new GridBagLayout());
@@ -741,17 +1995,86 @@ public class SettingsForm extends JPanel {
         JLabel openWebUInoSettingsLabel = new JLabel("No configuration required at this time :-)");
         openWebUIPanel.add(openWebUInoSettingsLabel, openaiGbc);
 
+        // ----- Initialize whisper.cpp Panel -----
+        whisperCppPanel = new JPanel(new GridBagLayout());
+        GridBagConstraints whisperCppGbc = new GridBagConstraints();
+        whisperCppGbc.insets = new Insets(5, 5, 5, 5);
+        whisperCppGbc.fill = GridBagConstraints.HORIZONTAL;
+        int whisperCppRow = 0;
+        whisperCppGbc.gridx = 0;
+        whisperCppGbc.gridy = whisperCppRow;
+        whisperCppGbc.gridwidth = 1;
+        whisperCppGbc.weightx = 0;
+        whisperCppGbc.anchor = GridBagConstraints.EAST;
+        whisperCppPanel.add(new JLabel("Server URL:"), whisperCppGbc);
+        whisperCppServerUrlField = new JTextField(20);
+        whisperCppServerUrlField.getDocument().addDocumentListener(new DocumentListener() {
+            @Override
+            public void insertUpdate(DocumentEvent e) { settingsDirty = true; }
+            @Override
+            public void removeUpdate(DocumentEvent e) { settingsDirty = true; }
+            @Override
+            public void changedUpdate(DocumentEvent e) { settingsDirty = true; }
+        });
+        whisperCppGbc.gridx = 1;
+        whisperCppGbc.gridy = whisperCppRow;
+        whisperCppGbc.gridwidth = 2;
+        whisperCppGbc.weightx = 1.0;
+        whisperCppGbc.anchor = GridBagConstraints.WEST;
+        whisperCppPanel.add(whisperCppServerUrlField, whisperCppGbc);
+        whisperCppRow++;
+
+        JLabel whisperCppHintLabel = new JLabel("Example: http://127.0.0.1:8080 (whisper.cpp server, transcribes via /inference)");
+        whisperCppHintLabel.setFont(new Font("Dialog", Font.ITALIC, 10));
+        whisperCppHintLabel.setForeground(Color.GRAY);
+        whisperCppGbc.gridx = 1;
+        whisperCppGbc.gridy = whisperCppRow;
+        whisperCppGbc.gridwidth = 2;
+        whisperCppGbc.anchor = GridBagConstraints.WEST;
+        whisperCppPanel.add(whisperCppHintLabel, whisperCppGbc);
+
         // Add sub-panels to the card layout panel
         whisperSettingsPanel.add(openaiPanel, SERVER_OPENAI);
         whisperSettingsPanel.add(fasterWhispererPanel, SERVER_FASTER_WHISPER);
         whisperSettingsPanel.add(openWebUIPanel, OPEN_WEB_UI);
         whisperSettingsPanel.add(groqPanel, SERVER_GROQ);
+        whisperSettingsPanel.add(whisperCppPanel, TranscriptionProviders.WHISPER_CPP);
 
         gbc.gridx = 0;
         gbc.gridy = row;
         gbc.gridwidth = 4;
         gbc.weightx = 1.0;
         whisperContainerPanel.add(whisperSettingsPanel, BorderLayout.CENTER);
+
+        // Extra form fields for the selected provider, edited as name=value lines
+        JPanel extraFieldsPanel = new JPanel(new GridBagLayout());
+        GridBagConstraints extraGbc = new GridBagConstraints();
+        extraGbc.insets = new Insets(5, 5, 5, 5);
+        extraGbc.fill = GridBagConstraints.HORIZONTAL;
+        extraGbc.gridx = 0;
+        extraGbc.gridy = 0;
+        extraGbc.weightx = 0;
+        extraGbc.anchor = GridBagConstraints.NORTHEAST;
+        extraFieldsPanel.add(new JLabel("Extra form fields:"), extraGbc);
+        extraFormFieldsArea = new JTextArea(3, 20);
+        extraFormFieldsArea.getDocument().addDocumentListener(new DocumentListener() {
+            @Override
+            public void insertUpdate(DocumentEvent e) { settingsDirty = true; }
+            @Override
+            public void removeUpdate(DocumentEvent e) { settingsDirty = true; }
+            @Override
+            public void changedUpdate(DocumentEvent e) { settingsDirty = true; }
+        });
+        extraGbc.gridx = 1;
+        extraGbc.weightx = 1.0;
+        extraGbc.anchor = GridBagConstraints.WEST;
+        extraFieldsPanel.add(new JScrollPane(extraFormFieldsArea), extraGbc);
+        JLabel extraFieldsHint = new JLabel("<html><i>One name=value per line, e.g. vad_filter=true or beam_size=5. Sent with every request to this server.</i></html>");
+        extraFieldsHint.setFont(new Font("Dialog", Font.PLAIN, 10));
+        extraFieldsHint.setForeground(Color.GRAY);
+        extraGbc.gridy = 1;
+        extraFieldsPanel.add(extraFieldsHint, extraGbc);
+        whisperContainerPanel.add(extraFieldsPanel, BorderLayout.SOUTH);
         contentPanel.add(whisperContainerPanel, gbc);
 
         // Add an ItemListener to switch cards based on Whisper Server selection
@@ -760,6 +2083,7 @@ public class SettingsForm extends JPanel {
                 CardLayout cl = (CardLayout) (whisperSettingsPanel.getLayout());
                 String selectedServer = (String) whisperServerComboBox.getSelectedItem();
                 cl.show(whisperSettingsPanel, selectedServer);
+                showExtraFormFields(selectedServer);
             }
         });
         // Set initial card based on default selection
@@ -787,6 +2111,19 @@ public class SettingsForm extends JPanel {
         autoSaveNote.setForeground(Color.GRAY);
         buttonPanel.add(autoSaveNote);
 
+        // Briefly shown whenever the configuration has been written to disk
+        JLabel savedIndicator = new JLabel("\u2713 Saved");
+        savedIndicator.setFont(new Font("Dialog", Font.PLAIN, 10));
+        savedIndicator.setForeground(new Color(76, 175, 80));
+        savedIndicator.setVisible(false);
+        buttonPanel.add(savedIndicator);
+        javax.swing.Timer hideSavedIndicator = new javax.swing.Timer(2000, e -> savedIndicator.setVisible(false));
+        hideSavedIndicator.setRepeats(false);
+        configManager.addSaveListener(() -> SwingUtilities.invokeLater(() -> {
+            savedIndicator.setVisible(true);
+            hideSavedIndicator.restart();
+        }));
+
         gbc.gridx = 0;
         gbc.gridy = row++;
         gbc.gridwidth = 3;
@@ -816,6 +2153,23 @@ public class SettingsForm extends JPanel {
         );
     }
 
+    /**
+     * Keeps the edited extra form fields of the previous provider and shows those of the given one.
+     */
+    private void showExtraFormFields(String provider) {
+        if (extraFormFieldsProvider != null) {
+            extraFormFieldsByProvider.put(extraFormFieldsProvider, extraFormFieldsArea.getText());
+        }
+        if (provider == null || provider.equals(extraFormFieldsProvider)) {
+            return;
+        }
+        extraFormFieldsProvider = provider;
+        boolean wasDirty = settingsDirty;
+        extraFormFieldsArea.setText(extraFormFieldsByProvider.getOrDefault(provider, ""));
+        extraFormFieldsArea.setCaretPosition(0);
+        settingsDirty = wasDirty;
+    }
+
     private void updateFasterWhisperLanguages() {
         String selectedModel = (String) fasterWhisperModelComboBox.getSelectedItem();
         List<String> languages = fastModelLanguages.getOrDefault(selectedModel, Arrays.asList(""));
@@ -831,22 +2185,72 @@ public class SettingsForm extends JPanel {
         }
     }
 
-    private void startAudioTest(String microphoneName) {
-        testMicrophoneButton.setEnabled(false);
-        format = configManager.getAudioFormat();
+    /**
+     * Shows a hint if a different model is known to transcribe the selected language better.
+     * The suggestion is never applied automatically.
+     */
+    private void updateLanguageRecommendation() {
+        LanguageModelRecommendation recommendation = getLanguageRecommendation();
+        JComponent panel = (JComponent) languageRecommendationLabel.getParent();
+        if (recommendation == null) {
+            panel.setVisible(false);
+            return;
+        }
+        String text = "Recommended: " + recommendation.getModel();
+        if (recommendation.getNote() != null && !recommendation.getNote().isEmpty()) {
+            text += " (" + recommendation.getNote() + ")";
+        }
+        languageRecommendationLabel.setText("<html><i>" + text + "</i></html>");
+        panel.setVisible(true);
+        panel.revalidate();
+    }
+
+    private LanguageModelRecommendation getLanguageRecommendation() {
+        return LanguageModelRecommendations.recommend(languageRecommendations,
+                (String) fasterWhisperLanguageComboBox.getSelectedItem(),
+                (String) fasterWhisperModelComboBox.getSelectedItem(),
+                fastModelLanguages.keySet());
+    }
+
+    private void applyRecommendedModel() {
+        LanguageModelRecommendation recommendation = getLanguageRecommendation();
+        if (recommendation == null) {
+            return;
+        }
+        String language = recommendation.getLanguage();
+        fasterWhisperModelComboBox.setSelectedItem(recommendation.getModel());
+        fasterWhisperLanguageComboBox.setSelectedItem(language);
+        settingsDirty = true;
+        updateLanguageRecommendation();
+    }
+
+    /**
+     * Opens the selected microphone and starts metering it.
+     *
+     * @return false if the microphone could not be opened; the reason was shown to the user
+     */
+    private boolean startAudioTest(String microphoneName) {
+        AudioFormat configuredFormat = configManager.getAudioFormat();
+        format = configuredFormat;
         try {
             Mixer.Info mixerInfo = getMixerInfoByName(microphoneName);
             if (mixerInfo == null) {
                 Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR,
                         "Microphone not found.");
-                return;
+                return false;
             }
             Mixer mixer = AudioSystem.getMixer(mixerInfo);
+            // Meter both channels separately if the device can deliver stereo
+            AudioFormat stereoFormat = new AudioFormat(configuredFormat.getSampleRate(),
+                    configuredFormat.getSampleSizeInBits(), 2, true, configuredFormat.isBigEndian());
+            if (mixer.isLineSupported(new DataLine.Info(TargetDataLine.class, stereoFormat))) {
+                format = stereoFormat;
+            }
             DataLine.Info dataLineInfo = new DataLine.Info(TargetDataLine.class, format);
             if (!AudioSystem.isLineSupported(dataLineInfo)) {
                 Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR,
                         "Audio Line not supported. Please select another device.");
-                return;
+                return false;
             }
             line = (TargetDataLine) mixer.getLine(dataLineInfo);
             int maxAttempts = 3;
@@ -864,40 +2268,66 @@ public class SettingsForm extends JPanel {
                         } catch (InterruptedException ie) {
                             Thread.currentThread().interrupt();
                             logger.error("Interrupted while waiting to retry opening microphone line", ie);
-                            return;
+                            line = null;
+                            return false;
                         }
                     } else {
                         logger.error("Mic Line not available after " + maxAttempts + " attempts.", ex);
                         Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR,
                                 "Please try again selecting this mic.");
-                        return;
+                        line = null;
+                        return false;
                     }
                 }
             }
             line.start();
+            levelHistory.clear();
+            lastClipMs = 0;
             testWorker = new TestWorker();
             testWorker.execute();
+            return true;
         } catch (LineUnavailableException ex) {
             logger.error("Mic Line is not available. Please select another device.", ex);
             Notificationmanager.getInstance().showNotification(ToastNotification.Type.ERROR,
                     "Mic Line is not available. Please select another device.");
+            line = null;
+            return false;
         }
     }
 
+    /**
+     * Ends the microphone test and releases the device.
+     */
     public void stopAudioTest() {
-        testMicrophoneButton.setEnabled(true);
+        testMicrophoneButton.setText("Test");
         if (testWorker != null && !testWorker.isDone()) {
             testWorker.cancel(true);
         }
         if (line != null) {
-            line.stop();
-            line.close();
+            releaseLine(line);
             line = null;
         }
         volumeBar.setVisible(false);
+        rightVolumeBar.setVisible(false);
+        levelSparkline.setVisible(false);
+        levelReadoutPanel.setVisible(false);
         stopTestButton.setVisible(false);
     }
 
+    /**
+     * Stops and closes a microphone line so other applications and the recorder can open the
+     * device again. The line is closed even if stopping it fails.
+     */
+    static void releaseLine(TargetDataLine testLine) {
+        try {
+            testLine.stop();
+        } catch (RuntimeException e) {
+            logger.warn("Failed to stop the microphone test line", e);
+        } finally {
+            testLine.close();
+        }
+    }
+
     private Mixer.Info getMixerInfoByName(String name) {
         Mixer.Info[] mixers = AudioSystem.getMixerInfo();
         for (Mixer.Info mixer : mixers) {
@@ -908,57 +2338,90 @@ public class SettingsForm extends JPanel {
         return null;
     }
 
-    private class TestWorker extends SwingWorker<Void, Integer> {
+    /**
+     * Levels of one buffer read during the mic test.
+     */
+    private static class TestReading {
+        final double[] rms;
+        final boolean clipped;
+
+        TestReading(double[] rms, boolean clipped) {
+            this.rms = rms;
+            this.clipped = clipped;
+        }
+    }
+
+    /**
+     * Reads the test line and publishes the level of each channel and whether it clipped.
+     */
+    private class TestWorker extends SwingWorker<Void, TestReading> {
+        // How long the clip indicator stays lit after the last clipped buffer
+        private static final long CLIP_HOLD_MS = 1000;
+        private final AudioFormat testFormat = format;
+        // The field is cleared when the test stops, so keep the line this worker reads from
+        private final TargetDataLine testLine = line;
+
         @Override
         protected Void doInBackground() {
             byte[] buffer = new byte[1024];
-            while (!isCancelled()) {
-                int bytesRead = line.read(buffer, 0, buffer.length);
+            while (!isCancelled() && testLine.isOpen()) {
+                int bytesRead = testLine.read(buffer, 0, buffer.length);
                 if (bytesRead > 0) {
-                    double rms = calculateRMS(buffer, bytesRead);
-                    int volume = (int) (rms * 100);
-                    publish(volume);
+                    publish(new TestReading(
+                            ChannelLevels.rmsPerChannel(buffer, bytesRead, testFormat),
+                            ChannelLevels.isClipping(buffer, bytesRead, testFormat.isBigEndian())));
                 }
             }
             return null;
         }
         @Override
-        protected void process(List<Integer> chunks) {
-            int latestVolume = chunks.get(chunks.size() - 1);
-            volumeBar.setValue(latestVolume);
-            volumeBar.setString(latestVolume + " %");
+        protected void process(List<TestReading> chunks) {
+            TestReading latest = chunks.get(chunks.size() - 1);
+            int[] volumes = toPercent(latest.rms);
+            boolean stereo = volumes.length > 1;
+            volumeBar.setValue(volumes[0]);
+            volumeBar.setString((stereo ? "L " : "") + volumes[0] + " %");
+            rightVolumeBar.setVisible(stereo);
+            if (stereo) {
+                rightVolumeBar.setValue(volumes[1]);
+                rightVolumeBar.setString("R " + volumes[1] + " %");
+            }
+            for (TestReading reading : chunks) {
+                levelHistory.push(Arrays.stream(toPercent(reading.rms)).max().orElse(0));
+                if (reading.clipped) {
+                    lastClipMs = System.currentTimeMillis();
+                }
+            }
+            levelSparkline.repaint();
+
+            double peakRms = Arrays.stream(latest.rms).max().orElse(0);
+            levelReadoutLabel.setText(DisplayFormat.format("%6.1f dBFS", ChannelLevels.toDecibels(peakRms)));
+            boolean clipLit = System.currentTimeMillis() - lastClipMs < CLIP_HOLD_MS;
+            clipLabel.setForeground(clipLit ? Color.RED : Color.LIGHT_GRAY);
         }
         @Override
         protected void done() {
             volumeBar.setValue(0);
+            rightVolumeBar.setValue(0);
+            levelReadoutLabel.setText("");
+            clipLabel.setForeground(Color.LIGHT_GRAY);
         }
-        private double calculateRMS(byte[] audioData, int bytesRead) {
-            long sum = 0;
-            for (int i = 0; i < bytesRead; i += 2) {
-                if (i + 1 < bytesRead) {
-                    int sample = (audioData[i + 1] << 8) | (audioData[i] & 0xFF);
-                    sum += (long) sample * sample;
-                }
+
+        private int[] toPercent(double[] rms) {
+            int[] volumes = new int[rms.length];
+            for (int channel = 0; channel < rms.length; channel++) {
+                volumes[channel] = (int) (rms[channel] * 100);
             }
-            double rms = Math.sqrt(sum / (bytesRead / 2));
-            return Math.min(rms / 32768.0, 1.0);
+            return volumes;
         }
     }
 
     public static String formatKeyCombination(String keyCombination) {
-        return Arrays.stream(keyCombination.split(","))
-                .map(String::trim)
-                .map(Integer::parseInt)
-                .map(NativeKeyEvent::getKeyText)
-                .collect(Collectors.joining(" + "));
+        return Hotkey.parse(keyCombination).toDisplayString();
     }
 
     public static String formatKeySequence(String keySequence) {
-        return Arrays.stream(keySequence.split(","))
-                .map(String::trim)
-                .map(Integer::parseInt)
-                .map(NativeKeyEvent::getKeyText)
-                .collect(Collectors.joining(" + "));
+        return Hotkey.parse(keySequence).toDisplayString();
     }
 
     public String[] getAvailableMicrophones() {
@@ -987,10 +2450,22 @@ public class SettingsForm extends JPanel {
                     logger.info("Mixer does not support format: " + mixerInfo.getName());
                     return false;
                 })
+                // Microphones first, system audio (loopback) devices after them
+                .sorted(Comparator.comparing(i -> AudioDeviceKind.classify(i.getName(), i.getDescription())))
                 .map(i -> i.getName() + " Description: " + i.getDescription())
                 .toArray(String[]::new);
     }
 
+    /**
+     * Checks whether an entry of the device picker captures system audio.
+     */
+    private static boolean isLoopbackDevice(String entry) {
+        int separator = entry.indexOf(" Description: ");
+        String name = separator >= 0 ? entry.substring(0, separator) : entry;
+        String description = separator >= 0 ? entry.substring(separator + " Description: ".length()) : "";
+        return AudioDeviceKind.classify(name, description) == AudioDeviceKind.LOOPBACK;
+    }
+
     private void loadSettings() {
         // Load key combination and key sequence settings
         String keyCombination = configManager.getKeyCombination();
@@ -999,22 +2474,24 @@ public class SettingsForm extends JPanel {
             keyCombinationTextField.setKeysDisplayed(new HashSet<>());
         } else {
             keyCombinationTextField.setText(formatKeyCombination(keyCombination));
-            Set<Integer> keySet = Arrays.stream(keyCombination.split(","))
-                    .map(String::trim)
-                    .map(Integer::parseInt)
-                    .collect(Collectors.toSet());
+            Set<Integer> keySet = new HashSet<>(Hotkey.parse(keyCombination).getKeyCodes());
             keyCombinationTextField.setKeysDisplayed(keySet);
         }
+        String pipelineKeyCombination = configManager.getPipelineKeyCombination();
+        if (pipelineKeyCombination.isEmpty()) {
+            pipelineKeyTextField.setText("");
+            pipelineKeyTextField.setKeysDisplayed(new HashSet<>());
+        } else {
+            pipelineKeyTextField.setText(formatKeyCombination(pipelineKeyCombination));
+            pipelineKeyTextField.setKeysDisplayed(new HashSet<>(Hotkey.parse(pipelineKeyCombination).getKeyCodes()));
+        }
         String keySequence = configManager.getProperty("keySequence");
         if (keySequence == null || keySequence.isEmpty()) {
             keySequenceTextField.setText("");
             keySequenceTextField.setKeysDisplayed(new ArrayList<>());
         } else {
             keySequenceTextField.setText(formatKeySequence(keySequence));
-            List<Integer> sequenceSet = Arrays.stream(keySequence.split(","))
-                    .map(String::trim)
-                    .map(Integer::parseInt)
-                    .collect(Collectors.toList());
+            List<Integer> sequenceSet = new ArrayList<>(Hotkey.parse(keySequence).getKeyCodes());
             keySequenceTextField.setKeysDisplayed(sequenceSet);
         }
 
@@ -1022,12 +2499,16 @@ public class SettingsForm extends JPanel {
         openaiApiKeyField.setText(apiKey != null ? apiKey : "");
         String customModels = configManager.getCustomOpenAIModelsString();
         customOpenAIModelsField.setText(customModels != null ? customModels : "");
+        openaiBaseUrlField.setText(configManager.getOpenAIBaseUrl());
+        openaiChatBaseUrlField.setText(configManager.getOpenAIChatBaseUrl());
         String grokApiKey = configManager.getProperty("grokApiKey");
         grokApiKeyField.setText(apiKey != null ? grokApiKey : "");
         String openwebUIApiKey = configManager.getOpenWebUIApiKey();
         openwebUIApiKeyField.setText(openwebUIApiKey != null ? openwebUIApiKey : "");
         String openwebUIApiURL = configManager.getOpenWebUIServerUrl();
         openwebUIApiURLField.setText(openwebUIApiURL != null ? openwebUIApiURL : "");
+        openwebUICaCertField.setText(configManager.getOpenWebUICaCertPath());
+        openwebUIAcceptInvalidCertsSwitch.setSelected(configManager.isOpenWebUIAcceptInvalidCerts());
 
         // Microphone and bitrate settings
         String selectedMicrophone = configManager.getProperty("selectedMicrophone");
@@ -1038,13 +2519,21 @@ public class SettingsForm extends JPanel {
         boolean isFinishSoundEnabled = Boolean.parseBoolean(finishSound);
         stopSoundSwitch.setSelected(isFinishSoundEnabled);
         // Load Whisper Server selection settings
+        extraFormFieldsByProvider.clear();
+        extraFormFieldsProvider = null;
+        for (TranscriptionProviderSpec spec : TranscriptionProviders.all()) {
+            extraFormFieldsByProvider.put(spec.getName(),
+                    ConfigManager.formatFormFields(configManager.getExtraFormFields(spec.getName())));
+        }
         String whisperServer = configManager.getProperty("whisperServer");
         if (whisperServer != null && !whisperServer.isEmpty()) {
             whisperServerComboBox.setSelectedItem(whisperServer);
         }
+        showExtraFormFields((String) whisperServerComboBox.getSelectedItem());
         // Load Faster-Whisperer settings
         String serverUrl = configManager.getFasterWhisperServerUrl();
         whisperServerUrlField.setText(serverUrl != null ? serverUrl : "");
+        whisperCppServerUrlField.setText(configManager.getWhisperCppServerUrl());
         String fasterModel = configManager.getProperty("fasterWhisperModel");
         if (fasterModel != null) {
             fasterWhisperModelComboBox.setSelectedItem(fasterModel);
@@ -1075,14 +2564,15 @@ public class SettingsForm extends JPanel {
         }
 
         // Save key combination and sequence
-        String keyCombinationString = keyCombinationTextField.getKeysDisplayed().stream()
-                .map(String::valueOf)
-                .collect(Collectors.joining(","));
-        configManager.setProperty("keyCombination", keyCombinationString);
-        String keySequenceString = keySequenceTextField.getKeysDisplayed().stream()
-                .map(String::valueOf)
-                .collect(Collectors.joining(","));
-        configManager.setProperty("keySequence", keySequenceString);
+        Hotkey keyCombination = Hotkey.of(new ArrayList<>(keyCombinationTextField.getKeysDisplayed()));
+        configManager.setProperty("keyCombination", keyCombination.toString());
+        Hotkey keySequence = Hotkey.of(new ArrayList<>(keySequenceTextField.getKeysDisplayed()));
+        configManager.setProperty("keySequence", keySequence.toString());
+        Hotkey pipelineKeyCombination = Hotkey.of(new ArrayList<>(pipelineKeyTextField.getKeysDisplayed()));
+        configManager.setProperty("pipelineKeyCombination", pipelineKeyCombination.toString());
+        configManager.setInAppShortcutEnabled(inAppShortcutSwitch.isSelected());
+        configManager.setLocalServerEnabled(localServerSwitch.isSelected());
+        configManager.setLocalServerPort((Integer) localServerPortSpinner.getValue());
         // Save OpenAI Whisper API Key
         String openaiKey = openaiApiKeyField.getText();
         configManager.setProperty("apiKey", openaiKey);
@@ -1090,6 +2580,8 @@ public class SettingsForm extends JPanel {
         // Save Custom OpenAI Models
         String customModels = customOpenAIModelsField.getText();
         configManager.setCustomOpenAIModelsFromString(customModels);
+        configManager.setOpenAIBaseUrl(openaiBaseUrlField.getText());
+        configManager.setOpenAIChatBaseUrl(openaiChatBaseUrlField.getText());
 
         String grokApiKey = grokApiKeyField.getText();
         configManager.setProperty("grokApiKey", grokApiKey);
@@ -1099,6 +2591,8 @@ public class SettingsForm extends JPanel {
 
         String openwebUIApiURL = openwebUIApiURLField.getText();
         configManager.setOpenWebUIServerUrl(openwebUIApiURL);
+        configManager.setOpenWebUICaCertPath(openwebUICaCertField.getText());
+        configManager.setOpenWebUIAcceptInvalidCerts(openwebUIAcceptInvalidCertsSwitch.isSelected());
         // Save microphone and bitrate settings
         configManager.setProperty("selectedMicrophone", (String) microphoneComboBox.getSelectedItem());
         int selectedBitrate = (Integer) bitrateComboBox.getSelectedItem();
@@ -1108,12 +2602,17 @@ public class SettingsForm extends JPanel {
         // Save Whisper Server selection and Faster-Whisperer settings
         String selectedWhisperServer = (String) whisperServerComboBox.getSelectedItem();
         configManager.setProperty("whisperServer", selectedWhisperServer);
+        showExtraFormFields(selectedWhisperServer);
+        for (Map.Entry<String, String> entry : extraFormFieldsByProvider.entrySet()) {
+            configManager.setExtraFormFields(entry.getKey(), ConfigManager.parseFormFields(entry.getValue()));
+        }
         String serverUrl = whisperServerUrlField.getText();
         configManager.setProperty("fasterWhisperServerUrl", serverUrl);
         String fwModel = (String) fasterWhisperModelComboBox.getSelectedItem();
         configManager.setProperty("fasterWhisperModel", fwModel);
         String selectedLanguage = (String) fasterWhisperLanguageComboBox.getSelectedItem();
         configManager.setProperty("fasterWhisperLanguage", selectedLanguage);
+        configManager.setWhisperCppServerUrl(whisperCppServerUrlField.getText());
         // Save Groq settings
         String groqApiKey = groqApiKeyField.getText();
         configManager.setProperty("groqApiKey", groqApiKey);
@@ -1122,26 +2621,92 @@ public class SettingsForm extends JPanel {
 
         // Save silence removal settings
         configManager.setSilenceRemovalEnabled(silenceRemovalSwitch.isSelected());
+        configManager.setForceMonoOutput(forceMonoOutputSwitch.isSelected());
+        configManager.setNormalizeAudio(normalizeAudioSwitch.isSelected());
         configManager.setSilenceThreshold(silenceThresholdSlider.getValue() / 1000.0f);
         configManager.setMinSilenceDuration(minSilenceDurationSlider.getValue());
+        configManager.setSilencePaddingMs(silencePaddingSlider.getValue());
+        configManager.setSilenceWindowMs((Integer) silenceWindowSpinner.getValue());
         configManager.setMinRecordingDurationForSilenceRemoval(minRecordingDurationSlider.getValue());
+        configManager.setMinSilenceReductionPercent(minReductionSlider.getValue());
         configManager.setKeepCompressedFile(keepCompressedSwitch.isSelected());
+        configManager.setIntermediateFileLocation((IntermediateFileLocation) intermediateLocationComboBox.getSelectedItem());
+        configManager.setMp3SampleRate((Integer) mp3SampleRateComboBox.getSelectedItem());
+        configManager.setMp3Mono(mp3MonoSwitch.isSelected());
+        configManager.setRecordingRetentionDays((Integer) retentionDaysSpinner.getValue());
+        configManager.setAutoPasteMinConfidence((Integer) autoPasteMinConfidenceSpinner.getValue() / 100.0);
+        configManager.setAutoPasteTargetApp(autoPasteTargetAppField.getText());
+        configManager.setUnreachableRetrySeconds((Integer) unreachableRetrySpinner.getValue());
+        configManager.setTranscriptionTimeoutSeconds((Integer) transcriptionTimeoutSpinner.getValue());
+        configManager.setDictationTrailingSilenceMs((Integer) dictationSilenceSpinner.getValue());
+        configManager.setRecordDuringTranscription(recordDuringTranscriptionSwitch.isSelected());
+        configManager.setMaxRecordingSeconds((Integer) maxRecordingSecondsSpinner.getValue());
+        configManager.setTranscribeInterruptedRecordings(transcribeInterruptedSwitch.isSelected());
+        configManager.setVoiceActivatedStart(voiceActivatedStartSwitch.isSelected());
+
+        configManager.setNormalizeOutput(normalizeOutputSwitch.isSelected());
+        configManager.setUsePreviousAsPrompt(usePreviousAsPromptSwitch.isSelected());
+        configManager.setRetryEmptyTranscription(retryEmptyTranscriptionSwitch.isSelected());
+
+        // Save output sinks
+        Set<OutputSink> outputSinks = EnumSet.noneOf(OutputSink.class);
+        if (outputClipboardSwitch.isSelected()) {
+            outputSinks.add(OutputSink.CLIPBOARD);
+        }
+        if (outputFileSwitch.isSelected()) {
+            outputSinks.add(OutputSink.FILE);
+        }
+        if (outputWebhookSwitch.isSelected()) {
+            outputSinks.add(OutputSink.WEBHOOK);
+        }
+        configManager.setOutputSinks(outputSinks);
+        configManager.setOutputFilePath(outputFilePathField.getText());
+        configManager.setOutputWebhookUrl(outputWebhookUrlField.getText());
+
+        String displayLocaleTag = DISPLAY_LOCALES[Math.max(0, displayLocaleComboBox.getSelectedIndex())][0];
+        configManager.setDisplayLocale(displayLocaleTag);
+        DisplayFormat.setLocale(configManager.getDisplayLocale());
+
+        // Save timestamp prefix settings, rejecting invalid patterns
+        configManager.setPrependTimestamp(prependTimestampSwitch.isSelected());
+        String timestampFormat = timestampFormatField.getText().trim();
+        if (TranscriptFormatter.isValidTimestampFormat(timestampFormat)) {
+            configManager.setTimestampFormat(timestampFormat);
+        } else {
+            Notificationmanager.getInstance().showNotification(ToastNotification.Type.WARNING,
+                    "Invalid timestamp format. Keeping: " + configManager.getTimestampFormat());
+            timestampFormatField.setText(configManager.getTimestampFormat());
+        }
+
+        configManager.setChainedMetaPrompt(chainedMetaPromptArea.getText());
+        configManager.setChatMaxTokens((Integer) chatMaxTokensSpinner.getValue());
+        if (chainedMetaPromptArea.getText().trim().isEmpty()) {
+            chainedMetaPromptArea.setText(configManager.getChainedMetaPrompt());
+        }
 
         configManager.saveConfig();
 
         // Reset dirty flag after successful save
         settingsDirty = false;
+        // Server URLs may have changed, so contact unreachable servers again
+        ProviderHealth.getInstance().clear();
+        // and the Open WebUI server may have been upgraded
+        OpenWebUIPaths.clearCache();
 
         Notificationmanager.getInstance().showNotification(ToastNotification.Type.SUCCESS,
                 "Settings saved.");
         logger.info("Settings saved: Key shortcuts - {}, Key sequence - {}, Microphone - {}",
-                keyCombinationString, keySequenceString, microphoneComboBox.getSelectedItem());
+                keyCombination, keySequence, microphoneComboBox.getSelectedItem());
     }
 
     public KeyCombinationTextField getKeybindTextField() {
         return keyCombinationTextField;
     }
 
+    public KeyCombinationTextField getPipelineKeyTextField() {
+        return pipelineKeyTextField;
+    }
+
     public KeySequenceTextField getKeySequenceTextField() {
         return keySequenceTextField;
     }
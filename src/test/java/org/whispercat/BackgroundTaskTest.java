@@ -0,0 +1,77 @@
+package org.whispercat;
+
+import org.junit.jupiter.api.Test;
+
+import javax.swing.SwingUtilities;
+import java.io.IOException;
+import java.util.List;
+import java.util.concurrent.CopyOnWriteArrayList;
+import java.util.concurrent.CountDownLatch;
+import java.util.concurrent.ExecutionException;
+import java.util.concurrent.TimeUnit;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertInstanceOf;
+import static org.junit.jupiter.api.Assertions.assertSame;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class BackgroundTaskTest {
+
+    @Test
+    void resultGoesToSuccessHandlerOnEventDispatchThread() throws Exception {
+        List<String> calls = new CopyOnWriteArrayList<>();
+        CountDownLatch finished = new CountDownLatch(1);
+
+        BackgroundTask.run(() -> "transcript",
+                result -> calls.add("ok " + result + " edt=" + SwingUtilities.isEventDispatchThread()),
+                e -> calls.add("error " + e),
+                finished::countDown);
+
+        assertTrue(finished.await(5, TimeUnit.SECONDS));
+        assertEquals(List.of("ok transcript edt=true"), calls);
+    }
+
+    @Test
+    void exceptionGoesUnwrappedToErrorHandler() throws Exception {
+        IOException failure = new IOException("Connection refused");
+        List<Exception> errors = new CopyOnWriteArrayList<>();
+        List<String> results = new CopyOnWriteArrayList<>();
+        CountDownLatch finished = new CountDownLatch(1);
+
+        BackgroundTask.<String>run(() -> {
+            throw failure;
+        }, results::add, errors::add, finished::countDown);
+
+        assertTrue(finished.await(5, TimeUnit.SECONDS));
+        assertTrue(results.isEmpty());
+        assertEquals(1, errors.size());
+        assertSame(failure, errors.get(0));
+    }
+
+    @Test
+    void errorStaysWrappedForErrorHandler() throws Exception {
+        List<Exception> errors = new CopyOnWriteArrayList<>();
+        CountDownLatch finished = new CountDownLatch(1);
+
+        BackgroundTask.run(() -> {
+            throw new StackOverflowError("decoder recursion");
+        }, result -> { }, errors::add, finished::countDown);
+
+        assertTrue(finished.await(5, TimeUnit.SECONDS));
+        assertInstanceOf(ExecutionException.class, errors.get(0));
+        assertInstanceOf(StackOverflowError.class, errors.get(0).getCause());
+    }
+
+    @Test
+    void failingSuccessHandlerGoesToErrorHandler() throws Exception {
+        List<Exception> errors = new CopyOnWriteArrayList<>();
+        CountDownLatch finished = new CountDownLatch(1);
+
+        BackgroundTask.run(() -> "transcript", result -> {
+            throw new IllegalStateException("UI gone");
+        }, errors::add, finished::countDown);
+
+        assertTrue(finished.await(5, TimeUnit.SECONDS));
+        assertEquals("UI gone", errors.get(0).getMessage());
+    }
+}
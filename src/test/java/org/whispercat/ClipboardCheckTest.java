@@ -0,0 +1,70 @@
+package org.whispercat;
+
+import org.junit.jupiter.api.Test;
+
+import java.awt.GraphicsEnvironment;
+import java.awt.datatransfer.Clipboard;
+import java.awt.datatransfer.ClipboardOwner;
+import java.awt.datatransfer.DataFlavor;
+import java.awt.datatransfer.StringSelection;
+import java.awt.datatransfer.Transferable;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+import static org.junit.jupiter.api.Assumptions.assumeTrue;
+
+class ClipboardCheckTest {
+
+    @Test
+    void workingClipboardPassesAndKeepsPreviousText() throws Exception {
+        Clipboard clipboard = new Clipboard("test");
+        clipboard.setContents(new StringSelection("user text"), null);
+
+        ClipboardCheck.Result result = ClipboardCheck.check(clipboard, "Test backend");
+
+        assertTrue(result.isSuccess());
+        assertEquals("Test backend", result.getBackend());
+        assertEquals("user text", clipboard.getContents(null).getTransferData(DataFlavor.stringFlavor));
+    }
+
+    @Test
+    void clipboardThatDropsWritesFails() {
+        // Like a broken Wayland bridge: writes are accepted but never show up
+        Clipboard clipboard = new Clipboard("broken") {
+            @Override
+            public synchronized void setContents(Transferable contents, ClipboardOwner owner) {
+            }
+        };
+
+        ClipboardCheck.Result result = ClipboardCheck.check(clipboard, "Test backend");
+
+        assertFalse(result.isSuccess());
+        assertEquals("Clipboard could not be read back", result.getMessage());
+    }
+
+    @Test
+    void clipboardReturningOtherContentFails() {
+        Clipboard clipboard = new Clipboard("stale") {
+            @Override
+            public synchronized Transferable getContents(Object requestor) {
+                return new StringSelection("stale text");
+            }
+        };
+
+        ClipboardCheck.Result result = ClipboardCheck.check(clipboard, "Test backend");
+
+        assertFalse(result.isSuccess());
+        assertEquals("Clipboard returned different content than was written", result.getMessage());
+    }
+
+    @Test
+    void headlessRunReportsMissingDisplay() {
+        assumeTrue(GraphicsEnvironment.isHeadless(), "needs a headless environment");
+
+        ClipboardCheck.Result result = ClipboardCheck.run();
+
+        assertFalse(result.isSuccess());
+        assertTrue(result.getBackend().contains("headless"), result.getBackend());
+    }
+}
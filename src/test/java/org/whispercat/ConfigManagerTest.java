@@ -0,0 +1,342 @@
+package org.whispercat;
+
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+import org.whispercat.postprocessing.Pipeline;
+import org.whispercat.postprocessing.PipelineUnitReference;
+import org.whispercat.postprocessing.PostProcessingData;
+import org.whispercat.postprocessing.ProcessingStepData;
+import org.whispercat.postprocessing.ProcessingUnit;
+
+import java.io.File;
+import java.nio.file.Path;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.Map;
+import java.util.concurrent.CountDownLatch;
+import java.util.concurrent.TimeUnit;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertNotEquals;
+import static org.junit.jupiter.api.Assertions.assertNull;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class ConfigManagerTest {
+
+    @TempDir
+    Path tempDir;
+
+    private File configFile() {
+        return tempDir.resolve("config.properties").toFile();
+    }
+
+    @Test
+    void clampsLogPanelHeight() {
+        assertEquals(ConfigManager.MIN_LOG_PANEL_HEIGHT, ConfigManager.clampLogPanelHeight(10));
+        assertEquals(ConfigManager.MAX_LOG_PANEL_HEIGHT, ConfigManager.clampLogPanelHeight(5000));
+        assertEquals(400, ConfigManager.clampLogPanelHeight(400));
+    }
+
+    @Test
+    void logPanelHeightIsStoredClamped() {
+        ConfigManager config = new ConfigManager(configFile());
+        assertEquals(ConfigManager.DEFAULT_LOG_PANEL_HEIGHT, config.getLogPanelHeight());
+
+        config.setLogPanelHeight(5000);
+        assertEquals(ConfigManager.MAX_LOG_PANEL_HEIGHT, config.getLogPanelHeight());
+
+        config.setProperty("logPanelHeight", "not a number");
+        assertEquals(ConfigManager.DEFAULT_LOG_PANEL_HEIGHT, config.getLogPanelHeight());
+    }
+
+    @Test
+    void detachedLogStatePersists() {
+        ConfigManager config = new ConfigManager(configFile());
+        assertFalse(config.isLogDetached());
+
+        config.setLogDetached(true);
+        config.setLogPanelHeight(450);
+        config.flushPendingSave();
+
+        ConfigManager reloaded = new ConfigManager(configFile());
+        assertTrue(reloaded.isLogDetached());
+        assertEquals(450, reloaded.getLogPanelHeight());
+    }
+
+    @Test
+    void persistsOnceChangesSettle() {
+        long first = 10_000;
+        assertFalse(ConfigManager.shouldPersistNow(first + 100, first, first));
+        assertFalse(ConfigManager.shouldPersistNow(first + ConfigManager.SAVE_DEBOUNCE_MS - 1, first, first));
+        assertTrue(ConfigManager.shouldPersistNow(first + ConfigManager.SAVE_DEBOUNCE_MS, first, first));
+    }
+
+    @Test
+    void laterChangesPostponeTheWrite() {
+        long first = 10_000;
+        long last = first + 400;
+        assertFalse(ConfigManager.shouldPersistNow(first + ConfigManager.SAVE_DEBOUNCE_MS, first, last));
+        assertTrue(ConfigManager.shouldPersistNow(last + ConfigManager.SAVE_DEBOUNCE_MS, first, last));
+    }
+
+    @Test
+    void continuousChangesAreWrittenAfterMaximumDelay() {
+        long first = 10_000;
+        long now = first + ConfigManager.MAX_SAVE_DELAY_MS;
+        assertFalse(ConfigManager.shouldPersistNow(now - 1, first, now - 100));
+        assertTrue(ConfigManager.shouldPersistNow(now, first, now - 100));
+    }
+
+    @Test
+    void saveIsDeferredUntilFlushed() {
+        ConfigManager config = new ConfigManager(configFile());
+        config.setLogDetached(true);
+        assertFalse(configFile().exists());
+
+        config.flushPendingSave();
+
+        assertTrue(configFile().exists());
+        assertTrue(new ConfigManager(configFile()).isLogDetached());
+    }
+
+    @Test
+    void debouncedSaveIsWrittenInTheBackground() throws Exception {
+        ConfigManager config = new ConfigManager(configFile());
+        CountDownLatch saved = new CountDownLatch(1);
+        config.addSaveListener(saved::countDown);
+
+        config.setLogPanelHeight(420);
+        config.setLogPanelHeight(430);
+
+        assertTrue(saved.await(5, TimeUnit.SECONDS));
+        assertEquals(430, new ConfigManager(configFile()).getLogPanelHeight());
+    }
+
+    @Test
+    void localServerTokenIsGeneratedOnceAndKept() {
+        ConfigManager config = new ConfigManager(configFile());
+        String token = config.getLocalServerToken();
+        config.flushPendingSave();
+
+        assertTrue(token.length() >= 32, token);
+        assertEquals(token, config.getLocalServerToken());
+        assertEquals(token, new ConfigManager(configFile()).getLocalServerToken());
+        assertNotEquals(token, new ConfigManager(tempDir.resolve("other.properties").toFile()).getLocalServerToken());
+    }
+
+    @Test
+    void concurrentPipelineSavesKeepBothPipelines() throws Exception {
+        ConfigManager config = new ConfigManager(configFile());
+        CountDownLatch start = new CountDownLatch(1);
+        List<Thread> threads = new ArrayList<>();
+        for (int i = 0; i < 8; i++) {
+            Pipeline pipeline = new Pipeline("pipeline-" + i, "Pipeline " + i, "");
+            Thread thread = new Thread(() -> {
+                try {
+                    start.await();
+                } catch (InterruptedException e) {
+                    Thread.currentThread().interrupt();
+                }
+                config.savePipeline(pipeline);
+            });
+            thread.start();
+            threads.add(thread);
+        }
+        start.countDown();
+        for (Thread thread : threads) {
+            thread.join();
+        }
+        config.flushPendingSave();
+
+        ConfigManager reloaded = new ConfigManager(configFile());
+        assertEquals(8, reloaded.getPipelines().size());
+        for (int i = 0; i < 8; i++) {
+            assertEquals("Pipeline " + i, reloaded.getPipelineByUuid("pipeline-" + i).title);
+        }
+    }
+
+    @Test
+    void rapidSettingsAndPipelineSavesBothSurvive() throws Exception {
+        ConfigManager config = new ConfigManager(configFile());
+        Thread settings = new Thread(() -> {
+            config.setLogPanelHeight(420);
+            config.saveConfigNow();
+        });
+        Thread pipelines = new Thread(() -> {
+            config.savePipeline(new Pipeline("pipeline", "Saved from the pipeline screen", ""));
+            config.saveConfigNow();
+        });
+        settings.start();
+        pipelines.start();
+        settings.join();
+        pipelines.join();
+
+        ConfigManager reloaded = new ConfigManager(configFile());
+        assertEquals(420, reloaded.getLogPanelHeight());
+        assertEquals("Saved from the pipeline screen", reloaded.getPipelineByUuid("pipeline").title);
+        assertFalse(new File(tempDir.toFile(), "config.properties.tmp").exists());
+    }
+
+    @Test
+    void autoPasteMinConfidenceIsClamped() {
+        ConfigManager config = new ConfigManager(configFile());
+        assertEquals(0.0, config.getAutoPasteMinConfidence());
+
+        config.setAutoPasteMinConfidence(1.5);
+        assertEquals(1.0, config.getAutoPasteMinConfidence());
+
+        config.setProperty("autoPasteMinConfidence", "high");
+        assertEquals(0.0, config.getAutoPasteMinConfidence());
+    }
+
+    @Test
+    void extraFormFieldsRoundTripInOrder() {
+        Map<String, String> fields = ConfigManager.parseFormFields("vad_filter = true\r\n\n=orphan\nbeam_size=5\nflag");
+
+        assertEquals(List.of("vad_filter", "beam_size", "flag"), new ArrayList<>(fields.keySet()));
+        assertEquals("true", fields.get("vad_filter"));
+        assertEquals("", fields.get("flag"));
+        assertEquals("vad_filter=true\nbeam_size=5\nflag=", ConfigManager.formatFormFields(fields));
+
+        ConfigManager config = new ConfigManager(configFile());
+        config.setExtraFormFields("Faster-Whisper", fields);
+        config.flushPendingSave();
+        assertEquals(fields, new ConfigManager(configFile()).getExtraFormFields("Faster-Whisper"));
+        config.setExtraFormFields("Faster-Whisper", Map.of());
+        assertTrue(config.getExtraFormFields("Faster-Whisper").isEmpty());
+    }
+
+    @Test
+    void duplicatedPipelineGetsNewIdAndSharesUnits() {
+        ConfigManager config = new ConfigManager(configFile());
+        Pipeline original = new Pipeline();
+        original.uuid = "pipeline-1";
+        original.title = "Cleanup";
+        original.favorite = true;
+        original.unitReferences.add(new PipelineUnitReference("unit-1", true));
+        config.savePipeline(original);
+
+        Pipeline copy = config.duplicatePipeline("pipeline-1");
+
+        assertNotEquals("pipeline-1", copy.uuid);
+        assertEquals("Cleanup (copy)", copy.title);
+        assertFalse(copy.favorite);
+        assertEquals("unit-1", copy.unitReferences.get(0).unitUuid);
+        copy.title = "Renamed";
+        config.savePipeline(copy);
+        assertEquals("Cleanup", config.getPipelineByUuid("pipeline-1").title);
+        assertEquals(2, config.getPipelines().size());
+        assertNull(config.duplicatePipeline("missing"));
+    }
+
+    @Test
+    void duplicatedUnitGetsNewIdAndIndependentName() {
+        ConfigManager config = new ConfigManager(configFile());
+        ProcessingUnit original = new ProcessingUnit();
+        original.uuid = "unit-1";
+        original.name = "Fix grammar";
+        original.type = "Prompt";
+        original.systemPrompt = "Correct the grammar.";
+        config.saveProcessingUnit(original);
+
+        ProcessingUnit copy = config.duplicateProcessingUnit("unit-1");
+
+        assertNotEquals("unit-1", copy.uuid);
+        assertEquals("Fix grammar (copy)", copy.name);
+        assertEquals("Correct the grammar.", copy.systemPrompt);
+        copy.name = "Fix spelling";
+        config.saveProcessingUnit(copy);
+        assertEquals("Fix grammar", config.getProcessingUnitByUuid("unit-1").name);
+        assertEquals("Fix spelling", config.getProcessingUnitByUuid(copy.uuid).name);
+    }
+
+    @Test
+    void unitLibraryRoundTripsThroughExport() throws Exception {
+        ConfigManager source = new ConfigManager(configFile());
+        source.saveProcessingUnit(unit("unit-1", "Fix grammar"));
+        ProcessingUnit replacement = unit("unit-2", "Expand abbreviations");
+        replacement.type = "Text Replacement";
+        replacement.textToReplace = "\\bASAP\\b";
+        replacement.replacementText = "as soon as possible";
+        replacement.useRegex = true;
+        source.saveProcessingUnit(replacement);
+        File library = tempDir.resolve("units.json").toFile();
+
+        source.exportProcessingUnits(library);
+        ConfigManager target = new ConfigManager(tempDir.resolve("other.properties").toFile());
+        String summary = target.importProcessingUnits(ConfigManager.readProcessingUnits(library), false);
+
+        assertEquals("Imported 2 unit(s)", summary);
+        ProcessingUnit imported = target.getProcessingUnitByUuid("unit-2");
+        assertEquals("Expand abbreviations", imported.name);
+        assertEquals("\\bASAP\\b", imported.textToReplace);
+        assertTrue(imported.useRegex);
+        assertEquals("Correct the grammar.", target.getProcessingUnitByUuid("unit-1").systemPrompt);
+    }
+
+    @Test
+    void importSkipsOrOverwritesUnitWithSameId() throws Exception {
+        ConfigManager config = new ConfigManager(configFile());
+        config.saveProcessingUnit(unit("unit-1", "Local version"));
+        List<ProcessingUnit> incoming = List.of(unit("unit-1", "Shared version"), unit("unit-3", "New unit"));
+
+        assertEquals("Imported 1 unit(s), skipped 1 existing", config.importProcessingUnits(incoming, false));
+        assertEquals("Local version", config.getProcessingUnitByUuid("unit-1").name);
+
+        // unit-3 was added by the first import
+        assertEquals("Imported 2 unit(s), replaced 2 existing", config.importProcessingUnits(incoming, true));
+        assertEquals("Shared version", config.getProcessingUnitByUuid("unit-1").name);
+        assertEquals(2, config.getProcessingUnits().size());
+    }
+
+    @Test
+    void migrationSummaryCountsConvertedLegacyPipelines() {
+        ConfigManager config = new ConfigManager(configFile());
+        config.savePostProcessingData(legacyData("legacy-1", "Cleanup", legacyStep("Prompt"), legacyStep("Text Replacement")));
+        config.savePostProcessingData(legacyData("legacy-2", "Translate", legacyStep("Prompt")));
+
+        assertEquals("Migrated 2 legacy pipelines (3 units)", config.migrateOldPostProcessingData());
+        assertEquals(2, config.getPipelines().size());
+        // The summary is only reported once
+        assertNull(config.migrateOldPostProcessingData());
+    }
+
+    @Test
+    void migrationWithoutLegacyDataReportsNothing() {
+        assertNull(new ConfigManager(configFile()).migrateOldPostProcessingData());
+    }
+
+    @Test
+    void describesMigrationFailures() {
+        assertEquals("Migrated 1 legacy pipeline (1 unit), 2 could not be converted (see log)",
+                ConfigManager.describeMigration(1, 1, 2));
+    }
+
+    private static ProcessingUnit unit(String uuid, String name) {
+        ProcessingUnit unit = new ProcessingUnit();
+        unit.uuid = uuid;
+        unit.name = name;
+        unit.type = "Prompt";
+        unit.systemPrompt = "Correct the grammar.";
+        return unit;
+    }
+
+    private static PostProcessingData legacyData(String uuid, String title, ProcessingStepData... steps) {
+        PostProcessingData data = new PostProcessingData();
+        data.uuid = uuid;
+        data.title = title;
+        data.steps = new ArrayList<>(List.of(steps));
+        return data;
+    }
+
+    private static ProcessingStepData legacyStep(String type) {
+        ProcessingStepData step = new ProcessingStepData();
+        step.type = type;
+        step.provider = "OpenAI";
+        step.userPrompt = "Fix: {{input}}";
+        step.textToReplace = "a";
+        step.replacementText = "b";
+        return step;
+    }
+}
@@ -0,0 +1,38 @@
+package org.whispercat;
+
+import org.junit.jupiter.api.Test;
+
+import javax.swing.JOptionPane;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class ConfirmDialogTest {
+
+    @Test
+    void confirmButtonNamesTheActionAndCancelComesSecond() {
+        List<Object> shown = new ArrayList<>();
+
+        ConfirmDialog.confirm("Delete", options -> {
+            shown.addAll(Arrays.asList(options));
+            return 1;
+        });
+
+        assertEquals(Arrays.asList("Delete", "Cancel"), shown);
+    }
+
+    @Test
+    void choosingConfirmReturnsTrue() {
+        assertTrue(ConfirmDialog.confirm("Delete", options -> 0));
+    }
+
+    @Test
+    void cancellingOrClosingReturnsFalse() {
+        assertFalse(ConfirmDialog.confirm("Delete", options -> 1));
+        assertFalse(ConfirmDialog.confirm("Delete", options -> JOptionPane.CLOSED_OPTION));
+    }
+}
@@ -0,0 +1,60 @@
+package org.whispercat;
+
+import org.junit.jupiter.api.AfterEach;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+
+import java.nio.file.Path;
+import java.util.Calendar;
+import java.util.Date;
+import java.util.GregorianCalendar;
+import java.util.Locale;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertNull;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class DisplayFormatTest {
+
+    // 15:04:27 on an arbitrary day
+    private static final Date AFTERNOON = new GregorianCalendar(2024, Calendar.MARCH, 5, 15, 4, 27).getTime();
+
+    @TempDir
+    Path tempDir;
+
+    @AfterEach
+    void restoreSystemLocale() {
+        DisplayFormat.setLocale(null);
+    }
+
+    @Test
+    void englishUsesDecimalPointAndTwelveHourTimes() {
+        DisplayFormat.setLocale(Locale.US);
+
+        assertEquals("Removed 12.5% silence (3.2s)", DisplayFormat.format("Removed %.1f%% silence (%.1fs)", 12.5, 3.2f));
+        assertEquals("0.015", DisplayFormat.number(0.015, 3));
+        // Newer JDKs put a narrow no-break space before the AM/PM marker
+        assertEquals("3:04 PM", DisplayFormat.shortTime(AFTERNOON).replace('\u202f', ' '));
+        assertTrue(DisplayFormat.time(AFTERNOON).startsWith("3:04:27"), DisplayFormat.time(AFTERNOON));
+    }
+
+    @Test
+    void germanUsesDecimalCommaAndTwentyFourHourTimes() {
+        DisplayFormat.setLocale(Locale.GERMANY);
+
+        assertEquals("Removed 12,5% silence (3,2s)", DisplayFormat.format("Removed %.1f%% silence (%.1fs)", 12.5, 3.2f));
+        assertEquals("0,015", DisplayFormat.number(0.015, 3));
+        assertEquals("15:04", DisplayFormat.shortTime(AFTERNOON));
+        assertEquals("15:04:27", DisplayFormat.time(AFTERNOON));
+    }
+
+    @Test
+    void displayLocaleIsReadFromLanguageTag() {
+        ConfigManager config = new ConfigManager(tempDir.resolve("config.properties").toFile());
+        assertNull(config.getDisplayLocale());
+
+        config.setDisplayLocale(" de-DE ");
+
+        assertEquals(Locale.GERMANY, config.getDisplayLocale());
+    }
+}
@@ -0,0 +1,34 @@
+package org.whispercat;
+
+import org.junit.jupiter.api.Test;
+
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class ForegroundWindowTest {
+
+    @Test
+    void pastesOnlyIntoTargetApp() {
+        assertTrue(ForegroundWindow.matches("obsidian", "Daily note - Obsidian v1.5.3"));
+        assertTrue(ForegroundWindow.matches("obsidian", "obsidian.exe"));
+        assertFalse(ForegroundWindow.matches("obsidian", "Inbox - Mozilla Firefox"));
+    }
+
+    @Test
+    void anyOfSeveralTargetAppsMatches() {
+        assertTrue(ForegroundWindow.matches("obsidian, notepad", "notepad.exe"));
+        assertFalse(ForegroundWindow.matches("obsidian, , notepad", "firefox"));
+    }
+
+    @Test
+    void noTargetAppPastesEverywhere() {
+        assertTrue(ForegroundWindow.matches("", "firefox"));
+        assertTrue(ForegroundWindow.matches(" ", null));
+        assertTrue(ForegroundWindow.matches(null, "firefox"));
+    }
+
+    @Test
+    void unknownWindowDoesNotMatchTarget() {
+        assertFalse(ForegroundWindow.matches("obsidian", null));
+    }
+}
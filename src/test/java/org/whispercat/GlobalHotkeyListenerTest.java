@@ -0,0 +1,188 @@
+package org.whispercat;
+
+import com.github.kwhat.jnativehook.keyboard.NativeKeyEvent;
+import com.github.kwhat.jnativehook.keyboard.NativeKeyListener;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+
+import java.nio.file.Path;
+import java.util.ArrayList;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class GlobalHotkeyListenerTest {
+
+    @TempDir
+    Path tempDir;
+
+    @Test
+    void registersHookAndListenerOnCreation() {
+        FakeHook hook = new FakeHook();
+
+        GlobalHotkeyListener listener = new GlobalHotkeyListener(null, "29,42", "", "", hook);
+
+        assertEquals(1, hook.registrations);
+        assertEquals(List.of(listener), hook.listeners);
+    }
+
+    @Test
+    void changedHotkeyIsReregistered() {
+        FakeHook hook = new FakeHook();
+        GlobalHotkeyListener listener = new GlobalHotkeyListener(null, "29,42", "", "", hook);
+
+        assertTrue(listener.reregisterHotkeys("29,56", "", ""));
+        assertFalse(listener.reregisterHotkeys("29,56", "", ""));
+        assertTrue(listener.reregisterHotkeys("29,56", "42,42", ""));
+    }
+
+    @Test
+    void unchangedHotkeyIsNotReregistered() {
+        FakeHook hook = new FakeHook();
+        GlobalHotkeyListener listener = new GlobalHotkeyListener(null, "29,42", "", "", hook);
+
+        assertFalse(listener.reregisterHotkeys("29,42", "", ""));
+        assertFalse(listener.reregisterHotkeys("29,42", null, ""));
+    }
+
+    @Test
+    void retriesHookThatFailedOnStartup() {
+        FakeHook hook = new FakeHook();
+        hook.failures = 1;
+        GlobalHotkeyListener listener = new GlobalHotkeyListener(null, "29,42", "", "", hook);
+        assertFalse(hook.registered);
+
+        assertTrue(listener.reregisterHotkeys("29,56", "", ""));
+
+        assertTrue(hook.registered);
+        assertEquals(2, hook.registrations);
+    }
+
+    @Test
+    void registrationFailureIsReportedAndKeepsOldHotkey() {
+        FakeHook hook = new FakeHook();
+        hook.failures = 2;
+        GlobalHotkeyListener listener = new GlobalHotkeyListener(null, "29,42", "", "", hook);
+
+        assertThrows(IllegalStateException.class, () -> listener.reregisterHotkeys("29,56", "", ""));
+        // the new hotkey was not applied, so saving it again retries
+        assertTrue(listener.reregisterHotkeys("29,56", "", ""));
+    }
+
+    @Test
+    void rejectsInvalidKeyCodes() {
+        GlobalHotkeyListener listener = new GlobalHotkeyListener(null, "29,42", "", "", new FakeHook());
+
+        assertThrows(IllegalStateException.class, () -> listener.reregisterHotkeys("29,ctrl", "", ""));
+        assertFalse(listener.reregisterHotkeys("29,42", "", ""));
+    }
+
+    @Test
+    void configSaveReregistersChangedHotkey() {
+        ConfigManager config = new ConfigManager(tempDir.resolve("config.properties").toFile());
+        FakeHook hook = new FakeHook();
+        GlobalHotkeyListener listener = new GlobalHotkeyListener(null, config.getKeyCombination(), config.getKeySequence(), "", hook);
+        List<Boolean> results = new ArrayList<>();
+        config.addSaveListener(() -> results.add(listener.reregisterHotkeys(config.getKeyCombination(), config.getKeySequence(), "")));
+
+        config.setProperty("keyCombination", "29,56");
+        config.saveConfigNow();
+        config.saveConfigNow();
+
+        assertEquals(List.of(true, false), results);
+    }
+
+    @Test
+    void changedPipelineHotkeyIsReregistered() {
+        GlobalHotkeyListener listener = new GlobalHotkeyListener(null, "29,42", "", "", new FakeHook());
+
+        assertTrue(listener.reregisterHotkeys("29,42", "", "29,42,25"));
+        assertFalse(listener.reregisterHotkeys("29,42", "", "29,42,25"));
+    }
+
+    @Test
+    void pipelineHotkeyRunsLastPipelineOncePerPress() {
+        List<String> runs = new ArrayList<>();
+        GlobalHotkeyListener listener = new GlobalHotkeyListener(null, "29,56", "", "29,42,25", new FakeHook()) {
+            @Override
+            void runLastPipeline() {
+                runs.add("pipeline");
+            }
+        };
+
+        press(listener, 29, 42, 25);
+        // Auto-repeat while the keys are held does not run it again
+        press(listener, 25);
+        assertEquals(List.of("pipeline"), runs);
+
+        release(listener, 25, 42, 29);
+        press(listener, 29, 42, 25);
+        assertEquals(List.of("pipeline", "pipeline"), runs);
+    }
+
+    @Test
+    void partialPipelineHotkeyDoesNothing() {
+        List<String> runs = new ArrayList<>();
+        GlobalHotkeyListener listener = new GlobalHotkeyListener(null, "", "", "29,42,25", new FakeHook()) {
+            @Override
+            void runLastPipeline() {
+                runs.add("pipeline");
+            }
+        };
+
+        press(listener, 29, 25);
+        release(listener, 25, 29);
+        press(listener, 42, 25);
+
+        assertTrue(runs.isEmpty());
+    }
+
+    private static void press(GlobalHotkeyListener listener, int... keyCodes) {
+        for (int keyCode : keyCodes) {
+            listener.nativeKeyPressed(keyEvent(NativeKeyEvent.NATIVE_KEY_PRESSED, keyCode));
+        }
+    }
+
+    private static void release(GlobalHotkeyListener listener, int... keyCodes) {
+        for (int keyCode : keyCodes) {
+            listener.nativeKeyReleased(keyEvent(NativeKeyEvent.NATIVE_KEY_RELEASED, keyCode));
+        }
+    }
+
+    private static NativeKeyEvent keyEvent(int id, int keyCode) {
+        return new NativeKeyEvent(id, 0, 0, keyCode, NativeKeyEvent.CHAR_UNDEFINED, NativeKeyEvent.KEY_LOCATION_STANDARD);
+    }
+
+    /**
+     * Hook that records registrations instead of hooking into the OS.
+     */
+    private static class FakeHook implements GlobalHotkeyListener.NativeHook {
+        final List<NativeKeyListener> listeners = new ArrayList<>();
+        boolean registered;
+        int registrations;
+        int failures;
+
+        @Override
+        public boolean isRegistered() {
+            return registered;
+        }
+
+        @Override
+        public void register() throws Exception {
+            registrations++;
+            if (failures > 0) {
+                failures--;
+                throw new Exception("no display");
+            }
+            registered = true;
+        }
+
+        @Override
+        public void addListener(NativeKeyListener listener) {
+            listeners.add(listener);
+        }
+    }
+}
@@ -0,0 +1,64 @@
+package org.whispercat;
+
+import com.github.kwhat.jnativehook.keyboard.NativeKeyEvent;
+import org.junit.jupiter.api.Test;
+
+import java.util.Arrays;
+import java.util.Collections;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertSame;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class HotkeyTest {
+
+    @Test
+    void roundTripsThroughConfigurationForm() {
+        Hotkey hotkey = Hotkey.of(Arrays.asList(NativeKeyEvent.VC_CONTROL, NativeKeyEvent.VC_SHIFT, NativeKeyEvent.VC_SPACE));
+
+        assertEquals("29,42,57", hotkey.toString());
+        assertEquals(hotkey, Hotkey.parse(hotkey.toString()));
+    }
+
+    @Test
+    void roundTripsSequencesWithRepeatedKeys() {
+        // Double-tap Ctrl, then Ctrl Alt Ctrl: order and repetitions are kept
+        for (String sequence : Arrays.asList("29,29", "29,56,29", "3675,3675,3675")) {
+            Hotkey hotkey = Hotkey.parse(sequence);
+
+            assertEquals(sequence, hotkey.toString());
+            assertEquals(hotkey, Hotkey.parse(hotkey.toString()));
+        }
+        assertEquals(Arrays.asList(29, 56, 29), Hotkey.parse("29,56,29").getKeyCodes());
+    }
+
+    @Test
+    void parsesWithSurroundingWhitespace() {
+        assertEquals(Arrays.asList(29, 42, 57), Hotkey.parse(" 29, 42 ,57 ").getKeyCodes());
+    }
+
+    @Test
+    void emptyHotkeyIsNone() {
+        assertSame(Hotkey.NONE, Hotkey.of(Collections.emptyList()));
+        assertSame(Hotkey.NONE, Hotkey.parse(null));
+        assertSame(Hotkey.NONE, Hotkey.parse("  "));
+        assertTrue(Hotkey.NONE.isEmpty());
+        assertEquals("", Hotkey.NONE.toString());
+    }
+
+    @Test
+    void rejectsInvalidKeyCodes() {
+        assertThrows(IllegalArgumentException.class, () -> Hotkey.parse("29,abc"));
+        assertThrows(IllegalArgumentException.class, () -> Hotkey.parse("29,0"));
+        assertThrows(IllegalArgumentException.class, () -> Hotkey.parse("29,,57"));
+    }
+
+    @Test
+    void separatesModifiersFromKeys() {
+        Hotkey hotkey = Hotkey.parse("29,42,57");
+
+        assertEquals(Arrays.asList(NativeKeyEvent.VC_CONTROL, NativeKeyEvent.VC_SHIFT), hotkey.getModifiers());
+        assertEquals(Collections.singletonList(NativeKeyEvent.VC_SPACE), hotkey.getKeys());
+    }
+}
@@ -0,0 +1,100 @@
+package org.whispercat;
+
+import org.junit.jupiter.api.Test;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+
+class HttpErrorsTest {
+
+    @Test
+    void classifiesUnauthorizedBodyAsAuthenticationError() {
+        ApiException e = assertThrows(ApiException.class, () -> HttpErrors.checkStatus("OpenAI API", 401,
+                "{\"error\": {\"message\": \"Incorrect API key provided\", \"type\": \"invalid_request_error\"}}"));
+
+        assertEquals(ApiException.Kind.AUTHENTICATION, e.getKind());
+        assertEquals(401, e.getStatusCode());
+        assertEquals("Incorrect API key provided", e.getApiMessage());
+        assertEquals("Error from OpenAI API (HTTP 401): Incorrect API key provided", e.getMessage());
+    }
+
+    @Test
+    void classifiesServerErrorBody() {
+        ApiException e = assertThrows(ApiException.class, () -> HttpErrors.checkStatus("Open WebUI", 500,
+                "{\"detail\": \"Internal Server Error\"}"));
+
+        assertEquals(ApiException.Kind.SERVER_ERROR, e.getKind());
+        assertEquals(500, e.getStatusCode());
+        assertEquals("Internal Server Error", e.getApiMessage());
+    }
+
+    @Test
+    void acceptsSuccessStatus() throws ApiException {
+        HttpErrors.checkStatus("OpenAI API", 200, "{}");
+        HttpErrors.checkStatus("OpenAI API", 204, null);
+    }
+
+    @Test
+    void classifiesStatusCodes() {
+        assertEquals(ApiException.Kind.AUTHENTICATION, ApiException.classify(403));
+        assertEquals(ApiException.Kind.RATE_LIMITED, ApiException.classify(429));
+        assertEquals(ApiException.Kind.CLIENT_ERROR, ApiException.classify(404));
+        assertEquals(ApiException.Kind.SERVER_ERROR, ApiException.classify(503));
+        assertEquals(ApiException.Kind.UNKNOWN, ApiException.classify(302));
+    }
+
+    @Test
+    void extractsOpenAiErrorMessage() {
+        assertEquals("Invalid API key",
+                HttpErrors.extractMessage("{\"error\": {\"message\": \"Invalid API key\", \"type\": \"auth\"}}"));
+    }
+
+    @Test
+    void extractsTextualError() {
+        assertEquals("Model not found", HttpErrors.extractMessage("{\"error\": \"Model not found\"}"));
+    }
+
+    @Test
+    void extractsTextualDetail() {
+        assertEquals("Not authenticated", HttpErrors.extractMessage("{\"detail\": \"Not authenticated\"}"));
+    }
+
+    @Test
+    void extractsFirstValidationMessageFromDetailArray() {
+        assertEquals("field required",
+                HttpErrors.extractMessage("{\"detail\": [{\"loc\": [\"body\", \"file\"], \"msg\": \"field required\"}]}"));
+    }
+
+    @Test
+    void extractsTopLevelMessage() {
+        assertEquals("Rate limited", HttpErrors.extractMessage("{\"message\": \"Rate limited\"}"));
+    }
+
+    @Test
+    void returnsRawBodyForUnknownJson() {
+        assertEquals("{\"status\": \"failed\"}", HttpErrors.extractMessage("  {\"status\": \"failed\"}  "));
+    }
+
+    @Test
+    void returnsRawBodyForNonJson() {
+        assertEquals("<html>Bad Gateway</html>", HttpErrors.extractMessage("<html>Bad Gateway</html>\n"));
+    }
+
+    @Test
+    void truncatesLongRawBody() {
+        StringBuilder body = new StringBuilder();
+        for (int i = 0; i < 600; i++) {
+            body.append('x');
+        }
+        String message = HttpErrors.extractMessage(body.toString());
+        assertEquals(500, message.length());
+        assertEquals(TextUtils.ELLIPSIS, message.substring(499));
+    }
+
+    @Test
+    void reportsMissingBody() {
+        assertEquals("No response body", HttpErrors.extractMessage(null));
+        assertEquals("No response body", HttpErrors.extractMessage(""));
+        assertEquals("No response body", HttpErrors.extractMessage("   "));
+    }
+}
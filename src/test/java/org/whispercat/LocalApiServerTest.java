@@ -0,0 +1,197 @@
+package org.whispercat;
+
+import com.fasterxml.jackson.databind.JsonNode;
+import com.fasterxml.jackson.databind.ObjectMapper;
+import org.junit.jupiter.api.AfterEach;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+import org.whispercat.recording.TranscriptionOutcome;
+import org.whispercat.recording.TranscriptionService;
+
+import java.io.File;
+import java.io.IOException;
+import java.net.ServerSocket;
+import java.net.URI;
+import java.net.http.HttpClient;
+import java.net.http.HttpRequest;
+import java.net.http.HttpResponse;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.concurrent.atomic.AtomicInteger;
+
+import static org.junit.jupiter.api.Assertions.assertArrayEquals;
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class LocalApiServerTest {
+
+    @TempDir
+    Path tempDir;
+
+    private final ObjectMapper mapper = new ObjectMapper();
+    private final AtomicInteger toggles = new AtomicInteger();
+    private ConfigManager configManager;
+    private StubTranscriptionService backend;
+    private LocalApiServer server;
+    private int port;
+
+    @BeforeEach
+    void setUp() throws Exception {
+        configManager = new ConfigManager(tempDir.resolve("config.properties").toFile());
+        try (ServerSocket socket = new ServerSocket(0)) {
+            port = socket.getLocalPort();
+        }
+        configManager.setLocalServerEnabled(true);
+        configManager.setLocalServerPort(port);
+        backend = new StubTranscriptionService(configManager);
+        server = new LocalApiServer(configManager, () -> {
+            toggles.incrementAndGet();
+            return true;
+        }, backend);
+        server.start();
+    }
+
+    @AfterEach
+    void tearDown() {
+        server.stop();
+    }
+
+    @Test
+    void rejectsMissingToken() throws Exception {
+        Response response = post("/record/toggle", null, null, new byte[0]);
+
+        assertEquals(401, response.status);
+        assertEquals(0, toggles.get());
+    }
+
+    @Test
+    void rejectsWrongToken() throws Exception {
+        Response response = post("/record/toggle", "Bearer not-the-token", null, new byte[0]);
+
+        assertEquals(401, response.status);
+        assertEquals(0, toggles.get());
+    }
+
+    @Test
+    void rejectsBrowserRequestsEvenWithToken() throws Exception {
+        Response response = send(request("/record/toggle", token(), null, new byte[0])
+                .header("Origin", "https://example.com"));
+
+        assertEquals(403, response.status);
+        assertEquals(0, toggles.get());
+    }
+
+    @Test
+    void acceptsRightToken() throws Exception {
+        Response response = post("/record/toggle", token(), null, new byte[0]);
+
+        assertEquals(200, response.status);
+        assertEquals("ok", response.json.path("status").asText());
+        assertEquals(1, toggles.get());
+    }
+
+    @Test
+    void routesTranscribePathToBackend() throws Exception {
+        File audio = tempDir.resolve("note.wav").toFile();
+        Files.write(audio.toPath(), new byte[]{1, 2, 3});
+        byte[] body = mapper.writeValueAsBytes(mapper.createObjectNode().put("path", audio.getAbsolutePath()));
+
+        Response response = post("/transcribe", token(), "application/json", body);
+
+        assertEquals(200, response.status);
+        assertEquals("Hello from the backend", response.json.path("text").asText());
+        assertEquals("english", response.json.path("language").asText());
+        assertEquals("OpenAI", response.json.path("provider").asText());
+        assertEquals(audio, backend.files.get(0));
+    }
+
+    @Test
+    void routesUploadedAudioToBackendAndDeletesIt() throws Exception {
+        byte[] audio = {10, 20, 30, 40};
+
+        Response response = post("/transcribe", token(), "audio/wav", audio);
+
+        assertEquals(200, response.status);
+        assertEquals("Hello from the backend", response.json.path("text").asText());
+        assertArrayEquals(audio, backend.contents.get(0));
+        assertTrue(backend.files.get(0).getName().endsWith(".wav"));
+        assertFalse(backend.files.get(0).exists());
+    }
+
+    @Test
+    void transcribeRequiresToken() throws Exception {
+        Response response = post("/transcribe", null, "audio/wav", new byte[]{1});
+
+        assertEquals(401, response.status);
+        assertTrue(backend.files.isEmpty());
+    }
+
+    @Test
+    void rejectsUnsupportedContentType() throws Exception {
+        Response response = post("/transcribe", token(), "text/plain", "hello".getBytes(StandardCharsets.UTF_8));
+
+        assertEquals(415, response.status);
+        assertTrue(backend.files.isEmpty());
+    }
+
+    private String token() {
+        return "Bearer " + configManager.getLocalServerToken();
+    }
+
+    private Response post(String path, String authorization, String contentType, byte[] body) throws Exception {
+        return send(request(path, authorization, contentType, body));
+    }
+
+    private HttpRequest.Builder request(String path, String authorization, String contentType, byte[] body) {
+        HttpRequest.Builder request = HttpRequest.newBuilder(URI.create("http://127.0.0.1:" + port + path))
+                .POST(HttpRequest.BodyPublishers.ofByteArray(body));
+        if (authorization != null) {
+            request.header("Authorization", authorization);
+        }
+        if (contentType != null) {
+            request.header("Content-Type", contentType);
+        }
+        return request;
+    }
+
+    private Response send(HttpRequest.Builder request) throws Exception {
+        HttpResponse<byte[]> response = HttpClient.newHttpClient().send(request.build(), HttpResponse.BodyHandlers.ofByteArray());
+        return new Response(response.statusCode(), mapper.readTree(response.body()));
+    }
+
+    private static class Response {
+        final int status;
+        final JsonNode json;
+
+        Response(int status, JsonNode json) {
+            this.status = status;
+            this.json = json;
+        }
+    }
+
+    /**
+     * Backend that records the files it was asked to transcribe and answers with a fixed text.
+     */
+    private static class StubTranscriptionService extends TranscriptionService {
+        final List<File> files = new ArrayList<>();
+        final List<byte[]> contents = new ArrayList<>();
+
+        StubTranscriptionService(ConfigManager configManager) {
+            super(configManager);
+        }
+
+        @Override
+        public TranscriptionOutcome transcribe(File audioFile) throws IOException {
+            files.add(audioFile);
+            contents.add(Files.readAllBytes(audioFile.toPath()));
+            TranscriptionOutcome outcome = new TranscriptionOutcome("Hello from the backend", "OpenAI", "whisper-1");
+            outcome.setLanguage("english");
+            return outcome;
+        }
+    }
+}
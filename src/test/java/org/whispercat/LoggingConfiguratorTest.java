@@ -0,0 +1,79 @@
+package org.whispercat;
+
+import org.apache.logging.log4j.Level;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+
+import java.nio.file.Path;
+import java.util.HashMap;
+import java.util.Map;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+
+class LoggingConfiguratorTest {
+
+    @TempDir
+    Path tempDir;
+
+    private ConfigManager configManager;
+    private final Map<String, String> env = new HashMap<>();
+
+    @BeforeEach
+    void setUp() {
+        configManager = new ConfigManager(tempDir.resolve("config.properties").toFile());
+    }
+
+    @Test
+    void usesConfiguredLevelsAndDirectory() {
+        configManager.setLogLevel("WARN");
+        configManager.setFileLogLevel("DEBUG");
+        configManager.setLogDirectory(" /var/log/whispercat ");
+
+        Map<String, String> settings = LoggingConfigurator.resolveSettings(env::get, configManager);
+
+        assertEquals("WARN", settings.get(LoggingConfigurator.PROPERTY_LOG_LEVEL));
+        assertEquals("DEBUG", settings.get(LoggingConfigurator.PROPERTY_FILE_LOG_LEVEL));
+        assertEquals("DEBUG", settings.get(LoggingConfigurator.PROPERTY_ROOT_LEVEL));
+        assertEquals("/var/log/whispercat", settings.get(LoggingConfigurator.PROPERTY_LOG_DIR));
+    }
+
+    @Test
+    void environmentOverridesConfiguration() {
+        configManager.setLogLevel("WARN");
+        configManager.setFileLogLevel("OFF");
+        configManager.setLogDirectory("/config/dir");
+        env.put(LoggingConfigurator.ENV_LOG_LEVEL, "trace");
+        env.put(LoggingConfigurator.ENV_FILE_LOG_LEVEL, "error");
+        env.put(LoggingConfigurator.ENV_LOG_DIR, "/env/dir");
+
+        Map<String, String> settings = LoggingConfigurator.resolveSettings(env::get, configManager);
+
+        assertEquals("TRACE", settings.get(LoggingConfigurator.PROPERTY_LOG_LEVEL));
+        assertEquals("ERROR", settings.get(LoggingConfigurator.PROPERTY_FILE_LOG_LEVEL));
+        assertEquals("TRACE", settings.get(LoggingConfigurator.PROPERTY_ROOT_LEVEL));
+        assertEquals("/env/dir", settings.get(LoggingConfigurator.PROPERTY_LOG_DIR));
+    }
+
+    @Test
+    void invalidOverrideFallsBackToConfiguration() {
+        configManager.setLogLevel("ERROR");
+        env.put(LoggingConfigurator.ENV_LOG_LEVEL, "loud");
+        env.put(LoggingConfigurator.ENV_LOG_DIR, "  ");
+
+        Map<String, String> settings = LoggingConfigurator.resolveSettings(env::get, configManager);
+
+        assertEquals("ERROR", settings.get(LoggingConfigurator.PROPERTY_LOG_LEVEL));
+        assertEquals("INFO", settings.get(LoggingConfigurator.PROPERTY_FILE_LOG_LEVEL));
+        assertEquals("INFO", settings.get(LoggingConfigurator.PROPERTY_ROOT_LEVEL));
+        assertFalse(settings.containsKey(LoggingConfigurator.PROPERTY_LOG_DIR));
+    }
+
+    @Test
+    void resolvesLevelNames() {
+        assertEquals(Level.DEBUG, LoggingConfigurator.resolveLevel(" debug ", "WARN"));
+        assertEquals(Level.WARN, LoggingConfigurator.resolveLevel(null, "warn"));
+        assertEquals(Level.INFO, LoggingConfigurator.resolveLevel("nope", "also nope"));
+    }
+}
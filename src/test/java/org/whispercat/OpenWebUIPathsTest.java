@@ -0,0 +1,91 @@
+package org.whispercat;
+
+import com.sun.net.httpserver.HttpServer;
+import org.junit.jupiter.api.AfterEach;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+
+import java.io.IOException;
+import java.io.OutputStream;
+import java.net.InetAddress;
+import java.net.InetSocketAddress;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Path;
+import java.util.concurrent.atomic.AtomicInteger;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertSame;
+
+class OpenWebUIPathsTest {
+
+    @TempDir
+    Path tempDir;
+
+    private HttpServer server;
+
+    @AfterEach
+    void tearDown() {
+        if (server != null) {
+            server.stop(0);
+        }
+        OpenWebUIPaths.clearCache();
+    }
+
+    @Test
+    void oldVersionsUseLegacyPaths() {
+        assertSame(OpenWebUIPaths.LEGACY, OpenWebUIPaths.forVersion("0.2.5"));
+        assertSame(OpenWebUIPaths.LEGACY, OpenWebUIPaths.forVersion("v0.1.125"));
+        assertEquals("/openai/api/models", OpenWebUIPaths.LEGACY.getModelsPath());
+        assertEquals("/audio/api/v1/transcriptions", OpenWebUIPaths.LEGACY.getTranscriptionPath());
+    }
+
+    @Test
+    void currentVersionsUseCurrentPaths() {
+        assertSame(OpenWebUIPaths.CURRENT, OpenWebUIPaths.forVersion("0.3.0"));
+        assertSame(OpenWebUIPaths.CURRENT, OpenWebUIPaths.forVersion("0.5.20"));
+        assertSame(OpenWebUIPaths.CURRENT, OpenWebUIPaths.forVersion("1.0.0-beta"));
+        assertEquals("/api/models", OpenWebUIPaths.CURRENT.getModelsPath());
+        assertEquals("/api/v1/audio/transcriptions", OpenWebUIPaths.CURRENT.getTranscriptionPath());
+    }
+
+    @Test
+    void unknownVersionFallsBackToCurrentPaths() {
+        assertSame(OpenWebUIPaths.CURRENT, OpenWebUIPaths.forVersion(null));
+        assertSame(OpenWebUIPaths.CURRENT, OpenWebUIPaths.forVersion("dev"));
+    }
+
+    @Test
+    void detectedVersionIsCachedPerServer() throws IOException {
+        AtomicInteger probes = new AtomicInteger();
+        server = HttpServer.create(new InetSocketAddress(InetAddress.getLoopbackAddress(), 0), 0);
+        server.createContext("/api/version", exchange -> {
+            probes.incrementAndGet();
+            byte[] body = "{\"version\":\"0.2.5\"}".getBytes(StandardCharsets.UTF_8);
+            exchange.sendResponseHeaders(200, body.length);
+            try (OutputStream out = exchange.getResponseBody()) {
+                out.write(body);
+            }
+        });
+        server.start();
+        ConfigManager config = new ConfigManager(tempDir.resolve("config.properties").toFile());
+        config.setOpenWebUIServerUrl("http://127.0.0.1:" + server.getAddress().getPort());
+
+        assertSame(OpenWebUIPaths.LEGACY, OpenWebUIPaths.detect(config));
+        assertSame(OpenWebUIPaths.LEGACY, OpenWebUIPaths.detect(config));
+        assertEquals(1, probes.get());
+    }
+
+    @Test
+    void serverWithoutVersionEndpointGetsCurrentPaths() throws IOException {
+        server = HttpServer.create(new InetSocketAddress(InetAddress.getLoopbackAddress(), 0), 0);
+        server.createContext("/", exchange -> {
+            exchange.sendResponseHeaders(404, -1);
+            exchange.close();
+        });
+        server.start();
+        ConfigManager config = new ConfigManager(tempDir.resolve("config.properties").toFile());
+        config.setOpenWebUIServerUrl("http://127.0.0.1:" + server.getAddress().getPort());
+
+        assertSame(OpenWebUIPaths.CURRENT, OpenWebUIPaths.detect(config));
+    }
+}
@@ -0,0 +1,93 @@
+package org.whispercat;
+
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+
+import java.io.File;
+import java.io.IOException;
+import java.io.InputStream;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.security.KeyStore;
+import java.security.cert.CertificateFactory;
+import java.security.cert.X509Certificate;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertNotNull;
+import static org.junit.jupiter.api.Assertions.assertNull;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class OpenWebUITlsTest {
+
+    private static final String TEST_CA = "/org/whispercat/openwebui-test-ca.pem";
+
+    @TempDir
+    Path tempDir;
+
+    private ConfigManager configManager;
+
+    @BeforeEach
+    void setUp() {
+        configManager = new ConfigManager(tempDir.resolve("config.properties").toFile());
+    }
+
+    @Test
+    void trustStoreContainsConfiguredCertificate() throws Exception {
+        X509Certificate testCa;
+        try (InputStream in = getClass().getResourceAsStream(TEST_CA)) {
+            testCa = (X509Certificate) CertificateFactory.getInstance("X.509").generateCertificate(in);
+        }
+
+        KeyStore trustStore = OpenWebUITls.trustStoreWith(testCaFile());
+
+        assertEquals("openwebui-0", trustStore.getCertificateAlias(testCa));
+        assertTrue(trustStore.size() > 1, "system CAs are kept");
+    }
+
+    @Test
+    void configuredCaCertificateTakesPrecedence() throws Exception {
+        configManager.setOpenWebUICaCertPath(testCaFile().getAbsolutePath());
+        configManager.setOpenWebUIAcceptInvalidCerts(false);
+
+        assertNotNull(OpenWebUITls.socketFactory(configManager));
+    }
+
+    @Test
+    void verifiesCertificatesByDefault() throws IOException {
+        assertFalse(configManager.isOpenWebUIAcceptInvalidCerts());
+        // No custom factory: the HTTP client's default verification applies
+        assertNull(OpenWebUITls.socketFactory(configManager));
+    }
+
+    @Test
+    void acceptsInvalidCertificatesOnlyWhenEnabled() throws IOException {
+        configManager.setOpenWebUIAcceptInvalidCerts(true);
+        assertNotNull(OpenWebUITls.socketFactory(configManager));
+
+        configManager.setOpenWebUIAcceptInvalidCerts(false);
+        assertNull(OpenWebUITls.socketFactory(configManager));
+    }
+
+    @Test
+    void missingCaCertificateIsReported() {
+        configManager.setOpenWebUICaCertPath(tempDir.resolve("missing.pem").toString());
+
+        IOException e = assertThrows(IOException.class, () -> OpenWebUITls.socketFactory(configManager));
+        assertTrue(e.getMessage().startsWith("CA certificate not found"), e.getMessage());
+    }
+
+    @Test
+    void fileWithoutCertificateIsRejected() throws IOException {
+        File empty = Files.createFile(tempDir.resolve("empty.pem")).toFile();
+        configManager.setOpenWebUICaCertPath(empty.getAbsolutePath());
+
+        assertThrows(IOException.class, () -> OpenWebUITls.socketFactory(configManager));
+    }
+
+    private File testCaFile() throws Exception {
+        return new File(getClass().getResource(TEST_CA).toURI());
+    }
+}
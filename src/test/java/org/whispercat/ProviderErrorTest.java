@@ -0,0 +1,69 @@
+package org.whispercat;
+
+import org.apache.http.conn.ConnectTimeoutException;
+import org.junit.jupiter.api.Test;
+import org.whispercat.recording.ProviderUnreachableException;
+
+import java.io.IOException;
+import java.net.ConnectException;
+import java.net.SocketTimeoutException;
+import java.net.UnknownHostException;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+
+class ProviderErrorTest {
+
+    @Test
+    void classifiesAuthenticationFailures() {
+        assertEquals(ProviderError.AUTH, ProviderError.classify(401, "Incorrect API key provided: sk-abc***"));
+        assertEquals(ProviderError.AUTH, ProviderError.classify(403, "Forbidden"));
+        assertEquals(ProviderError.AUTH, ProviderError.classify(400, "Invalid API key"));
+    }
+
+    @Test
+    void tellsExhaustedQuotaFromRateLimit() {
+        assertEquals(ProviderError.QUOTA_EXCEEDED, ProviderError.classify(429,
+                "You exceeded your current quota, please check your plan and billing details."));
+        assertEquals(ProviderError.QUOTA_EXCEEDED, ProviderError.classify(402, null));
+        assertEquals(ProviderError.RATE_LIMITED, ProviderError.classify(429,
+                "Rate limit reached for whisper-1 on requests per min (RPM): Limit 50."));
+    }
+
+    @Test
+    void classifiesServerErrorsAndTimeouts() {
+        assertEquals(ProviderError.SERVER_ERROR, ProviderError.classify(500, "The server had an error"));
+        assertEquals(ProviderError.SERVER_ERROR, ProviderError.classify(503, null));
+        assertEquals(ProviderError.TIMEOUT, ProviderError.classify(504, "Gateway Timeout"));
+        assertEquals(ProviderError.TIMEOUT, ProviderError.classify(408, null));
+    }
+
+    @Test
+    void otherClientErrorsAreUnclassified() {
+        assertEquals(ProviderError.OTHER, ProviderError.classify(400, "Invalid file format"));
+        assertEquals(ProviderError.OTHER, ProviderError.classify(404, "model not found"));
+    }
+
+    @Test
+    void findsApiExceptionInCauses() {
+        IOException wrapped = new IOException("Transcription failed",
+                new ApiException("OpenAI API", 401, "Incorrect API key provided"));
+
+        assertEquals(ProviderError.AUTH, ProviderError.from(wrapped));
+    }
+
+    @Test
+    void classifiesConnectionFailures() {
+        assertEquals(ProviderError.TIMEOUT, ProviderError.from(new SocketTimeoutException("Read timed out")));
+        assertEquals(ProviderError.TIMEOUT, ProviderError.from(new ConnectTimeoutException("connect timed out")));
+        assertEquals(ProviderError.NETWORK, ProviderError.from(new ConnectException("Connection refused")));
+        assertEquals(ProviderError.NETWORK, ProviderError.from(new IOException(new UnknownHostException("api.openai.com"))));
+        assertEquals(ProviderError.NETWORK, ProviderError.from(
+                new ProviderUnreachableException("Faster-Whisper", "Connection refused", 30_000)));
+    }
+
+    @Test
+    void unknownFailuresAreOther() {
+        assertEquals(ProviderError.OTHER, ProviderError.from(new IllegalStateException("bug")));
+        assertEquals(ProviderError.OTHER, ProviderError.from(null));
+    }
+}
@@ -0,0 +1,63 @@
+package org.whispercat;
+
+import org.junit.jupiter.api.Test;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertNull;
+
+class TextUtilsTest {
+
+    // Two emoji outside the Basic Multilingual Plane, each a surrogate pair in Java
+    private static final String GRIN = "😀";
+    private static final String CAT = "🐱";
+
+    @Test
+    void truncateKeepsShortText() {
+        assertEquals("hello", TextUtils.truncateChars("hello", 5));
+        assertEquals("hello", TextUtils.truncateChars("hello", 10));
+    }
+
+    @Test
+    void truncateCountsEllipsisTowardsLimit() {
+        assertEquals("hell" + TextUtils.ELLIPSIS, TextUtils.truncateChars("hello world", 5));
+        assertEquals(TextUtils.ELLIPSIS, TextUtils.truncateChars("hello", 1));
+    }
+
+    @Test
+    void truncateHandlesNullAndNonPositiveLimit() {
+        assertNull(TextUtils.truncateChars(null, 5));
+        assertEquals("", TextUtils.truncateChars("hello", 0));
+        assertEquals("", TextUtils.truncateChars("hello", -1));
+    }
+
+    @Test
+    void truncateDoesNotSplitSurrogatePairs() {
+        String text = GRIN + CAT + GRIN + CAT;
+        assertEquals(GRIN + CAT + TextUtils.ELLIPSIS, TextUtils.truncateChars(text, 3));
+        assertEquals(text, TextUtils.truncateChars(text, 4));
+    }
+
+    @Test
+    void truncateCountsAccentedCharactersOnce() {
+        assertEquals("Crème brû" + TextUtils.ELLIPSIS, TextUtils.truncateChars("Crème brûlée à la carte", 10));
+        assertEquals("Ünïcödé", TextUtils.truncateChars("Ünïcödé", 7));
+    }
+
+    @Test
+    void limitCutsWithoutEllipsis() {
+        assertEquals("hello", TextUtils.limitChars("hello world", 5));
+        assertEquals("hi", TextUtils.limitChars("hi", 5));
+    }
+
+    @Test
+    void limitTreatsNonPositiveLimitAsUnlimited() {
+        assertEquals("hello", TextUtils.limitChars("hello", 0));
+        assertEquals("hello", TextUtils.limitChars("hello", -1));
+        assertNull(TextUtils.limitChars(null, 3));
+    }
+
+    @Test
+    void limitDoesNotSplitSurrogatePairs() {
+        assertEquals(GRIN + CAT, TextUtils.limitChars(GRIN + CAT + GRIN, 2));
+    }
+}
@@ -0,0 +1,82 @@
+package org.whispercat.postprocessing;
+
+import org.junit.jupiter.api.Test;
+
+import java.io.IOException;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.Collections;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.concurrent.Callable;
+import java.util.concurrent.atomic.AtomicInteger;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertNull;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class ModelFetcherTest {
+
+    @Test
+    void keepsModelsWithTheirProvider() {
+        Map<String, Callable<List<String>>> fetchers = new LinkedHashMap<>();
+        fetchers.put("OpenAI", () -> {
+            // Finishes last, but stays first in the results
+            Thread.sleep(50);
+            return Arrays.asList("gpt-4o", "gpt-4o-mini");
+        });
+        fetchers.put("Open WebUI", () -> Collections.singletonList("llama3"));
+
+        Map<String, ModelFetcher.Result> results = ModelFetcher.fetchAll(fetchers, 2);
+
+        assertEquals(Arrays.asList("OpenAI", "Open WebUI"), new ArrayList<>(results.keySet()));
+        assertEquals("OpenAI", results.get("OpenAI").getProvider());
+        assertEquals(Arrays.asList("gpt-4o", "gpt-4o-mini"), results.get("OpenAI").getModels());
+        assertEquals(Collections.singletonList("llama3"), results.get("Open WebUI").getModels());
+        assertTrue(results.get("Open WebUI").isSuccess());
+        assertNull(results.get("Open WebUI").getError());
+    }
+
+    @Test
+    void failureIsReportedForItsProviderOnly() {
+        Map<String, Callable<List<String>>> fetchers = new LinkedHashMap<>();
+        fetchers.put("OpenAI", () -> {
+            throw new IOException("401 Unauthorized");
+        });
+        fetchers.put("Open WebUI", () -> Collections.singletonList("llama3"));
+
+        Map<String, ModelFetcher.Result> results = ModelFetcher.fetchAll(fetchers, 2);
+
+        assertFalse(results.get("OpenAI").isSuccess());
+        assertEquals("401 Unauthorized", results.get("OpenAI").getError());
+        assertTrue(results.get("OpenAI").getModels().isEmpty());
+        assertTrue(results.get("Open WebUI").isSuccess());
+    }
+
+    @Test
+    void limitsConcurrentFetches() {
+        AtomicInteger running = new AtomicInteger();
+        AtomicInteger maxRunning = new AtomicInteger();
+        Map<String, Callable<List<String>>> fetchers = new LinkedHashMap<>();
+        for (int i = 0; i < 5; i++) {
+            fetchers.put("Provider " + i, () -> {
+                maxRunning.accumulateAndGet(running.incrementAndGet(), Math::max);
+                Thread.sleep(30);
+                running.decrementAndGet();
+                return Collections.singletonList("model");
+            });
+        }
+
+        Map<String, ModelFetcher.Result> results = ModelFetcher.fetchAll(fetchers, 2);
+
+        assertEquals(5, results.size());
+        assertEquals(2, maxRunning.get());
+    }
+
+    @Test
+    void noProvidersGiveNoResults() {
+        assertTrue(ModelFetcher.fetchAll(Collections.emptyMap(), 2).isEmpty());
+    }
+}
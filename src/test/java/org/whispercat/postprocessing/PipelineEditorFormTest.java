@@ -0,0 +1,66 @@
+package org.whispercat.postprocessing;
+
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+import org.whispercat.ConfigManager;
+
+import java.nio.file.Path;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertNotNull;
+
+class PipelineEditorFormTest {
+
+    @TempDir
+    Path tempDir;
+
+    private ConfigManager configManager;
+
+    @BeforeEach
+    void setUp() {
+        configManager = new ConfigManager(tempDir.resolve("config.properties").toFile());
+    }
+
+    @Test
+    void savingTwiceUpdatesTheSamePipeline() {
+        PipelineEditorForm editor = new PipelineEditorForm(configManager, null, null);
+
+        Pipeline first = editor.persistPipeline();
+        Pipeline second = editor.persistPipeline();
+
+        assertNotNull(first.uuid);
+        assertFalse(first.uuid.isEmpty());
+        assertEquals(first.uuid, second.uuid);
+        assertEquals(1, configManager.getPipelines().size());
+    }
+
+    @Test
+    void savingKeepsTheEditorState() {
+        ProcessingUnit unit = new ProcessingUnit();
+        unit.uuid = "unit-polish";
+        unit.name = "Polish";
+        unit.type = "Prompt";
+        unit.provider = "OpenAI";
+        unit.model = "gpt-4o-mini";
+        unit.userPrompt = "Fix: {{input}}";
+        configManager.saveProcessingUnit(unit);
+        Pipeline existing = new Pipeline("pipeline-1", "Cleanup", "Fixes transcripts");
+        existing.unitReferences.add(new PipelineUnitReference(unit.uuid, false));
+        configManager.savePipeline(existing);
+        PipelineEditorForm editor = new PipelineEditorForm(configManager, null, existing);
+
+        editor.persistPipeline();
+        Pipeline saved = editor.persistPipeline();
+
+        assertEquals("pipeline-1", saved.uuid);
+        assertEquals("Cleanup", saved.title);
+        assertEquals("Fixes transcripts", saved.description);
+        assertEquals(1, saved.unitReferences.size());
+        assertEquals("unit-polish", saved.unitReferences.get(0).unitUuid);
+        assertFalse(saved.unitReferences.get(0).enabled);
+        assertEquals(1, configManager.getPipelines().size());
+        assertEquals("Cleanup", configManager.getPipelineByUuid("pipeline-1").title);
+    }
+}
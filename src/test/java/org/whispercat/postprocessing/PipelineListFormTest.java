@@ -0,0 +1,59 @@
+package org.whispercat.postprocessing;
+
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+import org.whispercat.ConfigManager;
+
+import java.io.File;
+import java.nio.file.Path;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+import java.util.stream.Collectors;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class PipelineListFormTest {
+
+    @TempDir
+    Path tempDir;
+
+    @Test
+    void favoritesComeFirstRegardlessOfName() {
+        List<Pipeline> pipelines = new ArrayList<>(Arrays.asList(
+                pipeline("Alpha", false),
+                pipeline("zeta", true),
+                pipeline("beta", false),
+                pipeline("Gamma", true),
+                pipeline(null, false)));
+
+        PipelineListForm.sortForDisplay(pipelines);
+
+        assertEquals(Arrays.asList("Gamma", "zeta", "Alpha", "beta", null),
+                pipelines.stream().map(p -> p.title).collect(Collectors.toList()));
+    }
+
+    @Test
+    void favoriteDefaultsToFalseAndPersists() {
+        File configFile = tempDir.resolve("config.properties").toFile();
+        ConfigManager config = new ConfigManager(configFile);
+        config.setProperty("pipelines", "[{\"uuid\":\"p1\",\"title\":\"Old pipeline\",\"enabled\":true}]");
+
+        Pipeline stored = config.getPipelines().get(0);
+        assertFalse(stored.favorite);
+
+        stored.favorite = true;
+        config.savePipeline(stored);
+        config.flushPendingSave();
+
+        assertTrue(new ConfigManager(configFile).getPipelines().get(0).favorite);
+    }
+
+    private static Pipeline pipeline(String title, boolean favorite) {
+        Pipeline pipeline = new Pipeline("uuid-" + title, title, "");
+        pipeline.favorite = favorite;
+        return pipeline;
+    }
+}
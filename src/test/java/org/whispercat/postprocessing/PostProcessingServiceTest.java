@@ -0,0 +1,447 @@
+package org.whispercat.postprocessing;
+
+import org.junit.jupiter.api.AfterEach;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+import org.whispercat.CancellationToken;
+import org.whispercat.ConfigManager;
+import org.whispercat.ConsoleLogger;
+import org.whispercat.postprocessing.clients.ChatCompletion;
+import org.whispercat.postprocessing.clients.OpenWebUIProcessClient;
+import org.whispercat.recording.OpenAIClient;
+
+import javax.swing.JTextArea;
+import javax.swing.SwingUtilities;
+import java.nio.file.Path;
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.List;
+import java.util.concurrent.atomic.AtomicInteger;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertNull;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class PostProcessingServiceTest {
+
+    @TempDir
+    Path tempDir;
+
+    private ConfigManager configManager;
+    private JTextArea console;
+
+    @BeforeEach
+    void setUp() {
+        configManager = new ConfigManager(tempDir.resolve("config.properties").toFile());
+        console = new JTextArea();
+        ConsoleLogger.getInstance().setConsoleArea(console);
+    }
+
+    @AfterEach
+    void tearDown() throws Exception {
+        // let queued appends finish before detaching the area
+        SwingUtilities.invokeAndWait(() -> { });
+        ConsoleLogger.getInstance().setConsoleArea(null);
+    }
+
+    @Test
+    void cancellingBetweenUnitsStopsAndKeepsPartialLog() throws Exception {
+        CancellationToken token = new CancellationToken();
+        StubOpenAIClient chat = new StubOpenAIClient(configManager, "polished text");
+        chat.onCall = token::cancel;
+
+        Pipeline pipeline = pipeline("Cleanup",
+                promptUnit("Polish"),
+                replacementUnit("Fix name", "polished", "POLISHED"),
+                replacementUnit("Fix dot", ".", "!"));
+
+        String result = service(chat).applyPipeline("raw text", pipeline, token);
+
+        assertEquals("polished text", result);
+        assertEquals(1, chat.calls.size());
+        String log = consoleText();
+        assertTrue(log.contains("Step 1/3: Polish (Prompt)"), log);
+        assertTrue(log.contains("Pipeline stopped: Cleanup (1/3 steps completed)"), log);
+        assertFalse(log.contains("Step 2/3"), log);
+        assertFalse(log.contains("Pipeline completed"), log);
+    }
+
+    @Test
+    void runsAllUnitsWithoutCancellation() throws Exception {
+        StubOpenAIClient chat = new StubOpenAIClient(configManager, "polished text");
+        Pipeline pipeline = pipeline("Cleanup",
+                promptUnit("Polish"),
+                replacementUnit("Fix name", "polished", "POLISHED"));
+
+        String result = service(chat).applyPipeline("raw text", pipeline, new CancellationToken());
+
+        assertEquals("POLISHED text", result);
+        assertTrue(consoleText().contains("Pipeline completed: Cleanup"));
+    }
+
+    @Test
+    void blankModelUsesProviderDefault() {
+        configManager.setCustomOpenAIModelsFromString("gpt-4.1-mini, gpt-4.1");
+        StubOpenAIClient chat = new StubOpenAIClient(configManager, "done");
+        ProcessingUnit unit = promptUnit("Polish");
+        unit.model = "  ";
+
+        service(chat).applyPipeline("raw text", pipeline("Default model", unit), new CancellationToken());
+
+        assertEquals(Collections.singletonList("gpt-4.1-mini"), chat.models);
+    }
+
+    @Test
+    void configuredModelIsKept() {
+        StubOpenAIClient chat = new StubOpenAIClient(configManager, "done");
+
+        service(chat).applyPipeline("raw text", pipeline("Own model", promptUnit("Polish")), new CancellationToken());
+
+        assertEquals(Collections.singletonList("gpt-4o-mini"), chat.models);
+    }
+
+    @Test
+    void limitsConcurrentChatCalls() throws Exception {
+        configManager.setMaxConcurrentChatCalls(2);
+        AtomicInteger active = new AtomicInteger();
+        AtomicInteger maxActive = new AtomicInteger();
+        StubOpenAIClient chat = new StubOpenAIClient(configManager, "done");
+        chat.onCall = () -> {
+            maxActive.accumulateAndGet(active.incrementAndGet(), Math::max);
+            try {
+                Thread.sleep(50);
+            } catch (InterruptedException e) {
+                Thread.currentThread().interrupt();
+            }
+            active.decrementAndGet();
+        };
+        PostProcessingService service = service(chat);
+        Pipeline pipeline = pipeline("Parallel", promptUnit("Polish"));
+
+        List<Thread> runs = new ArrayList<>();
+        for (int i = 0; i < 6; i++) {
+            Thread run = new Thread(() -> service.applyPipeline("raw text", pipeline, new CancellationToken()));
+            runs.add(run);
+            run.start();
+        }
+        for (Thread run : runs) {
+            run.join();
+        }
+
+        assertEquals(6, chat.calls.size());
+        assertTrue(maxActive.get() <= 2, "at most 2 calls in flight, saw " + maxActive.get());
+    }
+
+    @Test
+    void limiterFollowsConfiguredLimit() {
+        assertEquals(3, PostProcessingService.getChatCallLimiter(3).availablePermits());
+        assertEquals(1, PostProcessingService.getChatCallLimiter(0).availablePermits());
+    }
+
+    @Test
+    void reportsRunWithoutEnabledUnits() {
+        StubOpenAIClient chat = new StubOpenAIClient(configManager, "done");
+        PostProcessingService service = service(chat);
+        Pipeline pipeline = pipeline("All off", replacementUnit("Fix name", "raw", "RAW"));
+        pipeline.unitReferences.get(0).enabled = false;
+
+        String result = service.applyPipeline("raw text", pipeline, new CancellationToken());
+
+        assertEquals("raw text", result);
+        assertEquals(0, service.getLastUnitsRun());
+        assertTrue(PostProcessingService.isEmptyRun("raw text", result, service.getLastUnitsRun()));
+    }
+
+    @Test
+    void disabledPipelineRunsNoUnits() {
+        PostProcessingService service = service(new StubOpenAIClient(configManager, "done"));
+        Pipeline pipeline = pipeline("Off", replacementUnit("Fix name", "raw", "RAW"));
+        pipeline.enabled = false;
+
+        String result = service.applyPipeline("raw text", pipeline, new CancellationToken());
+
+        assertTrue(PostProcessingService.isEmptyRun("raw text", result, service.getLastUnitsRun()));
+    }
+
+    @Test
+    void countsExecutedUnits() {
+        PostProcessingService service = service(new StubOpenAIClient(configManager, "done"));
+        Pipeline pipeline = pipeline("Noop",
+                replacementUnit("Fix name", "missing", "MISSING"),
+                replacementUnit("Fix dot", "absent", "ABSENT"));
+
+        String result = service.applyPipeline("raw text", pipeline, new CancellationToken());
+
+        // The text is unchanged, but the units did run
+        assertEquals("raw text", result);
+        assertEquals(2, service.getLastUnitsRun());
+        assertFalse(PostProcessingService.isEmptyRun("raw text", result, service.getLastUnitsRun()));
+    }
+
+    @Test
+    void validatesJsonModeOutput() {
+        assertTrue(PostProcessingService.isValidJson("{\"title\": \"Notes\"}"));
+        assertTrue(PostProcessingService.isValidJson("[1, 2]"));
+        assertFalse(PostProcessingService.isValidJson("Here is the JSON: {\"title\": \"Notes\"}"));
+        assertFalse(PostProcessingService.isValidJson("  "));
+        assertFalse(PostProcessingService.isValidJson(null));
+    }
+
+    @Test
+    void emptyRunNeedsUnchangedText() {
+        assertTrue(PostProcessingService.isEmptyRun("text", "text", 0));
+        assertFalse(PostProcessingService.isEmptyRun("text", "other", 0));
+        assertFalse(PostProcessingService.isEmptyRun("text", "text", 1));
+    }
+
+    @Test
+    void overLimitOutputIsTruncatedAndFlagged() throws Exception {
+        StubOpenAIClient chat = new StubOpenAIClient(configManager, "a very long runaway answer");
+        ProcessingUnit polish = promptUnit("Polish");
+        polish.maxOutputChars = 6;
+        PostProcessingService service = service(chat);
+
+        String result = service.applyPipeline("raw text", pipeline("Limited", polish,
+                replacementUnit("Fix name", "very", "VERY")), new CancellationToken());
+
+        assertEquals("a VERY", result);
+        List<ExecutionLogEntry> log = service.getLastExecutionLog();
+        assertEquals(2, log.size());
+        assertTrue(log.get(0).isTruncated());
+        assertEquals(26, log.get(0).getOriginalOutputChars());
+        assertFalse(log.get(1).isTruncated());
+        assertTrue(consoleText().contains("[truncated] Output of 'Polish' exceeded 6 characters (26)"));
+    }
+
+    @Test
+    void outputWithinLimitIsNotFlagged() {
+        StubOpenAIClient chat = new StubOpenAIClient(configManager, "short");
+        ProcessingUnit polish = promptUnit("Polish");
+        polish.maxOutputChars = 100;
+        PostProcessingService service = service(chat);
+
+        String result = service.applyPipeline("raw text", pipeline("Limited", polish), new CancellationToken());
+
+        assertEquals("short", result);
+        assertFalse(service.getLastExecutionLog().get(0).isTruncated());
+    }
+
+    @Test
+    void recordsTokenUsagePerUnitAndForTheRun() {
+        StubOpenAIClient chat = new StubOpenAIClient(configManager, "done");
+        chat.usage = new TokenUsage(120, 30);
+        PostProcessingService service = service(chat);
+
+        service.applyPipeline("raw text", pipeline("Two calls", promptUnit("Polish"),
+                replacementUnit("Fix name", "done", "Done"), promptUnit("Summarize")), new CancellationToken());
+
+        List<ExecutionLogEntry> log = service.getLastExecutionLog();
+        assertEquals(150, log.get(0).getTokenUsage().getTotalTokens());
+        assertNull(log.get(1).getTokenUsage());
+        assertEquals(150, log.get(2).getTokenUsage().getTotalTokens());
+        assertEquals(240, service.getLastTokenUsage().getPromptTokens());
+        assertEquals(60, service.getLastTokenUsage().getCompletionTokens());
+    }
+
+    @Test
+    void truncateOutputIgnoresMissingLimit() {
+        assertEquals("abc", PostProcessingService.truncateOutput("abcdef", 3));
+        assertEquals("abcdef", PostProcessingService.truncateOutput("abcdef", 0));
+        assertEquals("abcdef", PostProcessingService.truncateOutput("abcdef", 6));
+    }
+
+    @Test
+    void pipelineCannotRunWithoutOpenAIKey() {
+        Pipeline pipeline = pipeline("Cleanup", promptUnit("Polish"));
+
+        assertEquals("OpenAI API key is not set (Settings)", PostProcessingService.checkPipelineReady(pipeline, configManager));
+
+        configManager.setProperty("apiKey", "sk-test");
+        assertNull(PostProcessingService.checkPipelineReady(pipeline, configManager));
+    }
+
+    @Test
+    void customChatEndpointNeedsNoOpenAIKey() {
+        configManager.setOpenAIChatBaseUrl("http://localhost:11434");
+
+        assertNull(PostProcessingService.checkPipelineReady(pipeline("Local", promptUnit("Polish")), configManager));
+    }
+
+    @Test
+    void pipelineCannotRunWithoutOpenWebUIServer() {
+        ProcessingUnit unit = promptUnit("Polish");
+        unit.provider = "Open WebUI";
+        Pipeline pipeline = pipeline("Web", unit);
+
+        assertEquals("Open WebUI server URL is not set (Settings)", PostProcessingService.checkPipelineReady(pipeline, configManager));
+
+        configManager.setOpenWebUIServerUrl("http://localhost:3000");
+        assertNull(PostProcessingService.checkPipelineReady(pipeline, configManager));
+    }
+
+    @Test
+    void replacementOnlyAndDisabledUnitsNeedNoProvider() {
+        Pipeline pipeline = pipeline("Offline", replacementUnit("Fix name", "raw", "RAW"), promptUnit("Polish"));
+        pipeline.unitReferences.get(1).enabled = false;
+
+        assertNull(PostProcessingService.checkPipelineReady(pipeline, configManager));
+        assertNull(PostProcessingService.checkPipelineReady(null, configManager));
+    }
+
+    @Test
+    void plansMixedPipeline() {
+        ProcessingUnit translate = promptUnit("Translate");
+        translate.model = "gpt-4o";
+        Pipeline pipeline = pipeline("Mixed",
+                promptUnit("Polish"),
+                promptUnit("Shorten"),
+                promptUnit("Format"),
+                replacementUnit("Fix name", "raw", "RAW"),
+                translate);
+
+        List<PlannedBatch> plan = service(new StubOpenAIClient(configManager, "done")).plan(pipeline);
+
+        assertEquals(3, plan.size());
+        assertEquals(List.of("Polish", "Shorten", "Format"), plan.get(0).getUnitNames());
+        assertTrue(plan.get(0).isOptimizable());
+        assertEquals("OpenAI", plan.get(0).getProvider());
+        assertEquals("gpt-4o-mini", plan.get(0).getModel());
+        assertEquals(List.of("Fix name"), plan.get(1).getUnitNames());
+        assertFalse(plan.get(1).isApiCall());
+        assertFalse(plan.get(1).isOptimizable());
+        assertEquals(List.of("Translate"), plan.get(2).getUnitNames());
+        assertFalse(plan.get(2).isOptimizable());
+        assertEquals("Execution plan: 2 API calls (1 optimized chain of 3), 1 text replacement",
+                PostProcessingService.describePlan(plan));
+    }
+
+    @Test
+    void promptAfterTextReplacementRuns() {
+        StubOpenAIClient chat = new StubOpenAIClient(configManager, "polished");
+        Pipeline pipeline = pipeline("Replace first", replacementUnit("Fix name", "raw", "RAW"), promptUnit("Polish"));
+
+        String result = service(chat).applyPipeline("raw text", pipeline, new CancellationToken());
+
+        assertEquals("polished", result);
+        assertEquals(List.of("Fix: RAW text"), chat.calls);
+    }
+
+    @Test
+    void planSkipsDisabledUnitsAndNeverRunsThem() {
+        StubOpenAIClient chat = new StubOpenAIClient(configManager, "done");
+        Pipeline pipeline = pipeline("Partial", promptUnit("Polish"), promptUnit("Shorten"));
+        pipeline.unitReferences.get(1).enabled = false;
+
+        List<PlannedBatch> plan = service(chat).plan(pipeline);
+
+        assertEquals(1, plan.size());
+        assertEquals(List.of("Polish"), plan.get(0).getUnitNames());
+        assertTrue(chat.calls.isEmpty());
+        assertEquals("Execution plan: 1 API call", PostProcessingService.describePlan(plan));
+    }
+
+    @Test
+    void describesSeveralChains() {
+        PlannedBatch chainOfTwo = new PlannedBatch(List.of("a", "b"), "Prompt", "OpenAI", "m", true);
+        PlannedBatch chainOfThree = new PlannedBatch(List.of("c", "d", "e"), "Prompt", "Open WebUI", "n", true);
+        PlannedBatch replacement = new PlannedBatch(List.of("f"), "Text Replacement", null, null, false);
+
+        assertEquals("Execution plan: 2 API calls (2 optimized chains of 2 and 3), 2 text replacements",
+                PostProcessingService.describePlan(List.of(chainOfTwo, replacement, chainOfThree, replacement)));
+        assertEquals("Execution plan: 0 API calls", PostProcessingService.describePlan(List.of()));
+    }
+
+    @Test
+    void chainedSystemPromptStartsWithConfiguredMetaPrompt() {
+        configManager.setChainedMetaPrompt("Output only the final text.");
+        StubOpenAIClient chat = new StubOpenAIClient(configManager, "done");
+        ProcessingUnit translate = promptUnit("Translate");
+        ProcessingUnit polish = promptUnit("Polish");
+
+        service(chat).applyPipeline("raw text", pipeline("Chain", translate, polish), new CancellationToken());
+
+        assertEquals(1, chat.systemPrompts.size());
+        String systemPrompt = chat.systemPrompts.get(0);
+        assertTrue(systemPrompt.startsWith("Output only the final text.\n\nStep Contexts:"), systemPrompt);
+        assertFalse(systemPrompt.contains(ConfigManager.DEFAULT_CHAINED_META_PROMPT), systemPrompt);
+    }
+
+    @Test
+    void chainedSystemPromptFallsBackToDefaultMetaPrompt() {
+        StubOpenAIClient chat = new StubOpenAIClient(configManager, "done");
+
+        service(chat).applyPipeline("raw text",
+                pipeline("Chain", promptUnit("Translate"), promptUnit("Polish")), new CancellationToken());
+
+        assertTrue(chat.systemPrompts.get(0).startsWith(ConfigManager.DEFAULT_CHAINED_META_PROMPT));
+    }
+
+    private PostProcessingService service(OpenAIClient chat) {
+        return new PostProcessingService(configManager, chat, new OpenWebUIProcessClient(configManager));
+    }
+
+    private Pipeline pipeline(String title, ProcessingUnit... units) {
+        Pipeline pipeline = new Pipeline("pipeline-" + title, title, "");
+        for (ProcessingUnit unit : units) {
+            configManager.saveProcessingUnit(unit);
+            pipeline.unitReferences.add(new PipelineUnitReference(unit.uuid, true));
+        }
+        return pipeline;
+    }
+
+    private static ProcessingUnit promptUnit(String name) {
+        ProcessingUnit unit = new ProcessingUnit();
+        unit.uuid = "unit-" + name;
+        unit.name = name;
+        unit.type = "Prompt";
+        unit.provider = "OpenAI";
+        unit.model = "gpt-4o-mini";
+        unit.systemPrompt = "You fix transcripts.";
+        unit.userPrompt = "Fix: {{input}}";
+        return unit;
+    }
+
+    private static ProcessingUnit replacementUnit(String name, String from, String to) {
+        ProcessingUnit unit = new ProcessingUnit();
+        unit.uuid = "unit-" + name;
+        unit.name = name;
+        unit.type = "Text Replacement";
+        unit.textToReplace = from;
+        unit.replacementText = to;
+        return unit;
+    }
+
+    private String consoleText() throws Exception {
+        // ConsoleLogger appends on the event dispatch thread
+        SwingUtilities.invokeAndWait(() -> { });
+        return console.getText();
+    }
+
+    /**
+     * Chat client that answers every request with a fixed text instead of calling the API.
+     */
+    static class StubOpenAIClient extends OpenAIClient {
+        final List<String> calls = Collections.synchronizedList(new ArrayList<>());
+        final List<String> systemPrompts = Collections.synchronizedList(new ArrayList<>());
+        final List<String> models = Collections.synchronizedList(new ArrayList<>());
+        final String response;
+        Runnable onCall = () -> { };
+        TokenUsage usage;
+
+        StubOpenAIClient(ConfigManager configManager, String response) {
+            super(configManager);
+            this.response = response;
+        }
+
+        @Override
+        public ChatCompletion complete(String systemPrompt, String userPrompt, String model, boolean jsonMode) {
+            calls.add(userPrompt);
+            systemPrompts.add(systemPrompt);
+            models.add(model);
+            onCall.run();
+            return new ChatCompletion(response, usage);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+package org.whispercat.postprocessing;
+
+import org.junit.jupiter.api.Test;
+
+import java.util.regex.PatternSyntaxException;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class RegexReplacerTest {
+
+    // Takes seconds without a limit; simpler patterns such as (a+)+$ are memoized by the JDK
+    private static final String BACKTRACKING_PATTERN = "(.*a){12}$";
+    private static final String BACKTRACKING_INPUT = "aaaaaaaaaaaaaaaaaaaaaaaaaaa!";
+
+    @Test
+    void replacesAllMatches() throws RegexTimeoutException {
+        assertEquals("one, two, three", RegexReplacer.replaceAll("one two three", "\\s+(?=\\w)", ", ", 1000));
+    }
+
+    @Test
+    void supportsGroupReferences() throws RegexTimeoutException {
+        assertEquals("Smith, John", RegexReplacer.replaceAll("John Smith", "(\\w+) (\\w+)", "$2, $1", 1000));
+    }
+
+    @Test
+    void replacesWithoutLimitWhenTimeoutIsNotPositive() throws RegexTimeoutException {
+        assertEquals("b-b", RegexReplacer.replaceAll("a-a", "a", "b", 0));
+        assertEquals("b-b", RegexReplacer.replaceAll("a-a", "a", "b", -1));
+    }
+
+    @Test
+    void abortsBacktrackingPatternAfterTimeout() {
+        long start = System.nanoTime();
+        RegexTimeoutException e = assertThrows(RegexTimeoutException.class,
+                () -> RegexReplacer.replaceAll(BACKTRACKING_INPUT, BACKTRACKING_PATTERN, "", 100));
+        long elapsedMs = (System.nanoTime() - start) / 1_000_000L;
+
+        assertEquals(100, e.getTimeoutMs());
+        assertTrue(elapsedMs < 5000, "took " + elapsedMs + " ms");
+    }
+
+    @Test
+    void rejectsInvalidPattern() {
+        assertThrows(PatternSyntaxException.class, () -> RegexReplacer.replaceAll("text", "(", "", 1000));
+    }
+
+    @Test
+    void rejectsMissingGroupReference() {
+        assertThrows(IndexOutOfBoundsException.class, () -> RegexReplacer.replaceAll("text", "t", "$2", 1000));
+        assertThrows(IllegalArgumentException.class, () -> RegexReplacer.replaceAll("text", "t", "${name}", 1000));
+    }
+}
@@ -0,0 +1,52 @@
+package org.whispercat.postprocessing.clients;
+
+import com.fasterxml.jackson.databind.ObjectMapper;
+import org.junit.jupiter.api.Test;
+import org.whispercat.postprocessing.TokenUsage;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertNull;
+
+class ChatCompletionTest {
+
+    private final ObjectMapper mapper = new ObjectMapper();
+
+    @Test
+    void readsTextAndUsage() throws Exception {
+        ChatCompletion completion = ChatCompletion.fromResponse(mapper.readTree("{"
+                + "\"id\": \"chatcmpl-123\", \"object\": \"chat.completion\", \"model\": \"gpt-4o-mini\","
+                + "\"choices\": [{\"index\": 0, \"message\": {\"role\": \"assistant\", \"content\": \"Cleaned up.\"},"
+                + " \"finish_reason\": \"stop\"}],"
+                + "\"usage\": {\"prompt_tokens\": 57, \"completion_tokens\": 12, \"total_tokens\": 69}}"));
+
+        assertEquals("Cleaned up.", completion.getText());
+        TokenUsage usage = completion.getUsage();
+        assertEquals(57, usage.getPromptTokens());
+        assertEquals(12, usage.getCompletionTokens());
+        assertEquals(69, usage.getTotalTokens());
+    }
+
+    @Test
+    void usageIsOptional() throws Exception {
+        // Some OpenAI-compatible servers leave the usage out
+        ChatCompletion completion = ChatCompletion.fromResponse(mapper.readTree(
+                "{\"choices\": [{\"message\": {\"content\": \"Hi\"}}]}"));
+
+        assertEquals("Hi", completion.getText());
+        assertNull(completion.getUsage());
+    }
+
+    @Test
+    void responseWithoutChoicesHasEmptyText() throws Exception {
+        assertEquals("", ChatCompletion.fromResponse(mapper.readTree("{\"choices\": []}")).getText());
+    }
+
+    @Test
+    void sumsUsageOfSeveralCalls() {
+        TokenUsage total = TokenUsage.sum(TokenUsage.sum(null, new TokenUsage(10, 5)), new TokenUsage(20, 7));
+
+        assertEquals(30, total.getPromptTokens());
+        assertEquals(12, total.getCompletionTokens());
+        assertNull(TokenUsage.sum(null, null));
+    }
+}
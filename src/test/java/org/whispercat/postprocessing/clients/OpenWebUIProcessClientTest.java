@@ -0,0 +1,68 @@
+package org.whispercat.postprocessing.clients;
+
+import com.fasterxml.jackson.databind.JsonNode;
+import com.fasterxml.jackson.databind.ObjectMapper;
+import com.sun.net.httpserver.HttpServer;
+import org.junit.jupiter.api.AfterEach;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+import org.whispercat.ConfigManager;
+import org.whispercat.OpenWebUIPaths;
+
+import java.io.IOException;
+import java.io.OutputStream;
+import java.net.InetAddress;
+import java.net.InetSocketAddress;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Path;
+import java.util.Map;
+import java.util.concurrent.ConcurrentHashMap;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+
+class OpenWebUIProcessClientTest {
+
+    @TempDir
+    Path tempDir;
+
+    private HttpServer server;
+
+    @AfterEach
+    void tearDown() {
+        if (server != null) {
+            server.stop(0);
+        }
+        OpenWebUIPaths.clearCache();
+    }
+
+    @Test
+    void sendsSharedChatPayloadWithParams() throws IOException {
+        Map<String, String> received = new ConcurrentHashMap<>();
+        server = HttpServer.create(new InetSocketAddress(InetAddress.getLoopbackAddress(), 0), 0);
+        server.createContext("/api/chat/completions", exchange -> {
+            received.put("authorization", exchange.getRequestHeaders().getFirst("Authorization"));
+            received.put("body", new String(exchange.getRequestBody().readAllBytes(), StandardCharsets.UTF_8));
+            byte[] body = "{\"choices\":[{\"message\":{\"content\":\"Hello.\"}}]}".getBytes(StandardCharsets.UTF_8);
+            exchange.sendResponseHeaders(200, body.length);
+            try (OutputStream out = exchange.getResponseBody()) {
+                out.write(body);
+            }
+        });
+        server.start();
+        ConfigManager config = new ConfigManager(tempDir.resolve("config.properties").toFile());
+        config.setOpenWebUIServerUrl("http://127.0.0.1:" + server.getAddress().getPort() + "/");
+        config.setProperty("openWebUIApiKey", "owui-key");
+
+        ChatCompletion completion = new OpenWebUIProcessClient(config).complete("Fix punctuation.", "hello", "llama3", false);
+
+        assertEquals("Hello.", completion.getText());
+        assertEquals("Bearer owui-key", received.get("authorization"));
+        JsonNode payload = new ObjectMapper().readTree(received.get("body"));
+        assertEquals("llama3", payload.path("model").asText());
+        assertEquals("system", payload.path("messages").get(0).path("role").asText());
+        assertEquals("hello", payload.path("messages").get(1).path("content").asText());
+        assertEquals("Fix punctuation.", payload.path("params").path("system").asText());
+        assertFalse(payload.has("response_format"));
+    }
+}
@@ -0,0 +1,148 @@
+package org.whispercat.recording;
+
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+
+import javax.sound.sampled.AudioFileFormat;
+import javax.sound.sampled.AudioFormat;
+import javax.sound.sampled.AudioInputStream;
+import javax.sound.sampled.AudioSystem;
+import java.io.ByteArrayInputStream;
+import java.io.File;
+import java.io.IOException;
+import java.nio.file.Path;
+import java.util.Arrays;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class AudioChunkerTest {
+
+    private static final int SAMPLE_RATE = 16000;
+    // 4 s of 16-bit mono audio per chunk, of which 2 s overlap the previous chunk
+    private static final long MAX_BYTES = 44 + 4 * SAMPLE_RATE * 2;
+
+    @TempDir
+    Path tempDir;
+
+    @Test
+    void splitsIntoOverlappingChunksUnderTheLimit() throws Exception {
+        File recording = writeRamp("long.wav", 10 * SAMPLE_RATE);
+
+        List<AudioChunker.Chunk> chunks = AudioChunker.split(recording, MAX_BYTES, tempDir.toFile());
+
+        assertEquals(4, chunks.size());
+        for (int i = 0; i < chunks.size(); i++) {
+            AudioChunker.Chunk chunk = chunks.get(i);
+            assertEquals(i * 2.0, chunk.getStartSeconds(), 1e-9);
+            assertTrue(chunk.getFile().length() <= MAX_BYTES, chunk.getFile().length() + " bytes");
+            int[] samples = readSamples(chunk.getFile());
+            assertEquals(4 * SAMPLE_RATE, samples.length);
+            // Each chunk continues the ramp where it starts in the recording
+            assertEquals(ramp(i * 2 * SAMPLE_RATE), samples[0]);
+            assertEquals(ramp((i * 2 + 4) * SAMPLE_RATE - 1), samples[samples.length - 1]);
+        }
+    }
+
+    @Test
+    void lastChunkHoldsTheRemainder() throws Exception {
+        File recording = writeRamp("uneven.wav", 9 * SAMPLE_RATE);
+
+        List<AudioChunker.Chunk> chunks = AudioChunker.split(recording, MAX_BYTES, tempDir.toFile());
+
+        assertEquals(4, chunks.size());
+        int[] last = readSamples(chunks.get(3).getFile());
+        assertEquals(3 * SAMPLE_RATE, last.length);
+        assertEquals(ramp(9 * SAMPLE_RATE - 1), last[last.length - 1]);
+    }
+
+    @Test
+    void shortRecordingFitsInOneChunk() throws Exception {
+        File recording = writeRamp("short.wav", SAMPLE_RATE);
+
+        List<AudioChunker.Chunk> chunks = AudioChunker.split(recording, MAX_BYTES, tempDir.toFile());
+
+        assertEquals(1, chunks.size());
+        assertEquals(SAMPLE_RATE, readSamples(chunks.get(0).getFile()).length);
+    }
+
+    @Test
+    void rejectsLimitSmallerThanOverlap() throws Exception {
+        File recording = writeRamp("limit.wav", 10 * SAMPLE_RATE);
+
+        assertThrows(IOException.class, () -> AudioChunker.split(recording, 44 + SAMPLE_RATE * 2, tempDir.toFile()));
+    }
+
+    @Test
+    void deletesChunkFiles() throws Exception {
+        List<AudioChunker.Chunk> chunks = AudioChunker.split(writeRamp("delete.wav", 10 * SAMPLE_RATE), MAX_BYTES, tempDir.toFile());
+
+        AudioChunker.delete(chunks);
+
+        for (AudioChunker.Chunk chunk : chunks) {
+            assertFalse(chunk.getFile().exists());
+        }
+    }
+
+    @Test
+    void mergeDropsWordsRepeatedInTheOverlap() {
+        assertEquals("we should meet on Monday at the office to plan the release",
+                AudioChunker.mergeTexts(Arrays.asList(
+                        "we should meet on Monday at the",
+                        "Monday at the office to plan the release")));
+    }
+
+    @Test
+    void mergeIgnoresCaseAndPunctuationWhenMatching() {
+        assertEquals("Thanks for joining the call. let's get started",
+                AudioChunker.mergeTexts(Arrays.asList("Thanks for joining the call.", "joining the call, let's get started")));
+    }
+
+    @Test
+    void mergeKeepsShortCoincidentalRepeats() {
+        // One or two shared words are more likely real speech than a repeat of the overlap
+        assertEquals("look at the the dog barks",
+                AudioChunker.mergeTexts(Arrays.asList("look at the", "the dog barks")));
+        assertEquals("we saw it and then we saw it again",
+                AudioChunker.mergeTexts(Arrays.asList("we saw it and then", "we saw it again")));
+    }
+
+    @Test
+    void mergeSkipsEmptyChunks() {
+        assertEquals("first part second part",
+                AudioChunker.mergeTexts(Arrays.asList("first part", "  ", null, "second part")));
+        assertEquals("", AudioChunker.mergeTexts(Arrays.asList("", null)));
+    }
+
+    private static int ramp(int frame) {
+        return (short) (frame % 65536);
+    }
+
+    private File writeRamp(String name, int frames) throws Exception {
+        byte[] data = new byte[frames * 2];
+        for (int frame = 0; frame < frames; frame++) {
+            int value = ramp(frame);
+            data[frame * 2] = (byte) value;
+            data[frame * 2 + 1] = (byte) (value >> 8);
+        }
+        File file = tempDir.resolve(name).toFile();
+        AudioSystem.write(new AudioInputStream(new ByteArrayInputStream(data),
+                new AudioFormat(SAMPLE_RATE, 16, 1, true, false), frames), AudioFileFormat.Type.WAVE, file);
+        return file;
+    }
+
+    private static int[] readSamples(File file) throws Exception {
+        byte[] data;
+        try (AudioInputStream stream = AudioSystem.getAudioInputStream(file)) {
+            data = stream.readAllBytes();
+        }
+        int[] samples = new int[data.length / 2];
+        for (int i = 0; i < samples.length; i++) {
+            samples[i] = (short) ((data[i * 2 + 1] << 8) | (data[i * 2] & 0xFF));
+        }
+        return samples;
+    }
+}
@@ -0,0 +1,39 @@
+package org.whispercat.recording;
+
+import org.junit.jupiter.api.Test;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+
+class AudioDeviceKindTest {
+
+    @Test
+    void recognizesPulseAudioMonitorSources() {
+        assertEquals(AudioDeviceKind.LOOPBACK,
+                AudioDeviceKind.classify("Monitor of Built-in Audio Analog Stereo", "Direct Audio Device"));
+        assertEquals(AudioDeviceKind.LOOPBACK,
+                AudioDeviceKind.classify("alsa_output.pci-0000_00_1f.3.analog-stereo.monitor", null));
+    }
+
+    @Test
+    void recognizesWindowsStereoMix() {
+        assertEquals(AudioDeviceKind.LOOPBACK,
+                AudioDeviceKind.classify("Stereo Mix (Realtek(R) Audio)", "Direct Audio Device: DirectSound Capture"));
+        assertEquals(AudioDeviceKind.LOOPBACK, AudioDeviceKind.classify("What U Hear (Sound Blaster)", ""));
+    }
+
+    @Test
+    void recognizesMacVirtualDevices() {
+        assertEquals(AudioDeviceKind.LOOPBACK, AudioDeviceKind.classify("BlackHole 2ch", null));
+        assertEquals(AudioDeviceKind.LOOPBACK, AudioDeviceKind.classify("Soundflower (2ch)", null));
+        // A device may only reveal itself in the description
+        assertEquals(AudioDeviceKind.LOOPBACK, AudioDeviceKind.classify("Device 3", "Loopback Audio"));
+    }
+
+    @Test
+    void classifiesMicrophonesAsMicrophone() {
+        assertEquals(AudioDeviceKind.MICROPHONE,
+                AudioDeviceKind.classify("Microphone (USB Audio Device)", "Direct Audio Device: DirectSound Capture"));
+        assertEquals(AudioDeviceKind.MICROPHONE, AudioDeviceKind.classify("MacBook Pro Microphone", null));
+        assertEquals(AudioDeviceKind.MICROPHONE, AudioDeviceKind.classify(null, null));
+    }
+}
@@ -0,0 +1,314 @@
+package org.whispercat.recording;
+
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+import org.whispercat.ConfigManager;
+
+import javax.sound.sampled.AudioFormat;
+import javax.sound.sampled.AudioInputStream;
+import javax.sound.sampled.AudioSystem;
+import javax.sound.sampled.TargetDataLine;
+import java.io.File;
+import java.lang.reflect.Proxy;
+import java.nio.file.Path;
+import java.util.concurrent.atomic.AtomicBoolean;
+import java.util.concurrent.atomic.AtomicInteger;
+import java.util.function.IntUnaryOperator;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertNull;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class AudioRecorderTest {
+
+    private static final AudioFormat FORMAT = new AudioFormat(16000f, 16, 1, true, false);
+    private static final AudioFormat STEREO = new AudioFormat(16000f, 16, 2, true, false);
+    private static final int BUFFER_SIZE = 3200;
+    private static final int CHUNK = BUFFER_SIZE / 5;
+
+    @TempDir
+    Path tempDir;
+
+    @Test
+    void readErrorNotifiesListenerAndKeepsCapturedAudio() throws Exception {
+        File wavFile = tempDir.resolve("record.wav").toFile();
+        AudioRecorder recorder = new AudioRecorder(wavFile, new ConfigManager(tempDir.resolve("config.properties").toFile()));
+        AtomicBoolean notified = new AtomicBoolean();
+        recorder.setDisconnectListener(() -> notified.set(true));
+
+        recorder.record(fakeLine(call -> {
+            if (call < 3) {
+                return CHUNK;
+            }
+            throw new IllegalStateException("Device removed");
+        }, true), FORMAT);
+
+        assertTrue(notified.get());
+        assertEquals(3 * CHUNK / 2, framesIn(wavFile));
+    }
+
+    @Test
+    void injectedErrorIsReportedAndCapturedAudioSaved() throws Exception {
+        File wavFile = tempDir.resolve("record.wav").toFile();
+        AudioRecorder recorder = new AudioRecorder(wavFile, new ConfigManager(tempDir.resolve("config.properties").toFile()));
+        AtomicBoolean notified = new AtomicBoolean();
+        recorder.setDisconnectListener(() -> notified.set(true));
+
+        recorder.record(fakeLine(call -> {
+            if (call < 2) {
+                return CHUNK;
+            }
+            throw new IllegalStateException("Device removed");
+        }, true), FORMAT);
+        // The form still calls stop() when handling the failure
+        recorder.stop();
+
+        assertTrue(notified.get());
+        assertEquals("Reading from the microphone failed: Device removed", recorder.getRecordingError());
+        assertEquals(2 * CHUNK / 2, framesIn(wavFile));
+    }
+
+    @Test
+    void closedLineNotifiesListener() throws Exception {
+        File wavFile = tempDir.resolve("record.wav").toFile();
+        AudioRecorder recorder = new AudioRecorder(wavFile, new ConfigManager(tempDir.resolve("config.properties").toFile()));
+        AtomicBoolean notified = new AtomicBoolean();
+        recorder.setDisconnectListener(() -> notified.set(true));
+
+        recorder.record(fakeLine(call -> call < 2 ? CHUNK : 0, false), FORMAT);
+
+        assertTrue(notified.get());
+        assertEquals("The microphone was closed by the system", recorder.getRecordingError());
+        assertEquals(2 * CHUNK / 2, framesIn(wavFile));
+    }
+
+    @Test
+    void regularStopDoesNotNotify() throws Exception {
+        File wavFile = tempDir.resolve("record.wav").toFile();
+        AudioRecorder recorder = new AudioRecorder(wavFile, new ConfigManager(tempDir.resolve("config.properties").toFile()));
+        AtomicBoolean notified = new AtomicBoolean();
+        recorder.setDisconnectListener(() -> notified.set(true));
+        AtomicInteger reads = new AtomicInteger();
+        TargetDataLine line = fakeLine(call -> {
+            reads.incrementAndGet();
+            sleep(5);
+            return CHUNK;
+        }, true);
+
+        Thread recording = new Thread(() -> recorder.record(line, FORMAT));
+        recording.start();
+        while (reads.get() < 3) {
+            Thread.sleep(5);
+        }
+        recorder.stop();
+        recording.join(5000);
+
+        assertFalse(recording.isAlive());
+        assertFalse(notified.get());
+        assertTrue(framesIn(wavFile) > 0);
+        assertNull(recorder.getRecordingError());
+    }
+
+    @Test
+    void dictationRecordsUtteranceWithPreRollAndStopsAfterPause() throws Exception {
+        File wavFile = tempDir.resolve("record.wav").toFile();
+        AudioRecorder recorder = new AudioRecorder(wavFile, new ConfigManager(tempDir.resolve("config.properties").toFile()));
+        AtomicBoolean speechStarted = new AtomicBoolean();
+        AtomicBoolean utteranceEnded = new AtomicBoolean();
+        recorder.enableDictation(new VoiceActivityDetector(0.05f, 100, 300),
+                () -> speechStarted.set(true), () -> utteranceEnded.set(true));
+
+        // 20 ms chunks: 200 ms silence, 400 ms speech, then silence
+        recorder.record(fakeLine(call -> CHUNK, call -> call >= 10 && call < 30 ? 8000 : 0, true), FORMAT);
+
+        assertTrue(speechStarted.get());
+        assertTrue(utteranceEnded.get());
+        assertTrue(recorder.hasSpeech());
+        // onset after chunk 14 with 300 ms pre-roll (chunks 0-14), end after 300 ms of silence (chunk 44)
+        assertEquals(45 * CHUNK / 2, framesIn(wavFile));
+    }
+
+    @Test
+    void dictationWithoutSpeechWritesNoAudio() throws Exception {
+        File wavFile = tempDir.resolve("record.wav").toFile();
+        AudioRecorder recorder = new AudioRecorder(wavFile, new ConfigManager(tempDir.resolve("config.properties").toFile()));
+        AtomicBoolean utteranceEnded = new AtomicBoolean();
+        recorder.enableDictation(new VoiceActivityDetector(0.05f, 100, 300), null, () -> utteranceEnded.set(true));
+
+        recorder.record(fakeLine(call -> call < 20 ? CHUNK : 0, call -> 0, false), FORMAT);
+
+        assertFalse(recorder.hasSpeech());
+        assertFalse(utteranceEnded.get());
+        assertEquals(0, framesIn(wavFile));
+    }
+
+    @Test
+    void voiceActivatedStartDropsAudioBeforeSpeechAndKeepsRecordingAfterIt() throws Exception {
+        File wavFile = tempDir.resolve("record.wav").toFile();
+        AudioRecorder recorder = new AudioRecorder(wavFile, new ConfigManager(tempDir.resolve("config.properties").toFile()));
+        AtomicBoolean speechStarted = new AtomicBoolean();
+        recorder.enableVoiceActivatedStart(new VoiceActivityDetector(0.05f, 100, 300), () -> speechStarted.set(true));
+
+        // 20 ms chunks: 600 ms silence, 400 ms speech, 600 ms silence, then the line closes
+        recorder.record(fakeLine(call -> call < 80 ? CHUNK : 0, call -> call >= 30 && call < 50 ? 8000 : 0, false),
+                FORMAT);
+
+        assertTrue(speechStarted.get());
+        assertTrue(recorder.hasSpeech());
+        // onset after chunk 34 with 300 ms pre-roll (chunks 20-34); the pause after the speech is kept
+        assertEquals(60 * CHUNK / 2, framesIn(wavFile));
+    }
+
+    @Test
+    void pausedAudioIsLeftOutOfTheFile() throws Exception {
+        File wavFile = tempDir.resolve("record.wav").toFile();
+        AudioRecorder recorder = new AudioRecorder(wavFile, new ConfigManager(tempDir.resolve("config.properties").toFile()));
+
+        recorder.record(fakeLine(call -> {
+            if (call == 5) {
+                recorder.pause();
+            } else if (call == 10) {
+                recorder.resume();
+            }
+            return call < 20 ? CHUNK : 0;
+        }, false), FORMAT);
+
+        assertFalse(recorder.isPaused());
+        assertEquals(15 * CHUNK / 2, framesIn(wavFile));
+    }
+
+    @Test
+    void levelFollowsCapturedAudio() throws Exception {
+        File wavFile = tempDir.resolve("record.wav").toFile();
+        AudioRecorder recorder = new AudioRecorder(wavFile, new ConfigManager(tempDir.resolve("config.properties").toFile()));
+        float[] levels = new float[3];
+
+        recorder.record(fakeLine(call -> {
+            if (call == 3) {
+                levels[0] = recorder.getCurrentLevel();
+                recorder.pause();
+            } else if (call == 5) {
+                levels[1] = recorder.getCurrentLevel();
+                recorder.resume();
+            } else if (call == 7) {
+                levels[2] = recorder.getCurrentLevel();
+            }
+            return call < 8 ? CHUNK : 0;
+        }, call -> 16384, false), FORMAT);
+
+        // A square wave at half of full scale
+        assertEquals(0.5f, levels[0], 0.001f);
+        assertEquals(0f, levels[1]);
+        assertEquals(0.5f, levels[2], 0.001f);
+        assertEquals(0f, recorder.getCurrentLevel());
+    }
+
+    @Test
+    void recordedTimeExcludesPauses() {
+        // Started at 1000, paused 3000-5000 and 8000-8500: 9000 - 1000 - 2500
+        assertEquals(5500, AudioRecorder.activeMillis(1000, 9000, 2500, -1));
+        // Started at 1000, paused 3000-5500 and again since 7000: only 1000-3000 and 5500-7000 count
+        assertEquals(3500, AudioRecorder.activeMillis(1000, 9000, 2500, 7000));
+        assertEquals(0, AudioRecorder.activeMillis(1000, 1000, 0, -1));
+    }
+
+    @Test
+    void stereoCaptureIsSavedAsMonoWhenForced() throws Exception {
+        File wavFile = tempDir.resolve("record.wav").toFile();
+        AudioRecorder recorder = new AudioRecorder(wavFile, new ConfigManager(tempDir.resolve("config.properties").toFile()));
+
+        recorder.record(fakeLine(call -> call < 5 ? CHUNK : 0, call -> 4000, false), STEREO, true);
+
+        try (AudioInputStream stream = AudioSystem.getAudioInputStream(wavFile)) {
+            assertEquals(1, stream.getFormat().getChannels());
+            // Same duration as the capture: one mono frame per stereo frame
+            assertEquals(5 * CHUNK / 4, stream.getFrameLength());
+        }
+    }
+
+    @Test
+    void stereoCaptureStaysStereoByDefault() throws Exception {
+        File wavFile = tempDir.resolve("record.wav").toFile();
+        AudioRecorder recorder = new AudioRecorder(wavFile, new ConfigManager(tempDir.resolve("config.properties").toFile()));
+
+        recorder.record(fakeLine(call -> call < 5 ? CHUNK : 0, false), STEREO, false);
+
+        try (AudioInputStream stream = AudioSystem.getAudioInputStream(wavFile)) {
+            assertEquals(2, stream.getFormat().getChannels());
+            assertEquals(5 * CHUNK / 4, stream.getFrameLength());
+        }
+    }
+
+    @Test
+    void unsignedCaptureIsSavedAsSigned() throws Exception {
+        File wavFile = tempDir.resolve("record.wav").toFile();
+        AudioRecorder recorder = new AudioRecorder(wavFile, new ConfigManager(tempDir.resolve("config.properties").toFile()));
+        AudioFormat unsigned = new AudioFormat(AudioFormat.Encoding.PCM_UNSIGNED, 16000f, 16, 1, 2, 16000f, false);
+
+        // The line delivers the bytes of 4000 and -4000, read as unsigned 4000 and 61536
+        recorder.record(fakeLine(call -> call < 2 ? CHUNK : 0, call -> 4000, false), unsigned);
+
+        try (AudioInputStream stream = AudioSystem.getAudioInputStream(wavFile)) {
+            assertEquals(AudioFormat.Encoding.PCM_SIGNED, stream.getFormat().getEncoding());
+            byte[] data = stream.readAllBytes();
+            assertEquals(2 * CHUNK, data.length);
+            assertEquals(4000 - 32768, PcmSamples.read(data, 0, 2, false));
+            assertEquals(61536 - 32768, PcmSamples.read(data, 2, 2, false));
+        }
+    }
+
+    /**
+     * Creates a line whose read calls are answered by the given function (call index to bytes read).
+     */
+    private static TargetDataLine fakeLine(IntUnaryOperator reads, boolean open) {
+        return fakeLine(reads, call -> 0, open);
+    }
+
+    /**
+     * Creates a line that also fills each read with a square wave of the given amplitude (call index to amplitude).
+     */
+    private static TargetDataLine fakeLine(IntUnaryOperator reads, IntUnaryOperator amplitudes, boolean open) {
+        AtomicInteger calls = new AtomicInteger();
+        return (TargetDataLine) Proxy.newProxyInstance(AudioRecorderTest.class.getClassLoader(),
+                new Class<?>[]{TargetDataLine.class}, (proxy, method, args) -> {
+                    switch (method.getName()) {
+                        case "read":
+                            int call = calls.getAndIncrement();
+                            int read = reads.applyAsInt(call);
+                            fill((byte[]) args[0], (int) args[1], read, amplitudes.applyAsInt(call));
+                            return read;
+                        case "getBufferSize":
+                            return BUFFER_SIZE;
+                        case "isOpen":
+                            return open;
+                        case "getFormat":
+                            return FORMAT;
+                        default:
+                            return null;
+                    }
+                });
+    }
+
+    private static void fill(byte[] buffer, int offset, int length, int amplitude) {
+        for (int i = 0; i + 1 < length; i += 2) {
+            int sample = (i / 2) % 2 == 0 ? amplitude : -amplitude;
+            buffer[offset + i] = (byte) sample;
+            buffer[offset + i + 1] = (byte) (sample >> 8);
+        }
+    }
+
+    private static long framesIn(File wavFile) throws Exception {
+        try (AudioInputStream stream = AudioSystem.getAudioInputStream(wavFile)) {
+            return stream.getFrameLength();
+        }
+    }
+
+    private static void sleep(long millis) {
+        try {
+            Thread.sleep(millis);
+        } catch (InterruptedException e) {
+            Thread.currentThread().interrupt();
+        }
+    }
+}
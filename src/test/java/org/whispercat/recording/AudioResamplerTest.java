@@ -0,0 +1,274 @@
+package org.whispercat.recording;
+
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+import org.whispercat.recording.clients.TempFile;
+
+import javax.sound.sampled.AudioFileFormat;
+import javax.sound.sampled.AudioFormat;
+import javax.sound.sampled.AudioInputStream;
+import javax.sound.sampled.AudioSystem;
+import java.io.ByteArrayInputStream;
+import java.io.File;
+import java.nio.file.Path;
+
+import static org.junit.jupiter.api.Assertions.assertArrayEquals;
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertNotEquals;
+import static org.junit.jupiter.api.Assertions.assertSame;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class AudioResamplerTest {
+
+    @TempDir
+    Path tempDir;
+
+    @Test
+    void resamples48kHzSineTo16kHz() {
+        AudioFormat format = new AudioFormat(48000f, 16, 1, true, false);
+
+        byte[] resampled = AudioResampler.resample(sine(48000, 48000, 1), format, 16000f);
+
+        assertEquals(16000 * 2, resampled.length);
+        // Every third source sample lands exactly on an output sample
+        byte[] source = sine(48000, 48000, 1);
+        assertEquals(sample(source, 300), sample(resampled, 100));
+    }
+
+    @Test
+    void nonIntegerRatioKeepsDuration() {
+        AudioFormat format = new AudioFormat(44100f, 16, 1, true, false);
+
+        // 2.5 s at 44.1 kHz -> 2.5 s at 16 kHz, although 44100 / 16000 is not a whole number
+        byte[] resampled = AudioResampler.resample(sine(44100, 110250, 1), format, 16000f);
+
+        assertEquals(40000 * 2, resampled.length);
+    }
+
+    @Test
+    void nonIntegerRatioKeepsStereoChannelsApart() {
+        AudioFormat format = new AudioFormat(44100f, 16, 2, true, false);
+        // Left constant 1000, right constant -1000
+        byte[] source = new byte[44100 * 4];
+        for (int frame = 0; frame < 44100; frame++) {
+            writeSample(source, frame * 4, 1000);
+            writeSample(source, frame * 4 + 2, -1000);
+        }
+
+        byte[] resampled = AudioResampler.resample(source, format, 16000f);
+
+        assertEquals(16000 * 4, resampled.length);
+        assertEquals(1000, sample(resampled, 2 * 7919));
+        assertEquals(-1000, sample(resampled, 2 * 7919 + 1));
+    }
+
+    @Test
+    void keepsRecordingAtTargetRate() throws Exception {
+        File recording = writeWav("recording.wav", 16000f, sine(16000, 16000, 1));
+
+        try (TempFile resampled = AudioResampler.resampleIfNeeded(recording, 16000f, tempDir.toFile())) {
+            assertSame(recording, resampled.getFile());
+        }
+        assertTrue(recording.exists());
+    }
+
+    @Test
+    void deletesResampledCopyWhenClosed() throws Exception {
+        File recording = writeWav("recording.wav", 48000f, sine(48000, 24000, 1));
+
+        File resampledFile;
+        try (TempFile resampled = AudioResampler.resampleIfNeeded(recording, 16000f, tempDir.toFile())) {
+            resampledFile = resampled.getFile();
+            assertNotEquals(recording, resampledFile);
+            try (AudioInputStream stream = AudioSystem.getAudioInputStream(resampledFile)) {
+                assertEquals(16000f, stream.getFormat().getSampleRate());
+                assertEquals(8000, stream.getFrameLength());
+            }
+        }
+        assertFalse(resampledFile.exists());
+        assertTrue(recording.exists());
+    }
+
+    @Test
+    void downmixAveragesChannelsAndKeepsDuration() {
+        AudioFormat format = new AudioFormat(16000f, 16, 2, true, false);
+        byte[] stereo = new byte[3 * 4];
+        int[][] frames = {{1000, 3000}, {-2000, 2000}, {32767, 32767}};
+        for (int frame = 0; frame < frames.length; frame++) {
+            writeSample(stereo, frame * 4, frames[frame][0]);
+            writeSample(stereo, frame * 4 + 2, frames[frame][1]);
+        }
+
+        byte[] mono = AudioResampler.toMono(stereo, format);
+
+        assertEquals(3 * 2, mono.length);
+        assertEquals(2000, sample(mono, 0));
+        assertEquals(0, sample(mono, 1));
+        assertEquals(32767, sample(mono, 2));
+    }
+
+    @Test
+    void deletesDownmixedCopyWhenClosed() throws Exception {
+        byte[] stereo = sine(16000, 16000, 2);
+        File recording = tempDir.resolve("stereo.wav").toFile();
+        AudioSystem.write(new AudioInputStream(new ByteArrayInputStream(stereo),
+                new AudioFormat(16000f, 16, 2, true, false), 16000), AudioFileFormat.Type.WAVE, recording);
+
+        File monoFile;
+        try (TempFile mono = AudioResampler.downmixIfNeeded(recording, tempDir.toFile())) {
+            monoFile = mono.getFile();
+            try (AudioInputStream stream = AudioSystem.getAudioInputStream(monoFile)) {
+                assertEquals(1, stream.getFormat().getChannels());
+                assertEquals(16000, stream.getFrameLength());
+            }
+        }
+        assertFalse(monoFile.exists());
+        assertTrue(recording.exists());
+    }
+
+    @Test
+    void clipsFloatSamplesOutsideFullScale() {
+        AudioFormat format = new AudioFormat(AudioFormat.Encoding.PCM_FLOAT, 16000f, 32, 1, 4, 16000f, false);
+        float[] values = {0.5f, 1.5f, -2.0f, -1.0f};
+        byte[] data = new byte[values.length * 4];
+        for (int i = 0; i < values.length; i++) {
+            int bits = Float.floatToIntBits(values[i]);
+            for (int b = 0; b < 4; b++) {
+                data[i * 4 + b] = (byte) (bits >> (8 * b));
+            }
+        }
+
+        byte[] pcm = AudioResampler.toPcm16(data, format);
+
+        assertEquals(16384, sample(pcm, 0));
+        assertEquals(32767, sample(pcm, 1));
+        assertEquals(-32767, sample(pcm, 2));
+        assertEquals(-32767, sample(pcm, 3));
+    }
+
+    @Test
+    void converts24BitWavTo16Bit() throws Exception {
+        // 0x400000 is half of full scale, 0x800000 the most negative 24-bit value
+        int[] samples = {0x400000, -0x800000, 0x000100, -0x000100};
+        byte[] data = new byte[samples.length * 3];
+        for (int i = 0; i < samples.length; i++) {
+            for (int b = 0; b < 3; b++) {
+                data[i * 3 + b] = (byte) (samples[i] >> (8 * b));
+            }
+        }
+        File recording = tempDir.resolve("external.wav").toFile();
+        AudioSystem.write(new AudioInputStream(new ByteArrayInputStream(data),
+                new AudioFormat(16000f, 24, 1, true, false), samples.length), AudioFileFormat.Type.WAVE, recording);
+
+        File converted;
+        try (TempFile pcm16 = AudioResampler.toPcm16IfNeeded(recording, tempDir.toFile())) {
+            converted = pcm16.getFile();
+            try (AudioInputStream stream = AudioSystem.getAudioInputStream(converted)) {
+                assertEquals(16, stream.getFormat().getSampleSizeInBits());
+                assertEquals(samples.length, stream.getFrameLength());
+                byte[] pcm = stream.readAllBytes();
+                assertEquals(16384, sample(pcm, 0));
+                assertEquals(-32767, sample(pcm, 1));
+                assertEquals(1, sample(pcm, 2));
+                assertEquals(-1, sample(pcm, 3));
+            }
+        }
+        assertFalse(converted.exists());
+        assertTrue(recording.exists());
+    }
+
+    @Test
+    void keeps16BitWav() throws Exception {
+        File recording = writeWav("recording.wav", 16000f, sine(16000, 1600, 1));
+
+        try (TempFile pcm16 = AudioResampler.toPcm16IfNeeded(recording, tempDir.toFile())) {
+            assertSame(recording, pcm16.getFile());
+        }
+    }
+
+    @Test
+    void normalizationRaisesQuietPeakToTargetWithoutClipping() {
+        // 0.1 of full scale, so the gain is about 9x
+        byte[] quiet = new byte[4 * 2];
+        int[] values = {3277, -3277, 1638, 0};
+        for (int i = 0; i < values.length; i++) {
+            writeSample(quiet, i * 2, values[i]);
+        }
+
+        float gain = AudioResampler.normalizePeak(quiet, false, AudioResampler.NORMALIZE_TARGET_PEAK);
+
+        assertTrue(gain > 1.0f, "gain " + gain);
+        int target = Math.round(AudioResampler.NORMALIZE_TARGET_PEAK * 32767);
+        assertEquals(target, sample(quiet, 0), 1);
+        assertEquals(-target, sample(quiet, 1), 1);
+        assertEquals(Math.round(1638 * gain), sample(quiet, 2), 1);
+        assertEquals(0, sample(quiet, 3));
+        for (int i = 0; i < values.length; i++) {
+            assertTrue(Math.abs(sample(quiet, i)) < 32767, "sample " + i + " clipped");
+        }
+    }
+
+    @Test
+    void normalizationLeavesLoudAudioUnchanged() {
+        byte[] loud = sine(16000, 1600, 1);
+        byte[] original = loud.clone();
+
+        float gain = AudioResampler.normalizePeak(loud, false, 0.2f);
+
+        assertEquals(1.0f, gain);
+        assertArrayEquals(original, loud);
+    }
+
+    @Test
+    void deletesNormalizedCopyWhenClosed() throws Exception {
+        byte[] quiet = new byte[1600 * 2];
+        for (int i = 0; i < 1600; i++) {
+            writeSample(quiet, i * 2, i % 2 == 0 ? 1000 : -1000);
+        }
+        File recording = writeWav("quiet.wav", 16000f, quiet);
+
+        File normalizedFile;
+        try (TempFile normalized = AudioResampler.normalizePeakIfNeeded(recording,
+                AudioResampler.NORMALIZE_TARGET_PEAK, tempDir.toFile())) {
+            normalizedFile = normalized.getFile();
+            assertNotEquals(recording, normalizedFile);
+            try (AudioInputStream stream = AudioSystem.getAudioInputStream(normalizedFile)) {
+                assertTrue(sample(stream.readAllBytes(), 0) > 1000);
+            }
+        }
+        assertFalse(normalizedFile.exists());
+        assertTrue(recording.exists());
+    }
+
+    /**
+     * Creates a little-endian 16-bit 440 Hz sine with the same value in every channel.
+     */
+    private static byte[] sine(int sampleRate, int frames, int channels) {
+        byte[] data = new byte[frames * channels * 2];
+        for (int frame = 0; frame < frames; frame++) {
+            int value = (int) (Math.sin(2 * Math.PI * 440 * frame / sampleRate) * 12000);
+            for (int channel = 0; channel < channels; channel++) {
+                writeSample(data, (frame * channels + channel) * 2, value);
+            }
+        }
+        return data;
+    }
+
+    private static void writeSample(byte[] data, int offset, int value) {
+        data[offset] = (byte) value;
+        data[offset + 1] = (byte) (value >> 8);
+    }
+
+    private static int sample(byte[] data, int index) {
+        return (short) ((data[index * 2 + 1] << 8) | (data[index * 2] & 0xFF));
+    }
+
+    private File writeWav(String name, float sampleRate, byte[] data) throws Exception {
+        AudioFormat format = new AudioFormat(sampleRate, 16, 1, true, false);
+        File file = tempDir.resolve(name).toFile();
+        AudioSystem.write(new AudioInputStream(new ByteArrayInputStream(data), format, data.length / 2),
+                AudioFileFormat.Type.WAVE, file);
+        return file;
+    }
+}
@@ -0,0 +1,124 @@
+package org.whispercat.recording;
+
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+import org.whispercat.CancellationToken;
+import org.whispercat.ConfigManager;
+
+import java.io.File;
+import java.io.IOException;
+import java.nio.file.Path;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.function.UnaryOperator;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertNull;
+import static org.junit.jupiter.api.Assertions.assertSame;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class ChainedTranscriptionTest {
+
+    @TempDir
+    Path tempDir;
+
+    private File audioFile;
+    private List<String> pipelineInputs;
+
+    @BeforeEach
+    void setUp() {
+        audioFile = tempDir.resolve("recording.wav").toFile();
+        pipelineInputs = new ArrayList<>();
+    }
+
+    @Test
+    void runsPipelineOnFormattedTranscriptInSameTask() throws IOException {
+        StubTranscriptionService service = service("hello world");
+
+        ChainedTranscription result = ChainedTranscription.run(service, audioFile, new CancellationToken(),
+                text -> text + ".", recordingPipeline(text -> text.toUpperCase()));
+
+        assertSame(audioFile, service.transcribed);
+        assertEquals("hello world.", result.getTranscript());
+        assertEquals("HELLO WORLD.", result.getProcessedResult());
+        assertEquals(List.of("hello world."), pipelineInputs);
+        assertEquals("hello world", result.getOutcome().getText());
+        assertNull(result.getPipelineError());
+    }
+
+    @Test
+    void onlyTranscribesWithoutPipeline() throws IOException {
+        ChainedTranscription result = ChainedTranscription.run(service("hello"), audioFile, new CancellationToken(),
+                UnaryOperator.identity(), null);
+
+        assertEquals("hello", result.getTranscript());
+        assertNull(result.getProcessedResult());
+    }
+
+    @Test
+    void skipsPipelineForEmptyTranscript() throws IOException {
+        ChainedTranscription result = ChainedTranscription.run(service("   "), audioFile, new CancellationToken(),
+                UnaryOperator.identity(), recordingPipeline(UnaryOperator.identity()));
+
+        assertTrue(pipelineInputs.isEmpty());
+        assertNull(result.getProcessedResult());
+    }
+
+    @Test
+    void pipelineFailureKeepsTranscript() throws IOException {
+        IllegalStateException failure = new IllegalStateException("LLM unavailable");
+
+        ChainedTranscription result = ChainedTranscription.run(service("hello"), audioFile, new CancellationToken(),
+                UnaryOperator.identity(), text -> {
+                    throw failure;
+                });
+
+        assertEquals("hello", result.getTranscript());
+        assertNull(result.getProcessedResult());
+        assertSame(failure, result.getPipelineError());
+    }
+
+    @Test
+    void transcriptionFailureIsPropagated() {
+        StubTranscriptionService service = service(null);
+
+        assertThrows(IOException.class, () -> ChainedTranscription.run(service, audioFile, new CancellationToken(),
+                UnaryOperator.identity(), recordingPipeline(UnaryOperator.identity())));
+        assertTrue(pipelineInputs.isEmpty());
+    }
+
+    private UnaryOperator<String> recordingPipeline(UnaryOperator<String> pipeline) {
+        return text -> {
+            pipelineInputs.add(text);
+            return pipeline.apply(text);
+        };
+    }
+
+    private StubTranscriptionService service(String text) {
+        return new StubTranscriptionService(new ConfigManager(tempDir.resolve("config.properties").toFile()), text);
+    }
+
+    /**
+     * Returns a fixed transcription, or fails if it has none.
+     */
+    private static class StubTranscriptionService extends TranscriptionService {
+        private final String text;
+        File transcribed;
+
+        StubTranscriptionService(ConfigManager configManager, String text) {
+            super(configManager);
+            this.text = text;
+        }
+
+        @Override
+        public TranscriptionOutcome transcribe(File audioFile, CancellationToken cancellation) throws IOException {
+            transcribed = audioFile;
+            if (text == null) {
+                throw new IOException("Backend unavailable");
+            }
+            return new TranscriptionOutcome(text, "OpenAI", "whisper-1");
+        }
+    }
+}
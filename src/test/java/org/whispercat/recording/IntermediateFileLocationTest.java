@@ -0,0 +1,42 @@
+package org.whispercat.recording;
+
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+import org.whispercat.ConfigManager;
+
+import java.io.File;
+import java.nio.file.Path;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+
+class IntermediateFileLocationTest {
+
+    @TempDir
+    Path tempDir;
+
+    @Test
+    void recordingDirIsTheRecordingsFolder() {
+        File recording = tempDir.resolve("recordings/take.wav").toFile();
+
+        assertEquals(tempDir.resolve("recordings").toFile(), IntermediateFileLocation.RECORDING_DIR.directoryFor(recording));
+    }
+
+    @Test
+    void tempDirIgnoresTheRecordingsFolder() {
+        File recording = tempDir.resolve("recordings/take.wav").toFile();
+
+        assertEquals(new File(System.getProperty("java.io.tmpdir")), IntermediateFileLocation.TEMP_DIR.directoryFor(recording));
+    }
+
+    @Test
+    void configDefaultsToRecordingDirAndPersistsChoice() {
+        ConfigManager config = new ConfigManager(tempDir.resolve("config.properties").toFile());
+        assertEquals(IntermediateFileLocation.RECORDING_DIR, config.getIntermediateFileLocation());
+
+        config.setIntermediateFileLocation(IntermediateFileLocation.TEMP_DIR);
+        assertEquals(IntermediateFileLocation.TEMP_DIR, config.getIntermediateFileLocation());
+
+        config.setProperty("intermediateFileLocation", "somewhere");
+        assertEquals(IntermediateFileLocation.RECORDING_DIR, config.getIntermediateFileLocation());
+    }
+}
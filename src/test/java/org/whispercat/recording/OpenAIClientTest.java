@@ -0,0 +1,138 @@
+package org.whispercat.recording;
+
+import com.fasterxml.jackson.databind.ObjectMapper;
+import com.fasterxml.jackson.databind.node.ObjectNode;
+import com.sun.net.httpserver.HttpServer;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+import org.whispercat.ConfigManager;
+
+import java.io.IOException;
+import java.io.OutputStream;
+import java.net.InetAddress;
+import java.net.InetSocketAddress;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Path;
+import java.util.Arrays;
+import java.util.Collections;
+import java.util.List;
+import java.util.Map;
+import java.util.concurrent.ConcurrentHashMap;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class OpenAIClientTest {
+
+    @TempDir
+    Path tempDir;
+
+    @Test
+    void serializesJsonModeRequest() {
+        ObjectMapper mapper = new ObjectMapper();
+
+        String body = OpenAIClient.buildChatPayload(mapper, "Return JSON.", "hello", "gpt-4o-mini", true, 0).toString();
+
+        assertEquals("{\"model\":\"gpt-4o-mini\",\"messages\":["
+                + "{\"role\":\"system\",\"content\":\"Return JSON.\"},"
+                + "{\"role\":\"user\",\"content\":\"hello\"}],"
+                + "\"response_format\":{\"type\":\"json_object\"}}", body);
+    }
+
+    @Test
+    void sendsChatRequestToConfiguredBaseUrl() throws IOException {
+        Map<String, String> received = new ConcurrentHashMap<>();
+        HttpServer server = HttpServer.create(new InetSocketAddress(InetAddress.getLoopbackAddress(), 0), 0);
+        server.createContext("/", exchange -> {
+            received.put("path", exchange.getRequestURI().getPath());
+            received.put("authorization", exchange.getRequestHeaders().getFirst("Authorization"));
+            received.put("body", new String(exchange.getRequestBody().readAllBytes(), StandardCharsets.UTF_8));
+            byte[] body = "{\"choices\":[{\"message\":{\"content\":\"Hello.\"}}]}".getBytes(StandardCharsets.UTF_8);
+            exchange.sendResponseHeaders(200, body.length);
+            try (OutputStream out = exchange.getResponseBody()) {
+                out.write(body);
+            }
+        });
+        server.start();
+        try {
+            ConfigManager config = new ConfigManager(tempDir.resolve("config.properties").toFile());
+            config.setProperty("apiKey", "sk-test");
+            config.setOpenAIBaseUrl("http://127.0.0.1:" + server.getAddress().getPort() + "/v1");
+
+            String text = new OpenAIClient(config).processText("Fix punctuation.", "hello", "gpt-4o-mini");
+
+            assertEquals("Hello.", text);
+            assertEquals("/v1/chat/completions", received.get("path"));
+            assertEquals("Bearer sk-test", received.get("authorization"));
+            assertEquals("{\"model\":\"gpt-4o-mini\",\"messages\":["
+                    + "{\"role\":\"system\",\"content\":\"Fix punctuation.\"},"
+                    + "{\"role\":\"user\",\"content\":\"hello\"}]}", received.get("body"));
+        } finally {
+            server.stop(0);
+        }
+    }
+
+    @Test
+    void omitsResponseFormatOutsideJsonMode() {
+        ObjectNode payload = OpenAIClient.buildChatPayload(new ObjectMapper(), "s", "u", "gpt-4o", false, 0);
+
+        assertFalse(payload.has("response_format"));
+    }
+
+    @Test
+    void sendsConfiguredMaxTokens() {
+        ObjectNode payload = OpenAIClient.buildChatPayload(new ObjectMapper(), "s", "u", "gpt-4o", false, 4000);
+
+        assertEquals(4000, payload.path("max_tokens").asInt());
+        assertFalse(OpenAIClient.buildChatPayload(new ObjectMapper(), "s", "u", "gpt-4o", false, 0).has("max_tokens"));
+    }
+
+    @Test
+    void keepsOnlyChatModels() {
+        List<String> models = Arrays.asList(
+                "whisper-1", "gpt-4o", "text-embedding-3-small", "tts-1-hd", "dall-e-3",
+                "gpt-4o-mini", "gpt-4o-transcribe", "gpt-4o-realtime-preview", "gpt-4o-audio-preview",
+                "omni-moderation-latest", "o1-mini", "o3", "chatgpt-4o-latest", "davinci-002",
+                "babbage-002", "gpt-image-1", "ft:gpt-4o-mini:acme::abc123", "gpt-3.5-turbo");
+
+        assertEquals(Arrays.asList(
+                "chatgpt-4o-latest", "ft:gpt-4o-mini:acme::abc123", "gpt-3.5-turbo", "gpt-4o",
+                "gpt-4o-mini", "o1-mini", "o3"), OpenAIClient.filterChatModels(models));
+    }
+
+    @Test
+    void skipsEmptyIds() {
+        assertEquals(Collections.singletonList("gpt-4o"),
+                OpenAIClient.filterChatModels(Arrays.asList("", null, "gpt-4o")));
+        assertTrue(OpenAIClient.filterChatModels(Collections.emptyList()).isEmpty());
+    }
+
+    @Test
+    void chatUrlDefaultsToOpenAI() {
+        assertEquals("https://api.openai.com/v1/chat/completions", OpenAIClient.buildChatUrl(""));
+        assertEquals("https://api.openai.com/v1/chat/completions", OpenAIClient.buildChatUrl(null));
+        assertEquals("https://api.openai.com/v1/chat/completions", OpenAIClient.buildChatUrl("   "));
+    }
+
+    @Test
+    void chatUrlHonorsOverride() {
+        assertEquals("http://localhost:1234/v1/chat/completions", OpenAIClient.buildChatUrl("http://localhost:1234/v1"));
+        assertEquals("http://localhost:11434/v1/chat/completions", OpenAIClient.buildChatUrl(" http://localhost:11434/v1// "));
+        assertEquals("https://llm.example.com/openai/v1/chat/completions",
+                OpenAIClient.buildChatUrl("https://llm.example.com/openai/v1"));
+    }
+
+    @Test
+    void chatUrlWithoutSchemeUsesHttp() {
+        assertEquals("http://192.168.1.20:8080/v1/chat/completions", OpenAIClient.buildChatUrl("192.168.1.20:8080/v1"));
+        assertEquals("HTTPS://Example.com/v1/chat/completions", OpenAIClient.buildChatUrl("HTTPS://Example.com/v1"));
+    }
+
+    @Test
+    void modelsUrlFollowsBaseUrl() {
+        assertEquals("https://api.openai.com/v1/models", OpenAIClient.buildModelsUrl(""));
+        assertEquals("https://gateway.example.com/v1/models", OpenAIClient.buildModelsUrl("https://gateway.example.com/v1/"));
+        assertEquals("http://litellm:4000/v1/models", OpenAIClient.buildModelsUrl("litellm:4000/v1"));
+    }
+}
@@ -0,0 +1,121 @@
+package org.whispercat.recording;
+
+import com.fasterxml.jackson.databind.JsonNode;
+import com.fasterxml.jackson.databind.ObjectMapper;
+import com.sun.net.httpserver.HttpServer;
+import org.junit.jupiter.api.AfterEach;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+import org.whispercat.ConfigManager;
+
+import java.io.File;
+import java.net.InetAddress;
+import java.net.InetSocketAddress;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.EnumSet;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class OutputDispatcherTest {
+
+    @TempDir
+    Path tempDir;
+
+    private final List<String> webhookBodies = Collections.synchronizedList(new ArrayList<>());
+    private ConfigManager configManager;
+    private OutputDispatcher dispatcher;
+    private HttpServer webhook;
+    private File outputFile;
+
+    @BeforeEach
+    void setUp() throws Exception {
+        configManager = new ConfigManager(tempDir.resolve("config.properties").toFile());
+        outputFile = tempDir.resolve("out/results.txt").toFile();
+        configManager.setOutputFilePath(outputFile.getAbsolutePath());
+
+        webhook = HttpServer.create(new InetSocketAddress(InetAddress.getLoopbackAddress(), 0), 0);
+        webhook.createContext("/hook", exchange -> {
+            webhookBodies.add(new String(exchange.getRequestBody().readAllBytes(), StandardCharsets.UTF_8));
+            exchange.sendResponseHeaders(204, -1);
+            exchange.close();
+        });
+        webhook.start();
+        configManager.setOutputWebhookUrl("http://127.0.0.1:" + webhook.getAddress().getPort() + "/hook");
+
+        dispatcher = new OutputDispatcher(configManager);
+    }
+
+    @AfterEach
+    void tearDown() {
+        webhook.stop(0);
+    }
+
+    @Test
+    void fileSinkAppendsLines() throws Exception {
+        dispatcher.deliverAll("first", EnumSet.of(OutputSink.FILE));
+        dispatcher.deliverAll("second", EnumSet.of(OutputSink.FILE));
+
+        assertEquals(List.of("first", "second"), Files.readAllLines(outputFile.toPath(), StandardCharsets.UTF_8));
+        assertTrue(webhookBodies.isEmpty());
+    }
+
+    @Test
+    void webhookSinkPostsPayload() throws Exception {
+        dispatcher.deliverAll("Hello", EnumSet.of(OutputSink.WEBHOOK));
+
+        assertEquals(1, webhookBodies.size());
+        assertEquals("Hello", new ObjectMapper().readTree(webhookBodies.get(0)).path("text").asText());
+        assertFalse(outputFile.exists());
+    }
+
+    @Test
+    void deliversToEverySelectedSink() throws Exception {
+        dispatcher.deliverAll("Both", EnumSet.allOf(OutputSink.class));
+
+        assertEquals(List.of("Both"), Files.readAllLines(outputFile.toPath(), StandardCharsets.UTF_8));
+        assertEquals(1, webhookBodies.size());
+    }
+
+    @Test
+    void clipboardOnlyDeliversNothingHere() {
+        dispatcher.deliverAll("Clipboard", EnumSet.of(OutputSink.CLIPBOARD));
+
+        assertFalse(outputFile.exists());
+        assertTrue(webhookBodies.isEmpty());
+    }
+
+    @Test
+    void webhookPayloadEscapesText() throws Exception {
+        String text = "Say \"hi\"\nand ünïcode";
+
+        JsonNode payload = new ObjectMapper().readTree(OutputDispatcher.buildWebhookPayload(text));
+
+        assertEquals(1, payload.size());
+        assertEquals(text, payload.path("text").asText());
+    }
+
+    @Test
+    void outputSinksPersist() {
+        configManager.setOutputSinks(EnumSet.of(OutputSink.FILE, OutputSink.WEBHOOK));
+        configManager.flushPendingSave();
+
+        ConfigManager reloaded = new ConfigManager(tempDir.resolve("config.properties").toFile());
+        assertEquals(EnumSet.of(OutputSink.FILE, OutputSink.WEBHOOK), reloaded.getOutputSinks());
+    }
+
+    @Test
+    void clipboardIsTheDefaultSinkAndUnknownNamesAreSkipped() {
+        assertEquals(EnumSet.of(OutputSink.CLIPBOARD), configManager.getOutputSinks());
+
+        configManager.setProperty("outputSinks", "file, printer");
+        assertEquals(EnumSet.of(OutputSink.FILE), configManager.getOutputSinks());
+    }
+}
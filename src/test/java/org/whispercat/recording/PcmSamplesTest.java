@@ -0,0 +1,65 @@
+package org.whispercat.recording;
+
+import org.junit.jupiter.api.Test;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+
+class PcmSamplesTest {
+
+    @Test
+    void signExtendsNegativeSamples() {
+        assertEquals(-1, PcmSamples.read(new byte[]{(byte) 0xFF}, 0, 1, false));
+        assertEquals(-32768, PcmSamples.read(new byte[]{0x00, (byte) 0x80}, 0, 2, false));
+        assertEquals(-8388608, PcmSamples.read(new byte[]{0x00, 0x00, (byte) 0x80}, 0, 3, false));
+        assertEquals(-2, PcmSamples.read(new byte[]{(byte) 0xFE, (byte) 0xFF, (byte) 0xFF}, 0, 3, false));
+        assertEquals(Integer.MIN_VALUE, PcmSamples.read(new byte[]{0x00, 0x00, 0x00, (byte) 0x80}, 0, 4, false));
+    }
+
+    @Test
+    void readsPositiveSamplesWithoutSignExtension() {
+        assertEquals(127, PcmSamples.read(new byte[]{0x7F}, 0, 1, false));
+        assertEquals(8388607, PcmSamples.read(new byte[]{(byte) 0xFF, (byte) 0xFF, 0x7F}, 0, 3, false));
+        assertEquals(0x123456, PcmSamples.read(new byte[]{0x56, 0x34, 0x12}, 0, 3, false));
+    }
+
+    @Test
+    void honorsByteOrderAndOffset() {
+        byte[] data = {0x00, (byte) 0x80, 0x00, 0x00};
+
+        assertEquals(-8388608, PcmSamples.read(data, 1, 3, true));
+        assertEquals(0x8000, PcmSamples.read(data, 0, 3, false));
+    }
+
+    @Test
+    void convertsUnsignedSamplesToSigned() {
+        // Unsigned 16-bit 0, 32768 and 65535, little-endian
+        byte[] data = {0x00, 0x00, 0x00, (byte) 0x80, (byte) 0xFF, (byte) 0xFF};
+
+        PcmSamples.unsignedToSigned(data, data.length, 2, false);
+
+        assertEquals(-32768, PcmSamples.read(data, 0, 2, false));
+        assertEquals(0, PcmSamples.read(data, 2, 2, false));
+        assertEquals(32767, PcmSamples.read(data, 4, 2, false));
+    }
+
+    @Test
+    void convertsBigEndianAndEightBitUnsignedSamples() {
+        byte[] bigEndian = {(byte) 0x80, 0x01, 0x7F, (byte) 0xFF};
+        PcmSamples.unsignedToSigned(bigEndian, bigEndian.length, 2, true);
+        assertEquals(1, PcmSamples.read(bigEndian, 0, 2, true));
+        assertEquals(-1, PcmSamples.read(bigEndian, 2, 2, true));
+
+        byte[] eightBit = {0x00, (byte) 0x80, (byte) 0xFF};
+        PcmSamples.unsignedToSigned(eightBit, eightBit.length, 1, false);
+        assertEquals(-128, PcmSamples.read(eightBit, 0, 1, false));
+        assertEquals(0, PcmSamples.read(eightBit, 1, 1, false));
+        assertEquals(127, PcmSamples.read(eightBit, 2, 1, false));
+    }
+
+    @Test
+    void normalizesToFullScale() {
+        assertEquals(-1.0, PcmSamples.readNormalized(new byte[]{0x00, 0x00, (byte) 0x80}, 0, 3, false));
+        assertEquals(0.5, PcmSamples.readNormalized(new byte[]{0x00, 0x40}, 0, 2, false));
+        assertEquals(8388608.0, PcmSamples.fullScale(3));
+    }
+}
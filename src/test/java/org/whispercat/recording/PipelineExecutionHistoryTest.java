@@ -0,0 +1,52 @@
+package org.whispercat.recording;
+
+import org.junit.jupiter.api.Test;
+
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertNull;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class PipelineExecutionHistoryTest {
+
+    @Test
+    void pipelineResultsKeepRawTranscription() {
+        PipelineExecutionHistory history = new PipelineExecutionHistory();
+        history.startNewSession(" raw transcription");
+
+        history.addResult("p1", "Polish", "Polished transcription.", 120);
+        history.addResult("p2", "Translate", "Transcription traduite.", 300);
+
+        assertEquals(" raw transcription", history.getOriginalTranscription());
+        List<PipelineExecutionHistory.PipelineResult> results = history.getResults();
+        assertEquals(2, results.size());
+        assertEquals("Transcription traduite.", results.get(0).getResultText());
+        assertEquals("Polished transcription.", results.get(1).getResultText());
+    }
+
+    @Test
+    void newSessionReplacesRawTranscriptionAndResults() {
+        PipelineExecutionHistory history = new PipelineExecutionHistory();
+        history.startNewSession("first");
+        history.addResult("p1", "Polish", "First.");
+
+        history.startNewSession("second");
+
+        assertEquals("second", history.getOriginalTranscription());
+        assertFalse(history.hasResults());
+        assertTrue(history.hasActiveSession());
+    }
+
+    @Test
+    void clearDropsSession() {
+        PipelineExecutionHistory history = new PipelineExecutionHistory();
+        history.startNewSession("text");
+
+        history.clear();
+
+        assertNull(history.getOriginalTranscription());
+        assertFalse(history.hasActiveSession());
+    }
+}
@@ -0,0 +1,83 @@
+package org.whispercat.recording;
+
+import org.apache.http.conn.ConnectTimeoutException;
+import org.junit.jupiter.api.Test;
+
+import java.io.IOException;
+import java.net.ConnectException;
+import java.util.concurrent.atomic.AtomicLong;
+
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class ProviderHealthTest {
+
+    private static final String URL = "http://localhost:8000";
+    private static final long WINDOW_MS = 30_000;
+
+    private final AtomicLong now = new AtomicLong(1_000_000);
+    private final ProviderHealth health = new ProviderHealth(now::get);
+
+    @Test
+    void failsFastWithinWindowAfterConnectionFailure() throws Exception {
+        health.record(URL, new ConnectException("Connection refused"));
+        now.addAndGet(WINDOW_MS - 1);
+
+        ProviderUnreachableException e = assertThrows(ProviderUnreachableException.class,
+                () -> health.checkReachable("Faster-Whisper", URL, WINDOW_MS));
+        assertTrue(e.getMessage().contains("Connection refused"), e.getMessage());
+    }
+
+    @Test
+    void triesAgainOnceWindowHasPassed() throws Exception {
+        health.record(URL, new ConnectException("Connection refused"));
+        now.addAndGet(WINDOW_MS);
+
+        health.checkReachable("Faster-Whisper", URL, WINDOW_MS);
+    }
+
+    @Test
+    void successClearsCachedFailure() throws Exception {
+        health.record(URL, new ConnectException("Connection refused"));
+        health.record(URL, null);
+
+        health.checkReachable("Faster-Whisper", URL, WINDOW_MS);
+    }
+
+    @Test
+    void manualRetryClearsCachedFailure() throws Exception {
+        health.record(URL, new ConnectException("Connection refused"));
+        health.clear();
+
+        health.checkReachable("Faster-Whisper", URL, WINDOW_MS);
+    }
+
+    @Test
+    void failuresAreKeptPerUrl() throws Exception {
+        health.record(URL, new ConnectException("Connection refused"));
+
+        health.checkReachable("Open WebUI", "http://localhost:3000", WINDOW_MS);
+    }
+
+    @Test
+    void disabledWindowNeverFailsFast() throws Exception {
+        health.record(URL, new ConnectException("Connection refused"));
+
+        health.checkReachable("Faster-Whisper", URL, 0);
+    }
+
+    @Test
+    void serverErrorsAreNotCached() throws Exception {
+        health.record(URL, new IOException("Error from Faster-Whisper (HTTP 500): boom"));
+
+        health.checkReachable("Faster-Whisper", URL, WINDOW_MS);
+    }
+
+    @Test
+    void recognizesWrappedConnectionFailures() {
+        assertTrue(ProviderHealth.isConnectionFailure(new IOException("upload failed", new ConnectException("refused"))));
+        assertTrue(ProviderHealth.isConnectionFailure(new ConnectTimeoutException("timed out")));
+        assertFalse(ProviderHealth.isConnectionFailure(new IOException("HTTP 401")));
+    }
+}
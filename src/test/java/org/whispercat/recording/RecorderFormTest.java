@@ -0,0 +1,221 @@
+package org.whispercat.recording;
+
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+import org.whispercat.BackgroundTask;
+import org.whispercat.CancellationToken;
+import org.whispercat.ConfigManager;
+import org.whispercat.postprocessing.Pipeline;
+import org.whispercat.postprocessing.PipelineUnitReference;
+import org.whispercat.postprocessing.ProcessingUnit;
+
+import javax.swing.JButton;
+import javax.swing.JTextArea;
+import javax.swing.JTextField;
+import java.io.File;
+import java.nio.file.Path;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.concurrent.CompletableFuture;
+import java.util.concurrent.TimeUnit;
+import java.util.function.UnaryOperator;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertNull;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class RecorderFormTest {
+
+    @TempDir
+    Path tempDir;
+
+    @Test
+    void shortcutFiresOutsideTextFields() {
+        assertTrue(RecorderForm.shouldHandleRecordingShortcut(true, new JButton("Record")));
+        assertTrue(RecorderForm.shouldHandleRecordingShortcut(true, null));
+    }
+
+    @Test
+    void shortcutIgnoredWhileTyping() {
+        assertFalse(RecorderForm.shouldHandleRecordingShortcut(true, new JTextField()));
+        assertFalse(RecorderForm.shouldHandleRecordingShortcut(true, new JTextArea()));
+    }
+
+    @Test
+    void shortcutFiresInReadOnlyText() {
+        JTextArea transcript = new JTextArea();
+        transcript.setEditable(false);
+
+        assertTrue(RecorderForm.shouldHandleRecordingShortcut(true, transcript));
+    }
+
+    @Test
+    void disabledShortcutNeverFires() {
+        assertFalse(RecorderForm.shouldHandleRecordingShortcut(false, new JButton("Record")));
+        assertFalse(RecorderForm.shouldHandleRecordingShortcut(false, null));
+    }
+
+    @Test
+    void formatsElapsedSeconds() {
+        assertEquals("0s", RecorderForm.formatElapsed(0));
+        assertEquals("0s", RecorderForm.formatElapsed(999));
+        assertEquals("12s", RecorderForm.formatElapsed(12_400));
+        assertEquals("59s", RecorderForm.formatElapsed(59_999));
+    }
+
+    @Test
+    void formatsElapsedMinutes() {
+        assertEquals("1m 00s", RecorderForm.formatElapsed(60_000));
+        assertEquals("2m 05s", RecorderForm.formatElapsed(125_000));
+        assertEquals("61m 01s", RecorderForm.formatElapsed(3_661_000));
+    }
+
+    @Test
+    void negativeElapsedTimeShowsZero() {
+        assertEquals("0s", RecorderForm.formatElapsed(-500));
+    }
+
+    @Test
+    void remainingRecordingTimeCountsDownToZero() {
+        assertEquals(300_000, RecorderForm.remainingRecordingMillis(300, 0));
+        assertEquals(1_500, RecorderForm.remainingRecordingMillis(300, 298_500));
+        assertEquals(0, RecorderForm.remainingRecordingMillis(300, 300_000));
+        assertEquals(0, RecorderForm.remainingRecordingMillis(300, 301_200));
+    }
+
+    @Test
+    void recordingsAreUnlimitedByDefault() {
+        ConfigManager config = new ConfigManager(tempDir.resolve("config.properties").toFile());
+        assertEquals(0, config.getMaxRecordingSeconds());
+
+        config.setMaxRecordingSeconds(-5);
+        assertEquals(0, config.getMaxRecordingSeconds());
+        config.setMaxRecordingSeconds(600);
+        assertEquals(600, config.getMaxRecordingSeconds());
+    }
+
+    @Test
+    void pinnedResultSurvivesNextTranscription() {
+        JTextArea result = new JTextArea();
+        JTextArea latest = new JTextArea();
+        assertTrue(RecorderForm.showTranscript("first take", false, result, latest));
+
+        assertFalse(RecorderForm.showTranscript("follow-up", true, result, latest));
+
+        assertEquals("first take", result.getText());
+        assertEquals("follow-up", latest.getText());
+    }
+
+    @Test
+    void unpinningShowsLatestTranscription() {
+        JTextArea result = new JTextArea("first take");
+        JTextArea latest = new JTextArea("follow-up");
+
+        assertTrue(RecorderForm.moveLatestToResult(result, latest));
+        assertEquals("follow-up", result.getText());
+        assertEquals("", latest.getText());
+
+        // Nothing arrived while pinned: the result stays
+        assertFalse(RecorderForm.moveLatestToResult(result, latest));
+        assertEquals("follow-up", result.getText());
+    }
+
+    @Test
+    void pipelineHotkeyRunsReadyPipelineOnTranscript() {
+        ConfigManager config = new ConfigManager(tempDir.resolve("config.properties").toFile());
+        Pipeline pipeline = new Pipeline();
+        pipeline.uuid = "pipeline-1";
+
+        assertNull(RecorderForm.lastPipelineProblem("some text", false, pipeline, config));
+    }
+
+    @Test
+    void pipelineHotkeyWarnsInsteadOfRunning() {
+        ConfigManager config = new ConfigManager(tempDir.resolve("config.properties").toFile());
+        Pipeline pipeline = new Pipeline();
+        pipeline.uuid = "pipeline-1";
+
+        assertEquals("No transcription to run the pipeline on",
+                RecorderForm.lastPipelineProblem("  ", false, pipeline, config));
+        assertEquals("Busy - wait for the current run to finish",
+                RecorderForm.lastPipelineProblem("some text", true, pipeline, config));
+        assertEquals("No pipeline to run. Select one on the recording screen first.",
+                RecorderForm.lastPipelineProblem("some text", false, null, config));
+        pipeline.enabled = false;
+        assertEquals("No pipeline to run. Select one on the recording screen first.",
+                RecorderForm.lastPipelineProblem("some text", false, pipeline, config));
+    }
+
+    @Test
+    void pipelineHotkeyReportsMissingProvider() {
+        ConfigManager config = new ConfigManager(tempDir.resolve("config.properties").toFile());
+        ProcessingUnit unit = new ProcessingUnit();
+        unit.uuid = "unit-1";
+        unit.name = "Grammar";
+        unit.type = "Prompt";
+        unit.provider = "OpenAI";
+        config.saveProcessingUnit(unit);
+        Pipeline pipeline = new Pipeline();
+        pipeline.uuid = "pipeline-1";
+        pipeline.unitReferences.add(new PipelineUnitReference("unit-1", true));
+
+        assertEquals("Pipeline skipped: OpenAI API key is not set (Settings)",
+                RecorderForm.lastPipelineProblem("some text", false, pipeline, config));
+    }
+
+    @Test
+    void crashingBackendReportsErrorAndLetsNextTranscriptionRun() throws Exception {
+        CrashingTranscriptionService service = new CrashingTranscriptionService(
+                new ConfigManager(tempDir.resolve("config.properties").toFile()));
+        File audioFile = tempDir.resolve("recording.wav").toFile();
+        List<String> errors = new ArrayList<>();
+
+        String crashed = transcribeInBackground(service, audioFile, errors);
+
+        assertNull(crashed);
+        assertEquals(List.of("Transcription crashed. See logs."), errors);
+
+        // The crash leaves nothing stuck, so the next recording transcribes normally
+        service.crash = false;
+        String recovered = transcribeInBackground(service, audioFile, errors);
+
+        assertEquals("hello", recovered);
+        assertEquals(1, errors.size());
+    }
+
+    /**
+     * Transcribes through a {@link BackgroundTask} like the recorder does, reporting crashes
+     * into the given list.
+     */
+    private static String transcribeInBackground(TranscriptionService service, File audioFile, List<String> errors)
+            throws Exception {
+        CompletableFuture<String> transcript = new CompletableFuture<>();
+        BackgroundTask.run(() -> ChainedTranscription.run(service, audioFile, new CancellationToken(),
+                UnaryOperator.identity(), null).getTranscript(),
+                transcript::complete, e -> {
+                    RecorderForm.reportCrash(e, errors::add);
+                    transcript.complete(null);
+                });
+        return transcript.get(5, TimeUnit.SECONDS);
+    }
+
+    /**
+     * Throws an Error like a decoder bug would, which the worker's own catch blocks do not handle.
+     */
+    private static class CrashingTranscriptionService extends TranscriptionService {
+        boolean crash = true;
+
+        CrashingTranscriptionService(ConfigManager configManager) {
+            super(configManager);
+        }
+
+        @Override
+        public TranscriptionOutcome transcribe(File audioFile, CancellationToken cancellation) {
+            if (crash) {
+                throw new StackOverflowError("decoder recursion");
+            }
+            return new TranscriptionOutcome("hello", "OpenAI", "whisper-1");
+        }
+    }
+}
@@ -0,0 +1,158 @@
+package org.whispercat.recording;
+
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+
+import javax.sound.sampled.Clip;
+import javax.sound.sampled.LineEvent;
+import javax.sound.sampled.LineListener;
+import java.io.File;
+import java.io.FileNotFoundException;
+import java.lang.reflect.Proxy;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.concurrent.atomic.AtomicInteger;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+
+class RecordingPlayerTest {
+
+    @TempDir
+    Path tempDir;
+
+    private final List<FakeClip> clips = new ArrayList<>();
+    private final AtomicInteger stateChanges = new AtomicInteger();
+
+    @Test
+    void playStartsClipAndNotifies() throws Exception {
+        RecordingPlayer player = player();
+
+        player.play(recording("a.wav"));
+
+        assertEquals(RecordingPlayer.State.PLAYING, player.getState());
+        assertEquals(1, clips.get(0).starts);
+        assertEquals(1, stateChanges.get());
+    }
+
+    @Test
+    void stopReleasesClipOnce() throws Exception {
+        RecordingPlayer player = player();
+        player.play(recording("a.wav"));
+
+        player.stop();
+        // the clip reports STOP for the same stop; it must not be handled twice
+        clips.get(0).fireStop();
+
+        assertEquals(RecordingPlayer.State.STOPPED, player.getState());
+        assertEquals(1, clips.get(0).stops);
+        assertEquals(1, clips.get(0).closes);
+        assertEquals(2, stateChanges.get());
+    }
+
+    @Test
+    void endOfRecordingStopsPlayback() throws Exception {
+        RecordingPlayer player = player();
+        player.play(recording("a.wav"));
+
+        clips.get(0).fireStop();
+
+        assertEquals(RecordingPlayer.State.STOPPED, player.getState());
+        assertEquals(1, clips.get(0).closes);
+    }
+
+    @Test
+    void playingAnotherRecordingStopsTheFirst() throws Exception {
+        RecordingPlayer player = player();
+        player.play(recording("a.wav"));
+
+        player.play(recording("b.wav"));
+
+        assertEquals(RecordingPlayer.State.PLAYING, player.getState());
+        assertEquals(1, clips.get(0).closes);
+        assertEquals(0, clips.get(1).closes);
+        // a late STOP from the first clip does not stop the second
+        clips.get(0).fireStop();
+        assertEquals(RecordingPlayer.State.PLAYING, player.getState());
+    }
+
+    @Test
+    void missingRecordingIsReportedAndStaysStopped() {
+        RecordingPlayer player = player();
+
+        assertThrows(FileNotFoundException.class, () -> player.play(tempDir.resolve("deleted.wav").toFile()));
+        assertThrows(FileNotFoundException.class, () -> player.play(null));
+
+        assertEquals(RecordingPlayer.State.STOPPED, player.getState());
+        assertEquals(0, clips.size());
+    }
+
+    @Test
+    void stopWhenIdleDoesNothing() {
+        RecordingPlayer player = player();
+
+        player.stop();
+
+        assertEquals(RecordingPlayer.State.STOPPED, player.getState());
+        assertEquals(0, stateChanges.get());
+    }
+
+    private RecordingPlayer player() {
+        RecordingPlayer player = new RecordingPlayer(file -> {
+            FakeClip clip = new FakeClip();
+            clips.add(clip);
+            return clip.proxy;
+        });
+        player.setStateListener(stateChanges::incrementAndGet);
+        return player;
+    }
+
+    private File recording(String name) throws Exception {
+        return Files.write(tempDir.resolve(name), new byte[]{0}).toFile();
+    }
+
+    /**
+     * Clip that counts calls instead of playing audio.
+     */
+    private static class FakeClip {
+        final List<LineListener> listeners = new ArrayList<>();
+        final Clip proxy;
+        int starts;
+        int stops;
+        int closes;
+
+        FakeClip() {
+            proxy = (Clip) Proxy.newProxyInstance(RecordingPlayerTest.class.getClassLoader(),
+                    new Class<?>[]{Clip.class}, (p, method, args) -> {
+                        switch (method.getName()) {
+                            case "addLineListener":
+                                listeners.add((LineListener) args[0]);
+                                return null;
+                            case "start":
+                                starts++;
+                                return null;
+                            case "stop":
+                                stops++;
+                                return null;
+                            case "close":
+                                closes++;
+                                return null;
+                            case "hashCode":
+                                return System.identityHashCode(p);
+                            case "equals":
+                                return p == args[0];
+                            default:
+                                return null;
+                        }
+                    });
+        }
+
+        void fireStop() {
+            for (LineListener listener : new ArrayList<>(listeners)) {
+                listener.update(new LineEvent(proxy, LineEvent.Type.STOP, 0));
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+package org.whispercat.recording;
+
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+
+import java.io.File;
+import java.io.IOException;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.util.concurrent.TimeUnit;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class RecordingRetentionTest {
+
+    private static final long NOW = TimeUnit.DAYS.toMillis(1000);
+
+    @TempDir
+    Path tempDir;
+
+    @Test
+    void expiresFilesOlderThanRetention() {
+        assertTrue(RecordingRetention.isExpired(NOW - TimeUnit.DAYS.toMillis(8), 7, NOW));
+        assertFalse(RecordingRetention.isExpired(NOW - TimeUnit.DAYS.toMillis(6), 7, NOW));
+        assertFalse(RecordingRetention.isExpired(NOW - TimeUnit.DAYS.toMillis(7), 7, NOW));
+    }
+
+    @Test
+    void disabledRetentionAndUnknownTimesNeverExpire() {
+        assertFalse(RecordingRetention.isExpired(0, 7, NOW));
+        assertFalse(RecordingRetention.isExpired(NOW - TimeUnit.DAYS.toMillis(100), 0, NOW));
+    }
+
+    @Test
+    void onlyRecognizesRecordingFiles() {
+        assertTrue(RecordingRetention.isRecordingFile("record_20240101_120000.wav"));
+        assertTrue(RecordingRetention.isRecordingFile("whispercat_compressed_123.mp3"));
+        assertTrue(RecordingRetention.isRecordingFile("record_20240101_120000.txt"));
+        assertFalse(RecordingRetention.isRecordingFile("notes.wav"));
+        assertFalse(RecordingRetention.isRecordingFile("record_20240101_120000.flac"));
+    }
+
+    @Test
+    void purgeDeletesOnlyOldRecordings() throws IOException {
+        long old = System.currentTimeMillis() - TimeUnit.DAYS.toMillis(10);
+        File oldRecording = file("record_old.wav", old);
+        File oldTranscript = file("record_old.txt", old);
+        File newRecording = file("record_new.wav", System.currentTimeMillis());
+        File oldOther = file("holiday.wav", old);
+
+        assertEquals(2, RecordingRetention.purge(tempDir.toFile(), 7));
+
+        assertFalse(oldRecording.exists());
+        assertFalse(oldTranscript.exists());
+        assertTrue(newRecording.exists());
+        assertTrue(oldOther.exists());
+    }
+
+    @Test
+    void purgeIsOptIn() throws IOException {
+        File oldRecording = file("record_old.wav", System.currentTimeMillis() - TimeUnit.DAYS.toMillis(400));
+
+        assertEquals(0, RecordingRetention.purge(tempDir.toFile(), 0));
+        assertTrue(oldRecording.exists());
+    }
+
+    private File file(String name, long lastModified) throws IOException {
+        File file = Files.write(tempDir.resolve(name), new byte[]{1}).toFile();
+        assertTrue(file.setLastModified(lastModified));
+        return file;
+    }
+}
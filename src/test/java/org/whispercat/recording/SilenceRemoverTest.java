@@ -0,0 +1,212 @@
+package org.whispercat.recording;
+
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+import org.whispercat.ConsoleLogger;
+
+import javax.sound.sampled.AudioFileFormat;
+import javax.sound.sampled.AudioFormat;
+import javax.sound.sampled.AudioInputStream;
+import javax.sound.sampled.AudioSystem;
+import java.io.ByteArrayInputStream;
+import java.io.File;
+import java.nio.file.Path;
+import java.util.Arrays;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.assertArrayEquals;
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertNotEquals;
+import static org.junit.jupiter.api.Assertions.assertNotNull;
+import static org.junit.jupiter.api.Assertions.assertNull;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class SilenceRemoverTest {
+
+    private static final float SAMPLE_RATE = 16000f;
+
+    @TempDir
+    Path tempDir;
+
+    @Test
+    void decidesOnProjectedReduction() {
+        assertFalse(SilenceRemover.isReductionWorthwhile(2.0f, 5));
+        assertTrue(SilenceRemover.isReductionWorthwhile(5.0f, 5));
+        assertTrue(SilenceRemover.isReductionWorthwhile(40.0f, 5));
+        assertTrue(SilenceRemover.isReductionWorthwhile(0.5f, 0));
+    }
+
+    @Test
+    void skipsRemovalForLowSilence() throws Exception {
+        // 10 s of tone with a single 1 s pause: roughly 10 % reduction
+        File recording = writeWav("low.wav", 4.5, 1.0, 4.5);
+
+        File result = SilenceRemover.removeSilence(recording, 0.01f, 500, 100, 0, false, 0, 25, tempDir.toFile()).getFile();
+
+        assertEquals(recording, result);
+    }
+
+    @Test
+    void removesHighSilence() throws Exception {
+        // 10 s with a 5 s pause: roughly 50 % reduction
+        File recording = writeWav("high.wav", 2.5, 5.0, 2.5);
+
+        File result = SilenceRemover.removeSilence(recording, 0.01f, 500, 100, 0, false, 0, 25, tempDir.toFile()).getFile();
+
+        assertNotEquals(recording, result);
+        assertTrue(result.length() < recording.length());
+    }
+
+    @Test
+    void keepsSpeechInPartialLastWindow() throws Exception {
+        // 50 ms of tone after the pause fills only half of the last 100 ms analysis window
+        File recording = writeWav("tail.wav", 2.5, 5.0, 0.05);
+
+        File result = SilenceRemover.removeSilence(recording, 0.01f, 500, 100, 0, false, 0, 25, tempDir.toFile()).getFile();
+
+        assertNotEquals(recording, result);
+        byte[] original = readFrames(recording);
+        byte[] spliced = readFrames(result);
+        assertEquals((int) (2.55 * SAMPLE_RATE) * 2, spliced.length);
+        // The first milliseconds after the cut are faded in, the rest of the tail is untouched
+        int tail = (int) ((0.05 - SilenceRemover.FADE_MS / 1000.0) * SAMPLE_RATE) * 2;
+        assertArrayEquals(Arrays.copyOfRange(original, original.length - tail, original.length),
+                Arrays.copyOfRange(spliced, spliced.length - tail, spliced.length));
+    }
+
+    @Test
+    void insetsRemovedRegionsByPadding() {
+        List<SilenceRemover.SilenceRegion> silences = Arrays.asList(
+                new SilenceRemover.SilenceRegion(0, 8000),
+                new SilenceRemover.SilenceRegion(40000, 120000),
+                new SilenceRemover.SilenceRegion(130000, 134000),
+                new SilenceRemover.SilenceRegion(150000, 160000));
+
+        List<SilenceRemover.SilenceRegion> padded = SilenceRemover.applyPadding(silences, 2400, 160000);
+
+        // Leading and trailing silence keep their outer edge; the 4000-frame region is consumed by the padding
+        assertEquals(3, padded.size());
+        assertEquals(0, padded.get(0).startFrame);
+        assertEquals(8000 - 2400, padded.get(0).endFrame);
+        assertEquals(40000 + 2400, padded.get(1).startFrame);
+        assertEquals(120000 - 2400, padded.get(1).endFrame);
+        assertEquals(150000 + 2400, padded.get(2).startFrame);
+        assertEquals(160000, padded.get(2).endFrame);
+    }
+
+    @Test
+    void keepsPaddingAroundRemovedSilence() throws Exception {
+        File recording = writeWav("padded.wav", 2.5, 5.0, 2.5);
+
+        File result = SilenceRemover.removeSilence(recording, 0.01f, 500, 100, 150, false, 0, 25, tempDir.toFile()).getFile();
+
+        // 150 ms of the pause survive on each side of the cut
+        int removedFrames = (int) ((5.0 - 2 * 0.15) * SAMPLE_RATE);
+        assertEquals(((int) (10.0 * SAMPLE_RATE) - removedFrames) * 2, readFrames(result).length);
+    }
+
+    @Test
+    void fadesLinearlyAcrossCut() {
+        AudioFormat format = new AudioFormat(SAMPLE_RATE, 16, 1, true, false);
+        byte[] data = new byte[8 * 2];
+        for (int i = 0; i < 8; i++) {
+            data[i * 2] = (byte) 1000;
+            data[i * 2 + 1] = (byte) (1000 >> 8);
+        }
+
+        SilenceRemover.fadeAcrossCut(data, 4 * 2, 4, format);
+
+        int[] expected = {750, 500, 250, 0, 0, 250, 500, 750};
+        for (int i = 0; i < expected.length; i++) {
+            assertEquals(expected[i], (short) ((data[i * 2 + 1] << 8) | (data[i * 2] & 0xFF)), "frame " + i);
+        }
+    }
+
+    @Test
+    void smallerWindowFindsMoreAndShorterPauses() {
+        // Fast speech: 60 ms gaps between words, plus one long pause
+        byte[] audio = toneAndSilence(0.3, 0.06, 0.3, 0.06, 0.3, 0.06, 0.3, 1.0, 0.4);
+        AudioFormat format = new AudioFormat(SAMPLE_RATE, 16, 1, true, false);
+        ConsoleLogger console = ConsoleLogger.getInstance();
+
+        List<SilenceRemover.SilenceRegion> fine = SilenceRemover.detectSilence(audio, format, 0.01f, 40, 20, console);
+        List<SilenceRemover.SilenceRegion> coarse = SilenceRemover.detectSilence(audio, format, 0.01f, 40, 200, console);
+
+        assertEquals(1, coarse.size());
+        assertEquals(4, fine.size());
+        long shortestFine = fine.stream().mapToLong(SilenceRemover.SilenceRegion::getDurationFrames).min().getAsLong();
+        long shortestCoarse = coarse.stream().mapToLong(SilenceRemover.SilenceRegion::getDurationFrames).min().getAsLong();
+        assertTrue(shortestFine < shortestCoarse, shortestFine + " vs " + shortestCoarse);
+    }
+
+    @Test
+    void keepsOriginalAndWarnsWhenRecordingIsEntirelySilent() throws Exception {
+        // 3 s of all-zero samples, e.g. recorded with the microphone muted
+        File recording = writeWav("muted.wav", 0.0, 3.0);
+
+        SilenceRemovalResult result = SilenceRemover.removeSilence(recording, 0.01f, 500, 100, 0, false, 0, 0, tempDir.toFile());
+
+        assertEquals(recording, result.getFile());
+        assertTrue(result.getFile().length() > 44, "the WAV keeps its audio data");
+        assertNotNull(result.getWarning());
+        assertTrue(result.getWarning().startsWith("No audible speech detected"), result.getWarning());
+    }
+
+    @Test
+    void audibleRecordingHasNoWarning() throws Exception {
+        File recording = writeWav("speech.wav", 2.5, 5.0, 2.5);
+
+        assertNull(SilenceRemover.removeSilence(recording, 0.01f, 500, 100, 0, false, 0, 25, tempDir.toFile()).getWarning());
+    }
+
+    @Test
+    void measuresRmsOfWholeFile() throws Exception {
+        float loud = SilenceRemover.measureRms(writeWav("tone.wav", 2.0));
+        float silent = SilenceRemover.measureRms(writeWav("zero.wav", 0.0, 2.0));
+
+        // A sine of amplitude 12000 has an RMS of 12000 / sqrt(2) / 32768
+        assertEquals(12000 / Math.sqrt(2) / 32768, loud, 0.01);
+        assertEquals(0.0f, silent, 1e-6);
+    }
+
+    private static byte[] readFrames(File file) throws Exception {
+        try (AudioInputStream stream = AudioSystem.getAudioInputStream(file)) {
+            return stream.readAllBytes();
+        }
+    }
+
+    /**
+     * Writes a mono 16-bit WAV of alternating tone and silence sections (seconds), starting with tone.
+     */
+    private File writeWav(String name, double... sections) throws Exception {
+        byte[] data = toneAndSilence(sections);
+        AudioFormat format = new AudioFormat(SAMPLE_RATE, 16, 1, true, false);
+        File file = tempDir.resolve(name).toFile();
+        AudioSystem.write(new AudioInputStream(new ByteArrayInputStream(data), format, data.length / 2),
+                AudioFileFormat.Type.WAVE, file);
+        return file;
+    }
+
+    /**
+     * Creates mono 16-bit samples of alternating tone and silence sections (seconds), starting with tone.
+     */
+    private static byte[] toneAndSilence(double... sections) {
+        int totalFrames = 0;
+        for (double seconds : sections) {
+            totalFrames += (int) (seconds * SAMPLE_RATE);
+        }
+        byte[] data = new byte[totalFrames * 2];
+        int frame = 0;
+        for (int i = 0; i < sections.length; i++) {
+            int frames = (int) (sections[i] * SAMPLE_RATE);
+            boolean tone = i % 2 == 0;
+            for (int f = 0; f < frames; f++, frame++) {
+                short sample = tone ? (short) (Math.sin(2 * Math.PI * 440 * frame / SAMPLE_RATE) * 12000) : 0;
+                data[frame * 2] = (byte) sample;
+                data[frame * 2 + 1] = (byte) (sample >> 8);
+            }
+        }
+        return data;
+    }
+}
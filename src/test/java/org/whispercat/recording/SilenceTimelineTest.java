@@ -0,0 +1,82 @@
+package org.whispercat.recording;
+
+import org.junit.jupiter.api.Test;
+import org.whispercat.recording.clients.TranscriptionSegment;
+
+import java.util.Arrays;
+import java.util.Collections;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+
+class SilenceTimelineTest {
+
+    private static final float SAMPLE_RATE = 100f;
+    private static final double DELTA = 1e-9;
+
+    // Removes 1.0-3.0 s and 5.0-6.0 s of the original recording, so the processed audio has
+    // cuts at 1.0 s (2 s removed) and at 3.0 s (1 s removed).
+    private static SilenceTimeline twoCuts() {
+        return SilenceTimeline.fromRemovedFrames(Arrays.asList(new long[]{100, 300}, new long[]{500, 600}), SAMPLE_RATE);
+    }
+
+    @Test
+    void keepsPositionsWithoutCuts() {
+        SilenceTimeline timeline = SilenceTimeline.fromRemovedFrames(Collections.emptyList(), SAMPLE_RATE);
+
+        assertEquals(2.5, timeline.toOriginalStart(2.5), DELTA);
+        assertEquals(2.5, timeline.toOriginalEnd(2.5), DELTA);
+    }
+
+    @Test
+    void shiftsPositionsByRemovedSilence() {
+        SilenceTimeline timeline = twoCuts();
+
+        assertEquals(0.5, timeline.toOriginalStart(0.5), DELTA);
+        assertEquals(4.0, timeline.toOriginalStart(2.0), DELTA);
+        assertEquals(7.0, timeline.toOriginalStart(4.0), DELTA);
+        assertEquals(7.0, timeline.toOriginalEnd(4.0), DELTA);
+    }
+
+    @Test
+    void mapsPositionAtCutDependingOnSide() {
+        SilenceTimeline timeline = twoCuts();
+
+        assertEquals(3.0, timeline.toOriginalStart(1.0), DELTA);
+        assertEquals(1.0, timeline.toOriginalEnd(1.0), DELTA);
+        assertEquals(6.0, timeline.toOriginalStart(3.0), DELTA);
+        assertEquals(5.0, timeline.toOriginalEnd(3.0), DELTA);
+    }
+
+    @Test
+    void ignoresEmptyRegions() {
+        SilenceTimeline timeline = SilenceTimeline.fromRemovedFrames(
+                Arrays.asList(new long[]{100, 100}, new long[]{200, 300}), SAMPLE_RATE);
+
+        assertEquals(1.5, timeline.toOriginalStart(1.5), DELTA);
+        assertEquals(3.5, timeline.toOriginalStart(2.5), DELTA);
+    }
+
+    @Test
+    void remapsSegments() {
+        List<TranscriptionSegment> segments = Arrays.asList(segment(0.0, 1.0), segment(1.0, 3.0), segment(3.0, 3.0));
+
+        twoCuts().remap(segments);
+
+        assertSegment(0.0, 1.0, segments.get(0));
+        assertSegment(3.0, 5.0, segments.get(1));
+        assertSegment(6.0, 6.0, segments.get(2));
+    }
+
+    private static TranscriptionSegment segment(double start, double end) {
+        TranscriptionSegment segment = new TranscriptionSegment();
+        segment.setStart(start);
+        segment.setEnd(end);
+        return segment;
+    }
+
+    private static void assertSegment(double start, double end, TranscriptionSegment segment) {
+        assertEquals(start, segment.getStart(), DELTA);
+        assertEquals(end, segment.getEnd(), DELTA);
+    }
+}
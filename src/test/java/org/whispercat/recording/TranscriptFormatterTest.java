@@ -0,0 +1,142 @@
+package org.whispercat.recording;
+
+import org.junit.jupiter.api.Test;
+import org.whispercat.recording.clients.TranscriptionSegment;
+
+import java.time.LocalDateTime;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertNull;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class TranscriptFormatterTest {
+
+    private static final LocalDateTime FIXED_TIME = LocalDateTime.of(2024, 3, 7, 9, 5, 42);
+
+    @Test
+    void prependsTimestampWithFormat() {
+        assertEquals("2024-03-07 09:05 Buy milk",
+                TranscriptFormatter.prependTimestamp("Buy milk", "yyyy-MM-dd HH:mm", FIXED_TIME));
+        assertEquals("[07.03.2024 09:05:42] Buy milk",
+                TranscriptFormatter.prependTimestamp("Buy milk", "'['dd.MM.yyyy HH:mm:ss']'", FIXED_TIME));
+    }
+
+    @Test
+    void invalidFormatFallsBackToDefault() {
+        assertEquals("2024-03-07 09:05 Note",
+                TranscriptFormatter.prependTimestamp("Note", "yyyy-MM-dd {{", FIXED_TIME));
+        assertEquals("2024-03-07 09:05 Note",
+                TranscriptFormatter.prependTimestamp("Note", "", FIXED_TIME));
+    }
+
+    @Test
+    void validatesTimestampFormats() {
+        assertTrue(TranscriptFormatter.isValidTimestampFormat(TranscriptFormatter.DEFAULT_TIMESTAMP_FORMAT));
+        assertTrue(TranscriptFormatter.isValidTimestampFormat("HH:mm"));
+        assertFalse(TranscriptFormatter.isValidTimestampFormat(null));
+        assertFalse(TranscriptFormatter.isValidTimestampFormat("   "));
+        assertFalse(TranscriptFormatter.isValidTimestampFormat("yyyy-MM-dd {{"));
+        // Parses, but a local date-time has no zone to format
+        assertFalse(TranscriptFormatter.isValidTimestampFormat("HH:mm VV"));
+    }
+
+    @Test
+    void normalizeTrimsLeadingSpace() {
+        assertEquals("Hello world.", TranscriptFormatter.normalizeText(" Hello world."));
+        assertEquals("Hello world.", TranscriptFormatter.normalizeText("\tHello world.  \n"));
+    }
+
+    @Test
+    void normalizeRemovesSpaceBeforePunctuation() {
+        assertEquals("Yes, that works.", TranscriptFormatter.normalizeText("Yes , that works ."));
+        assertEquals("One. Two.", TranscriptFormatter.normalizeText("One  . Two ."));
+    }
+
+    @Test
+    void normalizeCollapsesSpacesButKeepsLineBreaks() {
+        assertEquals("first line\nsecond line", TranscriptFormatter.normalizeText("first   line\nsecond \t line"));
+    }
+
+    @Test
+    void normalizeLeavesOtherPunctuationAlone() {
+        assertEquals("Really ? Bonjour !", TranscriptFormatter.normalizeText("Really ? Bonjour !"));
+        assertEquals("3.14 and 1,000", TranscriptFormatter.normalizeText("3.14 and 1,000"));
+        assertNull(TranscriptFormatter.normalizeText(null));
+    }
+
+    @Test
+    void promptTailKeepsShortTextWhole() {
+        assertEquals("Hello world.", TranscriptFormatter.extractPromptTail("  Hello world.  ", 200));
+        assertEquals("", TranscriptFormatter.extractPromptTail(null, 200));
+        assertEquals("", TranscriptFormatter.extractPromptTail("Hello", 0));
+    }
+
+    @Test
+    void promptTailStartsAtWordBoundary() {
+        // the last 12 characters are "ck brown fox"; the partial word is dropped
+        assertEquals("brown fox", TranscriptFormatter.extractPromptTail("The quick brown fox", 12));
+        // the cut falls right after a space, so the first word is complete
+        assertEquals("quick brown fox", TranscriptFormatter.extractPromptTail("The quick brown fox", 15));
+    }
+
+    @Test
+    void formatsMetadataHeader() {
+        TranscriptionOutcome outcome = new TranscriptionOutcome("Hello there", "OpenAI", "whisper-1");
+        outcome.setLanguage("en");
+        outcome.setDurationSeconds(12.34);
+
+        assertEquals("[OpenAI whisper-1, en, 12.3s]", TranscriptFormatter.formatMetadataHeader(outcome));
+        assertEquals("[OpenAI whisper-1, en, 12.3s]\nHello there",
+                TranscriptFormatter.withMetadataHeader(outcome.getText(), outcome));
+    }
+
+    @Test
+    void metadataHeaderLeavesOutUnknownValues() {
+        TranscriptionOutcome outcome = new TranscriptionOutcome("Hi", "Open WebUI", "");
+        outcome.setLanguage(" ");
+        outcome.setDurationSeconds(-1);
+
+        assertEquals("[Open WebUI]", TranscriptFormatter.formatMetadataHeader(outcome));
+
+        TranscriptionOutcome empty = new TranscriptionOutcome(null, null, null);
+        empty.setDurationSeconds(-1);
+        assertEquals("[]\n", TranscriptFormatter.withMetadataHeader(null, empty));
+    }
+
+    @Test
+    void formatsTwoSegmentsAsSrt() {
+        List<TranscriptionSegment> segments = List.of(segment(0.0, 2.5, " Hello there."),
+                segment(62.345, 3725.0, " How are you?"));
+
+        assertEquals("1\n00:00:00,000 --> 00:00:02,500\nHello there.\n\n"
+                        + "2\n00:01:02,345 --> 01:02:05,000\nHow are you?\n\n",
+                TranscriptFormatter.toSrt(segments));
+    }
+
+    @Test
+    void formatsTwoSegmentsAsVtt() {
+        List<TranscriptionSegment> segments = List.of(segment(0.0, 2.5, " Hello there."),
+                segment(62.345, 64.0, " How are you?"));
+
+        assertEquals("WEBVTT\n\n00:00:00.000 --> 00:00:02.500\nHello there.\n\n"
+                        + "00:01:02.345 --> 00:01:04.000\nHow are you?\n\n",
+                TranscriptFormatter.toVtt(segments));
+    }
+
+    @Test
+    void noSegmentsGiveNoSubtitles() {
+        assertNull(TranscriptFormatter.toSrt(List.of()));
+        assertNull(TranscriptFormatter.toSrt(null));
+        assertNull(TranscriptFormatter.toVtt(List.of()));
+    }
+
+    private static TranscriptionSegment segment(double start, double end, String text) {
+        TranscriptionSegment segment = new TranscriptionSegment();
+        segment.setStart(start);
+        segment.setEnd(end);
+        segment.setText(text);
+        return segment;
+    }
+}
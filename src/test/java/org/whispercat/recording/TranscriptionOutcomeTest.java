@@ -0,0 +1,68 @@
+package org.whispercat.recording;
+
+import com.fasterxml.jackson.databind.JsonNode;
+import com.fasterxml.jackson.databind.ObjectMapper;
+import org.junit.jupiter.api.Test;
+import org.whispercat.recording.clients.TranscriptionConfidence;
+import org.whispercat.recording.clients.TranscriptionSegment;
+
+import java.util.Collections;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class TranscriptionOutcomeTest {
+
+    private final ObjectMapper objectMapper = new ObjectMapper();
+
+    @Test
+    void serializesAllFields() throws Exception {
+        TranscriptionSegment segment = new TranscriptionSegment();
+        segment.setStart(0.0);
+        segment.setEnd(2.5);
+        segment.setText(" Hello world.");
+        TranscriptionOutcome outcome = new TranscriptionOutcome("Hello world.", "OpenAI", "whisper-1");
+        outcome.setLanguage("english");
+        outcome.setDurationSeconds(2.5);
+        outcome.setSilenceRemovalMs(40);
+        outcome.setTranscriptionMs(1200);
+        outcome.setConfidence(0.9);
+        outcome.setSegments(Collections.singletonList(segment));
+
+        JsonNode json = objectMapper.readTree(objectMapper.writeValueAsString(outcome));
+
+        assertEquals("Hello world.", json.path("text").asText());
+        assertEquals("english", json.path("language").asText());
+        assertEquals(2.5, json.path("durationSeconds").asDouble());
+        assertEquals("OpenAI", json.path("provider").asText());
+        assertEquals("whisper-1", json.path("model").asText());
+        assertEquals(40, json.path("silenceRemovalMs").asLong());
+        assertEquals(1200, json.path("transcriptionMs").asLong());
+        assertEquals(0.9, json.path("confidence").asDouble());
+        assertEquals(" Hello world.", json.path("segments").path(0).path("text").asText());
+    }
+
+    @Test
+    void roundTripsThroughJson() throws Exception {
+        TranscriptionOutcome outcome = new TranscriptionOutcome("Hallo", "Faster-Whisper", "Systran/faster-whisper-base");
+        outcome.setLanguage("de");
+        outcome.setTranscriptionMs(300);
+
+        TranscriptionOutcome copy = objectMapper.readValue(objectMapper.writeValueAsString(outcome), TranscriptionOutcome.class);
+
+        assertEquals("Hallo", copy.getText());
+        assertEquals("de", copy.getLanguage());
+        assertEquals("Faster-Whisper", copy.getProvider());
+        assertEquals("Systran/faster-whisper-base", copy.getModel());
+        assertEquals(300, copy.getTranscriptionMs());
+    }
+
+    @Test
+    void defaultsToUnknownConfidenceAndNoSegments() {
+        TranscriptionOutcome outcome = new TranscriptionOutcome("text", "Open WebUI", "");
+        outcome.setSegments(null);
+
+        assertEquals(TranscriptionConfidence.UNKNOWN, outcome.getConfidence());
+        assertTrue(outcome.getSegments().isEmpty());
+    }
+}
@@ -0,0 +1,65 @@
+package org.whispercat.recording;
+
+import org.junit.jupiter.api.Test;
+
+import java.io.File;
+import java.util.ArrayList;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertNull;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class TranscriptionQueueTest {
+
+    private final List<File> started = new ArrayList<>();
+    private final TranscriptionQueue queue = new TranscriptionQueue(started::add);
+
+    @Test
+    void recordingDuringTranscriptionIsQueuedSeparately() {
+        File first = new File("record_1.wav");
+        File second = new File("record_2.wav");
+
+        assertTrue(queue.submit(first));
+        assertTrue(queue.isTranscribing());
+        assertTrue(queue.canStartRecording(true));
+
+        // The second recording was made while the first one was transcribing
+        assertFalse(queue.submit(second));
+        assertEquals(List.of(first), started);
+        assertEquals(1, queue.getQueuedCount());
+
+        assertEquals(second, queue.finishActive());
+        assertEquals(List.of(first, second), started);
+        assertTrue(queue.isTranscribing());
+        assertEquals(0, queue.getQueuedCount());
+
+        assertNull(queue.finishActive());
+        assertFalse(queue.isTranscribing());
+    }
+
+    @Test
+    void recorderBlockedDuringTranscriptionByDefault() {
+        assertTrue(queue.canStartRecording(false));
+
+        queue.submit(new File("record_1.wav"));
+
+        assertFalse(queue.canStartRecording(false));
+        queue.finishActive();
+        assertTrue(queue.canStartRecording(false));
+    }
+
+    @Test
+    void queuedRecordingsKeepRecordingOrder() {
+        File[] recordings = {new File("a.wav"), new File("b.wav"), new File("c.wav")};
+        for (File recording : recordings) {
+            queue.submit(recording);
+        }
+
+        queue.finishActive();
+        queue.finishActive();
+
+        assertEquals(List.of(recordings), started);
+    }
+}
@@ -0,0 +1,227 @@
+package org.whispercat.recording;
+
+import com.sun.net.httpserver.HttpServer;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+import org.whispercat.CancellationToken;
+import org.whispercat.ConfigManager;
+import org.whispercat.recording.clients.TranscriptionProviders;
+
+import javax.sound.sampled.AudioFileFormat;
+import javax.sound.sampled.AudioFormat;
+import javax.sound.sampled.AudioInputStream;
+import javax.sound.sampled.AudioSystem;
+import java.io.ByteArrayInputStream;
+import java.io.File;
+import java.io.IOException;
+import java.io.OutputStream;
+import java.io.UncheckedIOException;
+import java.net.InetAddress;
+import java.net.InetSocketAddress;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Path;
+import java.util.concurrent.CompletableFuture;
+import java.util.concurrent.CountDownLatch;
+import java.util.concurrent.ExecutionException;
+import java.util.concurrent.ExecutorService;
+import java.util.concurrent.Executors;
+import java.util.concurrent.TimeUnit;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertInstanceOf;
+import static org.junit.jupiter.api.Assertions.assertNull;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class TranscriptionServiceTest {
+
+    @TempDir
+    Path tempDir;
+
+    private ConfigManager configManager;
+    private TranscriptionService service;
+
+    @BeforeEach
+    void setUp() {
+        configManager = new ConfigManager(tempDir.resolve("config.properties").toFile());
+        service = new TranscriptionService(configManager);
+    }
+
+    @Test
+    void previousTranscriptionBecomesPrompt() {
+        configManager.setUsePreviousAsPrompt(true);
+        service.rememberForPrompt("First part of the dictation.");
+
+        assertEquals("First part of the dictation.", service.buildPrompt());
+    }
+
+    @Test
+    void emptyResultKeepsPreviousPrompt() {
+        configManager.setUsePreviousAsPrompt(true);
+        service.rememberForPrompt("First part.");
+        service.rememberForPrompt("  ");
+
+        assertEquals("First part.", service.buildPrompt());
+    }
+
+    @Test
+    void resetDropsPromptContext() {
+        configManager.setUsePreviousAsPrompt(true);
+        service.rememberForPrompt("First part.");
+
+        service.resetPromptContext();
+
+        assertNull(service.buildPrompt());
+    }
+
+    @Test
+    void noPromptWhenDisabled() {
+        service.rememberForPrompt("First part.");
+
+        assertNull(service.buildPrompt());
+    }
+
+    @Test
+    void initialPromptComesBeforePreviousTranscription() {
+        configManager.setTranscriptionPrompt(" Kubernetes, kubectl ");
+        configManager.setUsePreviousAsPrompt(true);
+        service.rememberForPrompt("First part.");
+
+        assertEquals("Kubernetes, kubectl First part.", service.buildPrompt());
+    }
+
+    @Test
+    void initialPromptIsSentWithoutPreviousTranscription() {
+        configManager.setTranscriptionPrompt("WhisperCat");
+        service.rememberForPrompt("First part.");
+
+        assertEquals("WhisperCat", service.buildPrompt());
+    }
+
+    @Test
+    void retriesEmptyResultForLoudAudio() {
+        assertTrue(TranscriptionService.shouldRetryEmptyResult("", 0.2f, 0.01f));
+        assertTrue(TranscriptionService.shouldRetryEmptyResult("  ", 0.01f, 0.01f));
+        assertTrue(TranscriptionService.shouldRetryEmptyResult(null, 0.2f, 0.01f));
+    }
+
+    @Test
+    void doesNotRetryEmptyResultForSilentAudio() {
+        assertFalse(TranscriptionService.shouldRetryEmptyResult("", 0.0f, 0.01f));
+        assertFalse(TranscriptionService.shouldRetryEmptyResult("", 0.005f, 0.01f));
+    }
+
+    @Test
+    void doesNotRetryNonEmptyResult() {
+        assertFalse(TranscriptionService.shouldRetryEmptyResult("Hello", 0.2f, 0.01f));
+    }
+
+    @Test
+    void retryOfEmptyResultIsOffByDefault() {
+        assertFalse(configManager.isRetryEmptyTranscription());
+        configManager.setRetryEmptyTranscription(true);
+        assertTrue(configManager.isRetryEmptyTranscription());
+    }
+
+    @Test
+    void translationFailsClearlyForProvidersWithoutIt() {
+        configManager.setProperty("whisperServer", TranscriptionProviders.FASTER_WHISPER);
+        configManager.setTranslateToEnglish(true);
+
+        TranslationNotSupportedException e = assertThrows(TranslationNotSupportedException.class,
+                () -> service.transcribe(tempDir.resolve("recording.wav").toFile()));
+
+        assertEquals(TranscriptionProviders.FASTER_WHISPER, e.getProvider());
+        assertTrue(e.getMessage().contains("cannot translate to English"), e.getMessage());
+    }
+
+    @Test
+    void cancellingOneTranscriptionLeavesOthersRunning() throws Exception {
+        CountDownLatch requestsArrived = new CountDownLatch(2);
+        CountDownLatch releaseServer = new CountDownLatch(1);
+        ExecutorService serverThreads = Executors.newCachedThreadPool();
+        HttpServer server = HttpServer.create(new InetSocketAddress(InetAddress.getLoopbackAddress(), 0), 0);
+        server.setExecutor(serverThreads);
+        server.createContext("/v1/audio/transcriptions", exchange -> {
+            try {
+                exchange.getRequestBody().readAllBytes();
+                requestsArrived.countDown();
+                releaseServer.await(10, TimeUnit.SECONDS);
+                byte[] body = "{\"text\":\"hello\"}".getBytes(StandardCharsets.UTF_8);
+                exchange.sendResponseHeaders(200, body.length);
+                try (OutputStream out = exchange.getResponseBody()) {
+                    out.write(body);
+                }
+            } catch (Exception e) {
+                // The cancelled client has hung up
+            } finally {
+                exchange.close();
+            }
+        });
+        server.start();
+        try {
+            configManager.setProperty("whisperServer", TranscriptionProviders.FASTER_WHISPER);
+            configManager.setProperty("fasterWhisperServerUrl", "http://127.0.0.1:" + server.getAddress().getPort());
+            configManager.setMinRecordingDurationForSilenceRemoval(0);
+            // 10 s with a 5 s pause, so silence removal writes a compressed copy
+            File cancelledRecording = writeWav("cancelled.wav", 2.5, 5.0, 2.5);
+            File keptRecording = writeWav("kept.wav", 2.5, 5.0, 2.5);
+            CancellationToken cancellation = new CancellationToken();
+
+            CompletableFuture<TranscriptionOutcome> cancelled = CompletableFuture.supplyAsync(
+                    () -> transcribe(cancelledRecording, cancellation));
+            CompletableFuture<TranscriptionOutcome> kept = CompletableFuture.supplyAsync(
+                    () -> transcribe(keptRecording, new CancellationToken()));
+            assertTrue(requestsArrived.await(10, TimeUnit.SECONDS));
+            cancellation.cancel();
+
+            ExecutionException e = assertThrows(ExecutionException.class, () -> cancelled.get(5, TimeUnit.SECONDS));
+            assertInstanceOf(TranscriptionCancelledException.class, e.getCause().getCause());
+            assertFalse(tempDir.resolve("cancelled_nosilence.wav").toFile().exists());
+            assertTrue(tempDir.resolve("kept_nosilence.wav").toFile().exists());
+            assertFalse(kept.isDone());
+            releaseServer.countDown();
+            assertEquals("hello", kept.get(5, TimeUnit.SECONDS).getText());
+        } finally {
+            releaseServer.countDown();
+            server.stop(0);
+            serverThreads.shutdownNow();
+        }
+    }
+
+    private TranscriptionOutcome transcribe(File recording, CancellationToken cancellation) {
+        try {
+            return service.transcribe(recording, cancellation);
+        } catch (IOException e) {
+            throw new UncheckedIOException(e);
+        }
+    }
+
+    /**
+     * Writes a mono 16-bit WAV at the configured rate of alternating tone and silence sections
+     * (seconds), starting with tone.
+     */
+    private File writeWav(String name, double... sections) throws Exception {
+        float sampleRate = configManager.getAudioBitrate();
+        int totalFrames = 0;
+        for (double seconds : sections) {
+            totalFrames += (int) (seconds * sampleRate);
+        }
+        byte[] data = new byte[totalFrames * 2];
+        int frame = 0;
+        for (int i = 0; i < sections.length; i++) {
+            int frames = (int) (sections[i] * sampleRate);
+            for (int f = 0; f < frames; f++, frame++) {
+                short sample = i % 2 == 0 ? (short) (Math.sin(2 * Math.PI * 440 * frame / sampleRate) * 12000) : 0;
+                data[frame * 2] = (byte) sample;
+                data[frame * 2 + 1] = (byte) (sample >> 8);
+            }
+        }
+        File file = tempDir.resolve(name).toFile();
+        AudioSystem.write(new AudioInputStream(new ByteArrayInputStream(data),
+                new AudioFormat(sampleRate, 16, 1, true, false), totalFrames), AudioFileFormat.Type.WAVE, file);
+        return file;
+    }
+}
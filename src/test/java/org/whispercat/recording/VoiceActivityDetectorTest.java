@@ -0,0 +1,98 @@
+package org.whispercat.recording;
+
+import org.junit.jupiter.api.Test;
+
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class VoiceActivityDetectorTest {
+
+    private static final float THRESHOLD = 0.05f;
+    private static final long CHUNK_MS = 50;
+
+    @Test
+    void startsAfterOnsetAndEndsAfterTrailingSilence() {
+        VoiceActivityDetector detector = new VoiceActivityDetector(THRESHOLD, 100, 200);
+
+        List<VoiceActivityDetector.Event> events = feed(detector,
+                0.01f, 0.2f, 0.2f, 0.3f, 0.2f, 0.01f, 0.01f, 0.01f, 0.01f);
+
+        assertEquals(Arrays.asList(
+                VoiceActivityDetector.Event.NONE,
+                VoiceActivityDetector.Event.NONE,
+                VoiceActivityDetector.Event.SPEECH_STARTED, // 100 ms above the threshold
+                VoiceActivityDetector.Event.NONE,
+                VoiceActivityDetector.Event.NONE,
+                VoiceActivityDetector.Event.NONE,
+                VoiceActivityDetector.Event.NONE,
+                VoiceActivityDetector.Event.NONE,
+                VoiceActivityDetector.Event.SPEECH_ENDED), // 200 ms below the threshold
+                events);
+        assertFalse(detector.isSpeaking());
+    }
+
+    @Test
+    void shortNoiseDoesNotStartSpeech() {
+        VoiceActivityDetector detector = new VoiceActivityDetector(THRESHOLD, 150, 200);
+
+        List<VoiceActivityDetector.Event> events = feed(detector, 0.2f, 0.2f, 0.01f, 0.2f, 0.2f, 0.01f);
+
+        assertFalse(events.contains(VoiceActivityDetector.Event.SPEECH_STARTED));
+        assertFalse(detector.isSpeaking());
+    }
+
+    @Test
+    void shortPauseDoesNotEndSpeech() {
+        VoiceActivityDetector detector = new VoiceActivityDetector(THRESHOLD, 50, 200);
+
+        List<VoiceActivityDetector.Event> events = feed(detector, 0.2f, 0.01f, 0.01f, 0.01f, 0.2f, 0.01f, 0.01f);
+
+        assertEquals(VoiceActivityDetector.Event.SPEECH_STARTED, events.get(0));
+        assertFalse(events.contains(VoiceActivityDetector.Event.SPEECH_ENDED));
+        assertTrue(detector.isSpeaking());
+    }
+
+    @Test
+    void levelAtThresholdCountsAsSpeech() {
+        VoiceActivityDetector detector = new VoiceActivityDetector(THRESHOLD, 50, 100);
+
+        assertEquals(VoiceActivityDetector.Event.SPEECH_STARTED, detector.process(THRESHOLD, CHUNK_MS));
+    }
+
+    @Test
+    void detectsFollowingUtteranceAfterEnd() {
+        VoiceActivityDetector detector = new VoiceActivityDetector(THRESHOLD, 50, 50);
+
+        List<VoiceActivityDetector.Event> events = feed(detector, 0.2f, 0.01f, 0.2f, 0.01f);
+
+        assertEquals(Arrays.asList(
+                VoiceActivityDetector.Event.SPEECH_STARTED,
+                VoiceActivityDetector.Event.SPEECH_ENDED,
+                VoiceActivityDetector.Event.SPEECH_STARTED,
+                VoiceActivityDetector.Event.SPEECH_ENDED), events);
+    }
+
+    @Test
+    void resetReturnsToSilence() {
+        VoiceActivityDetector detector = new VoiceActivityDetector(THRESHOLD, 50, 200);
+        detector.process(0.2f, CHUNK_MS);
+
+        detector.reset();
+
+        assertFalse(detector.isSpeaking());
+        assertEquals(VoiceActivityDetector.Event.SPEECH_STARTED, detector.process(0.2f, CHUNK_MS));
+    }
+
+    private static List<VoiceActivityDetector.Event> feed(VoiceActivityDetector detector, float... levels) {
+        List<VoiceActivityDetector.Event> events = new ArrayList<>();
+        for (float level : levels) {
+            events.add(detector.process(level, CHUNK_MS));
+        }
+        return events;
+    }
+}
@@ -0,0 +1,147 @@
+package org.whispercat.recording;
+
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+
+import javax.sound.sampled.AudioFileFormat;
+import javax.sound.sampled.AudioFormat;
+import javax.sound.sampled.AudioInputStream;
+import javax.sound.sampled.AudioSystem;
+import java.io.ByteArrayInputStream;
+import java.io.File;
+import java.io.IOException;
+import java.io.RandomAccessFile;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.util.Arrays;
+
+import static org.junit.jupiter.api.Assertions.assertArrayEquals;
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+
+class WavStreamWriterTest {
+
+    private static final AudioFormat MONO_16K = new AudioFormat(16000f, 16, 1, true, false);
+
+    @TempDir
+    Path tempDir;
+
+    @Test
+    void streamedSamplesMatchOneShotWrite() throws IOException {
+        float[] samples = sine(16000, 440, 0.5f);
+
+        File streamed = tempDir.resolve("streamed.wav").toFile();
+        try (WavStreamWriter writer = new WavStreamWriter(streamed, MONO_16K)) {
+            for (int offset = 0; offset < samples.length; offset += 1000) {
+                writer.writeSamples(Arrays.copyOfRange(samples, offset, Math.min(samples.length, offset + 1000)));
+            }
+        }
+
+        File oneShot = tempDir.resolve("one-shot.wav").toFile();
+        byte[] pcm = toPcm16Bytes(samples);
+        try (AudioInputStream stream = new AudioInputStream(new ByteArrayInputStream(pcm), MONO_16K, samples.length)) {
+            AudioSystem.write(stream, AudioFileFormat.Type.WAVE, oneShot);
+        }
+
+        assertArrayEquals(Files.readAllBytes(oneShot.toPath()), Files.readAllBytes(streamed.toPath()));
+    }
+
+    @Test
+    void finishPatchesHeaderSizes() throws Exception {
+        File file = tempDir.resolve("sizes.wav").toFile();
+        WavStreamWriter writer = new WavStreamWriter(file, MONO_16K);
+        writer.writeSamples(new float[100]);
+        writer.writeSamples(new float[50]);
+        writer.finish();
+
+        assertEquals(44 + 300, file.length());
+        try (AudioInputStream stream = AudioSystem.getAudioInputStream(file)) {
+            assertEquals(150, stream.getFrameLength());
+        }
+    }
+
+    @Test
+    void failedWriteLeavesFinalizedPartialFile() throws Exception {
+        File file = tempDir.resolve("partial.wav").toFile();
+        // Takes the header and 400 bytes of audio, then half of the next write before failing
+        RandomAccessFile fillingDisk = new RandomAccessFile(file, "rw") {
+            @Override
+            public void write(byte[] b, int off, int len) throws IOException {
+                if (getFilePointer() + len > 44 + 400) {
+                    super.write(b, off, len / 2);
+                    throw new IOException("No space left on device");
+                }
+                super.write(b, off, len);
+            }
+
+            @Override
+            public void write(byte[] b) throws IOException {
+                write(b, 0, b.length);
+            }
+        };
+        WavStreamWriter writer = new WavStreamWriter(MONO_16K, fillingDisk);
+        writer.writeSamples(new float[100]);
+        writer.writeSamples(new float[100]);
+
+        assertThrows(IOException.class, () -> writer.writeSamples(new float[100]));
+        writer.finish();
+
+        assertEquals(44 + 400, file.length());
+        try (AudioInputStream stream = AudioSystem.getAudioInputStream(file)) {
+            assertEquals(200, stream.getFrameLength());
+        }
+    }
+
+    @Test
+    void rawPcmIsSwappedFromBigEndian() throws IOException {
+        AudioFormat bigEndian = new AudioFormat(16000f, 16, 1, true, true);
+        File file = tempDir.resolve("swapped.wav").toFile();
+        try (WavStreamWriter writer = new WavStreamWriter(file, bigEndian)) {
+            // The trailing odd byte is an incomplete frame and must be dropped
+            writer.writePcm(new byte[]{0x12, 0x34, 0x56, 0x78, 0x01}, 0, 5);
+        }
+
+        byte[] bytes = Files.readAllBytes(file.toPath());
+        assertArrayEquals(new byte[]{0x34, 0x12, 0x78, 0x56}, Arrays.copyOfRange(bytes, 44, bytes.length));
+    }
+
+    @Test
+    void clampsOutOfRangeSamples() {
+        assertEquals(Short.MAX_VALUE, WavStreamWriter.toPcm16(1.5f));
+        assertEquals(-Short.MAX_VALUE, WavStreamWriter.toPcm16(-2f));
+        assertEquals(0, WavStreamWriter.toPcm16(0f));
+    }
+
+    @Test
+    void rejectsWritesAfterFinish() throws IOException {
+        WavStreamWriter writer = new WavStreamWriter(tempDir.resolve("done.wav").toFile(), MONO_16K);
+        writer.finish();
+        writer.finish();
+
+        assertThrows(IOException.class, () -> writer.writeSamples(new float[1]));
+    }
+
+    @Test
+    void rejectsNon16BitFormats() {
+        AudioFormat eightBit = new AudioFormat(16000f, 8, 1, true, false);
+        assertThrows(IOException.class, () -> new WavStreamWriter(tempDir.resolve("x.wav").toFile(), eightBit));
+    }
+
+    private static float[] sine(int count, double frequency, float amplitude) {
+        float[] samples = new float[count];
+        for (int i = 0; i < count; i++) {
+            samples[i] = (float) (amplitude * Math.sin(2 * Math.PI * frequency * i / 16000.0));
+        }
+        return samples;
+    }
+
+    private static byte[] toPcm16Bytes(float[] samples) {
+        byte[] bytes = new byte[samples.length * 2];
+        for (int i = 0; i < samples.length; i++) {
+            short value = WavStreamWriter.toPcm16(samples[i]);
+            bytes[i * 2] = (byte) value;
+            bytes[i * 2 + 1] = (byte) (value >> 8);
+        }
+        return bytes;
+    }
+}
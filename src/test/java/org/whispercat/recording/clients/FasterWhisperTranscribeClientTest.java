@@ -0,0 +1,46 @@
+package org.whispercat.recording.clients;
+
+import org.junit.jupiter.api.Test;
+import org.whispercat.recording.TranscriptionOutcome;
+
+import java.io.IOException;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class FasterWhisperTranscribeClientTest {
+
+    @Test
+    void blankModelUsesDefault() {
+        assertEquals(FasterWhisperTranscribeClient.DEFAULT_MODEL, FasterWhisperTranscribeClient.resolveModel(null));
+        assertEquals(FasterWhisperTranscribeClient.DEFAULT_MODEL, FasterWhisperTranscribeClient.resolveModel(""));
+        assertEquals(FasterWhisperTranscribeClient.DEFAULT_MODEL, FasterWhisperTranscribeClient.resolveModel("   "));
+    }
+
+    @Test
+    void configuredModelIsTrimmed() {
+        assertEquals("Systran/faster-whisper-large-v3",
+                FasterWhisperTranscribeClient.resolveModel(" Systran/faster-whisper-large-v3 "));
+    }
+
+    @Test
+    void verboseJsonResponseCarriesSegments() throws IOException {
+        TranscriptionOutcome outcome = FasterWhisperTranscribeClient.parseResponse("{\"text\":\"Hello there.\","
+                + "\"segments\":[{\"start\":0.0,\"end\":1.2,\"text\":\" Hello\"},{\"start\":1.2,\"end\":2.5,\"text\":\" there.\"}]}",
+                "Systran/faster-whisper-small", "de");
+
+        assertEquals("Hello there.", outcome.getText());
+        assertEquals(2, outcome.getSegments().size());
+        assertEquals(1.2, outcome.getSegments().get(1).getStart());
+        assertEquals("Faster-Whisper", outcome.getProvider());
+        assertEquals("de", outcome.getLanguage());
+    }
+
+    @Test
+    void plainTextResponseHasNoSegments() throws IOException {
+        TranscriptionOutcome outcome = FasterWhisperTranscribeClient.parseResponse("\"Hello\"", "", "");
+
+        assertEquals("Hello", outcome.getText());
+        assertTrue(outcome.getSegments().isEmpty());
+    }
+}
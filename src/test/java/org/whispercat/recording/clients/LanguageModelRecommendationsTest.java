@@ -0,0 +1,65 @@
+package org.whispercat.recording.clients;
+
+import com.fasterxml.jackson.databind.ObjectMapper;
+import org.junit.jupiter.api.Test;
+
+import java.io.InputStream;
+import java.util.Arrays;
+import java.util.List;
+import java.util.Map;
+import java.util.Set;
+import java.util.stream.Collectors;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertNotNull;
+import static org.junit.jupiter.api.Assertions.assertNull;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class LanguageModelRecommendationsTest {
+
+    private static final String JAPANESE_MODEL = "kotoba-tech/kotoba-whisper-v2.0-faster";
+    private static final List<String> AVAILABLE = Arrays.asList("Systran/faster-whisper-base", JAPANESE_MODEL);
+
+    @Test
+    void recommendsModelForKnownLanguage() throws Exception {
+        Map<String, LanguageModelRecommendation> recommendations = LanguageModelRecommendations.load();
+
+        LanguageModelRecommendation recommendation = LanguageModelRecommendations.recommend(
+                recommendations, "ja", "Systran/faster-whisper-base", AVAILABLE);
+
+        assertNotNull(recommendation);
+        assertEquals(JAPANESE_MODEL, recommendation.getModel());
+    }
+
+    @Test
+    void unknownLanguageHasNoSuggestion() throws Exception {
+        Map<String, LanguageModelRecommendation> recommendations = LanguageModelRecommendations.load();
+
+        assertNull(LanguageModelRecommendations.recommend(recommendations, "xx", "Systran/faster-whisper-base", AVAILABLE));
+        assertNull(LanguageModelRecommendations.recommend(recommendations, "", "Systran/faster-whisper-base", AVAILABLE));
+        assertNull(LanguageModelRecommendations.recommend(recommendations, null, "Systran/faster-whisper-base", AVAILABLE));
+    }
+
+    @Test
+    void noSuggestionWhenAlreadySelectedOrUnavailable() throws Exception {
+        Map<String, LanguageModelRecommendation> recommendations = LanguageModelRecommendations.load();
+
+        assertNull(LanguageModelRecommendations.recommend(recommendations, "ja", JAPANESE_MODEL, AVAILABLE));
+        assertNull(LanguageModelRecommendations.recommend(recommendations, "ja", "Systran/faster-whisper-base",
+                Arrays.asList("Systran/faster-whisper-base")));
+    }
+
+    @Test
+    void bundledRecommendationsReferenceKnownModels() throws Exception {
+        Set<String> knownModels;
+        try (InputStream is = getClass().getResourceAsStream("/fasterwhispermodels.json")) {
+            knownModels = new ObjectMapper().readValue(is, FasterWhisperModelsResponse.class).getData().stream()
+                    .map(FasterWhisperModel::getId)
+                    .collect(Collectors.toSet());
+        }
+
+        for (LanguageModelRecommendation recommendation : LanguageModelRecommendations.load().values()) {
+            assertTrue(knownModels.contains(recommendation.getModel()), recommendation.getModel());
+        }
+    }
+}
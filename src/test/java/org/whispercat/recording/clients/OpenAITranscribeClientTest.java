@@ -0,0 +1,158 @@
+package org.whispercat.recording.clients;
+
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+import org.whispercat.recording.TranscriptionOutcome;
+
+import javax.sound.sampled.AudioFileFormat;
+import javax.sound.sampled.AudioFormat;
+import javax.sound.sampled.AudioInputStream;
+import javax.sound.sampled.AudioSystem;
+import java.io.ByteArrayInputStream;
+import java.io.File;
+import java.io.IOException;
+import java.io.RandomAccessFile;
+import java.nio.file.Path;
+import java.util.Arrays;
+import java.util.concurrent.TimeUnit;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+import static org.junit.jupiter.api.Assumptions.assumeTrue;
+
+class OpenAITranscribeClientTest {
+
+    @TempDir
+    Path tempDir;
+
+    @Test
+    void parsesTextLanguageAndSegments() throws Exception {
+        String response = "{\"task\":\"transcribe\",\"language\":\"german\",\"duration\":1.5,\"text\":\"Guten Morgen.\","
+                + "\"segments\":[{\"id\":0,\"start\":0.0,\"end\":1.5,\"text\":\" Guten Morgen.\","
+                + "\"avg_logprob\":-0.2,\"no_speech_prob\":0.01}]}";
+
+        TranscriptionOutcome outcome = OpenAITranscribeClient.parseResponse(response);
+
+        assertEquals("Guten Morgen.", outcome.getText());
+        assertEquals("german", outcome.getLanguage());
+        assertEquals("OpenAI", outcome.getProvider());
+        assertEquals("whisper-1", outcome.getModel());
+        assertEquals(1, outcome.getSegments().size());
+        assertEquals(1.5, outcome.getSegments().get(0).getEnd());
+    }
+
+    @Test
+    void plainJsonHasNoSegmentsOrLanguage() throws Exception {
+        TranscriptionOutcome outcome = OpenAITranscribeClient.parseResponse("{\"text\":\"Hello\"}");
+
+        assertEquals("Hello", outcome.getText());
+        assertEquals("", outcome.getLanguage());
+        assertTrue(outcome.getSegments().isEmpty());
+    }
+
+    @Test
+    void rejectsEmptyText() {
+        assertThrows(IOException.class, () -> OpenAITranscribeClient.parseResponse("{\"text\":\"\"}"));
+        assertThrows(IOException.class, () -> OpenAITranscribeClient.parseResponse("not json"));
+    }
+
+    @Test
+    void mp3CommandFollowsCompressionSettings() {
+        File input = new File("in.wav");
+        File output = new File("out.mp3");
+
+        assertEquals(Arrays.asList("ffmpeg", "-y", "-i", input.getAbsolutePath(), "-codec:a", "libmp3lame",
+                        "-q:a", "4", "-ac", "1", "-ar", "16000", output.getAbsolutePath()),
+                OpenAITranscribeClient.buildMp3Command(input, output, 4, 16000, true));
+        assertEquals(Arrays.asList("ffmpeg", "-y", "-i", input.getAbsolutePath(), "-codec:a", "libmp3lame",
+                        "-q:a", "2", "-ar", "44100", output.getAbsolutePath()),
+                OpenAITranscribeClient.buildMp3Command(input, output, 2, 44100, false));
+    }
+
+    @Test
+    void ffmpegAcceptsGeneratedMp3Command() throws Exception {
+        assumeTrue(ffmpegAvailable(), "ffmpeg is not installed");
+        File input = tempDir.resolve("tone.wav").toFile();
+        byte[] data = new byte[16000 * 4];
+        AudioSystem.write(new AudioInputStream(new ByteArrayInputStream(data),
+                new AudioFormat(16000f, 16, 2, true, false), 16000), AudioFileFormat.Type.WAVE, input);
+        File output = tempDir.resolve("tone.mp3").toFile();
+
+        Process process = new ProcessBuilder(OpenAITranscribeClient.buildMp3Command(input, output, 6, 22050, true))
+                .redirectErrorStream(true).start();
+        process.getInputStream().readAllBytes();
+
+        assertEquals(0, process.waitFor());
+        assertTrue(output.length() > 0);
+    }
+
+    @Test
+    void rejectsCompressedFileOverUploadLimit() throws Exception {
+        File file = sizedFile("too_large.mp3", OpenAITranscribeClient.MAX_FILE_SIZE + 1);
+
+        AudioFileTooLargeException e = assertThrows(AudioFileTooLargeException.class,
+                () -> OpenAITranscribeClient.checkUploadSize(file));
+
+        assertEquals(file.length(), e.getFileSize());
+        assertEquals(OpenAITranscribeClient.MAX_FILE_SIZE, e.getMaxFileSize());
+        assertTrue(e.getMessage().contains("Split the recording"), e.getMessage());
+    }
+
+    @Test
+    void acceptsCompressedFileAtUploadLimit() throws Exception {
+        OpenAITranscribeClient.checkUploadSize(sizedFile("fits.mp3", OpenAITranscribeClient.MAX_FILE_SIZE));
+    }
+
+    @Test
+    void oversizedCompressedFileIsDeletedBeforeTheErrorPropagates() throws Exception {
+        File compressed = sizedFile("whispercat_compressed_1.mp3", OpenAITranscribeClient.MAX_FILE_SIZE + 1);
+
+        assertThrows(AudioFileTooLargeException.class,
+                () -> OpenAITranscribeClient.verifyUploadSize(TempFile.owned(compressed)));
+
+        assertFalse(compressed.exists());
+    }
+
+    @Test
+    void oversizedOriginalIsKept() throws Exception {
+        File original = sizedFile("record_1.wav", OpenAITranscribeClient.MAX_FILE_SIZE + 1);
+
+        assertThrows(AudioFileTooLargeException.class,
+                () -> OpenAITranscribeClient.verifyUploadSize(TempFile.borrowed(original)));
+
+        assertTrue(original.exists());
+    }
+
+    @Test
+    void fittingCompressedFileIsKeptForUpload() throws Exception {
+        File compressed = sizedFile("whispercat_compressed_2.mp3", 1024);
+
+        try (TempFile upload = OpenAITranscribeClient.verifyUploadSize(TempFile.owned(compressed))) {
+            assertTrue(upload.getFile().exists());
+        }
+        assertFalse(compressed.exists());
+    }
+
+    private static boolean ffmpegAvailable() {
+        try {
+            Process process = new ProcessBuilder("ffmpeg", "-version").redirectErrorStream(true).start();
+            process.getInputStream().readAllBytes();
+            return process.waitFor(10, TimeUnit.SECONDS) && process.exitValue() == 0;
+        } catch (IOException e) {
+            return false;
+        } catch (InterruptedException e) {
+            Thread.currentThread().interrupt();
+            return false;
+        }
+    }
+
+    private File sizedFile(String name, long length) throws IOException {
+        File file = tempDir.resolve(name).toFile();
+        try (RandomAccessFile raf = new RandomAccessFile(file, "rw")) {
+            raf.setLength(length);
+        }
+        return file;
+    }
+}
@@ -0,0 +1,66 @@
+package org.whispercat.recording.clients;
+
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+
+import java.io.File;
+import java.io.IOException;
+import java.nio.file.Files;
+import java.nio.file.Path;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class TempFileTest {
+
+    @TempDir
+    Path tempDir;
+
+    @Test
+    void ownedFileIsDeletedOnClose() throws IOException {
+        File file = file("compressed.mp3");
+
+        try (TempFile guard = TempFile.owned(file)) {
+            assertEquals(file, guard.getFile());
+            assertTrue(file.exists());
+        }
+
+        assertFalse(file.exists());
+    }
+
+    @Test
+    void ownedFileIsDeletedWhenAnErrorEndsTheBlock() throws IOException {
+        File file = file("compressed.mp3");
+
+        assertThrows(IOException.class, () -> {
+            try (TempFile ignored = TempFile.owned(file)) {
+                throw new IOException("upload failed");
+            }
+        });
+
+        assertFalse(file.exists());
+    }
+
+    @Test
+    void borrowedFileIsKept() throws IOException {
+        File file = file("recording.wav");
+
+        try (TempFile ignored = TempFile.borrowed(file)) {
+            assertTrue(file.exists());
+        }
+
+        assertTrue(file.exists());
+    }
+
+    @Test
+    void missingFilesAreIgnored() {
+        TempFile.owned(null).close();
+        TempFile.owned(tempDir.resolve("gone.mp3").toFile()).close();
+    }
+
+    private File file(String name) throws IOException {
+        return Files.write(tempDir.resolve(name), new byte[]{1, 2, 3}).toFile();
+    }
+}
@@ -0,0 +1,90 @@
+package org.whispercat.recording.clients;
+
+import org.junit.jupiter.api.Test;
+
+import java.util.Collections;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class TranscriptionConfidenceTest {
+
+    private static final String VERBOSE_JSON = "{"
+            + "\"task\":\"transcribe\",\"language\":\"english\",\"duration\":4.0,\"text\":\"Hello there. Thanks.\","
+            + "\"segments\":["
+            + "{\"id\":0,\"seek\":0,\"start\":0.0,\"end\":3.0,\"text\":\" Hello there.\","
+            + "\"tokens\":[50364,2425],\"temperature\":0.0,\"avg_logprob\":-0.1,"
+            + "\"compression_ratio\":0.8,\"no_speech_prob\":0.05},"
+            + "{\"id\":1,\"seek\":0,\"start\":3.0,\"end\":4.0,\"text\":\" Thanks.\","
+            + "\"tokens\":[50514],\"temperature\":0.0,\"avg_logprob\":-1.5,"
+            + "\"compression_ratio\":0.5,\"no_speech_prob\":0.9}"
+            + "]}";
+
+    @Test
+    void parsesSegmentFields() throws Exception {
+        List<TranscriptionSegment> segments = parse(VERBOSE_JSON);
+
+        assertEquals(2, segments.size());
+        TranscriptionSegment first = segments.get(0);
+        assertEquals(0.0, first.getStart());
+        assertEquals(3.0, first.getEnd());
+        assertEquals(" Hello there.", first.getText());
+        assertEquals(-0.1, first.getAvgLogprob());
+        assertEquals(0.05, first.getNoSpeechProb());
+    }
+
+    @Test
+    void weightsSegmentsByDuration() throws Exception {
+        List<TranscriptionSegment> segments = parse(VERBOSE_JSON);
+
+        double first = Math.exp(-0.1) * 0.95;
+        double second = Math.exp(-1.5) * 0.1;
+        double expected = (first * 3.0 + second * 1.0) / 4.0;
+        double confidence = TranscriptionConfidence.compute(segments);
+
+        assertEquals(expected, confidence, 1e-9);
+        assertFalse(TranscriptionConfidence.isLow(confidence));
+    }
+
+    @Test
+    void flagsSilenceHallucination() throws Exception {
+        String json = "{\"text\":\"Thank you.\",\"segments\":[{\"start\":0.0,\"end\":2.0,"
+                + "\"text\":\" Thank you.\",\"avg_logprob\":-0.9,\"no_speech_prob\":0.8}]}";
+
+        double confidence = TranscriptionConfidence.compute(parse(json));
+
+        assertTrue(TranscriptionConfidence.isLow(confidence));
+    }
+
+    @Test
+    void missingSegmentsAreUnknown() throws Exception {
+        assertTrue(parse("{\"text\":\"Hello\"}").isEmpty());
+        assertEquals(TranscriptionConfidence.UNKNOWN, TranscriptionConfidence.compute(Collections.emptyList()));
+        assertEquals(TranscriptionConfidence.UNKNOWN, TranscriptionConfidence.compute(null));
+        assertFalse(TranscriptionConfidence.isLow(TranscriptionConfidence.UNKNOWN));
+    }
+
+    @Test
+    void belowThresholdSkipsAutoPaste() {
+        assertFalse(TranscriptionConfidence.allowsAutoPaste(0.4, 0.6));
+        assertFalse(TranscriptionConfidence.allowsAutoPaste(0.59, 0.6));
+    }
+
+    @Test
+    void atOrAboveThresholdAutoPastes() {
+        assertTrue(TranscriptionConfidence.allowsAutoPaste(0.6, 0.6));
+        assertTrue(TranscriptionConfidence.allowsAutoPaste(0.95, 0.6));
+    }
+
+    @Test
+    void unknownConfidenceOrDisabledGateAlwaysPastes() {
+        assertTrue(TranscriptionConfidence.allowsAutoPaste(TranscriptionConfidence.UNKNOWN, 0.9));
+        assertTrue(TranscriptionConfidence.allowsAutoPaste(0.1, 0.0));
+    }
+
+    private List<TranscriptionSegment> parse(String json) throws Exception {
+        return TranscriptionProviders.parseSegments(json);
+    }
+}
@@ -0,0 +1,298 @@
+package org.whispercat.recording.clients;
+
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+import org.whispercat.ConfigManager;
+
+import java.io.File;
+import java.io.IOException;
+import java.io.InputStream;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Path;
+import java.util.Arrays;
+import java.util.LinkedHashMap;
+import java.util.List;
+import java.util.Map;
+import java.util.stream.Collectors;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertNotNull;
+import static org.junit.jupiter.api.Assertions.assertNull;
+import static org.junit.jupiter.api.Assertions.assertSame;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class TranscriptionProvidersTest {
+
+    @TempDir
+    Path tempDir;
+
+    private ConfigManager configManager;
+    private File audioFile;
+
+    @BeforeEach
+    void setUp() {
+        configManager = new ConfigManager(tempDir.resolve("config.properties").toFile());
+        audioFile = tempDir.resolve("recording.wav").toFile();
+    }
+
+    @Test
+    void registersBuiltInProvidersInOrder() {
+        List<String> names = TranscriptionProviders.all().stream()
+                .map(TranscriptionProviderSpec::getName)
+                .collect(Collectors.toList());
+
+        assertEquals(Arrays.asList("OpenAI", "Faster-Whisper", "Open WebUI", "whisper.cpp"), names.subList(0, 4));
+        assertNull(TranscriptionProviders.get("Unknown"));
+    }
+
+    @Test
+    void buildsOpenAIRequest() throws IOException {
+        configManager.setProperty("apiKey", "sk-test");
+
+        TranscriptionRequest request = TranscriptionProviders.get(TranscriptionProviders.OPENAI)
+                .buildRequest(configManager, audioFile, "previous words");
+
+        assertEquals("https://api.openai.com/v1/audio/transcriptions", request.getUrl());
+        assertEquals("Bearer sk-test", request.getHeaders().get("Authorization"));
+        Map<String, String> expected = new LinkedHashMap<>();
+        expected.put("model", "whisper-1");
+        expected.put("response_format", "verbose_json");
+        expected.put("prompt", "previous words");
+        assertEquals(expected, request.getFields());
+        assertSame(audioFile, request.getAudioFile());
+    }
+
+    @Test
+    void openAIRequestUsesCustomBaseUrl() throws IOException {
+        configManager.setOpenAIBaseUrl(" https://gateway.example.com/openai/v1// ");
+
+        TranscriptionRequest request = TranscriptionProviders.get(TranscriptionProviders.OPENAI)
+                .buildRequest(configManager, audioFile, null);
+
+        assertEquals("https://gateway.example.com/openai/v1/audio/transcriptions", request.getUrl());
+    }
+
+    @Test
+    void openAITranslationUsesTranslationsEndpoint() throws IOException {
+        configManager.setTranscriptionLanguage("de");
+        configManager.setTranslateToEnglish(true);
+
+        TranscriptionRequest request = TranscriptionProviders.get(TranscriptionProviders.OPENAI)
+                .buildRequest(configManager, audioFile, "previous words");
+
+        assertEquals("https://api.openai.com/v1/audio/translations", request.getUrl());
+        Map<String, String> expected = new LinkedHashMap<>();
+        expected.put("model", "whisper-1");
+        expected.put("response_format", "verbose_json");
+        expected.put("prompt", "previous words");
+        assertEquals(expected, request.getFields());
+    }
+
+    @Test
+    void onlyOpenAISupportsTranslation() {
+        for (TranscriptionProviderSpec spec : TranscriptionProviders.all()) {
+            assertEquals(spec.getName().equals(TranscriptionProviders.OPENAI), spec.supportsTranslation(), spec.getName());
+        }
+    }
+
+    @Test
+    void buildsFasterWhisperRequest() throws IOException {
+        configManager.setProperty("fasterWhisperServerUrl", "localhost:8000/");
+        configManager.setProperty("fasterWhisperModel", "Systran/faster-whisper-small");
+        configManager.setProperty("fasterWhisperLanguage", "de");
+
+        TranscriptionRequest request = TranscriptionProviders.get(TranscriptionProviders.FASTER_WHISPER)
+                .buildRequest(configManager, audioFile, null);
+
+        assertEquals("http://localhost:8000/v1/audio/transcriptions", request.getUrl());
+        assertFalse(request.getHeaders().containsKey("Authorization"));
+        Map<String, String> expected = new LinkedHashMap<>();
+        expected.put("model", "Systran/faster-whisper-small");
+        expected.put("response_format", "verbose_json");
+        expected.put("language", "de");
+        assertEquals(expected, request.getFields());
+    }
+
+    @Test
+    void recordingScreenLanguageAndTemperatureAreSent() throws IOException {
+        configManager.setProperty("fasterWhisperLanguage", "en");
+        configManager.setTranscriptionLanguage(" DE ");
+        configManager.setTranscriptionTemperature(0.4f);
+
+        Map<String, String> openAIFields = TranscriptionProviders.get(TranscriptionProviders.OPENAI)
+                .buildRequest(configManager, audioFile, null).getFields();
+        Map<String, String> fasterWhisperFields = TranscriptionProviders.get(TranscriptionProviders.FASTER_WHISPER)
+                .buildRequest(configManager, audioFile, null).getFields();
+
+        assertEquals("de", openAIFields.get("language"));
+        assertEquals("0.4", openAIFields.get("temperature"));
+        // Overrides the language chosen with the model in the settings
+        assertEquals("de", fasterWhisperFields.get("language"));
+        assertEquals("0.4", fasterWhisperFields.get("temperature"));
+    }
+
+    @Test
+    void zeroTemperatureIsNotSent() throws IOException {
+        configManager.setTranscriptionTemperature(-1.0f);
+
+        assertEquals(0.0f, configManager.getTranscriptionTemperature());
+        assertFalse(TranscriptionProviders.get(TranscriptionProviders.OPENAI)
+                .buildRequest(configManager, audioFile, null).getFields().containsKey("temperature"));
+    }
+
+    @Test
+    void buildsWhisperCppRequest() throws IOException {
+        configManager.setProperty("whisperCppServerUrl", "localhost:8080/");
+
+        TranscriptionRequest request = TranscriptionProviders.get(TranscriptionProviders.WHISPER_CPP)
+                .buildRequest(configManager, audioFile, "previous words");
+
+        assertEquals("http://localhost:8080/inference", request.getUrl());
+        assertNull(request.getHeaders().get("Authorization"));
+        Map<String, String> expected = new LinkedHashMap<>();
+        expected.put("response_format", "json");
+        expected.put("prompt", "previous words");
+        assertEquals(expected, request.getFields());
+    }
+
+    @Test
+    void buildsOpenWebUIRequest() throws IOException {
+        configManager.setOpenWebUIServerUrl("webui.local");
+        configManager.setOpenWebUIApiKey("webui-key");
+
+        TranscriptionProviderSpec spec = TranscriptionProviders.get(TranscriptionProviders.OPEN_WEBUI);
+        TranscriptionRequest request = spec.buildRequest(configManager, audioFile, "ignored prompt");
+
+        assertEquals("https://webui.local/api/v1/audio/transcriptions", request.getUrl());
+        assertEquals("Bearer webui-key", request.getHeaders().get("Authorization"));
+        assertTrue(request.getFields().isEmpty());
+        // Certificates are verified by the HTTP client's defaults unless configured otherwise
+        assertNull(request.getSocketFactory());
+    }
+
+    @Test
+    void openWebUIRequestAcceptsInvalidCertsOnlyWhenEnabled() throws IOException {
+        configManager.setOpenWebUIServerUrl("webui.local");
+        configManager.setOpenWebUIAcceptInvalidCerts(true);
+
+        TranscriptionRequest request = TranscriptionProviders.get(TranscriptionProviders.OPEN_WEBUI)
+                .buildRequest(configManager, audioFile, null);
+
+        assertNotNull(request.getSocketFactory());
+    }
+
+    @Test
+    void openWebUIRequestTrustsConfiguredCaCertificate() throws Exception {
+        File caCert = new File(getClass().getResource("/org/whispercat/openwebui-test-ca.pem").toURI());
+        configManager.setOpenWebUIServerUrl("webui.local");
+        configManager.setOpenWebUICaCertPath(caCert.getAbsolutePath());
+
+        TranscriptionRequest request = TranscriptionProviders.get(TranscriptionProviders.OPEN_WEBUI)
+                .buildRequest(configManager, audioFile, null);
+
+        assertNotNull(request.getSocketFactory());
+    }
+
+    @Test
+    void otherProvidersUseDefaultVerification() throws IOException {
+        assertNull(TranscriptionProviders.get(TranscriptionProviders.OPENAI)
+                .buildRequest(configManager, audioFile, null).getSocketFactory());
+    }
+
+    @Test
+    void appendsConfiguredExtraFormFields() throws IOException {
+        configManager.setProperty("fasterWhisperModel", "Systran/faster-whisper-small");
+        Map<String, String> extra = new LinkedHashMap<>();
+        extra.put("vad_filter", "true");
+        extra.put("beam_size", "5");
+        extra.put("model", "ignored");
+        configManager.setExtraFormFields(TranscriptionProviders.FASTER_WHISPER, extra);
+
+        TranscriptionRequest request = TranscriptionProviders.get(TranscriptionProviders.FASTER_WHISPER)
+                .buildRequest(configManager, audioFile, null);
+
+        Map<String, String> expected = new LinkedHashMap<>();
+        expected.put("model", "Systran/faster-whisper-small");
+        expected.put("response_format", "verbose_json");
+        expected.put("vad_filter", "true");
+        expected.put("beam_size", "5");
+        assertEquals(expected, request.getFields());
+        // Fields are configured per provider
+        assertTrue(TranscriptionProviders.get(TranscriptionProviders.OPEN_WEBUI)
+                .buildRequest(configManager, audioFile, null).getFields().isEmpty());
+    }
+
+    @Test
+    void keepsExplicitSchemeAndStripsTrailingSlashes() {
+        configManager.setProperty("fasterWhisperServerUrl", " https://whisper.example.com// ");
+
+        assertEquals("https://whisper.example.com",
+                TranscriptionProviders.get(TranscriptionProviders.FASTER_WHISPER).resolveBaseUrl(configManager));
+    }
+
+    @Test
+    void parsesTextFromObjectOrString() throws IOException {
+        assertEquals("hello", TranscriptionProviders.parseText("{\"text\":\"hello\"}"));
+        assertEquals("hello", TranscriptionProviders.parseText("\"hello\""));
+        assertEquals("", TranscriptionProviders.parseText("{}"));
+    }
+
+    @Test
+    void parsesPlainTextBody() throws IOException {
+        assertEquals("And so my fellow Americans", TranscriptionProviders.parseText(" And so my fellow Americans\n"));
+        assertEquals("", TranscriptionProviders.parseText(""));
+    }
+
+    @Test
+    void parsesVerboseJsonSegments() {
+        List<TranscriptionSegment> segments = TranscriptionProviders.parseSegments("{"
+                + "\"task\":\"transcribe\",\"language\":\"english\",\"duration\":5.5,"
+                + "\"text\":\"Welcome back. Let's begin.\",\"segments\":["
+                + "{\"id\":0,\"seek\":0,\"start\":0.0,\"end\":2.48,\"text\":\" Welcome back.\",\"tokens\":[50364,5768,646]},"
+                + "{\"id\":1,\"seek\":0,\"start\":2.48,\"end\":5.5,\"text\":\" Let's begin.\",\"tokens\":[50488,961]}]}");
+
+        assertEquals(2, segments.size());
+        assertEquals(0.0, segments.get(0).getStart());
+        assertEquals(2.48, segments.get(0).getEnd());
+        assertEquals(" Welcome back.", segments.get(0).getText());
+        assertEquals(5.5, segments.get(1).getEnd());
+    }
+
+    @Test
+    void parsesWhisperCppServerSegments() throws IOException {
+        String body;
+        try (InputStream in = getClass().getResourceAsStream("whispercpp-verbose.json")) {
+            body = new String(in.readAllBytes(), StandardCharsets.UTF_8);
+        }
+
+        List<TranscriptionSegment> segments = TranscriptionProviders.parseSegments(body);
+
+        assertEquals(2, segments.size());
+        assertEquals(0.32, segments.get(0).getStart());
+        assertEquals(7.6, segments.get(0).getEnd());
+        assertEquals(" And so my fellow Americans, ask not what your country can do for you,", segments.get(0).getText());
+        assertEquals(-0.2375, segments.get(0).getAvgLogprob());
+        assertEquals(7.6, segments.get(1).getStart());
+        assertEquals(10.6, segments.get(1).getEnd());
+        assertEquals(" And so my fellow Americans, ask not what your country can do for you, ask what you can do for your country.\n",
+                TranscriptionProviders.parseText(body));
+    }
+
+    @Test
+    void responsesWithoutSegmentsParseToEmptyList() {
+        assertTrue(TranscriptionProviders.parseSegments("{\"text\":\"hello\"}").isEmpty());
+        assertTrue(TranscriptionProviders.parseSegments("\"hello\"").isEmpty());
+        assertTrue(TranscriptionProviders.parseSegments("not json").isEmpty());
+        assertTrue(TranscriptionProviders.parseSegments("[1, 2]").isEmpty());
+        assertTrue(TranscriptionProviders.parseSegments(null).isEmpty());
+    }
+
+    @Test
+    void detectsUploadContentType() {
+        assertEquals("audio/mpeg", TranscriptionRequest.contentTypeFor(new File("a.MP3")));
+        assertEquals("audio/flac", TranscriptionRequest.contentTypeFor(new File("a.flac")));
+        assertEquals("audio/wav", TranscriptionRequest.contentTypeFor(new File("a.wav")));
+    }
+}
@@ -0,0 +1,104 @@
+package org.whispercat.recording.clients;
+
+import com.sun.net.httpserver.HttpServer;
+import org.junit.jupiter.api.AfterEach;
+import org.junit.jupiter.api.BeforeEach;
+import org.junit.jupiter.api.Test;
+import org.junit.jupiter.api.io.TempDir;
+import org.whispercat.ConfigManager;
+import org.whispercat.ProviderError;
+
+import java.io.File;
+import java.io.IOException;
+import java.io.OutputStream;
+import java.net.InetAddress;
+import java.net.InetSocketAddress;
+import java.net.SocketTimeoutException;
+import java.nio.charset.StandardCharsets;
+import java.nio.file.Files;
+import java.nio.file.Path;
+import java.util.concurrent.CountDownLatch;
+import java.util.concurrent.TimeUnit;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class TranscriptionRequestTest {
+
+    @TempDir
+    Path tempDir;
+
+    private ConfigManager configManager;
+    private File audioFile;
+    private HttpServer server;
+    private final CountDownLatch releaseSlowServer = new CountDownLatch(1);
+
+    @BeforeEach
+    void setUp() throws IOException {
+        configManager = new ConfigManager(tempDir.resolve("config.properties").toFile());
+        audioFile = tempDir.resolve("recording.wav").toFile();
+        Files.write(audioFile.toPath(), new byte[]{1, 2, 3});
+
+        server = HttpServer.create(new InetSocketAddress(InetAddress.getLoopbackAddress(), 0), 0);
+        server.createContext("/slow/v1/audio/transcriptions", exchange -> {
+            try {
+                // Answers only after the client has given up
+                releaseSlowServer.await(10, TimeUnit.SECONDS);
+                exchange.sendResponseHeaders(200, -1);
+            } catch (InterruptedException e) {
+                Thread.currentThread().interrupt();
+            } finally {
+                exchange.close();
+            }
+        });
+        server.createContext("/fast/v1/audio/transcriptions", exchange -> {
+            byte[] body = "{\"text\":\"hello\"}".getBytes(StandardCharsets.UTF_8);
+            exchange.getRequestBody().readAllBytes();
+            exchange.sendResponseHeaders(200, body.length);
+            try (OutputStream out = exchange.getResponseBody()) {
+                out.write(body);
+            }
+        });
+        server.start();
+    }
+
+    @AfterEach
+    void tearDown() {
+        releaseSlowServer.countDown();
+        server.stop(0);
+    }
+
+    @Test
+    void slowServerTimesOutWithClearMessage() throws IOException {
+        configManager.setTranscriptionTimeoutSeconds(1);
+        TranscriptionRequest request = fasterWhisperRequest("slow");
+        assertEquals(1, request.getTimeoutSeconds());
+
+        SocketTimeoutException e = assertThrows(SocketTimeoutException.class, request::execute);
+
+        assertTrue(e.getMessage().startsWith("Faster-Whisper did not answer within 1 s."), e.getMessage());
+        assertEquals(ProviderError.TIMEOUT, ProviderError.from(e));
+    }
+
+    @Test
+    void answerWithinTimeoutIsReturned() throws IOException {
+        configManager.setTranscriptionTimeoutSeconds(5);
+
+        assertEquals("{\"text\":\"hello\"}", fasterWhisperRequest("fast").execute());
+    }
+
+    @Test
+    void timeoutDefaultsToTwoMinutes() {
+        assertEquals(120, configManager.getTranscriptionTimeoutSeconds());
+        configManager.setTranscriptionTimeoutSeconds(-3);
+        assertEquals(0, configManager.getTranscriptionTimeoutSeconds());
+    }
+
+    private TranscriptionRequest fasterWhisperRequest(String path) throws IOException {
+        configManager.setProperty("fasterWhisperServerUrl",
+                "http://127.0.0.1:" + server.getAddress().getPort() + "/" + path);
+        return TranscriptionProviders.get(TranscriptionProviders.FASTER_WHISPER)
+                .buildRequest(configManager, audioFile, null);
+    }
+}
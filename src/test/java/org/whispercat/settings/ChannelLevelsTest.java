@@ -0,0 +1,99 @@
+package org.whispercat.settings;
+
+import org.junit.jupiter.api.Test;
+
+import javax.sound.sampled.AudioFormat;
+
+import static org.junit.jupiter.api.Assertions.assertArrayEquals;
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+class ChannelLevelsTest {
+
+    private static final double DELTA = 1e-9;
+
+    @Test
+    void separatesInterleavedStereoChannels() {
+        // Left alternates +/-16384 (RMS 0.5), right is silent: a dead right channel
+        byte[] data = littleEndian(16384, 0, -16384, 0, 16384, 0, -16384, 0);
+
+        assertArrayEquals(new double[]{0.5, 0.0}, ChannelLevels.rmsPerChannel(data, data.length, 2, false), DELTA);
+    }
+
+    @Test
+    void computesRmsOfEachChannel() {
+        // Left: 8192 and 0 -> sqrt(8192^2 / 2); right constant -32768 -> full scale
+        byte[] data = littleEndian(8192, -32768, 0, -32768);
+
+        double[] levels = ChannelLevels.rmsPerChannel(data, data.length, 2, false);
+
+        assertEquals(Math.sqrt(8192.0 * 8192.0 / 2) / 32768.0, levels[0], DELTA);
+        assertEquals(1.0, levels[1], DELTA);
+    }
+
+    @Test
+    void readsBigEndianSamples() {
+        byte[] data = {0x40, 0x00, 0x00, 0x00}; // left 16384, right 0
+        AudioFormat format = new AudioFormat(44100f, 16, 2, true, true);
+
+        assertArrayEquals(new double[]{0.5, 0.0}, ChannelLevels.rmsPerChannel(data, data.length, format), DELTA);
+    }
+
+    @Test
+    void ignoresIncompleteTrailingFrame() {
+        byte[] data = littleEndian(16384, 16384, 32767);
+
+        assertArrayEquals(new double[]{0.5, 0.5}, ChannelLevels.rmsPerChannel(data, data.length, 2, false), DELTA);
+    }
+
+    @Test
+    void emptyBufferIsSilent() {
+        assertArrayEquals(new double[]{0.0, 0.0}, ChannelLevels.rmsPerChannel(new byte[0], 0, 2, false), DELTA);
+    }
+
+    @Test
+    void rejectsNonPositiveChannelCount() {
+        assertThrows(IllegalArgumentException.class, () -> ChannelLevels.rmsPerChannel(new byte[4], 4, 0, false));
+    }
+
+    @Test
+    void convertsLinearLevelToDecibels() {
+        assertEquals(0.0, ChannelLevels.toDecibels(1.0), DELTA);
+        assertEquals(-6.0206, ChannelLevels.toDecibels(0.5), 1e-4);
+        assertEquals(-20.0, ChannelLevels.toDecibels(0.1), DELTA);
+        assertEquals(-60.0, ChannelLevels.toDecibels(0.001), DELTA);
+    }
+
+    @Test
+    void silenceIsClampedToMinimumDecibels() {
+        assertEquals(ChannelLevels.MIN_DECIBELS, ChannelLevels.toDecibels(0.0), DELTA);
+        assertEquals(ChannelLevels.MIN_DECIBELS, ChannelLevels.toDecibels(-0.5), DELTA);
+        assertEquals(ChannelLevels.MIN_DECIBELS, ChannelLevels.toDecibels(1e-9), DELTA);
+    }
+
+    @Test
+    void detectsClippingAtThreshold() {
+        // 0.999 of full scale is 32734.2, so 32735 is the first clipped value
+        assertTrue(ChannelLevels.isClipping(littleEndian(0, 32735), 4, false));
+        assertTrue(ChannelLevels.isClipping(littleEndian(-32768), 2, false));
+        assertFalse(ChannelLevels.isClipping(littleEndian(0, 32734, -32734), 6, false));
+    }
+
+    @Test
+    void clipDetectionReadsBigEndianAndIgnoresBytesBeyondLength() {
+        assertTrue(ChannelLevels.isClipping(new byte[]{0x7F, (byte) 0xFF}, 2, true));
+        byte[] data = littleEndian(1000, 32767);
+        assertFalse(ChannelLevels.isClipping(data, 2, false));
+    }
+
+    private static byte[] littleEndian(int... samples) {
+        byte[] data = new byte[samples.length * 2];
+        for (int i = 0; i < samples.length; i++) {
+            data[2 * i] = (byte) samples[i];
+            data[2 * i + 1] = (byte) (samples[i] >> 8);
+        }
+        return data;
+    }
+}
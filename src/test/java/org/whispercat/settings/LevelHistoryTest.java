@@ -0,0 +1,62 @@
+package org.whispercat.settings;
+
+import org.junit.jupiter.api.Test;
+
+import static org.junit.jupiter.api.Assertions.assertArrayEquals;
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+
+class LevelHistoryTest {
+
+    @Test
+    void rejectsNonPositiveCapacity() {
+        assertThrows(IllegalArgumentException.class, () -> new LevelHistory(0));
+        assertThrows(IllegalArgumentException.class, () -> new LevelHistory(-1));
+    }
+
+    @Test
+    void keepsLevelsOldestFirstUntilFull() {
+        LevelHistory history = new LevelHistory(3);
+        history.push(10);
+        history.push(20);
+
+        assertEquals(2, history.size());
+        assertEquals(3, history.capacity());
+        assertArrayEquals(new int[]{10, 20}, history.toArray());
+    }
+
+    @Test
+    void overwritesOldestLevelWhenFull() {
+        LevelHistory history = new LevelHistory(3);
+        for (int level = 1; level <= 7; level++) {
+            history.push(level);
+        }
+
+        assertEquals(3, history.size());
+        assertArrayEquals(new int[]{5, 6, 7}, history.toArray());
+    }
+
+    @Test
+    void clampsLevels() {
+        LevelHistory history = new LevelHistory(2);
+        history.push(-5);
+        history.push(150);
+
+        assertArrayEquals(new int[]{0, 100}, history.toArray());
+    }
+
+    @Test
+    void clearEmptiesHistory() {
+        LevelHistory history = new LevelHistory(2);
+        history.push(30);
+        history.push(40);
+        history.push(50);
+        history.clear();
+
+        assertEquals(0, history.size());
+        assertArrayEquals(new int[0], history.toArray());
+
+        history.push(60);
+        assertArrayEquals(new int[]{60}, history.toArray());
+    }
+}
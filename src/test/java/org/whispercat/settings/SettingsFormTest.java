@@ -0,0 +1,48 @@
+package org.whispercat.settings;
+
+import org.junit.jupiter.api.Test;
+
+import javax.sound.sampled.TargetDataLine;
+import java.lang.reflect.Proxy;
+import java.util.ArrayList;
+import java.util.List;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+
+class SettingsFormTest {
+
+    @Test
+    void stoppingTheTestReleasesTheDevice() {
+        List<String> calls = new ArrayList<>();
+
+        SettingsForm.releaseLine(fakeLine(calls, false));
+
+        assertEquals(List.of("stop", "close"), calls);
+    }
+
+    @Test
+    void lineIsClosedEvenIfStoppingFails() {
+        List<String> calls = new ArrayList<>();
+
+        SettingsForm.releaseLine(fakeLine(calls, true));
+
+        assertEquals(List.of("stop", "close"), calls);
+    }
+
+    /**
+     * Creates a line that records stop and close calls.
+     */
+    private static TargetDataLine fakeLine(List<String> calls, boolean stopFails) {
+        return (TargetDataLine) Proxy.newProxyInstance(SettingsFormTest.class.getClassLoader(),
+                new Class<?>[]{TargetDataLine.class}, (proxy, method, args) -> {
+                    String name = method.getName();
+                    if (name.equals("stop") || name.equals("close")) {
+                        calls.add(name);
+                        if (name.equals("stop") && stopFails) {
+                            throw new IllegalStateException("Device removed");
+                        }
+                    }
+                    return null;
+                });
+    }
+}